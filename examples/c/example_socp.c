@@ -0,0 +1,51 @@
+// SOCP Example using the Clarabel C interface.
+//
+// Build the library with the C interface enabled, e.g.
+//
+//   cargo build --release --features c
+//   cc example_socp.c -I../../include -L../../target/release -lclarabel
+
+#include <stdio.h>
+#include "clarabel.h"
+
+int main(void)
+{
+    // P = [0 0; 0 2], upper triangular part only
+    size_t P_colptr[] = {0, 0, 1};
+    size_t P_rowval[] = {1};
+    double P_nzval[] = {2.};
+    ClarabelCscMatrix P = {2, 2, P_colptr, P_rowval, P_nzval};
+
+    double q[] = {0., 0.};
+
+    // A = [0 0; -2 0; 0 -1]
+    size_t A_colptr[] = {0, 1, 2};
+    size_t A_rowval[] = {1, 2};
+    double A_nzval[] = {-2., -1.};
+    ClarabelCscMatrix A = {3, 2, A_colptr, A_rowval, A_nzval};
+
+    double b[] = {1., -2., -2.};
+
+    ClarabelConeSpec cones[] = {
+        {ClarabelConeTag_SecondOrderConeT, 3, 0., NULL, 0},
+    };
+
+    ClarabelDefaultSettings settings = clarabel_default_settings();
+
+    ClarabelDefaultSolver *solver = clarabel_solver_new(&P, q, &A, b, 1, cones, &settings);
+    if (solver == NULL) {
+        return 1;
+    }
+
+    ClarabelSolverStatus status = clarabel_solve(solver);
+    if (status == ClarabelSolverStatus_InternalError) {
+        clarabel_solver_free(solver);
+        return 1;
+    }
+
+    const double *x = clarabel_solution_x(solver);
+    printf("Solution = [%f, %f]\n", x[0], x[1]);
+
+    clarabel_solver_free(solver);
+    return 0;
+}
@@ -0,0 +1,372 @@
+#ifndef CLARABEL_H
+#define CLARABEL_H
+
+/* This file is generated by cbindgen.  Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Termination modes for [`ClarabelDefaultSettings`].
+enum ClarabelTerminationMode
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  ClarabelTerminationMode_Standard = 0,
+  ClarabelTerminationMode_GapPriority = 1,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelTerminationMode ClarabelTerminationMode;
+#else
+typedef uint32_t ClarabelTerminationMode;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// Equilibration methods for [`ClarabelDefaultSettings`].
+enum ClarabelEquilibrationMethod
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  ClarabelEquilibrationMethod_Ruiz = 0,
+  ClarabelEquilibrationMethod_L2 = 1,
+  ClarabelEquilibrationMethod_Geometric = 2,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelEquilibrationMethod ClarabelEquilibrationMethod;
+#else
+typedef uint32_t ClarabelEquilibrationMethod;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// Initialization strategies for [`ClarabelDefaultSettings`].
+enum ClarabelInitStrategy
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  ClarabelInitStrategy_Default = 0,
+  ClarabelInitStrategy_EqualityProject = 1,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelInitStrategy ClarabelInitStrategy;
+#else
+typedef uint32_t ClarabelInitStrategy;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// LDL factorization methods for [`ClarabelDefaultSettings`].
+enum ClarabelDirectSolveMethod
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  ClarabelDirectSolveMethod_QDLDL = 0,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelDirectSolveMethod ClarabelDirectSolveMethod;
+#else
+typedef uint32_t ClarabelDirectSolveMethod;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// KKT system forms for [`ClarabelDefaultSettings`].
+enum ClarabelKKTForm
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  ClarabelKKTForm_Full = 0,
+  ClarabelKKTForm_Reduced = 1,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelKKTForm ClarabelKKTForm;
+#else
+typedef uint32_t ClarabelKKTForm;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// Stopping metrics for the indirect solver in [`ClarabelDefaultSettings`].
+enum ClarabelIndirectStopMetric
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  ClarabelIndirectStopMetric_Absolute = 0,
+  ClarabelIndirectStopMetric_Relative = 1,
+  ClarabelIndirectStopMetric_Preconditioned = 2,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelIndirectStopMetric ClarabelIndirectStopMetric;
+#else
+typedef uint32_t ClarabelIndirectStopMetric;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// Solver termination status.   Values are as in [`SolverStatus`], with
+// the addition of `InternalError` for a null handle or a solver panic.
+enum ClarabelSolverStatus
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  ClarabelSolverStatus_Unsolved = 0,
+  ClarabelSolverStatus_Solved,
+  ClarabelSolverStatus_PrimalInfeasible,
+  ClarabelSolverStatus_DualInfeasible,
+  ClarabelSolverStatus_AlmostSolved,
+  ClarabelSolverStatus_AlmostPrimalInfeasible,
+  ClarabelSolverStatus_AlmostDualInfeasible,
+  ClarabelSolverStatus_MaxIterations,
+  ClarabelSolverStatus_MaxTime,
+  ClarabelSolverStatus_NumericalError,
+  ClarabelSolverStatus_InsufficientProgress,
+  ClarabelSolverStatus_CallbackTerminated,
+  ClarabelSolverStatus_InsufficientMemory,
+  ClarabelSolverStatus_InternalError,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelSolverStatus ClarabelSolverStatus;
+#else
+typedef uint32_t ClarabelSolverStatus;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// Cone type tags used in [`ClarabelConeSpec`].
+enum ClarabelConeTag
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  // zero cone of dimension `dim`
+  ClarabelConeTag_ZeroConeT = 0,
+  // nonnegative orthant of dimension `dim`
+  ClarabelConeTag_NonnegativeConeT = 1,
+  // second order cone of dimension `dim`
+  ClarabelConeTag_SecondOrderConeT = 2,
+  // exponential cone
+  ClarabelConeTag_ExponentialConeT = 3,
+  // power cone with exponent `param`
+  ClarabelConeTag_PowerConeT = 4,
+  // generalized power cone with exponents `vec` and dual dimension `dim`
+  ClarabelConeTag_GenPowerConeT = 5,
+  // positive semidefinite cone of triangular dimension `dim`.
+  // Requires the `sdp` feature.
+  ClarabelConeTag_PSDTriangleConeT = 6,
+  // box cone with `dim` bounded elements, i.e. of dimension `dim + 1`,
+  // with `vec` holding the `dim` lower bounds followed by the `dim`
+  // upper bounds
+  ClarabelConeTag_BoxConeT = 7,
+  // complex Hermitian positive semidefinite cone of matrix
+  // dimension `dim`.  Requires the `sdp` feature.
+  ClarabelConeTag_PSDTriangleComplexConeT = 8,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ClarabelConeTag ClarabelConeTag;
+#else
+typedef uint32_t ClarabelConeTag;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+// Opaque solver handle for the C interface.
+typedef struct ClarabelDefaultSolver ClarabelDefaultSolver;
+
+// Solver settings.   Fields are as in [`DefaultSettings`].
+// Obtain a default set from `clarabel_default_settings`.
+typedef struct ClarabelDefaultSettings {
+  uint32_t max_iter;
+  double time_limit;
+  bool verbose;
+  uint32_t print_stride;
+  bool collect_timings;
+  bool time_limit_hard;
+  double max_step_fraction;
+  double tol_gap_abs;
+  double tol_gap_rel;
+  double tol_feas;
+  double tol_infeas_abs;
+  double tol_infeas_rel;
+  double tol_ktratio;
+  double reduced_tol_gap_abs;
+  double reduced_tol_gap_rel;
+  double reduced_tol_feas;
+  double reduced_tol_infeas_abs;
+  double reduced_tol_infeas_rel;
+  double reduced_tol_ktratio;
+  ClarabelTerminationMode termination_mode;
+  bool tol_normalize;
+  uint32_t insufficient_progress_window;
+  double insufficient_progress_tol;
+  bool equilibrate_enable;
+  uint32_t equilibrate_max_iter;
+  double equilibrate_min_scaling;
+  double equilibrate_max_scaling;
+  ClarabelEquilibrationMethod equilibration_method;
+  // Zero uses the objective scaling chosen during equilibration
+  double objective_scaling;
+  ClarabelInitStrategy init_strategy;
+  double linesearch_backtrack_step;
+  double min_switch_step_length;
+  double min_terminate_step_length;
+  uint32_t max_centrality_corrections;
+  bool direct_kkt_solver;
+  ClarabelDirectSolveMethod direct_solve_method;
+  ClarabelKKTForm kkt_form;
+  size_t dense_kkt_threshold;
+  ClarabelIndirectStopMetric indirect_stop_metric;
+  bool direct_solve_cache_symbolic;
+  // Zero places no limit on the size of the KKT matrix
+  size_t max_kkt_nnz;
+  // When false, random_seed is ignored and no seed is given
+  bool random_seed_enable;
+  uint64_t random_seed;
+  bool static_regularization_enable;
+  double static_regularization_constant;
+  double static_regularization_proportional;
+  bool dynamic_regularization_enable;
+  double dynamic_regularization_eps;
+  double dynamic_regularization_delta;
+  uint32_t factorization_max_retries;
+  double factorization_retry_factor;
+  bool iterative_refinement_enable;
+  double iterative_refinement_reltol;
+  double iterative_refinement_abstol;
+  uint32_t iterative_refinement_max_iter;
+  double iterative_refinement_stop_ratio;
+  bool presolve_enable;
+  bool crossover;
+} ClarabelDefaultSettings;
+
+// Sparse matrix in compressed sparse column format, with
+// 0-based indexing.  Arrays are borrowed and must remain
+// valid only for the duration of the call they are passed to.
+typedef struct ClarabelCscMatrix {
+  // number of rows
+  size_t m;
+  // number of columns
+  size_t n;
+  // column pointers, of length `n + 1`
+  const size_t *colptr;
+  // row indices, of length `colptr[n]`
+  const size_t *rowval;
+  // nonzero values, of length `colptr[n]`
+  const double *nzval;
+} ClarabelCscMatrix;
+
+// Description of a single cone.   The fields used depend on the tag.
+typedef struct ClarabelConeSpec {
+  // cone type, a value of [`ClarabelConeTag`]
+  uint32_t tag;
+  // cone dimension
+  size_t dim;
+  // scalar cone parameter
+  double param;
+  // vector parameter
+  const double *vec;
+  // length of `vec`
+  size_t vec_len;
+} ClarabelConeSpec;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Default solver settings.
+struct ClarabelDefaultSettings clarabel_default_settings(void);
+
+// Create a new solver for the problem data `(P,q,A,b,cones)`.  `P` should
+// be upper triangular, `q` has length `P.n`, `b` has length `A.m` and
+// `cones` is an array of `ncones` cone descriptions.   All data is copied.
+//
+// Returns null if the problem data is invalid.   The solver must be
+// released with `clarabel_solver_free`.
+//
+// # Safety
+//
+// All pointers must be valid for arrays of the stated dimensions.
+struct ClarabelDefaultSolver *clarabel_solver_new(const struct ClarabelCscMatrix *P,
+                                                  const double *q,
+                                                  const struct ClarabelCscMatrix *A,
+                                                  const double *b,
+                                                  size_t ncones,
+                                                  const struct ClarabelConeSpec *cones,
+                                                  const struct ClarabelDefaultSettings *settings);
+
+// Solve the problem, returning the termination status.
+//
+// Returns `InternalError` if `solver` is null or the solver panics, in
+// which case `clarabel_solution_status` also reports `InternalError`
+// until the next successful solve.
+//
+// # Safety
+//
+// `solver` must be null or a handle returned by `clarabel_solver_new`.
+ClarabelSolverStatus clarabel_solve(struct ClarabelDefaultSolver *solver);
+
+// Release a solver.   Passing null is allowed.
+//
+// # Safety
+//
+// `solver` must be a handle returned by `clarabel_solver_new`, and
+// must not be used afterwards.
+void clarabel_solver_free(struct ClarabelDefaultSolver *solver);
+
+// Primal solution `x`, of length `n`.   Returns null if `solver` is null.
+//
+// # Safety
+//
+// `solver` must be null or a handle returned by `clarabel_solver_new`.
+const double *clarabel_solution_x(const struct ClarabelDefaultSolver *solver);
+
+// Dual solution `z`, of length `m`.   Returns null if `solver` is null.
+//
+// # Safety
+//
+// `solver` must be null or a handle returned by `clarabel_solver_new`.
+const double *clarabel_solution_z(const struct ClarabelDefaultSolver *solver);
+
+// Primal slacks `s`, of length `m`.   Returns null if `solver` is null.
+//
+// # Safety
+//
+// `solver` must be null or a handle returned by `clarabel_solver_new`.
+const double *clarabel_solution_s(const struct ClarabelDefaultSolver *solver);
+
+// Termination status.   Returns `InternalError` if `solver` is null or
+// the last solve panicked.
+//
+// # Safety
+//
+// `solver` must be null or a handle returned by `clarabel_solver_new`.
+ClarabelSolverStatus clarabel_solution_status(const struct ClarabelDefaultSolver *solver);
+
+// Primal objective value.   Returns NaN if `solver` is null.
+//
+// # Safety
+//
+// `solver` must be null or a handle returned by `clarabel_solver_new`.
+double clarabel_solution_obj_val(const struct ClarabelDefaultSolver *solver);
+
+// Dual objective value.   Returns NaN if `solver` is null.
+//
+// # Safety
+//
+// `solver` must be null or a handle returned by `clarabel_solver_new`.
+double clarabel_solution_obj_val_dual(const struct ClarabelDefaultSolver *solver);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CLARABEL_H */
@@ -0,0 +1,56 @@
+import clarabel
+import numpy as np
+import pytest
+from scipy import sparse
+
+
+def test_as_csc_sums_duplicates():
+    # the (1,1) entry is given as 2 + 3
+    rows = np.array([1, 0, 1, 2])
+    cols = np.array([1, 0, 1, 0])
+    vals = np.array([2., 1., 3., -1.])
+    coo = sparse.coo_matrix((vals, (rows, cols)), shape=(3, 2))
+
+    csc = clarabel.as_csc(coo)
+    assert sparse.isspmatrix_csc(csc)
+    assert csc.nnz == 3
+    assert np.array_equal(csc.toarray(), [[1., 0.], [0., 5.], [-1., 0.]])
+
+
+@pytest.mark.parametrize("fmt", ["csr", "lil", "dok", "csc"])
+def test_as_csc_other_formats(fmt):
+    dense = np.array([[4., 0., 1.], [0., 2., 0.]])
+    csc = clarabel.as_csc(sparse.csc_matrix(dense).asformat(fmt))
+    assert np.array_equal(csc.toarray(), dense)
+
+
+def test_as_csc_bad_index():
+    # a COO matrix with an index past its shape, bypassing scipy's checks
+    coo = sparse.coo_matrix(np.eye(2))
+    coo.row = np.array([0, 2])
+    with pytest.raises(clarabel.DimensionMismatchError):
+        clarabel.as_csc(coo)
+
+
+def test_solver_coo_input():
+    # the same QP with P and A given as CSC, and as COO with split entries
+    P = sparse.csc_matrix([[4., 1.], [0., 2.]])
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix([[1., 1.], [1., 0.], [0., 1.]])
+    b = np.array([1., 0.7, 0.7])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(2)]
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+
+    Pcoo = sparse.coo_matrix(
+        ([2., 1., 2., 1.], ([0, 0, 0, 1], [0, 1, 0, 1])), shape=(2, 2))
+    Acoo = sparse.coo_matrix(
+        ([0.5, 0.5, 1., 1., 1.], ([0, 0, 0, 1, 2], [0, 0, 1, 0, 1])), shape=(3, 2))
+
+    assert np.array_equal(clarabel.as_csc(Pcoo).toarray(), P.toarray())
+    assert np.array_equal(clarabel.as_csc(Acoo).toarray(), A.toarray())
+
+    expected = clarabel.DefaultSolver(P, q, A, b, cones, settings).solve()
+    solution = clarabel.DefaultSolver(Pcoo, q, Acoo, b, cones, settings).solve()
+    assert solution.status == clarabel.SolverStatus.Solved
+    assert np.array_equal(solution.x, expected.x)
@@ -0,0 +1,31 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def test_cone_residuals():
+    P = sparse.csc_matrix((2, 2))
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(
+        [[-1., 0.],
+         [0., -1.],
+         [0., 0.],
+         [-1., 0.],
+         [0., -1.]])
+    b = np.array([0., 0., 1., 0., 0.])
+    cones = [clarabel.NonnegativeConeT(2), clarabel.SecondOrderConeT(3)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+    solution = solver.solve()
+    assert solution.status == clarabel.SolverStatus.Solved
+
+    residuals = solution.cone_residuals
+    assert [r[0] for r in residuals] == ["NonnegativeCone", "SecondOrderCone"]
+
+    # primal terms combine in the 2-norm to the overall primal residual
+    r_prim = np.sqrt(sum(r[1] ** 2 for r in residuals))
+    assert np.isclose(r_prim, solution.r_prim, rtol=1e-8, atol=1e-14)
+
+    assert all(np.isfinite(r[2]) for r in residuals)
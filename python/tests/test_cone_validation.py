@@ -0,0 +1,33 @@
+import clarabel
+import numpy as np
+import pytest
+from scipy import sparse
+
+
+def _solver(cones, m):
+    P = sparse.csc_matrix((2, 2))
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(np.ones((m, 2)))
+    b = np.ones(m)
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    return clarabel.DefaultSolver(P, q, A, b, cones, settings)
+
+
+def test_valid_cones():
+    _solver([clarabel.NonnegativeConeT(2), clarabel.SecondOrderConeT(3)], 5)
+
+
+def test_bad_soc_dimension():
+    with pytest.raises(ValueError, match="SecondOrderConeT"):
+        _solver([clarabel.NonnegativeConeT(2), clarabel.SecondOrderConeT(0)], 2)
+
+
+def test_bad_genpow_sum():
+    with pytest.raises(ValueError, match="sum to 1"):
+        _solver([clarabel.GenPowerConeT([0.5, 0.6], 1)], 3)
+
+
+def test_bad_total_dimension():
+    with pytest.raises(ValueError, match="total dimension"):
+        _solver([clarabel.NonnegativeConeT(2)], 3)
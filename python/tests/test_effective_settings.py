@@ -0,0 +1,25 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def test_effective_settings():
+    P = sparse.identity(2, format="csc")
+    q = np.array([1.0, -1.0])
+    A = sparse.identity(2, format="csc")
+    b = np.array([1.0, 1.0])
+    cones = [clarabel.NonnegativeConeT(2)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.tol_gap_abs = -1e-8
+    settings.max_step_fraction = 1.5
+
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+    effective = solver.settings
+
+    assert isinstance(effective, clarabel.DefaultSettings)
+    assert effective.tol_gap_abs != settings.tol_gap_abs
+    assert effective.tol_gap_abs == np.finfo(float).eps
+    assert effective.max_step_fraction == 1.0
+    assert effective.tol_gap_rel == settings.tol_gap_rel
@@ -0,0 +1,34 @@
+import clarabel
+import numpy as np
+import pytest
+from scipy import sparse
+
+
+def _qp_data():
+    P = sparse.triu(sparse.csc_matrix([[6., 0.], [0., 4.]])).tocsc()
+    q = np.array([-1., -4.])
+    A = sparse.csc_matrix(
+        [[1., -2.],
+         [1., 0.],
+         [0., 1.],
+         [-1., 0.],
+         [0., -1.]])
+    b = np.array([0., 1., 1., 1., 1.])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(4)]
+    return P, q, A, b, cones
+
+
+@pytest.mark.parametrize("method", ["ruiz", "l2", "geometric"])
+def test_equilibration_method(method):
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.equilibration_method = method
+    assert settings.equilibration_method == method
+
+    solver = clarabel.DefaultSolver(*_qp_data(), settings)
+    solution = solver.solve()
+    assert solution.status == clarabel.SolverStatus.Solved
+
+
+def test_equilibration_method_default():
+    assert clarabel.DefaultSettings().equilibration_method == "ruiz"
@@ -0,0 +1,34 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _solve(enable, max_iter=10):
+    P = sparse.csc_matrix(np.diag([1e4, 1e-2, 1e-3]))
+    q = np.array([1., -1e3, 1e-2])
+    A = sparse.csc_matrix(
+        [[1e3, 1e-3, 1.],
+         [-1e3, 0., 0.],
+         [0., -1e-3, 0.],
+         [0., 0., -1e2]])
+    b = np.array([1., 0., 0., 0.])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(3)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.equilibrate_enable = enable
+    settings.equilibrate_max_iter = max_iter
+    return clarabel.DefaultSolver(P, q, A, b, cones, settings).solve()
+
+
+def test_equilibration_iters():
+    solution = _solve(True)
+    assert 0 < solution.equilibration_iters <= 10
+
+    solution = _solve(True, max_iter=2)
+    assert solution.equilibration_iters == 2
+
+
+def test_equilibration_iters_disabled():
+    solution = _solve(False)
+    assert solution.equilibration_iters == 0
@@ -0,0 +1,97 @@
+import clarabel
+import numpy as np
+import pytest
+from scipy import sparse
+
+
+def _qp_data():
+    P = sparse.triu(sparse.csc_matrix([[4., 1.], [1., 2.]])).tocsc()
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(
+        [[1., 1.],
+         [1., 0.],
+         [0., 1.]])
+    b = np.array([1., 0.7, 0.7])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(2)]
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    return P, q, A, b, cones, settings
+
+
+def test_exception_hierarchy():
+    for exc in [clarabel.InvalidSettingsError,
+                clarabel.DimensionMismatchError,
+                clarabel.InvalidDataError,
+                clarabel.NumericalError]:
+        assert issubclass(exc, clarabel.ClarabelError)
+
+    # existing code catching ValueError still works
+    assert issubclass(clarabel.ClarabelError, ValueError)
+
+
+@pytest.mark.parametrize("cls", [clarabel.DefaultSolver, clarabel.DefaultSolverF32])
+def test_invalid_settings(cls):
+    P, q, A, b, cones, settings = _qp_data()
+    settings.direct_solve_method = "not_a_method"
+    with pytest.raises(clarabel.InvalidSettingsError, match="direct_solve_method"):
+        cls(P, q, A, b, cones, settings)
+
+    P, q, A, b, cones, settings = _qp_data()
+    settings.equilibration_method = "not_a_method"
+    with pytest.raises(clarabel.InvalidSettingsError, match="equilibration_method"):
+        cls(P, q, A, b, cones, settings)
+
+
+def test_invalid_settings_json():
+    with pytest.raises(clarabel.InvalidSettingsError):
+        clarabel.DefaultSettings.from_json("not json")
+
+
+@pytest.mark.parametrize("cls", [clarabel.DefaultSolver, clarabel.DefaultSolverF32])
+def test_dimension_mismatch(cls):
+    P, q, A, b, cones, settings = _qp_data()
+
+    with pytest.raises(clarabel.DimensionMismatchError, match="P must be"):
+        cls(P, np.ones(3), A, b, cones, settings)
+
+    with pytest.raises(clarabel.DimensionMismatchError, match="A must be"):
+        cls(P, q, A, np.ones(4), cones, settings)
+
+    with pytest.raises(clarabel.DimensionMismatchError, match="total dimension"):
+        cls(P, q, A, b, [clarabel.NonnegativeConeT(2)], settings)
+
+
+def test_dimension_mismatch_arrays():
+    P, q, A, b, cones, settings = _qp_data()
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+
+    with pytest.raises(clarabel.DimensionMismatchError):
+        solver.solve_into(np.zeros(3), np.zeros(3), np.zeros(3))
+
+    with pytest.raises(clarabel.DimensionMismatchError):
+        solver.solve_warm(np.zeros(3), np.zeros(3), np.zeros(3))
+
+
+def test_try_solve():
+    P, q, A, b, cones, settings = _qp_data()
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+    solution = solver.try_solve()
+    assert solution.status == clarabel.SolverStatus.Solved
+
+    # a KKT nonzero limit too small for the problem
+    P, q, A, b, cones, settings = _qp_data()
+    settings.max_kkt_nnz = 1
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+    with pytest.raises(clarabel.ClarabelError):
+        solver.try_solve()
+
+
+@pytest.mark.parametrize("field", ["P", "q", "A", "b"])
+def test_invalid_data(field):
+    P, q, A, b, cones, settings = _qp_data()
+    data = {"P": P.copy(), "q": q.copy(), "A": A.copy(), "b": b.copy()}
+    values = data[field].data if field in ["P", "A"] else data[field]
+    values[1] = np.nan
+
+    with pytest.raises(clarabel.InvalidDataError, match=f"value in {field} at index 1"):
+        clarabel.DefaultSolver(data["P"], data["q"], data["A"], data["b"], cones, settings)
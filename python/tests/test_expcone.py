@@ -0,0 +1,39 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def test_expcone_entropy():
+    # max sum(-x log x) + c'x s.t. sum(x) == 1, as
+    # max sum(t) + c'x s.t. (t_i, x_i, 1) in K_exp, sum(x) == 1
+    # with optimum x = softmax(c) and value log(sum(exp(c)))
+    n = 3
+    c = np.array([0., 1., 2.])
+
+    P = sparse.csc_matrix((2 * n, 2 * n))
+    q = np.concatenate([-np.ones(n), -c])
+
+    rows = []
+    b = []
+    for i in range(n):
+        row_t = np.zeros(2 * n)
+        row_t[i] = -1.
+        row_x = np.zeros(2 * n)
+        row_x[n + i] = -1.
+        rows += [row_t, row_x, np.zeros(2 * n)]
+        b += [0., 0., 1.]
+    rows.append(np.concatenate([np.zeros(n), np.ones(n)]))
+    b.append(1.)
+    A = sparse.csc_matrix(np.array(rows))
+    b = np.array(b)
+
+    cones = [clarabel.ExponentialConeT()] * n + [clarabel.ZeroConeT(1)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    solution = clarabel.DefaultSolver(P, q, A, b, cones, settings).solve()
+
+    lse = np.log(np.sum(np.exp(c)))
+    assert solution.status == clarabel.SolverStatus.Solved
+    assert np.allclose(solution.x[n:], np.exp(c - lse), atol=1e-4)
+    assert np.isclose(solution.obj_val, -lse, atol=1e-6)
@@ -0,0 +1,44 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _qp_data(dtype):
+    P = sparse.csc_matrix([[4., 1.], [1., 2.]], dtype=dtype)
+    P = sparse.triu(P).tocsc()
+    q = np.array([1., 1.], dtype=dtype)
+    A = sparse.csc_matrix(
+        [[1., 1.],
+         [1., 0.],
+         [0., 1.],
+         [-1., -1.],
+         [-1., 0.],
+         [0., -1.]], dtype=dtype)
+    b = np.array([1., 0.7, 0.7, -1., 0., 0.], dtype=dtype)
+    cones = [clarabel.NonnegativeConeT(6)]
+    return P, q, A, b, cones
+
+
+def _settings():
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.tol_gap_abs = 1e-5
+    settings.tol_gap_rel = 1e-5
+    settings.tol_feas = 1e-5
+    return settings
+
+
+def test_f32_repr():
+    solver = clarabel.DefaultSolverF32(*_qp_data(np.float32), _settings())
+    assert "f32" in repr(solver)
+
+
+def test_f32_matches_f64():
+    sol64 = clarabel.DefaultSolver(*_qp_data(np.float64), _settings()).solve()
+    sol32 = clarabel.DefaultSolverF32(*_qp_data(np.float32), _settings()).solve()
+
+    assert sol64.status == clarabel.SolverStatus.Solved
+    assert sol32.status in (clarabel.SolverStatus.Solved,
+                            clarabel.SolverStatus.AlmostSolved)
+    assert np.isclose(sol32.obj_val, sol64.obj_val, rtol=1e-4)
+    assert np.allclose(sol32.x, sol64.x, atol=1e-3)
@@ -0,0 +1,66 @@
+import gc
+
+import clarabel
+import numpy as np
+import pytest
+from scipy import sparse
+
+
+def _solver(cls=clarabel.DefaultSolver):
+    P = sparse.csc_matrix([[4., 1.], [1., 2.]])
+    P = sparse.triu(P).tocsc()
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix([[1., 1.], [-1., 0.], [0., -1.]])
+    b = np.array([1., 0., 0.])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(2)]
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    return cls(P, q, A, b, cones, settings)
+
+
+@pytest.mark.parametrize("cls", [clarabel.DefaultSolver, clarabel.DefaultSolverF32])
+def test_solve_after_free_raises(cls):
+    solver = _solver(cls)
+    assert solver.solve().status == clarabel.SolverStatus.Solved
+
+    solver.free()
+    with pytest.raises(RuntimeError):
+        solver.solve()
+    with pytest.raises(RuntimeError):
+        solver.print_timers()
+
+    # freeing twice is allowed
+    solver.free()
+
+
+def test_context_manager():
+    with _solver() as solver:
+        solution = solver.solve()
+        assert solution.status == clarabel.SolverStatus.Solved
+
+    with pytest.raises(RuntimeError):
+        solver.solve()
+    with pytest.raises(RuntimeError):
+        solver.get_kkt_matrix()
+
+
+def test_context_manager_exception_propagates():
+    with pytest.raises(ValueError):
+        with _solver() as solver:
+            raise ValueError("raised inside the block")
+
+    with pytest.raises(RuntimeError):
+        solver.solve()
+
+
+def test_solution_outlives_free():
+    # results already returned remain usable once the solver is freed
+    solver = _solver()
+    solution = solver.solve()
+
+    solver.free()
+    del solver
+    gc.collect()
+
+    assert solution.status == clarabel.SolverStatus.Solved
+    assert np.all(np.isfinite(solution.x))
@@ -0,0 +1,51 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _solver():
+    P = sparse.csc_matrix([[4., 1.], [1., 2.]])
+    P = sparse.triu(P).tocsc()
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(
+        [[-1., -1.],
+         [-1., 0.],
+         [0., -1.],
+         [1., 1.],
+         [1., 0.],
+         [0., 1.]])
+    b = np.array([-1., 0., 0., 1., 0.7, 0.7])
+    cones = [clarabel.NonnegativeConeT(3), clarabel.NonnegativeConeT(3)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.save_iterates = True
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+    solver.solve()
+    return solver
+
+
+def test_write_iterate_history_csv(tmp_path):
+    solver = _solver()
+    iterations = solver.solve().iterations
+
+    path = tmp_path / "history.csv"
+    solver.write_iterate_history_csv(str(path))
+
+    header = path.read_text().splitlines()[0].split(",")
+    assert header == ["iter", "x0", "x1"]
+
+    data = np.loadtxt(path, delimiter=",", skiprows=1, ndmin=2)
+    assert data.shape == (iterations + 1, 3)
+    assert np.array_equal(data[:, 0], np.arange(iterations + 1))
+
+
+def test_write_iterate_history_csv_full(tmp_path):
+    solver = _solver()
+    iterations = solver.solve().iterations
+
+    path = tmp_path / "history.csv"
+    solver.write_iterate_history_csv(str(path), full=True)
+
+    data = np.loadtxt(path, delimiter=",", skiprows=1, ndmin=2)
+    assert data.shape == (iterations + 1, 1 + 2 + 6 + 6)
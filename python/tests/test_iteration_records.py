@@ -0,0 +1,52 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _solver(collect):
+    P = sparse.triu(sparse.csc_matrix([[4., 1.], [1., 2.]])).tocsc()
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(
+        [[-1., -1.],
+         [-1., 0.],
+         [0., -1.],
+         [1., 0.],
+         [0., 1.]])
+    b = np.array([-1., 0., 0., 0.7, 0.7])
+    cones = [clarabel.NonnegativeConeT(5)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.collect_iteration_records = collect
+    return clarabel.DefaultSolver(P, q, A, b, cones, settings)
+
+
+def test_iteration_records():
+    solver = _solver(True)
+    solution = solver.solve()
+
+    records = solver.get_iteration_records()
+    assert len(records) == solution.iterations
+    assert [r["iteration"] for r in records] == list(range(1, solution.iterations + 1))
+    assert records[-1]["res_primal"] == solution.r_prim
+    assert records[-1]["res_dual"] == solution.r_dual
+
+
+def test_iteration_records_disabled():
+    solver = _solver(False)
+    solver.solve()
+    assert solver.get_iteration_records() == []
+
+
+def test_iteration_step_lengths():
+    solver = _solver(True)
+    solution = solver.solve()
+
+    records = solver.get_iteration_records()
+    for r in records:
+        assert 0. < r["step_length_primal"] <= 0.99
+        assert 0. < r["step_length_dual"] <= 0.99
+        assert r["step_length"] <= min(r["step_length_primal"], r["step_length_dual"])
+
+    assert solution.step_length_primal == records[-1]["step_length_primal"]
+    assert solution.step_length_dual == records[-1]["step_length_dual"]
@@ -0,0 +1,39 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _qp_data():
+    P = sparse.triu(sparse.csc_matrix([[6., 0.], [0., 4.]])).tocsc()
+    q = np.array([-1., -4.])
+    A = sparse.csc_matrix(
+        [[1., -2.],
+         [1., 0.],
+         [0., 1.],
+         [-1., 0.],
+         [0., -1.]])
+    b = np.array([0., 1., 1., 1., 1.])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(4)]
+    return P, q, A, b, cones
+
+
+def _check_kkt(K, dim):
+    assert sparse.isspmatrix_csc(K)
+    assert K.shape == (dim, dim)
+    assert abs(K - K.T).max() == 0.
+
+
+def test_kkt_matrix():
+    P, q, A, b, cones = _qp_data()
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+
+    (m, n) = A.shape
+
+    # as initially assembled
+    _check_kkt(solver.get_kkt_matrix(), m + n)
+
+    # with the scalings of the final iterate
+    solver.solve()
+    _check_kkt(solver.get_kkt_matrix(), m + n)
@@ -0,0 +1,43 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _qp_data():
+    P = sparse.csc_matrix([[4., 1.], [1., 2.]])
+    P = sparse.triu(P).tocsc()
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(
+        [[1., 1.],
+         [1., 0.],
+         [0., 1.],
+         [-1., -1.],
+         [-1., 0.],
+         [0., -1.]])
+    b = np.array([1., 0.7, 0.7, -1., 0., 0.])
+    cones = [clarabel.NonnegativeConeT(6)]
+    return P, q, A, b, cones
+
+
+def test_max_kkt_nnz_default():
+    settings = clarabel.DefaultSettings()
+    assert settings.max_kkt_nnz is None
+
+
+def test_max_kkt_nnz_exceeded():
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.max_kkt_nnz = 1
+
+    solution = clarabel.DefaultSolver(*_qp_data(), settings).solve()
+    assert solution.status == clarabel.SolverStatus.InsufficientMemory
+    assert solution.iterations == 0
+
+
+def test_max_kkt_nnz_not_exceeded():
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.max_kkt_nnz = 1000
+
+    solution = clarabel.DefaultSolver(*_qp_data(), settings).solve()
+    assert solution.status == clarabel.SolverStatus.Solved
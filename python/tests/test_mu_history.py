@@ -0,0 +1,35 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _solve(collect):
+    P = sparse.triu(sparse.csc_matrix([[4., 1.], [1., 2.]])).tocsc()
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(
+        [[-1., -1.],
+         [-1., 0.],
+         [0., -1.],
+         [1., 0.],
+         [0., 1.]])
+    b = np.array([-1., 0., 0., 0.7, 0.7])
+    cones = [clarabel.NonnegativeConeT(5)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.collect_mu_history = collect
+    return clarabel.DefaultSolver(P, q, A, b, cones, settings).solve()
+
+
+def test_mu_history():
+    solution = _solve(True)
+    assert solution.status == clarabel.SolverStatus.Solved
+
+    mu = np.array(solution.mu_hist)
+    assert len(mu) == solution.iterations + 1
+    assert np.all(mu[1:] <= mu[:-1] * (1. + 1e-8))
+
+
+def test_mu_history_disabled():
+    solution = _solve(False)
+    assert solution.mu_hist == []
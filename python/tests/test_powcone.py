@@ -0,0 +1,25 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def test_powcone_geometric_mean():
+    # max x^0.5 y^0.5 s.t. x + 2y == 4, as
+    # max t s.t. (x, y, t) in K_pow(0.5), x + 2y == 4
+    P = sparse.csc_matrix((3, 3))
+    q = np.array([0., 0., -1.])
+    A = sparse.csc_matrix(
+        [[-1., 0., 0.],
+         [0., -1., 0.],
+         [0., 0., -1.],
+         [1., 2., 0.]])
+    b = np.array([0., 0., 0., 4.])
+    cones = [clarabel.PowerConeT(0.5), clarabel.ZeroConeT(1)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    solution = clarabel.DefaultSolver(P, q, A, b, cones, settings).solve()
+
+    assert solution.status == clarabel.SolverStatus.Solved
+    assert np.allclose(solution.x, [2., 1., np.sqrt(2.)], atol=1e-3)
+    assert np.isclose(solution.obj_val, -np.sqrt(2.), atol=1e-6)
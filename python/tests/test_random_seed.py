@@ -0,0 +1,32 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _solve(seed):
+    P = sparse.csc_matrix([[4.0, 1.0], [1.0, 2.0]])
+    P = sparse.triu(P).tocsc()
+    q = np.array([1.0, 1.0])
+    A = sparse.csc_matrix([[-1.0, -1.0], [-1.0, 0.0], [0.0, -1.0],
+                           [1.0, 1.0], [1.0, 0.0], [0.0, 1.0]])
+    b = np.array([-1.0, 0.0, 0.0, 1.0, 0.7, 0.7])
+    cones = [clarabel.NonnegativeConeT(3), clarabel.NonnegativeConeT(3)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.random_seed = seed
+
+    solver = clarabel.DefaultSolver(P, q, A, b, cones, settings)
+    return solver.solve()
+
+
+def test_random_seed_default():
+    assert clarabel.DefaultSettings().random_seed is None
+
+
+def test_random_seed_reproducible():
+    sol1 = _solve(42)
+    sol2 = _solve(42)
+    assert sol1.iterations == sol2.iterations
+    assert sol1.x == sol2.x
+    assert sol1.z == sol2.z
@@ -0,0 +1,33 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _solve(refine):
+    P = sparse.csc_matrix(np.diag([1e6, 1e-6, 1.]))
+    q = np.array([1., -1e-3, 1e3])
+    A = sparse.csc_matrix(
+        [[1e4, 1e-4, 1.],
+         [-1., 0., 0.],
+         [0., -1., 0.],
+         [0., 0., -1.]])
+    b = np.array([1e3, 0., 0., 0.])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(3)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.equilibrate_enable = False
+    settings.iterative_refinement_enable = refine
+    return clarabel.DefaultSolver(P, q, A, b, cones, settings).solve()
+
+
+def test_refinement_stats_enabled():
+    solution = _solve(True)
+    assert solution.total_refinement_iters > 0
+    assert np.isfinite(solution.final_refinement_residual)
+
+
+def test_refinement_stats_disabled():
+    solution = _solve(False)
+    assert solution.total_refinement_iters == 0
+    assert np.isnan(solution.final_refinement_residual)
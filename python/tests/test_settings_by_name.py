@@ -0,0 +1,50 @@
+import clarabel
+import pytest
+
+
+def test_get_set_float():
+    settings = clarabel.DefaultSettings()
+    settings.set("tol_gap_abs", 1e-6)
+    assert settings.tol_gap_abs == 1e-6
+    assert settings.get("tol_gap_abs") == 1e-6
+
+
+def test_get_set_bool():
+    settings = clarabel.DefaultSettings()
+    settings.set("verbose", False)
+    assert settings.verbose is False
+    assert settings.get("verbose") is False
+
+
+def test_get_set_string():
+    settings = clarabel.DefaultSettings()
+    assert settings.get("direct_solve_method") == "qdldl"
+    settings.set("direct_solve_method", "qdldl")
+    assert settings.direct_solve_method == "qdldl"
+
+
+def test_forward_options_dict():
+    options = {"max_iter": 10, "tol_feas": 1e-7, "presolve_enable": False}
+    settings = clarabel.DefaultSettings()
+    for name, value in options.items():
+        settings.set(name, value)
+    for name, value in options.items():
+        assert settings.get(name) == value
+
+
+def test_unknown_setting():
+    settings = clarabel.DefaultSettings()
+    with pytest.raises(KeyError):
+        settings.get("not_a_setting")
+    with pytest.raises(KeyError):
+        settings.set("not_a_setting", 1.0)
+
+
+def test_type_mismatch():
+    settings = clarabel.DefaultSettings()
+    with pytest.raises(TypeError):
+        settings.set("verbose", "yes")
+    with pytest.raises(TypeError):
+        settings.set("max_iter", 1.5)
+    with pytest.raises(TypeError):
+        settings.set("direct_solve_method", 1)
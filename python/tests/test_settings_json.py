@@ -0,0 +1,31 @@
+import clarabel
+import pytest
+
+
+def test_settings_json_round_trip():
+    settings = clarabel.DefaultSettings()
+    settings.max_iter = 50
+    settings.tol_gap_abs = 1e-6
+    settings.direct_solve_method = "qdldl"
+
+    settings2 = clarabel.DefaultSettings.from_json(settings.to_json())
+
+    assert settings2.max_iter == 50
+    assert settings2.tol_gap_abs == 1e-6
+    assert settings2.time_limit == float("inf")
+    assert settings2.to_json() == settings.to_json()
+
+
+def test_settings_json_missing_and_unknown_fields():
+    json = '{"max_iter": 25, "verbose": false, "not_a_setting": 1.0}'
+    settings = clarabel.DefaultSettings.from_json(json)
+    defaults = clarabel.DefaultSettings()
+
+    assert settings.max_iter == 25
+    assert not settings.verbose
+    assert settings.tol_feas == defaults.tol_feas
+
+
+def test_settings_json_invalid():
+    with pytest.raises(ValueError):
+        clarabel.DefaultSettings.from_json("not json")
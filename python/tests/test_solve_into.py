@@ -0,0 +1,65 @@
+import clarabel
+import numpy as np
+import pytest
+from scipy import sparse
+
+
+def _qp_data():
+    P = sparse.csc_matrix([[4., 1.], [1., 2.]])
+    P = sparse.triu(P).tocsc()
+    q = np.array([1., 1.])
+    A = sparse.csc_matrix(
+        [[1., 1.],
+         [1., 0.],
+         [0., 1.],
+         [-1., -1.],
+         [-1., 0.],
+         [0., -1.]])
+    b = np.array([1., 0.7, 0.7, -1., 0., 0.])
+    cones = [clarabel.NonnegativeConeT(6)]
+    return P, q, A, b, cones
+
+
+def _settings():
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    return settings
+
+
+def test_solve_into_matches_solve():
+    solution = clarabel.DefaultSolver(*_qp_data(), _settings()).solve()
+
+    solver = clarabel.DefaultSolver(*_qp_data(), _settings())
+    x, s, z = np.empty(2), np.empty(6), np.empty(6)
+    status = solver.solve_into(x, s, z)
+
+    assert status == clarabel.SolverStatus.Solved
+    assert np.array_equal(x, solution.x)
+    assert np.array_equal(s, solution.s)
+    assert np.array_equal(z, solution.z)
+
+
+def test_solve_into_no_reallocation():
+    solver = clarabel.DefaultSolver(*_qp_data(), _settings())
+    x, s, z = np.zeros(2), np.zeros(6), np.zeros(6)
+    ptrs = [v.__array_interface__["data"][0] for v in (x, s, z)]
+
+    for _ in range(3):
+        solver.solve_into(x, s, z)
+        assert [v.__array_interface__["data"][0] for v in (x, s, z)] == ptrs
+
+    assert np.allclose(x, [0.3, 0.7], atol=1e-6)
+
+
+def test_solve_into_bad_arrays():
+    solver = clarabel.DefaultSolver(*_qp_data(), _settings())
+
+    # wrong length
+    with pytest.raises(ValueError):
+        solver.solve_into(np.empty(3), np.empty(6), np.empty(6))
+
+    # read only
+    x = np.empty(2)
+    x.flags.writeable = False
+    with pytest.raises(ValueError):
+        solver.solve_into(x, np.empty(6), np.empty(6))
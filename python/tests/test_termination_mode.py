@@ -0,0 +1,37 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _lp_data():
+    P = sparse.csc_matrix((2, 2))
+    q = np.array([1., 2.])
+    A = sparse.csc_matrix(
+        [[1., 1.],
+         [-1., -1.],
+         [-1., 0.],
+         [0., -1.]])
+    b = np.array([1.001, -1., 0., 0.])
+    cones = [clarabel.NonnegativeConeT(4)]
+    return P, q, A, b, cones
+
+
+def _solve(mode):
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.tol_feas = 1e-12
+    settings.termination_mode = mode
+    solver = clarabel.DefaultSolver(*_lp_data(), settings)
+    return solver.solve()
+
+
+def test_termination_mode_default():
+    assert clarabel.DefaultSettings().termination_mode == "standard"
+
+
+def test_termination_mode_gap_priority():
+    standard = _solve("standard")
+    gap_priority = _solve("gap_priority")
+    assert standard.status == clarabel.SolverStatus.Solved
+    assert gap_priority.status == clarabel.SolverStatus.Solved
+    assert gap_priority.iterations < standard.iterations
@@ -0,0 +1,32 @@
+import clarabel
+import numpy as np
+from scipy import sparse
+
+
+def _solve(scale, normalize):
+    P = sparse.csc_matrix((2, 2))
+    q = np.array([scale, scale])
+    A = sparse.csc_matrix(
+        [[-1., 0.],
+         [0., -1.],
+         [1., 0.],
+         [0., 1.]])
+    b = np.array([0., 0., 1., 1.])
+    cones = [clarabel.NonnegativeConeT(4)]
+
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    settings.tol_normalize = normalize
+    return clarabel.DefaultSolver(P, q, A, b, cones, settings).solve()
+
+
+def test_tol_normalize_default():
+    assert not clarabel.DefaultSettings().tol_normalize
+
+
+def test_tol_normalize_scale_invariant():
+    small = _solve(1., True)
+    large = _solve(1e6, True)
+    assert small.status == clarabel.SolverStatus.Solved
+    assert large.status == clarabel.SolverStatus.Solved
+    assert small.iterations == large.iterations
@@ -0,0 +1,41 @@
+import clarabel
+import numpy as np
+import pytest
+from scipy import sparse
+
+
+def _qp_solver():
+    P = sparse.triu(sparse.csc_matrix([[6., 0.], [0., 4.]])).tocsc()
+    q = np.array([-1., -4.])
+    A = sparse.csc_matrix(
+        [[1., -2.],
+         [1., 0.],
+         [0., 1.],
+         [-1., 0.],
+         [0., -1.]])
+    b = np.array([0., 1., 1., 1., 1.])
+    cones = [clarabel.ZeroConeT(1), clarabel.NonnegativeConeT(4)]
+    settings = clarabel.DefaultSettings()
+    settings.verbose = False
+    return clarabel.DefaultSolver(P, q, A, b, cones, settings)
+
+
+def test_warm_start():
+    cold = _qp_solver().solve()
+
+    for mode in [0, 1, 2]:
+        warm = _qp_solver().solve_warm(cold.x, cold.s, cold.z, mode, 0.5)
+        assert warm.status == clarabel.SolverStatus.Solved
+        assert np.allclose(warm.x, cold.x, atol=1e-6)
+
+
+def test_warm_start_invalid_mode():
+    cold = _qp_solver().solve()
+    with pytest.raises(ValueError):
+        _qp_solver().solve_warm(cold.x, cold.s, cold.z, 3)
+
+
+def test_warm_start_invalid_lambda():
+    cold = _qp_solver().solve()
+    with pytest.raises(ValueError):
+        _qp_solver().solve_warm(cold.x, cold.s, cold.z, 2, 1.5)
@@ -59,3 +59,46 @@ where
         C
     }
 }
+
+impl<T> CscMatrix<T>
+where
+    T: FloatT,
+{
+    /// block diagonal matrix concatenation
+    ///
+    /// ```text
+    /// C = [ A1          ]
+    ///     [    A2       ]
+    ///     [       ...   ]
+    ///     [          Ak ]
+    /// ```
+    ///
+    /// Blocks need not be square.  An empty list of blocks gives a 0x0 matrix.
+    pub fn blockdiag(blocks: &[&Self]) -> Self {
+        let m = blocks.iter().map(|B| B.m).sum();
+        let n = blocks.iter().map(|B| B.n).sum();
+        let nnz = blocks.iter().map(|B| B.nnz()).sum();
+        let mut C = CscMatrix::spalloc((m, n), nnz);
+
+        //dummy mapping indices, as in hcat and vcat
+        let mut map = vec![0usize; blocks.iter().map(|B| B.nnz()).max().unwrap_or(0)];
+
+        //compute column counts and fill
+        let mut col = 0;
+        for B in blocks {
+            C.colcount_block(B, col, MatrixShape::N);
+            col += B.n;
+        }
+        C.colcount_to_colptr();
+
+        let (mut row, mut col) = (0, 0);
+        for B in blocks {
+            C.fill_block(B, &mut map, row, col, MatrixShape::N);
+            row += B.m;
+            col += B.n;
+        }
+        C.backshift_colptrs();
+
+        C
+    }
+}
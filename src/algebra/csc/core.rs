@@ -1,8 +1,14 @@
 #![allow(non_snake_case)]
 
-use crate::algebra::{Adjoint, FloatT, MatrixShape, ShapedMatrix, SparseFormatError, Symmetric};
+use crate::algebra::{
+    Adjoint, CscError, FloatT, MatrixMath, MatrixShape, MatrixTriangle, ShapedMatrix,
+    SparseFormatError, Symmetric,
+};
 use std::iter::zip;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Sparse matrix in standard Compressed Sparse Column (CSC) format
 ///
 /// __Example usage__ : To construct the 3 x 3 matrix
@@ -36,6 +42,7 @@ use std::iter::zip;
 ///
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CscMatrix<T = f64> {
     /// number of rows
     pub m: usize,
@@ -162,6 +169,63 @@ where
         CscMatrix::new(n, n, colptr, rowval, nzval)
     }
 
+    /// Construct a sparse matrix of size `m` x `n` from data in coordinate
+    /// (COO) format, with the `k`th entry having value `vals[k]` at
+    /// position `(rows[k], cols[k])`.   Indexing is 0-based.
+    ///
+    /// Entries may be supplied in any order.   Entries that appear more
+    /// than once at the same position are summed.   Explicitly supplied
+    /// zeros, including those resulting from summation, are retained.
+    ///
+    /// # Panics
+    /// Panics if the input slices have different lengths or if any
+    /// index is out of bounds.
+    ///
+    /// Example:
+    /// ```
+    /// use clarabel::algebra::CscMatrix;
+    ///
+    /// // the matrix [1. 0.; 0. 5.], with the (1,1) entry given as 2 + 3
+    /// let A = CscMatrix::from_triplets(2, 2, &[1, 0, 1], &[1, 0, 1], &[2., 1., 3.]);
+    ///
+    /// assert_eq!(A, CscMatrix::from(&[[1., 0.], [0., 5.]]));
+    /// ```
+    pub fn from_triplets(m: usize, n: usize, rows: &[usize], cols: &[usize], vals: &[T]) -> Self {
+        assert_eq!(rows.len(), vals.len());
+        assert_eq!(cols.len(), vals.len());
+        assert!(rows.iter().all(|&i| i < m), "row index out of bounds");
+        assert!(cols.iter().all(|&j| j < n), "column index out of bounds");
+
+        // order entries by column, then row.  The sort
+        // is stable, so duplicates are summed in input order
+        let mut perm: Vec<usize> = (0..vals.len()).collect();
+        perm.sort_by_key(|&k| (cols[k], rows[k]));
+
+        let mut colptr = vec![0; n + 1];
+        let mut rowval = Vec::with_capacity(vals.len());
+        let mut nzval = Vec::<T>::with_capacity(vals.len());
+        let mut last = None;
+
+        for k in perm {
+            let (i, j) = (rows[k], cols[k]);
+            if last == Some((i, j)) {
+                *nzval.last_mut().unwrap() += vals[k];
+            } else {
+                rowval.push(i);
+                nzval.push(vals[k]);
+                colptr[j + 1] += 1;
+                last = Some((i, j));
+            }
+        }
+
+        // column counts to column pointers
+        for j in 0..n {
+            colptr[j + 1] += colptr[j];
+        }
+
+        CscMatrix::new(m, n, colptr, rowval, nzval)
+    }
+
     /// number of nonzeros
     pub fn nnz(&self) -> usize {
         self.colptr[self.n]
@@ -172,6 +236,14 @@ where
         Adjoint { src: self }
     }
 
+    /// Transpose into a new matrix.   Allocates and copies.
+    ///
+    /// Row indices within each column of the output remain sorted.
+    /// Explicitly stored zeros in `self` are preserved.
+    pub fn transpose(&self) -> Self {
+        self.t().into()
+    }
+
     /// symmetric view
     pub fn sym(&self) -> Symmetric<'_, Self> {
         debug_assert!(self.is_triu());
@@ -210,6 +282,47 @@ where
 
         Ok(())
     }
+
+    /// Check the structure of the matrix data, returning an error
+    /// describing the first violation found.
+    pub fn is_valid(&self) -> Result<(), CscError> {
+        if self.colptr.len() != self.n + 1 {
+            return Err(CscError::BadColptrLength {
+                expected: self.n + 1,
+                found: self.colptr.len(),
+            });
+        }
+
+        if let Some(col) = self.colptr.windows(2).position(|c| c[0] > c[1]) {
+            return Err(CscError::NonMonotonicColptr { col });
+        }
+
+        let colptr_end = self.colptr[self.n];
+        if colptr_end != self.rowval.len() || colptr_end != self.nzval.len() {
+            return Err(CscError::NnzMismatch {
+                colptr_end,
+                rowval_len: self.rowval.len(),
+                nzval_len: self.nzval.len(),
+            });
+        }
+
+        for col in 0..self.n {
+            let (first, last) = (self.colptr[col], self.colptr[col + 1]);
+            for index in first..last {
+                let row = self.rowval[index];
+                if row >= self.m {
+                    let m = self.m;
+                    return Err(CscError::RowOutOfBounds { col, index, row, m });
+                }
+                if index > first && self.rowval[index - 1] >= row {
+                    return Err(CscError::UnsortedRows { col, index });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// True if matrices if the same size and sparsity pattern
     pub fn is_equal_sparsity(&self, other: &Self) -> bool {
         self.size() == other.size() && self.colptr == other.colptr && self.rowval == other.rowval
@@ -339,6 +452,79 @@ where
         true
     }
 
+    /// Allocates a new matrix containing the full symmetric matrix with
+    /// the given triangle `tri` of `self`, i.e. with each off-diagonal entry
+    /// of that triangle also stored at its transposed position.  Diagonal
+    /// entries appear once.   Entries outside of `tri` are ignored.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// Example:
+    /// ```
+    /// use clarabel::algebra::{CscMatrix, MatrixTriangle};
+    ///
+    /// let P = CscMatrix::from(&[[4., 1.], [0., 2.]]);
+    ///
+    /// assert_eq!(
+    ///     P.to_symmetric(MatrixTriangle::Triu),
+    ///     CscMatrix::from(&[[4., 1.], [1., 2.]])
+    /// );
+    /// ```
+    pub fn to_symmetric(&self, tri: MatrixTriangle) -> Self {
+        assert!(self.is_square());
+
+        let nnz = self.nnz();
+        let mut rows = Vec::with_capacity(2 * nnz);
+        let mut cols = Vec::with_capacity(2 * nnz);
+        let mut vals = Vec::with_capacity(2 * nnz);
+
+        for col in 0..self.n {
+            for ptr in self.colptr[col]..self.colptr[col + 1] {
+                let row = self.rowval[ptr];
+                let in_tri = match tri {
+                    MatrixTriangle::Triu => row <= col,
+                    MatrixTriangle::Tril => row >= col,
+                };
+                if !in_tri {
+                    continue;
+                }
+                rows.push(row);
+                cols.push(col);
+                vals.push(self.nzval[ptr]);
+                if row != col {
+                    rows.push(col);
+                    cols.push(row);
+                    vals.push(self.nzval[ptr]);
+                }
+            }
+        }
+
+        CscMatrix::from_triplets(self.m, self.n, &rows, &cols, &vals)
+    }
+
+    /// True if the matrix is square and each entry differs from its
+    /// transposed entry by at most `tol`.  Entries that are not structural
+    /// nonzeros are treated as zero.
+    ///
+    /// NB: assumes that entries in each column have monotonically
+    /// increasing row numbers
+    pub fn is_symmetric(&self, tol: T) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        for col in 0..self.ncols() {
+            for ptr in self.colptr[col]..self.colptr[col + 1] {
+                let row = self.rowval[ptr];
+                let other = self.get_entry((col, row)).unwrap_or_else(T::zero);
+                if T::abs(self.nzval[ptr] - other) > tol {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
     /// Returns the value at the given (row,col) index as an Option.
     /// Returns None if the given index is not a structural nonzero.
     ///
@@ -357,6 +543,37 @@ where
         }
     }
 
+    /// Scales the rows of the matrix in place, producing `Diagonal(d)*A`.
+    ///
+    /// # Panics
+    /// Panics if the length of `d` is not the number of rows.
+    pub fn scale_rows(&mut self, d: &[T]) {
+        assert_eq!(d.len(), self.m);
+        self.lscale(d);
+    }
+
+    /// Scales the columns of the matrix in place, producing `A*Diagonal(e)`.
+    ///
+    /// # Panics
+    /// Panics if the length of `e` is not the number of columns.
+    pub fn scale_cols(&mut self, e: &[T]) {
+        assert_eq!(e.len(), self.n);
+        self.rscale(e);
+    }
+
+    /// Scales a square matrix symmetrically in place, producing
+    /// `Diagonal(d)*A*Diagonal(d)`.   Triangular storage is preserved,
+    /// e.g. for the upper triangular part of a symmetric matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square, or if the length of `d`
+    /// is not the matrix dimension.
+    pub fn scale_symmetric(&mut self, d: &[T]) {
+        assert!(self.is_square());
+        assert_eq!(d.len(), self.n);
+        self.lrscale(d, d);
+    }
+
     /// Returns the (row,col) coordinates of the given linear index.
     ///
     /// # Panics
@@ -1,19 +1,24 @@
 use crate::algebra::*;
 use std::iter::zip;
 
-impl<T: FloatT> MatrixVectorMultiply for CscMatrix<T> {
-    type T = T;
-
-    fn gemv(&self, y: &mut [T], x: &[T], a: T, b: T) {
-        _csc_axpby_N(self, y, x, a, b);
-    }
-}
-
-impl<T: FloatT> MatrixVectorMultiply for Adjoint<'_, CscMatrix<T>> {
-    type T = T;
-
-    fn gemv(&self, y: &mut [T], x: &[T], a: T, b: T) {
-        _csc_axpby_T(self.src, y, x, a, b);
+impl<T: FloatT> CscMatrix<T> {
+    /// BLAS-like general matrix-vector multiply.  Produces `y = a*op(A)*x + b*y`,
+    /// where `op(A) = A` for [`MatrixShape::N`] and `op(A) = Aᵀ` for [`MatrixShape::T`].
+    ///
+    /// # Panics
+    /// Panics if the dimensions of `x` or `y` are incompatible with `op(A)`.
+    pub fn gemv(&self, y: &mut [T], x: &[T], a: T, b: T, shape: MatrixShape) {
+        let (m, n) = match shape {
+            MatrixShape::N => (self.m, self.n),
+            MatrixShape::T => (self.n, self.m),
+        };
+        assert_eq!(x.len(), n);
+        assert_eq!(y.len(), m);
+
+        match shape {
+            MatrixShape::N => _csc_axpby_N(self, y, x, a, b),
+            MatrixShape::T => _csc_axpby_T(self, y, x, a, b),
+        }
     }
 }
 
@@ -224,9 +229,11 @@ fn _csc_quad_form<T: FloatT>(M: &CscMatrix<T>, y: &[T], x: &[T]) -> T {
     out
 }
 
-// sparse matrix-vector multiply, no transpose
+// sparse matrix-vector multiply, no transpose.  Used directly in
+// place of gemv on internal hot paths, where the dimensions are
+// fixed at setup, to skip the dimension checks of the public API
 #[allow(non_snake_case)]
-fn _csc_axpby_N<T: FloatT>(A: &CscMatrix<T>, y: &mut [T], x: &[T], a: T, b: T) {
+pub(crate) fn _csc_axpby_N<T: FloatT>(A: &CscMatrix<T>, y: &mut [T], x: &[T], a: T, b: T) {
     //first do the b*y part
     if b == T::zero() {
         y.fill(T::zero());
@@ -269,7 +276,7 @@ fn _csc_axpby_N<T: FloatT>(A: &CscMatrix<T>, y: &mut [T], x: &[T], a: T, b: T) {
 
 // sparse matrix-vector multiply, transposed
 #[allow(non_snake_case)]
-fn _csc_axpby_T<T: FloatT>(A: &CscMatrix<T>, y: &mut [T], x: &[T], a: T, b: T) {
+pub(crate) fn _csc_axpby_T<T: FloatT>(A: &CscMatrix<T>, y: &mut [T], x: &[T], a: T, b: T) {
     //first do the b*y part
     if b == T::zero() {
         y.fill(T::zero());
@@ -5,6 +5,6 @@ pub use self::core::*;
 mod utils;
 pub use utils::*;
 mod matrix_math;
-pub use matrix_math::*;
+pub(crate) use matrix_math::{_csc_axpby_N, _csc_axpby_T};
 mod block_concatenate;
 pub use block_concatenate::*;
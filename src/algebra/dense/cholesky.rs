@@ -5,6 +5,7 @@ use crate::algebra::{
     VectorMath,
 };
 
+#[derive(Clone)]
 pub(crate) struct CholeskyEngine<T> {
     /// lower triangular factor (stored as square dense)
     pub L: Matrix<T>,
@@ -3,7 +3,7 @@
 use crate::algebra::{DenseFactorizationError, FactorSVD, FloatT, Matrix, ShapedMatrix};
 use core::cmp::min;
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone)]
 #[allow(dead_code)] //QRDecomposition is not used yet
 pub(crate) enum SVDEngineAlgorithm {
     DivideAndConquer,
@@ -12,6 +12,7 @@ pub(crate) enum SVDEngineAlgorithm {
 
 const DEFAULT_SVD_ALGORITHM: SVDEngineAlgorithm = SVDEngineAlgorithm::DivideAndConquer;
 
+#[derive(Clone)]
 pub(crate) struct SVDEngine<T> {
     /// Computed singular values
     pub s: Vec<T>,
@@ -4,6 +4,7 @@ use crate::algebra::{
     DenseFactorizationError, FactorEigen, FloatT, Matrix, MatrixTriangle, ShapedMatrix,
 };
 
+#[derive(Clone)]
 pub(crate) struct EigEngine<T> {
     /// Computed eigenvalues in ascending order
     pub λ: Vec<T>,
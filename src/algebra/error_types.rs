@@ -15,6 +15,31 @@ pub enum SparseFormatError {
     SparsityMismatch,
 }
 
+/// Error type returned by the structural validator [`is_valid`](crate::algebra::CscMatrix::is_valid),
+/// describing the first violation found.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CscError {
+    #[error("colptr has length {found}, but should have length n + 1 = {expected}")]
+    BadColptrLength { expected: usize, found: usize },
+    #[error("colptr decreases from column {col} to column {}", .col + 1)]
+    NonMonotonicColptr { col: usize },
+    #[error("colptr[n] = {colptr_end}, but rowval has length {rowval_len} and nzval has length {nzval_len}")]
+    NnzMismatch {
+        colptr_end: usize,
+        rowval_len: usize,
+        nzval_len: usize,
+    },
+    #[error("row index {row} at position {index} in column {col} is outside of [0, {m})")]
+    RowOutOfBounds {
+        col: usize,
+        index: usize,
+        row: usize,
+        m: usize,
+    },
+    #[error("row indices in column {col} are not sorted and unique at position {index}")]
+    UnsortedRows { col: usize, index: usize },
+}
+
 /// Error type returned by BLAS-like dense factorization routines.  Errors
 /// return the internal BLAS error codes.
 #[derive(Error, Debug)]
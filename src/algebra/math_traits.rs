@@ -133,13 +133,6 @@ pub trait VectorMath {
 
 /// Matrix operations for matrices of [`FloatT`](crate::algebra::FloatT)
 
-pub(crate) trait MatrixVectorMultiply {
-    type T: FloatT;
-
-    /// BLAS-like general matrix-vector multiply.  Produces `y = a*self*x + b*y`
-    fn gemv(&self, y: &mut [Self::T], x: &[Self::T], a: Self::T, b: Self::T);
-}
-
 pub(crate) trait SymMatrixVectorMultiply {
     type T: FloatT;
 
@@ -17,6 +17,7 @@ mod floats;
 mod math_traits;
 mod matrix_traits;
 mod matrix_types;
+mod problem_norms;
 mod reshaped;
 mod scalarmath;
 mod symmetric;
@@ -27,6 +28,7 @@ pub use floats::*;
 pub use math_traits::*;
 pub use matrix_traits::*;
 pub use matrix_types::*;
+pub use problem_norms::*;
 pub use reshaped::*;
 pub(crate) use scalarmath::*;
 pub use symmetric::*;
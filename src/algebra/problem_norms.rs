@@ -0,0 +1,58 @@
+#![allow(non_snake_case)]
+use crate::algebra::{CscMatrix, FloatT, VectorMath};
+
+/// Norms of the problem data used by the solver's relative tolerances.
+/// See [`problem_norms`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProblemNorms<T> {
+    /// Infinity norm of the linear cost `q`
+    pub norm_q: T,
+    /// Infinity norm of the constraint vector `b`
+    pub norm_b: T,
+    /// Largest absolute entry of `P`
+    pub norm_P: T,
+    /// Largest absolute entry of `A`
+    pub norm_A: T,
+}
+
+impl<T> ProblemNorms<T>
+where
+    T: FloatT,
+{
+    /// Scaling `max(1, ‖q‖∞, ‖b‖∞)` applied to the absolute gap and
+    /// infeasibility tolerances when the `tol_normalize` setting is enabled.
+    pub fn tol_scale(&self) -> T {
+        T::max(T::one(), T::max(self.norm_q, self.norm_b))
+    }
+}
+
+/// Norms of the problem data `(P, q, A, b)` as used in the solver's
+/// convergence checks.
+///
+/// With `x`, `s` and `z` the unscaled iterates, the relative residuals
+/// reported by the solver are
+///
+/// - `res_primal = ‖Ax + s - b‖ / max(1, ‖b‖∞ + ‖x‖ + ‖s‖)`
+/// - `res_dual = ‖Px + A'z + q‖ / max(1, ‖q‖∞ + ‖x‖ + ‖z‖)`
+///
+/// where the unmarked norms are 2-norms, and `‖q‖∞`, `‖b‖∞` are `norm_q`
+/// and `norm_b` of the result.  `P` may be given either in full or as its
+/// upper triangular part.
+///
+/// __NB__: the solver evaluates these norms on the equilibrated problem data,
+/// so the values match its own exactly only if the `equilibrate_enable` setting
+/// is false.   Otherwise they agree up to the equilibration scaling, which can
+/// be inspected with [`DefaultSolver::equilibration`](crate::solver::DefaultSolver::equilibration).
+pub fn problem_norms<T: FloatT>(
+    P: &CscMatrix<T>,
+    q: &[T],
+    A: &CscMatrix<T>,
+    b: &[T],
+) -> ProblemNorms<T> {
+    ProblemNorms {
+        norm_q: q.norm_inf(),
+        norm_b: b.norm_inf(),
+        norm_P: P.nzval.norm_inf(),
+        norm_A: A.nzval.norm_inf(),
+    }
+}
@@ -138,6 +138,108 @@ fn test_check_format() {
     assert!(A.check_format().is_err());
 }
 
+#[test]
+fn test_is_valid() {
+    assert!(test_matrix_3x4().is_valid().is_ok());
+    assert!(test_matrix_4x4().is_valid().is_ok());
+    assert!(test_matrix_4x4_triu_2().is_valid().is_ok());
+    assert!(CscMatrix::<f64>::zeros((3, 2)).is_valid().is_ok());
+    assert!(CscMatrix::<f64>::identity(4).is_valid().is_ok());
+
+    //bad colptr length
+    let mut A = test_matrix_4x4();
+    A.n = 10;
+    let err = A.is_valid().unwrap_err();
+    assert_eq!(
+        err,
+        CscError::BadColptrLength {
+            expected: 11,
+            found: 5
+        }
+    );
+
+    //empty colptr
+    let mut A = test_matrix_4x4();
+    A.colptr = vec![];
+    assert!(matches!(
+        A.is_valid(),
+        Err(CscError::BadColptrLength { .. })
+    ));
+
+    //decreasing colptr
+    let mut A = test_matrix_4x4();
+    //was Ap = vec![0, 2, 5, 8, 10]
+    A.colptr = vec![0, 6, 5, 8, 10];
+    assert_eq!(
+        A.is_valid().unwrap_err(),
+        CscError::NonMonotonicColptr { col: 1 }
+    );
+
+    //colptr end inconsistent with rowval / nzval
+    let mut A = test_matrix_4x4();
+    *A.colptr.last_mut().unwrap() = 100;
+    assert!(matches!(A.is_valid(), Err(CscError::NnzMismatch { .. })));
+
+    let mut A = test_matrix_4x4();
+    A.rowval.pop();
+    assert!(matches!(A.is_valid(), Err(CscError::NnzMismatch { .. })));
+
+    let mut A = test_matrix_4x4();
+    A.nzval.pop();
+    assert_eq!(
+        A.is_valid().unwrap_err(),
+        CscError::NnzMismatch {
+            colptr_end: 10,
+            rowval_len: 10,
+            nzval_len: 9
+        }
+    );
+
+    //row index out of bounds
+    let mut A = test_matrix_4x4();
+    //was Ai = vec![0, 2, 0, 1, 3, 0, 1, 2, 2, 3]
+    A.rowval = vec![0, 2, 0, 1, 4, 0, 1, 2, 2, 3];
+    assert_eq!(
+        A.is_valid().unwrap_err(),
+        CscError::RowOutOfBounds {
+            col: 1,
+            index: 4,
+            row: 4,
+            m: 4
+        }
+    );
+
+    //badly ordered rows
+    let mut A = test_matrix_4x4();
+    //was Ai = vec![0, 2, 0, 1, 3, 0, 1, 2, 2, 3]
+    A.rowval = vec![0, 2, 0, 3, 1, 0, 1, 2, 2, 3];
+    assert_eq!(
+        A.is_valid().unwrap_err(),
+        CscError::UnsortedRows { col: 1, index: 4 }
+    );
+
+    //repeated matrix entry
+    let mut A = test_matrix_4x4();
+    //was Ai = vec![0, 2, 0, 1, 3, 0, 1, 2, 2, 3]
+    A.rowval = vec![0, 2, 0, 1, 1, 0, 1, 2, 2, 3];
+    assert_eq!(
+        A.is_valid().unwrap_err(),
+        CscError::UnsortedRows { col: 1, index: 4 }
+    );
+
+    //the first violation is reported
+    let mut A = test_matrix_4x4();
+    A.rowval = vec![2, 0, 0, 1, 4, 0, 1, 2, 2, 3];
+    assert_eq!(
+        A.is_valid().unwrap_err(),
+        CscError::UnsortedRows { col: 0, index: 1 }
+    );
+
+    //errors are descriptive
+    let msg = CscError::UnsortedRows { col: 0, index: 1 }.to_string();
+    assert!(msg.contains("column 0"));
+}
+
 #[test]
 fn test_col_norms() {
     let A = test_matrix_3x4();
@@ -207,6 +309,82 @@ fn test_lrscaling() {
     assert_eq!(A.nzval, vec![-4., -6., 34., 12., 12., -7., 80., -60.]);
 }
 
+// dense reference implementation of Diagonal(l)*A*Diagonal(r)
+fn dense_lrscale<const M: usize, const N: usize>(
+    A: &[[f64; N]; M],
+    l: &[f64],
+    r: &[f64],
+) -> CscMatrix<f64> {
+    let mut B = *A;
+    for (i, row) in B.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            *v *= l[i] * r[j];
+        }
+    }
+    CscMatrix::from(&B)
+}
+
+#[test]
+fn test_scale_rows_cols() {
+    let Adense = [
+        [-1., -17., 6., 10.], //
+        [3., 0., 7., 0.],     //
+        [0., -4., 0., -5.],   //
+    ];
+    let d = vec![2., -1., 3.];
+    let e = vec![2., -1., 0.5, 4.];
+    let (ones3, ones4) = (vec![1.; 3], vec![1.; 4]);
+
+    let mut A = test_matrix_3x4();
+    A.scale_rows(&d);
+    assert_eq!(A, dense_lrscale(&Adense, &d, &ones4));
+
+    let mut A = test_matrix_3x4();
+    A.scale_cols(&e);
+    assert_eq!(A, dense_lrscale(&Adense, &ones3, &e));
+
+    let mut A = test_matrix_3x4();
+    A.scale_rows(&d);
+    A.scale_cols(&e);
+    assert_eq!(A, dense_lrscale(&Adense, &d, &e));
+
+    // a tall matrix
+    let mut At = test_matrix_3x4().transpose();
+    At.scale_rows(&e);
+    At.scale_cols(&d);
+    assert_eq!(At, dense_lrscale(&Adense, &d, &e).transpose());
+}
+
+#[test]
+fn test_scale_symmetric() {
+    let Adense = [
+        [4., -3., 7., 0.], //
+        [0., 8., -1., 0.], //
+        [0., 0., 2., -3.], //
+        [0., 0., 0., 1.],  //
+    ];
+    let d = vec![2., -1., 0.5, 3.];
+
+    let mut A = test_matrix_4x4_triu();
+    A.scale_symmetric(&d);
+    assert_eq!(A, dense_lrscale(&Adense, &d, &d));
+    assert!(A.is_triu());
+}
+
+#[test]
+#[should_panic]
+fn test_scale_rows_bad_dimension() {
+    let mut A = test_matrix_3x4();
+    A.scale_rows(&[1.; 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_scale_symmetric_not_square() {
+    let mut A = test_matrix_3x4();
+    A.scale_symmetric(&[1.; 4]);
+}
+
 #[test]
 fn test_gemv() {
     let A = test_matrix_3x4();
@@ -215,16 +393,83 @@ fn test_gemv() {
     let a = 2.;
     let b = -3.;
 
-    A.gemv(&mut y, &x, a, b);
+    A.gemv(&mut y, &x, a, b, MatrixShape::N);
     assert_eq!(y, vec![7., 66., 35.]);
 
     let mut y = vec![1., -2., 3., -4.];
     let x = vec![5., -6., 7.];
 
-    A.t().gemv(&mut y, &x, a, b);
+    A.gemv(&mut y, &x, a, b, MatrixShape::T);
     assert_eq!(y, vec![-49., -220., -33., 42.]);
 }
 
+// dense reference implementation of y = a*op(A)*x + b*y
+fn dense_gemv(A: &[[f64; 4]; 3], y: &mut [f64], x: &[f64], a: f64, b: f64, shape: MatrixShape) {
+    let y0 = y.to_vec();
+    for (i, yi) in y.iter_mut().enumerate() {
+        let mut Ax = 0.;
+        for (j, xj) in x.iter().enumerate() {
+            Ax += match shape {
+                MatrixShape::N => A[i][j] * xj,
+                MatrixShape::T => A[j][i] * xj,
+            };
+        }
+        *yi = a * Ax + b * y0[i];
+    }
+}
+
+#[test]
+fn test_gemv_dense_reference() {
+    let Adense = [
+        [-1., -17., 6., 10.], //
+        [3., 0., 7., 0.],     //
+        [0., -4., 0., -5.],   //
+    ];
+    let A = test_matrix_3x4();
+
+    for (a, b) in [(1., 0.), (2., 0.), (-1., 1.), (0.5, -3.), (0., 2.)] {
+        // N shape
+        let x = vec![1., -2., 3., -4.];
+        let mut y = vec![5., -6., 7.];
+        let mut yref = y.clone();
+        A.gemv(&mut y, &x, a, b, MatrixShape::N);
+        dense_gemv(&Adense, &mut yref, &x, a, b, MatrixShape::N);
+        assert_eq!(y, yref);
+
+        // T shape
+        let x = vec![5., -6., 7.];
+        let mut y = vec![1., -2., 3., -4.];
+        let mut yref = y.clone();
+        A.gemv(&mut y, &x, a, b, MatrixShape::T);
+        dense_gemv(&Adense, &mut yref, &x, a, b, MatrixShape::T);
+        assert_eq!(y, yref);
+    }
+
+    // b = 0 should overwrite non-finite values in y
+    let x = vec![1., -2., 3., -4.];
+    let mut y = vec![f64::NAN; 3];
+    A.gemv(&mut y, &x, 1., 0., MatrixShape::N);
+    assert_eq!(y, vec![11., 24., 28.]);
+}
+
+#[test]
+#[should_panic]
+fn test_gemv_bad_dimension_N() {
+    let A = test_matrix_3x4();
+    let x = vec![1.; 3];
+    let mut y = vec![0.; 3];
+    A.gemv(&mut y, &x, 1., 0., MatrixShape::N);
+}
+
+#[test]
+#[should_panic]
+fn test_gemv_bad_dimension_T() {
+    let A = test_matrix_3x4();
+    let x = vec![1.; 3];
+    let mut y = vec![0.; 3];
+    A.gemv(&mut y, &x, 1., 0., MatrixShape::T);
+}
+
 #[test]
 fn test_symv() {
     let A = test_matrix_4x4_triu();
@@ -311,6 +556,43 @@ fn test_matrix_hcat_and_vcat() {
     assert_eq!(Ah.nzval, vec![1., 1., 1., -1., -1., -1.]);
 }
 
+#[test]
+fn test_matrix_blockdiag() {
+    // A =
+    //[-1.0  -17.0  6.0  10.0]
+    //[ 3.0     ⋅   7.0    ⋅ ]
+    //[  ⋅    -4.0   ⋅   -5.0]
+    let A = test_matrix_3x4();
+    let I = CscMatrix::<f64>::identity(2);
+    let Z = CscMatrix::<f64>::zeros((1, 1));
+
+    let C = CscMatrix::blockdiag(&[&I, &A, &Z, &I]);
+    assert!(C.check_format().is_ok());
+    assert_eq!(C.size(), (8, 9));
+    assert_eq!(C.nnz(), 2 * I.nnz() + A.nnz());
+
+    let D = CscMatrix::from(&[
+        [1., 0., 0., 0., 0., 0., 0., 0., 0.],     //
+        [0., 1., 0., 0., 0., 0., 0., 0., 0.],     //
+        [0., 0., -1., -17., 6., 10., 0., 0., 0.], //
+        [0., 0., 3., 0., 7., 0., 0., 0., 0.],     //
+        [0., 0., 0., -4., 0., -5., 0., 0., 0.],   //
+        [0., 0., 0., 0., 0., 0., 0., 0., 0.],     //
+        [0., 0., 0., 0., 0., 0., 0., 1., 0.],     //
+        [0., 0., 0., 0., 0., 0., 0., 0., 1.],     //
+    ]);
+    assert_eq!(C, D);
+
+    // agrees with concatenation of zero padded blocks
+    let Ah = CscMatrix::hcat(&A, &CscMatrix::zeros((3, 2)));
+    let Ih = CscMatrix::hcat(&CscMatrix::zeros((2, 4)), &I);
+    assert_eq!(CscMatrix::blockdiag(&[&A, &I]), CscMatrix::vcat(&Ah, &Ih));
+
+    let E = CscMatrix::<f64>::blockdiag(&[]);
+    assert_eq!(E.size(), (0, 0));
+    assert!(E.check_format().is_ok());
+}
+
 #[test]
 fn test_matrix_select_rows() {
     let A = test_matrix_4x4();
@@ -345,3 +627,353 @@ fn test_matrix_select_rows() {
     assert_eq!(Ared.rowval, Vec::<usize>::new());
     assert_eq!(Ared.nzval, Vec::<f64>::new());
 }
+
+// assemble a 4x4 matrix with a dense 3x3 triangle placed on the
+// diagonal at offset 1, using the same count / fill sequence as the
+// KKT assembly routines.  Returns the matrix and the block mapping.
+fn assemble_dense_triangle(shape: MatrixTriangle) -> (CscMatrix<f64>, Vec<usize>) {
+    let (n, offset, blockdim) = (4, 1, 3);
+    let nnz = (blockdim * (blockdim + 1)) >> 1;
+    let mut K = CscMatrix::<f64>::spalloc((n, n), nnz);
+    let mut blocktoKKT = vec![0; nnz];
+
+    // junk values so that we can confirm structural zeros are written
+    K.nzval.fill(f64::NAN);
+
+    K.colptr.fill(0);
+    K.colcount_dense_triangle(offset, blockdim, shape);
+    K.colcount_to_colptr();
+    K.fill_dense_triangle(&mut blocktoKKT, offset, blockdim, shape);
+    K.backshift_colptrs();
+
+    (K, blocktoKKT)
+}
+
+// returns the (row,col) coordinate of the ith stored entry of A
+fn entry_coord(A: &CscMatrix<f64>, idx: usize) -> (usize, usize) {
+    let col = (0..A.n)
+        .find(|&c| A.colptr[c] <= idx && idx < A.colptr[c + 1])
+        .unwrap();
+    (A.rowval[idx], col)
+}
+
+#[test]
+fn test_fill_dense_triangle_triu() {
+    let (K, map) = assemble_dense_triangle(MatrixTriangle::Triu);
+
+    // K =
+    //[ ⋅  ⋅  ⋅  ⋅ ]
+    //[ ⋅  0  0  0 ]
+    //[ ⋅  ⋅  0  0 ]
+    //[ ⋅  ⋅  ⋅  0 ]
+    assert!(K.check_format().is_ok());
+    assert_eq!(K.colptr, vec![0, 0, 1, 3, 6]);
+    assert_eq!(K.rowval, vec![1, 1, 2, 1, 2, 3]);
+    assert_eq!(K.nzval, vec![0.; 6]);
+    assert_eq!(map, vec![0, 1, 2, 3, 4, 5]);
+    assert!(K.is_triu());
+}
+
+#[test]
+fn test_fill_dense_triangle_tril() {
+    let (K, map) = assemble_dense_triangle(MatrixTriangle::Tril);
+
+    // K =
+    //[ ⋅  ⋅  ⋅  ⋅ ]
+    //[ ⋅  0  ⋅  ⋅ ]
+    //[ ⋅  0  0  ⋅ ]
+    //[ ⋅  0  0  0 ]
+    assert!(K.check_format().is_ok());
+    assert_eq!(K.colptr, vec![0, 0, 3, 5, 6]);
+    assert_eq!(K.rowval, vec![1, 2, 3, 2, 3, 3]);
+    assert_eq!(K.nzval, vec![0.; 6]);
+    assert_eq!(map, vec![0, 1, 3, 2, 4, 5]);
+    assert!(CscMatrix::from(K.t()).is_triu());
+}
+
+#[test]
+fn test_fill_dense_triangle_maps_transpose() {
+    // the kth entry of the (triu ordered) block data should land
+    // at (i,j) in the triu assembly and at (j,i) in the tril one
+    let (Ku, mapu) = assemble_dense_triangle(MatrixTriangle::Triu);
+    let (Kl, mapl) = assemble_dense_triangle(MatrixTriangle::Tril);
+
+    for (&du, &dl) in mapu.iter().zip(mapl.iter()) {
+        let (ru, cu) = entry_coord(&Ku, du);
+        let (rl, cl) = entry_coord(&Kl, dl);
+        assert!(ru <= cu);
+        assert_eq!((ru, cu), (cl, rl));
+    }
+}
+
+#[test]
+fn test_fill_missing_diag_with_offset() {
+    // P =
+    //[ ⋅  1.0  2.0]
+    //[ ⋅  3.0  4.0]
+    //[ ⋅   ⋅    ⋅ ]
+    // NB: missing diagonal entries in columns 0 and 2
+    let P = CscMatrix::new(
+        3,
+        3,
+        vec![0, 0, 2, 4],
+        vec![0, 1, 0, 1],
+        vec![1., 3., 2., 4.],
+    );
+
+    // place a 2x2 diagonal block first, then P and its
+    // missing diagonal entries starting at column 2
+    let nnz = 2 + P.nnz() + 2;
+    let mut K = CscMatrix::<f64>::spalloc((5, 5), nnz);
+    let mut diagtoKKT = vec![0; 2];
+    let mut PtoKKT = vec![0; P.nnz()];
+
+    // sentinels so that we can confirm every slot is written exactly once
+    K.rowval.fill(usize::MAX);
+    K.nzval.fill(f64::NAN);
+
+    K.colptr.fill(0);
+    K.colcount_diag(0, 2);
+    K.colcount_block(&P, 2, MatrixShape::N);
+    K.colcount_missing_diag(&P, 2);
+    K.colcount_to_colptr();
+    K.fill_diag(&mut diagtoKKT, 0, 2);
+    K.fill_block(&P, &mut PtoKKT, 2, 2, MatrixShape::N);
+    K.fill_missing_diag(&P, 2);
+    K.backshift_colptrs();
+
+    // K =
+    //[ 0  ⋅  ⋅   ⋅    ⋅ ]
+    //[ ⋅  0  ⋅   ⋅    ⋅ ]
+    //[ ⋅  ⋅  0  1.0  2.0]
+    //[ ⋅  ⋅  ⋅  3.0  4.0]
+    //[ ⋅  ⋅  ⋅   ⋅    0 ]
+    assert!(K.check_format().is_ok());
+    assert!(K.rowval.iter().all(|&r| r != usize::MAX));
+    assert_eq!(K.colptr, vec![0, 1, 2, 3, 5, 8]);
+    assert_eq!(K.rowval, vec![0, 1, 2, 2, 3, 2, 3, 4]);
+    assert_eq!(K.nzval, vec![0., 0., 0., 1., 3., 2., 4., 0.]);
+    assert_eq!(K.count_diagonal_entries(), 5);
+    assert_eq!(diagtoKKT, vec![0, 1]);
+    assert_eq!(PtoKKT, vec![3, 4, 5, 6]);
+}
+
+#[test]
+fn test_spalloc() {
+    let (m, n, nnz) = (3, 4, 5);
+    let mut A = CscMatrix::<f64>::spalloc((m, n), nnz);
+
+    assert_eq!(A.size(), (m, n));
+    assert_eq!(A.colptr.len(), n + 1);
+    assert_eq!(A.colptr[n], nnz);
+    assert_eq!(A.rowval.len(), nnz);
+    assert_eq!(A.nzval.len(), nnz);
+    assert_eq!(A.nnz(), nnz);
+
+    // populate a valid sparsity pattern into the allocated space
+    A.colptr.copy_from_slice(&[0, 2, 2, 3, 5]);
+    A.rowval.copy_from_slice(&[0, 2, 1, 0, 2]);
+    assert!(A.check_format().is_ok());
+
+    // empty allocations are consistent without modification
+    assert!(CscMatrix::<f64>::spalloc((m, n), 0).check_format().is_ok());
+    assert!(CscMatrix::<f64>::spalloc((0, 0), 0).check_format().is_ok());
+}
+
+#[test]
+fn test_transpose() {
+    // A =
+    //[-1.0  -17.0  6.0  10.0]
+    //[ 3.0     ⋅   7.0    ⋅ ]
+    //[  ⋅    -4.0   ⋅   -5.0]
+    let A = test_matrix_3x4();
+    let At = A.transpose();
+
+    let B = CscMatrix::from(&[
+        [-1., 3., 0.],   //
+        [-17., 0., -4.], //
+        [6., 7., 0.],    //
+        [10., 0., -5.],  //
+    ]);
+
+    assert_eq!(At, B);
+    assert!(At.check_format().is_ok());
+    assert_eq!(At.transpose(), A);
+}
+
+#[test]
+fn test_transpose_empty_columns() {
+    // A =
+    //[ ⋅  1.0  ⋅   ⋅ ]
+    //[ ⋅   ⋅   ⋅  2.0]
+    let A = CscMatrix::new(2, 4, vec![0, 0, 1, 1, 2], vec![0, 1], vec![1., 2.]);
+    let At = A.transpose();
+
+    assert_eq!(At.size(), (4, 2));
+    assert_eq!(At.colptr, vec![0, 1, 2]);
+    assert_eq!(At.rowval, vec![1, 3]);
+    assert_eq!(At.nzval, vec![1., 2.]);
+    assert!(At.check_format().is_ok());
+
+    let Z = CscMatrix::<f64>::zeros((3, 5)).transpose();
+    assert_eq!(Z, CscMatrix::<f64>::zeros((5, 3)));
+}
+
+#[test]
+fn test_transpose_explicit_zeros() {
+    // A =
+    //[ 0.0   ⋅   1.0]
+    //[ 2.0  0.0   ⋅ ]
+    // NB: entries (0,0) and (1,1) are stored zeros
+    let A = CscMatrix::new(
+        2,
+        3,
+        vec![0, 2, 3, 4],
+        vec![0, 1, 1, 0],
+        vec![0., 2., 0., 1.],
+    );
+    let At = A.transpose();
+
+    assert_eq!(At.nnz(), A.nnz());
+    assert_eq!(At.colptr, vec![0, 2, 4]);
+    assert_eq!(At.rowval, vec![0, 2, 0, 1]);
+    assert_eq!(At.nzval, vec![0., 1., 2., 0.]);
+    assert!(At.check_format().is_ok());
+}
+
+#[test]
+fn test_from_triplets() {
+    // entries out of order, including columns with no entries
+    let rows = vec![2, 0, 1, 3, 0];
+    let cols = vec![3, 3, 0, 0, 1];
+    let vals = vec![4., 3., 1., 2., 5.];
+    let A = CscMatrix::from_triplets(4, 5, &rows, &cols, &vals);
+
+    assert!(A.check_format().is_ok());
+    assert_eq!((A.m, A.n), (4, 5));
+    assert_eq!(A.colptr, vec![0, 2, 3, 3, 5, 5]);
+    assert_eq!(A.rowval, vec![1, 3, 0, 0, 2]);
+    assert_eq!(A.nzval, vec![1., 2., 5., 3., 4.]);
+}
+
+#[test]
+fn test_from_triplets_duplicates() {
+    // repeated coordinates are summed, and zeros are kept
+    let rows = vec![1, 0, 1, 1, 0, 0];
+    let cols = vec![0, 1, 0, 1, 1, 0];
+    let vals = vec![1., 2., 3., 0., -2., 0.];
+    let A = CscMatrix::from_triplets(2, 2, &rows, &cols, &vals);
+
+    assert!(A.check_format().is_ok());
+    assert_eq!(A.colptr, vec![0, 2, 4]);
+    assert_eq!(A.rowval, vec![0, 1, 0, 1]);
+    assert_eq!(A.nzval, vec![0., 4., 0., 0.]);
+}
+
+#[test]
+fn test_from_triplets_empty() {
+    let A = CscMatrix::<f64>::from_triplets(3, 2, &[], &[], &[]);
+    assert_eq!(A, CscMatrix::<f64>::zeros((3, 2)));
+
+    let A = CscMatrix::<f64>::from_triplets(0, 0, &[], &[], &[]);
+    assert_eq!(A, CscMatrix::<f64>::zeros((0, 0)));
+}
+
+#[test]
+#[should_panic]
+fn test_from_triplets_out_of_bounds() {
+    CscMatrix::from_triplets(2, 2, &[2], &[0], &[1.]);
+}
+
+#[test]
+#[should_panic]
+fn test_from_triplets_bad_lengths() {
+    CscMatrix::from_triplets(2, 2, &[0, 1], &[0], &[1., 2.]);
+}
+
+#[test]
+fn test_is_symmetric() {
+    // triu entries only, so symmetric only if diagonal
+    assert!(!test_matrix_4x4_triu().is_symmetric(0.));
+    assert!(!test_matrix_4x4().is_symmetric(0.));
+    assert!(!test_matrix_3x4().is_symmetric(0.));
+
+    let A = CscMatrix::from(&[
+        [4., 1., 0.],  //
+        [1., 2., -1.], //
+        [0., -1., 3.], //
+    ]);
+    assert!(A.is_symmetric(0.));
+
+    // asymmetric values, within and beyond the tolerance
+    let mut B = A.clone();
+    B.nzval[1] += 1e-10;
+    assert!(!B.is_symmetric(0.));
+    assert!(B.is_symmetric(1e-8));
+
+    // an entry without a structural transpose
+    let C = CscMatrix::from(&[
+        [4., 0.],    //
+        [1e-10, 2.], //
+    ]);
+    assert!(!C.is_symmetric(0.));
+    assert!(C.is_symmetric(1e-8));
+}
+
+#[test]
+fn test_to_symmetric() {
+    // full symmetric matrix with the triangles of test_matrix_4x4_triu
+    let Asym = CscMatrix::from(&[
+        [4., -3., 7., 0.],  //
+        [-3., 8., -1., 0.], //
+        [7., -1., 2., -3.], //
+        [0., 0., -3., 1.],  //
+    ]);
+
+    let Atriu = test_matrix_4x4_triu();
+    let B = Atriu.to_symmetric(MatrixTriangle::Triu);
+    assert!(B.check_format().is_ok());
+    assert!(B.is_symmetric(0.));
+    assert_eq!(B, Asym);
+
+    let Atril = Atriu.transpose();
+    assert_eq!(Atril.to_symmetric(MatrixTriangle::Tril), Asym);
+
+    // entries outside of the given triangle are ignored
+    assert_eq!(test_matrix_4x4().to_symmetric(MatrixTriangle::Triu), Asym);
+
+    // missing diagonal entries stay missing
+    let B = test_matrix_4x4_triu_2().to_symmetric(MatrixTriangle::Triu);
+    assert!(B.is_symmetric(0.));
+    assert_eq!(B.get_entry((1, 1)), None);
+}
+
+#[test]
+fn test_to_symmetric_gemv() {
+    // to_symmetric(U)*x = U*x + Uᵀ*x - diag(U)∘x
+    let U = test_matrix_4x4_triu();
+    let S = U.to_symmetric(MatrixTriangle::Triu);
+    let x = vec![1., -2., 3., 0.5];
+
+    let mut y = vec![0.; 4];
+    S.gemv(&mut y, &x, 1., 0., MatrixShape::N);
+
+    let mut yref = vec![0.; 4];
+    U.gemv(&mut yref, &x, 1., 0., MatrixShape::N);
+    U.gemv(&mut yref, &x, 1., 1., MatrixShape::T);
+    for (i, yi) in yref.iter_mut().enumerate() {
+        *yi -= U.get_entry((i, i)).unwrap_or(0.) * x[i];
+    }
+    assert!(y.dist(&yref) < 1e-14);
+
+    // and agrees with the symmetric view of U
+    let mut ysym = vec![0.; 4];
+    U.sym().symv(&mut ysym, &x, 1., 0.);
+    assert!(y.dist(&ysym) < 1e-14);
+}
+
+#[test]
+#[should_panic]
+fn test_to_symmetric_notsquare() {
+    test_matrix_3x4().to_symmetric(MatrixTriangle::Triu);
+}
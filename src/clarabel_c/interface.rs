@@ -0,0 +1,249 @@
+#![allow(non_snake_case)]
+
+use super::types::*;
+use crate::solver::{
+    core::{
+        cones::{SupportedConeT, SupportedConeT::*},
+        IPSolver,
+    },
+    implementations::default::*,
+};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Opaque solver handle for the C interface.
+pub struct ClarabelDefaultSolver {
+    solver: DefaultSolver<f64>,
+    // set if the last solve panicked
+    panicked: bool,
+}
+
+// function for sending cone specifications into rust
+// from the tagged form supplied from C
+
+unsafe fn c_arrays_to_cones(
+    cones: *const ClarabelConeSpec,
+    ncones: usize,
+) -> Option<Vec<SupportedConeT<f64>>> {
+    let mut out: Vec<SupportedConeT<f64>> = Vec::with_capacity(ncones);
+
+    for spec in to_vec(cones, ncones)? {
+        let cone = match spec.tag {
+            t if t == ClarabelConeTag::ZeroConeT as u32 => ZeroConeT(spec.dim),
+            t if t == ClarabelConeTag::NonnegativeConeT as u32 => NonnegativeConeT(spec.dim),
+            t if t == ClarabelConeTag::SecondOrderConeT as u32 => SecondOrderConeT(spec.dim),
+            t if t == ClarabelConeTag::ExponentialConeT as u32 => ExponentialConeT(),
+            t if t == ClarabelConeTag::PowerConeT as u32 => PowerConeT(spec.param),
+            t if t == ClarabelConeTag::GenPowerConeT as u32 => {
+                GenPowerConeT(to_vec(spec.vec, spec.vec_len)?, spec.dim)
+            }
+            #[cfg(feature = "sdp")]
+            t if t == ClarabelConeTag::PSDTriangleConeT as u32 => PSDTriangleConeT(spec.dim),
+            #[cfg(feature = "sdp")]
+            t if t == ClarabelConeTag::PSDTriangleComplexConeT as u32 => {
+                PSDTriangleComplexConeT(spec.dim)
+            }
+            t if t == ClarabelConeTag::BoxConeT as u32 => {
+                if spec.vec_len != 2 * spec.dim {
+                    return None;
+                }
+                let mut lower = to_vec(spec.vec, spec.vec_len)?;
+                let upper = lower.split_off(spec.dim);
+                BoxConeT { lower, upper }
+            }
+            _ => return None,
+        };
+        out.push(cone)
+    }
+    Some(out)
+}
+
+/// Default solver settings.
+#[no_mangle]
+pub extern "C" fn clarabel_default_settings() -> ClarabelDefaultSettings {
+    ClarabelDefaultSettings::from(&DefaultSettings::<f64>::default())
+}
+
+/// Create a new solver for the problem data `(P,q,A,b,cones)`.  `P` should
+/// be upper triangular, `q` has length `P.n`, `b` has length `A.m` and
+/// `cones` is an array of `ncones` cone descriptions.   All data is copied.
+///
+/// Returns null if the problem data is invalid.   The solver must be
+/// released with `clarabel_solver_free`.
+///
+/// # Safety
+///
+/// All pointers must be valid for arrays of the stated dimensions.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solver_new(
+    P: *const ClarabelCscMatrix,
+    q: *const f64,
+    A: *const ClarabelCscMatrix,
+    b: *const f64,
+    ncones: usize,
+    cones: *const ClarabelConeSpec,
+    settings: *const ClarabelDefaultSettings,
+) -> *mut ClarabelDefaultSolver {
+    let solver = solver_from_c(P, q, A, b, ncones, cones, settings);
+
+    match solver {
+        Some(solver) => Box::into_raw(Box::new(ClarabelDefaultSolver {
+            solver,
+            panicked: false,
+        })),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe fn solver_from_c(
+    P: *const ClarabelCscMatrix,
+    q: *const f64,
+    A: *const ClarabelCscMatrix,
+    b: *const f64,
+    ncones: usize,
+    cones: *const ClarabelConeSpec,
+    settings: *const ClarabelDefaultSettings,
+) -> Option<DefaultSolver<f64>> {
+    let P = P.as_ref()?.to_CscMatrix()?;
+    let A = A.as_ref()?.to_CscMatrix()?;
+    let q = to_vec(q, P.n)?;
+    let b = to_vec(b, A.m)?;
+    let cones = c_arrays_to_cones(cones, ncones)?;
+    let settings = DefaultSettings::<f64>::from(settings.as_ref()?);
+
+    // invalid data is reported as an error, but any other
+    // panic during setup must not unwind into C either
+    catch_unwind(AssertUnwindSafe(|| {
+        DefaultSolver::try_new(&P, &q, &A, &b, &cones, settings).ok()
+    }))
+    .ok()
+    .flatten()
+}
+
+// runs `f` on the solver behind a handle, returning `default` if
+// the handle is null or `f` panics.  Panics must not unwind into C
+
+unsafe fn with_solver<R>(
+    solver: *const ClarabelDefaultSolver,
+    default: R,
+    f: impl FnOnce(&ClarabelDefaultSolver) -> R,
+) -> R {
+    match solver.as_ref() {
+        Some(s) => catch_unwind(AssertUnwindSafe(|| f(s))).unwrap_or(default),
+        None => default,
+    }
+}
+
+/// Solve the problem, returning the termination status.
+///
+/// Returns `InternalError` if `solver` is null or the solver panics, in
+/// which case `clarabel_solution_status` also reports `InternalError`
+/// until the next successful solve.
+///
+/// # Safety
+///
+/// `solver` must be null or a handle returned by `clarabel_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solve(
+    solver: *mut ClarabelDefaultSolver,
+) -> ClarabelSolverStatus {
+    let s = match solver.as_mut() {
+        Some(s) => s,
+        None => return ClarabelSolverStatus::InternalError,
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| s.solver.solve()));
+    s.panicked = result.is_err();
+
+    if s.panicked {
+        ClarabelSolverStatus::InternalError
+    } else {
+        s.solver.solution.status.into()
+    }
+}
+
+/// Release a solver.   Passing null is allowed.
+///
+/// # Safety
+///
+/// `solver` must be a handle returned by `clarabel_solver_new`, and
+/// must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solver_free(solver: *mut ClarabelDefaultSolver) {
+    if !solver.is_null() {
+        drop(Box::from_raw(solver));
+    }
+}
+
+// accessors for the solution.   Returned arrays are owned by
+// the solver and remain valid until the next solve or free.
+
+/// Primal solution `x`, of length `n`.   Returns null if `solver` is null.
+///
+/// # Safety
+///
+/// `solver` must be null or a handle returned by `clarabel_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solution_x(solver: *const ClarabelDefaultSolver) -> *const f64 {
+    with_solver(solver, std::ptr::null(), |s| s.solver.solution.x.as_ptr())
+}
+
+/// Dual solution `z`, of length `m`.   Returns null if `solver` is null.
+///
+/// # Safety
+///
+/// `solver` must be null or a handle returned by `clarabel_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solution_z(solver: *const ClarabelDefaultSolver) -> *const f64 {
+    with_solver(solver, std::ptr::null(), |s| s.solver.solution.z.as_ptr())
+}
+
+/// Primal slacks `s`, of length `m`.   Returns null if `solver` is null.
+///
+/// # Safety
+///
+/// `solver` must be null or a handle returned by `clarabel_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solution_s(solver: *const ClarabelDefaultSolver) -> *const f64 {
+    with_solver(solver, std::ptr::null(), |s| s.solver.solution.s.as_ptr())
+}
+
+/// Termination status.   Returns `InternalError` if `solver` is null or
+/// the last solve panicked.
+///
+/// # Safety
+///
+/// `solver` must be null or a handle returned by `clarabel_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solution_status(
+    solver: *const ClarabelDefaultSolver,
+) -> ClarabelSolverStatus {
+    with_solver(solver, ClarabelSolverStatus::InternalError, |s| {
+        if s.panicked {
+            ClarabelSolverStatus::InternalError
+        } else {
+            s.solver.solution.status.into()
+        }
+    })
+}
+
+/// Primal objective value.   Returns NaN if `solver` is null.
+///
+/// # Safety
+///
+/// `solver` must be null or a handle returned by `clarabel_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solution_obj_val(solver: *const ClarabelDefaultSolver) -> f64 {
+    with_solver(solver, f64::NAN, |s| s.solver.solution.obj_val)
+}
+
+/// Dual objective value.   Returns NaN if `solver` is null.
+///
+/// # Safety
+///
+/// `solver` must be null or a handle returned by `clarabel_solver_new`.
+#[no_mangle]
+pub unsafe extern "C" fn clarabel_solution_obj_val_dual(
+    solver: *const ClarabelDefaultSolver,
+) -> f64 {
+    with_solver(solver, f64::NAN, |s| s.solver.solution.obj_val_dual)
+}
@@ -0,0 +1,31 @@
+//! Clarabel C interface.
+//!
+//! This module exposes the default solver through a set of `extern "C"`
+//! functions, allowing Clarabel to be embedded directly in C or C++ projects.
+//! A solver is created with [`clarabel_solver_new`](interface::clarabel_solver_new),
+//! which returns an opaque handle that must eventually be released with
+//! [`clarabel_solver_free`](interface::clarabel_solver_free).
+//!
+//! The corresponding C header is `include/clarabel.h`.   It is generated using
+//! [cbindgen](https://github.com/mozilla/cbindgen) and can be rebuilt after
+//! changes to this module with
+//!
+//! ```text
+//! cbindgen --config cbindgen.toml --output include/clarabel.h src/clarabel_c/mod.rs
+//! ```
+//!
+//! Example usage from C:
+//!
+//! ```c
+//! ClarabelDefaultSettings settings = clarabel_default_settings();
+//! ClarabelDefaultSolver *solver = clarabel_solver_new(&P, q, &A, b, ncones, cones, &settings);
+//! clarabel_solve(solver);
+//! const double *x = clarabel_solution_x(solver);
+//! clarabel_solver_free(solver);
+//! ```
+
+mod interface;
+mod types;
+
+pub use interface::*;
+pub use types::*;
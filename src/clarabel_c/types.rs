@@ -0,0 +1,462 @@
+#![allow(non_snake_case)]
+
+use crate::algebra::CscMatrix;
+use crate::solver::{core::SolverStatus, implementations::default::*};
+use std::slice;
+
+// The types defined here are for exchanging data between
+// Rust and C.   Settings and status values are repeated
+// here in a C compatible form, as for the python interface.
+
+/// Sparse matrix in compressed sparse column format, with
+/// 0-based indexing.  Arrays are borrowed and must remain
+/// valid only for the duration of the call they are passed to.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct ClarabelCscMatrix {
+    /// number of rows
+    pub m: usize,
+    /// number of columns
+    pub n: usize,
+    /// column pointers, of length `n + 1`
+    pub colptr: *const usize,
+    /// row indices, of length `colptr[n]`
+    pub rowval: *const usize,
+    /// nonzero values, of length `colptr[n]`
+    pub nzval: *const f64,
+}
+
+impl ClarabelCscMatrix {
+    pub(crate) unsafe fn to_CscMatrix(&self) -> Option<CscMatrix<f64>> {
+        let colptr = to_vec(self.colptr, self.n + 1)?;
+        let nnz = *colptr.last().unwrap();
+        let rowval = to_vec(self.rowval, nnz)?;
+        let nzval = to_vec(self.nzval, nnz)?;
+        Some(CscMatrix::new(self.m, self.n, colptr, rowval, nzval))
+    }
+}
+
+/// Cone type tags used in [`ClarabelConeSpec`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelConeTag {
+    /// zero cone of dimension `dim`
+    ZeroConeT = 0,
+    /// nonnegative orthant of dimension `dim`
+    NonnegativeConeT = 1,
+    /// second order cone of dimension `dim`
+    SecondOrderConeT = 2,
+    /// exponential cone
+    ExponentialConeT = 3,
+    /// power cone with exponent `param`
+    PowerConeT = 4,
+    /// generalized power cone with exponents `vec` and dual dimension `dim`
+    GenPowerConeT = 5,
+    /// positive semidefinite cone of triangular dimension `dim`.
+    /// Requires the `sdp` feature.
+    PSDTriangleConeT = 6,
+    /// box cone with `dim` bounded elements, i.e. of dimension `dim + 1`,
+    /// with `vec` holding the `dim` lower bounds followed by the `dim`
+    /// upper bounds
+    BoxConeT = 7,
+    /// complex Hermitian positive semidefinite cone of matrix
+    /// dimension `dim`.  Requires the `sdp` feature.
+    PSDTriangleComplexConeT = 8,
+}
+
+/// Description of a single cone.   The fields used depend on the tag.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct ClarabelConeSpec {
+    /// cone type, a value of [`ClarabelConeTag`]
+    pub tag: u32,
+    /// cone dimension
+    pub dim: usize,
+    /// scalar cone parameter
+    pub param: f64,
+    /// vector parameter
+    pub vec: *const f64,
+    /// length of `vec`
+    pub vec_len: usize,
+}
+
+/// LDL factorization methods for [`ClarabelDefaultSettings`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelDirectSolveMethod {
+    QDLDL = 0,
+}
+
+/// KKT system forms for [`ClarabelDefaultSettings`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelKKTForm {
+    Full = 0,
+    Reduced = 1,
+}
+
+/// Initialization strategies for [`ClarabelDefaultSettings`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelInitStrategy {
+    Default = 0,
+    EqualityProject = 1,
+}
+
+/// Equilibration methods for [`ClarabelDefaultSettings`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelEquilibrationMethod {
+    Ruiz = 0,
+    L2 = 1,
+    Geometric = 2,
+}
+
+/// Termination modes for [`ClarabelDefaultSettings`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelTerminationMode {
+    Standard = 0,
+    GapPriority = 1,
+}
+
+/// Stopping metrics for the indirect solver in [`ClarabelDefaultSettings`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelIndirectStopMetric {
+    Absolute = 0,
+    Relative = 1,
+    Preconditioned = 2,
+}
+
+/// Solver settings.   Fields are as in [`DefaultSettings`].
+/// Obtain a default set from `clarabel_default_settings`.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct ClarabelDefaultSettings {
+    pub max_iter: u32,
+    pub time_limit: f64,
+    pub verbose: bool,
+    pub print_stride: u32,
+    pub collect_timings: bool,
+    pub time_limit_hard: bool,
+    pub max_step_fraction: f64,
+    pub tol_gap_abs: f64,
+    pub tol_gap_rel: f64,
+    pub tol_feas: f64,
+    pub tol_infeas_abs: f64,
+    pub tol_infeas_rel: f64,
+    pub tol_ktratio: f64,
+    pub reduced_tol_gap_abs: f64,
+    pub reduced_tol_gap_rel: f64,
+    pub reduced_tol_feas: f64,
+    pub reduced_tol_infeas_abs: f64,
+    pub reduced_tol_infeas_rel: f64,
+    pub reduced_tol_ktratio: f64,
+    pub termination_mode: ClarabelTerminationMode,
+    pub tol_normalize: bool,
+    pub insufficient_progress_window: u32,
+    pub insufficient_progress_tol: f64,
+    pub equilibrate_enable: bool,
+    pub equilibrate_max_iter: u32,
+    pub equilibrate_min_scaling: f64,
+    pub equilibrate_max_scaling: f64,
+    pub equilibration_method: ClarabelEquilibrationMethod,
+    /// Zero uses the objective scaling chosen during equilibration
+    pub objective_scaling: f64,
+    pub init_strategy: ClarabelInitStrategy,
+    pub linesearch_backtrack_step: f64,
+    pub min_switch_step_length: f64,
+    pub min_terminate_step_length: f64,
+    pub max_centrality_corrections: u32,
+    pub direct_kkt_solver: bool,
+    pub direct_solve_method: ClarabelDirectSolveMethod,
+    pub kkt_form: ClarabelKKTForm,
+    pub dense_kkt_threshold: usize,
+    pub indirect_stop_metric: ClarabelIndirectStopMetric,
+    pub direct_solve_cache_symbolic: bool,
+    /// Zero places no limit on the size of the KKT matrix
+    pub max_kkt_nnz: usize,
+    /// When false, random_seed is ignored and no seed is given
+    pub random_seed_enable: bool,
+    pub random_seed: u64,
+    pub static_regularization_enable: bool,
+    pub static_regularization_constant: f64,
+    pub static_regularization_proportional: f64,
+    pub dynamic_regularization_enable: bool,
+    pub dynamic_regularization_eps: f64,
+    pub dynamic_regularization_delta: f64,
+    pub factorization_max_retries: u32,
+    pub factorization_retry_factor: f64,
+    pub iterative_refinement_enable: bool,
+    pub iterative_refinement_reltol: f64,
+    pub iterative_refinement_abstol: f64,
+    pub iterative_refinement_max_iter: u32,
+    pub iterative_refinement_stop_ratio: f64,
+    pub presolve_enable: bool,
+    pub crossover: bool,
+}
+
+impl From<&DefaultSettings<f64>> for ClarabelDefaultSettings {
+    fn from(set: &DefaultSettings<f64>) -> Self {
+        let direct_solve_method = match set.direct_solve_method.as_str() {
+            "qdldl" => ClarabelDirectSolveMethod::QDLDL,
+            _ => unreachable!(),
+        };
+        let kkt_form = match set.kkt_form.as_str() {
+            "full" => ClarabelKKTForm::Full,
+            "reduced" => ClarabelKKTForm::Reduced,
+            _ => unreachable!(),
+        };
+        let init_strategy = match set.init_strategy.as_str() {
+            "default" => ClarabelInitStrategy::Default,
+            "equality_project" => ClarabelInitStrategy::EqualityProject,
+            _ => unreachable!(),
+        };
+        let equilibration_method = match set.equilibration_method.as_str() {
+            "ruiz" => ClarabelEquilibrationMethod::Ruiz,
+            "l2" => ClarabelEquilibrationMethod::L2,
+            "geometric" => ClarabelEquilibrationMethod::Geometric,
+            _ => unreachable!(),
+        };
+        let termination_mode = match set.termination_mode.as_str() {
+            "standard" => ClarabelTerminationMode::Standard,
+            "gap_priority" => ClarabelTerminationMode::GapPriority,
+            _ => unreachable!(),
+        };
+        let indirect_stop_metric = match set.indirect_stop_metric.as_str() {
+            "absolute" => ClarabelIndirectStopMetric::Absolute,
+            "relative" => ClarabelIndirectStopMetric::Relative,
+            "preconditioned" => ClarabelIndirectStopMetric::Preconditioned,
+            _ => unreachable!(),
+        };
+        ClarabelDefaultSettings {
+            max_iter: set.max_iter,
+            time_limit: set.time_limit,
+            verbose: set.verbose,
+            print_stride: set.print_stride,
+            collect_timings: set.collect_timings,
+            time_limit_hard: set.time_limit_hard,
+            max_step_fraction: set.max_step_fraction,
+            tol_gap_abs: set.tol_gap_abs,
+            tol_gap_rel: set.tol_gap_rel,
+            tol_feas: set.tol_feas,
+            tol_infeas_abs: set.tol_infeas_abs,
+            tol_infeas_rel: set.tol_infeas_rel,
+            tol_ktratio: set.tol_ktratio,
+            reduced_tol_gap_abs: set.reduced_tol_gap_abs,
+            reduced_tol_gap_rel: set.reduced_tol_gap_rel,
+            reduced_tol_feas: set.reduced_tol_feas,
+            reduced_tol_infeas_abs: set.reduced_tol_infeas_abs,
+            reduced_tol_infeas_rel: set.reduced_tol_infeas_rel,
+            reduced_tol_ktratio: set.reduced_tol_ktratio,
+            termination_mode,
+            tol_normalize: set.tol_normalize,
+            insufficient_progress_window: set.insufficient_progress_window,
+            insufficient_progress_tol: set.insufficient_progress_tol,
+            equilibrate_enable: set.equilibrate_enable,
+            equilibrate_max_iter: set.equilibrate_max_iter,
+            equilibrate_min_scaling: set.equilibrate_min_scaling,
+            equilibrate_max_scaling: set.equilibrate_max_scaling,
+            equilibration_method,
+            objective_scaling: set.objective_scaling.unwrap_or(0.),
+            init_strategy,
+            linesearch_backtrack_step: set.linesearch_backtrack_step,
+            min_switch_step_length: set.min_switch_step_length,
+            min_terminate_step_length: set.min_terminate_step_length,
+            max_centrality_corrections: set.max_centrality_corrections,
+            direct_kkt_solver: set.direct_kkt_solver,
+            direct_solve_method,
+            kkt_form,
+            dense_kkt_threshold: set.dense_kkt_threshold,
+            indirect_stop_metric,
+            direct_solve_cache_symbolic: set.direct_solve_cache_symbolic,
+            max_kkt_nnz: set.max_kkt_nnz.unwrap_or(0),
+            random_seed_enable: set.random_seed.is_some(),
+            random_seed: set.random_seed.unwrap_or(0),
+            static_regularization_enable: set.static_regularization_enable,
+            static_regularization_constant: set.static_regularization_constant,
+            static_regularization_proportional: set.static_regularization_proportional,
+            dynamic_regularization_enable: set.dynamic_regularization_enable,
+            dynamic_regularization_eps: set.dynamic_regularization_eps,
+            dynamic_regularization_delta: set.dynamic_regularization_delta,
+            factorization_max_retries: set.factorization_max_retries,
+            factorization_retry_factor: set.factorization_retry_factor,
+            iterative_refinement_enable: set.iterative_refinement_enable,
+            iterative_refinement_reltol: set.iterative_refinement_reltol,
+            iterative_refinement_abstol: set.iterative_refinement_abstol,
+            iterative_refinement_max_iter: set.iterative_refinement_max_iter,
+            iterative_refinement_stop_ratio: set.iterative_refinement_stop_ratio,
+            presolve_enable: set.presolve_enable,
+            crossover: set.crossover,
+        }
+    }
+}
+
+impl From<&ClarabelDefaultSettings> for DefaultSettings<f64> {
+    fn from(set: &ClarabelDefaultSettings) -> Self {
+        let direct_solve_method = match set.direct_solve_method {
+            ClarabelDirectSolveMethod::QDLDL => "qdldl".to_string(),
+        };
+        let kkt_form = match set.kkt_form {
+            ClarabelKKTForm::Full => "full",
+            ClarabelKKTForm::Reduced => "reduced",
+        }
+        .to_string();
+        let init_strategy = match set.init_strategy {
+            ClarabelInitStrategy::Default => "default",
+            ClarabelInitStrategy::EqualityProject => "equality_project",
+        }
+        .to_string();
+        let equilibration_method = match set.equilibration_method {
+            ClarabelEquilibrationMethod::Ruiz => "ruiz",
+            ClarabelEquilibrationMethod::L2 => "l2",
+            ClarabelEquilibrationMethod::Geometric => "geometric",
+        }
+        .to_string();
+        let termination_mode = match set.termination_mode {
+            ClarabelTerminationMode::Standard => "standard",
+            ClarabelTerminationMode::GapPriority => "gap_priority",
+        }
+        .to_string();
+        let indirect_stop_metric = match set.indirect_stop_metric {
+            ClarabelIndirectStopMetric::Absolute => "absolute",
+            ClarabelIndirectStopMetric::Relative => "relative",
+            ClarabelIndirectStopMetric::Preconditioned => "preconditioned",
+        }
+        .to_string();
+        DefaultSettings::<f64> {
+            max_iter: set.max_iter,
+            time_limit: set.time_limit,
+            verbose: set.verbose,
+            print_stride: set.print_stride,
+            collect_timings: set.collect_timings,
+            time_limit_hard: set.time_limit_hard,
+            max_step_fraction: set.max_step_fraction,
+            tol_gap_abs: set.tol_gap_abs,
+            tol_gap_rel: set.tol_gap_rel,
+            tol_feas: set.tol_feas,
+            tol_infeas_abs: set.tol_infeas_abs,
+            tol_infeas_rel: set.tol_infeas_rel,
+            tol_ktratio: set.tol_ktratio,
+            reduced_tol_gap_abs: set.reduced_tol_gap_abs,
+            reduced_tol_gap_rel: set.reduced_tol_gap_rel,
+            reduced_tol_feas: set.reduced_tol_feas,
+            reduced_tol_infeas_abs: set.reduced_tol_infeas_abs,
+            reduced_tol_infeas_rel: set.reduced_tol_infeas_rel,
+            reduced_tol_ktratio: set.reduced_tol_ktratio,
+            termination_mode,
+            tol_normalize: set.tol_normalize,
+            insufficient_progress_window: set.insufficient_progress_window,
+            insufficient_progress_tol: set.insufficient_progress_tol,
+            equilibrate_enable: set.equilibrate_enable,
+            equilibrate_max_iter: set.equilibrate_max_iter,
+            equilibrate_min_scaling: set.equilibrate_min_scaling,
+            equilibrate_max_scaling: set.equilibrate_max_scaling,
+            equilibration_method,
+            objective_scaling: if set.objective_scaling == 0. {
+                None
+            } else {
+                Some(set.objective_scaling)
+            },
+            init_strategy,
+            linesearch_backtrack_step: set.linesearch_backtrack_step,
+            min_switch_step_length: set.min_switch_step_length,
+            min_terminate_step_length: set.min_terminate_step_length,
+            max_centrality_corrections: set.max_centrality_corrections,
+            direct_kkt_solver: set.direct_kkt_solver,
+            direct_solve_method,
+            kkt_form,
+            dense_kkt_threshold: set.dense_kkt_threshold,
+            indirect_stop_metric,
+            direct_solve_cache_symbolic: set.direct_solve_cache_symbolic,
+            max_kkt_nnz: match set.max_kkt_nnz {
+                0 => None,
+                max => Some(max),
+            },
+            random_seed: if set.random_seed_enable {
+                Some(set.random_seed)
+            } else {
+                None
+            },
+            static_regularization_enable: set.static_regularization_enable,
+            static_regularization_constant: set.static_regularization_constant,
+            static_regularization_proportional: set.static_regularization_proportional,
+            dynamic_regularization_enable: set.dynamic_regularization_enable,
+            dynamic_regularization_eps: set.dynamic_regularization_eps,
+            dynamic_regularization_delta: set.dynamic_regularization_delta,
+            factorization_max_retries: set.factorization_max_retries,
+            factorization_retry_factor: set.factorization_retry_factor,
+            iterative_refinement_enable: set.iterative_refinement_enable,
+            iterative_refinement_reltol: set.iterative_refinement_reltol,
+            iterative_refinement_abstol: set.iterative_refinement_abstol,
+            iterative_refinement_max_iter: set.iterative_refinement_max_iter,
+            iterative_refinement_stop_ratio: set.iterative_refinement_stop_ratio,
+            presolve_enable: set.presolve_enable,
+            crossover: set.crossover,
+            // iterate history, iteration records, μ history and scaled
+            // solutions are not accessible through the C interface
+            save_iterates: false,
+            save_iterates_max: None,
+            collect_iteration_records: false,
+            collect_mu_history: false,
+            return_scaled: false,
+            // nor are KKT dumps on numerical failure
+            dump_on_failure: None,
+        }
+    }
+}
+
+/// Solver termination status.   Values are as in [`SolverStatus`], with
+/// the addition of `InternalError` for a null handle or a solver panic.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClarabelSolverStatus {
+    Unsolved = 0,
+    Solved,
+    PrimalInfeasible,
+    DualInfeasible,
+    AlmostSolved,
+    AlmostPrimalInfeasible,
+    AlmostDualInfeasible,
+    MaxIterations,
+    MaxTime,
+    NumericalError,
+    InsufficientProgress,
+    CallbackTerminated,
+    InsufficientMemory,
+    InternalError,
+}
+
+impl From<SolverStatus> for ClarabelSolverStatus {
+    fn from(status: SolverStatus) -> Self {
+        match status {
+            SolverStatus::Unsolved => ClarabelSolverStatus::Unsolved,
+            SolverStatus::Solved => ClarabelSolverStatus::Solved,
+            SolverStatus::PrimalInfeasible => ClarabelSolverStatus::PrimalInfeasible,
+            SolverStatus::DualInfeasible => ClarabelSolverStatus::DualInfeasible,
+            SolverStatus::AlmostSolved => ClarabelSolverStatus::AlmostSolved,
+            SolverStatus::AlmostPrimalInfeasible => ClarabelSolverStatus::AlmostPrimalInfeasible,
+            SolverStatus::AlmostDualInfeasible => ClarabelSolverStatus::AlmostDualInfeasible,
+            SolverStatus::MaxIterations => ClarabelSolverStatus::MaxIterations,
+            SolverStatus::MaxTime => ClarabelSolverStatus::MaxTime,
+            SolverStatus::NumericalError => ClarabelSolverStatus::NumericalError,
+            SolverStatus::InsufficientProgress => ClarabelSolverStatus::InsufficientProgress,
+            SolverStatus::CallbackTerminated => ClarabelSolverStatus::CallbackTerminated,
+            SolverStatus::InsufficientMemory => ClarabelSolverStatus::InsufficientMemory,
+        }
+    }
+}
+
+// copy a C array into a Vec.   Returns None for a null
+// pointer, unless the array is empty.
+
+pub(crate) unsafe fn to_vec<T: Clone>(p: *const T, len: usize) -> Option<Vec<T>> {
+    if len == 0 {
+        Some(Vec::new())
+    } else if p.is_null() {
+        None
+    } else {
+        Some(slice::from_raw_parts(p, len).to_vec())
+    }
+}
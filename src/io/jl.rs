@@ -0,0 +1,245 @@
+//! Reader and writer for problem data in the JSON format used by Clarabel.jl.
+//!
+//! Problems in the standard Clarabel form
+//!
+//! ```text
+//! minimize    (1/2)x'Px + q'x
+//! subject to  Ax + s = b,  s ∈ K
+//! ```
+//!
+//! are stored as a single JSON object
+//!
+//! ```text
+//! {
+//!   "P": {"m": 2, "n": 2, "colptr": [1,2,3], "rowval": [1,2], "nzval": [4.0,2.0]},
+//!   "q": [1.0, 1.0],
+//!   "A": { ... },
+//!   "b": [1.0, 1.0],
+//!   "cones": [{"type": "NonnegativeConeT", "dim": 2}]
+//! }
+//! ```
+//!
+//! with `P` and `A` in the layout of a Julia `SparseMatrixCSC`, i.e. with one
+//! based `colptr` and `rowval`.  Each cone is given by the name of the
+//! Clarabel.jl cone type in a `"type"` field, together with the fields of that
+//! type:
+//!
+//! | Julia type         | fields           |
+//! |--------------------|------------------|
+//! | `ZeroConeT`        | `dim`            |
+//! | `NonnegativeConeT` | `dim`            |
+//! | `SecondOrderConeT` | `dim`            |
+//! | `ExponentialConeT` |                  |
+//! | `PowerConeT`       | `α`              |
+//! | `GenPowerConeT`    | `α`, `dim2`      |
+//! | `PSDTriangleConeT` | `dim`            |
+//!
+//! Any other fields in the file, e.g. solver settings, are ignored on reading.
+//! [`BoxConeT`](crate::solver::SupportedConeT::BoxConeT) and
+//! [`PSDTriangleComplexConeT`](crate::solver::SupportedConeT::PSDTriangleComplexConeT)
+//! constraints have no Clarabel.jl equivalent and cannot be written.
+
+#![allow(non_snake_case)]
+
+use crate::algebra::{CscMatrix, ShapedMatrix};
+use crate::solver::core::cones::SupportedConeAsTag;
+use crate::solver::{SupportedConeT, SupportedConeT::*};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use thiserror::Error;
+
+/// Error type returned by [`read_jl`] and [`write_jl`].
+#[derive(Error, Debug)]
+pub enum JlError {
+    #[error("Error reading or writing Clarabel.jl data")]
+    Json(#[from] serde_json::Error),
+    #[error("Cones not supported in Clarabel.jl format: {}", .0.join(", "))]
+    UnsupportedCones(Vec<&'static str>),
+    #[error("Incompatible problem data dimensions")]
+    IncompatibleDimensions,
+    #[error("Malformed sparse matrix {0}")]
+    BadMatrix(&'static str),
+}
+
+/// Problem data as read from a Clarabel.jl file, as `(P, q, A, b, cones)`.
+pub type JlProblem = (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+);
+
+#[derive(Serialize, Deserialize)]
+struct JlSparseMatrix {
+    m: usize,
+    n: usize,
+    colptr: Vec<usize>,
+    rowval: Vec<usize>,
+    nzval: Vec<f64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum JlCone {
+    ZeroConeT { dim: usize },
+    NonnegativeConeT { dim: usize },
+    SecondOrderConeT { dim: usize },
+    ExponentialConeT {},
+    PowerConeT { α: f64 },
+    GenPowerConeT { α: Vec<f64>, dim2: usize },
+    PSDTriangleConeT { dim: usize },
+}
+
+#[derive(Serialize, Deserialize)]
+struct JlProblemData {
+    P: JlSparseMatrix,
+    q: Vec<f64>,
+    A: JlSparseMatrix,
+    b: Vec<f64>,
+    cones: Vec<JlCone>,
+}
+
+/// Write problem data in the JSON format used by Clarabel.jl.
+///
+/// Takes problem data `(P, q, A, b, cones)` in the same form as
+/// [`DefaultSolver::new`](crate::solver::DefaultSolver::new), with `P` upper
+/// triangular.   A [`JlError::UnsupportedCones`] listing any cone types with
+/// no Clarabel.jl equivalent is returned if there are any.
+pub fn write_jl<W: Write>(
+    writer: W,
+    P: &CscMatrix<f64>,
+    q: &[f64],
+    A: &CscMatrix<f64>,
+    b: &[f64],
+    cones: &[SupportedConeT<f64>],
+) -> Result<(), JlError> {
+    let n = q.len();
+    let m = b.len();
+    let cone_rows: usize = cones.iter().map(|cone| cone.nvars()).sum();
+    if P.size() != (n, n) || A.size() != (m, n) || cone_rows != m {
+        return Err(JlError::IncompatibleDimensions);
+    }
+
+    let data = JlProblemData {
+        P: to_jl_matrix(P),
+        q: q.to_vec(),
+        A: to_jl_matrix(A),
+        b: b.to_vec(),
+        cones: to_jl_cones(cones)?,
+    };
+    serde_json::to_writer(writer, &data)?;
+    Ok(())
+}
+
+/// Read problem data in the JSON format used by Clarabel.jl.
+pub fn read_jl<R: Read>(reader: R) -> Result<JlProblem, JlError> {
+    let data: JlProblemData = serde_json::from_reader(reader)?;
+
+    let P = from_jl_matrix(data.P, "P")?;
+    let A = from_jl_matrix(data.A, "A")?;
+    let cones = from_jl_cones(data.cones)?;
+
+    let n = data.q.len();
+    let m = data.b.len();
+    let cone_rows: usize = cones.iter().map(|cone| cone.nvars()).sum();
+    if P.size() != (n, n) || A.size() != (m, n) || cone_rows != m {
+        return Err(JlError::IncompatibleDimensions);
+    }
+
+    Ok((P, data.q, A, data.b, cones))
+}
+
+fn to_jl_matrix(M: &CscMatrix<f64>) -> JlSparseMatrix {
+    JlSparseMatrix {
+        m: M.m,
+        n: M.n,
+        colptr: M.colptr.iter().map(|&p| p + 1).collect(),
+        rowval: M.rowval.iter().map(|&r| r + 1).collect(),
+        nzval: M.nzval.clone(),
+    }
+}
+
+fn from_jl_matrix(M: JlSparseMatrix, name: &'static str) -> Result<CscMatrix<f64>, JlError> {
+    let bad = || JlError::BadMatrix(name);
+
+    if M.colptr.len() != M.n + 1 || M.rowval.len() != M.nzval.len() {
+        return Err(bad());
+    }
+    let colptr = M
+        .colptr
+        .iter()
+        .map(|&p| p.checked_sub(1))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(bad)?;
+    let rowval = M
+        .rowval
+        .iter()
+        .map(|&r| r.checked_sub(1).filter(|&r| r < M.m))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(bad)?;
+    if colptr[0] != 0 || colptr[M.n] != rowval.len() || colptr.windows(2).any(|w| w[0] > w[1]) {
+        return Err(bad());
+    }
+
+    Ok(CscMatrix::new(M.m, M.n, colptr, rowval, M.nzval))
+}
+
+fn to_jl_cones(cones: &[SupportedConeT<f64>]) -> Result<Vec<JlCone>, JlError> {
+    let mut out = Vec::with_capacity(cones.len());
+    let mut unsupported = Vec::new();
+
+    for cone in cones {
+        let jlcone = match cone {
+            ZeroConeT(dim) => JlCone::ZeroConeT { dim: *dim },
+            NonnegativeConeT(dim) => JlCone::NonnegativeConeT { dim: *dim },
+            SecondOrderConeT(dim) => JlCone::SecondOrderConeT { dim: *dim },
+            ExponentialConeT() => JlCone::ExponentialConeT {},
+            PowerConeT(α) => JlCone::PowerConeT { α: *α },
+            GenPowerConeT(α, dim2) => JlCone::GenPowerConeT {
+                α: α.clone(),
+                dim2: *dim2,
+            },
+            #[cfg(feature = "sdp")]
+            PSDTriangleConeT(dim) => JlCone::PSDTriangleConeT { dim: *dim },
+            _ => {
+                let name = cone.as_tag().as_str();
+                if !unsupported.contains(&name) {
+                    unsupported.push(name);
+                }
+                continue;
+            }
+        };
+        out.push(jlcone);
+    }
+
+    if unsupported.is_empty() {
+        Ok(out)
+    } else {
+        Err(JlError::UnsupportedCones(unsupported))
+    }
+}
+
+fn from_jl_cones(cones: Vec<JlCone>) -> Result<Vec<SupportedConeT<f64>>, JlError> {
+    let mut out = Vec::with_capacity(cones.len());
+
+    for cone in cones {
+        let cone = match cone {
+            JlCone::ZeroConeT { dim } => ZeroConeT(dim),
+            JlCone::NonnegativeConeT { dim } => NonnegativeConeT(dim),
+            JlCone::SecondOrderConeT { dim } => SecondOrderConeT(dim),
+            JlCone::ExponentialConeT {} => ExponentialConeT(),
+            JlCone::PowerConeT { α } => PowerConeT(α),
+            JlCone::GenPowerConeT { α, dim2 } => GenPowerConeT(α, dim2),
+            #[cfg(feature = "sdp")]
+            JlCone::PSDTriangleConeT { dim } => PSDTriangleConeT(dim),
+            #[cfg(not(feature = "sdp"))]
+            JlCone::PSDTriangleConeT { .. } => {
+                return Err(JlError::UnsupportedCones(vec!["PSDTriangleConeT"]));
+            }
+        };
+        out.push(cone);
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,255 @@
+//! Writer for LP and QP problems in CPLEX LP format.
+//!
+//! Problems in the standard Clarabel form
+//!
+//! ```text
+//! minimize    (1/2)x'Px + q'x
+//! subject to  Ax + s = b,  s ∈ K
+//! ```
+//!
+//! are written with rows of
+//! [`ZeroConeT`](crate::solver::SupportedConeT::ZeroConeT) constraints as
+//! equalities `a'x = b`, rows of
+//! [`NonnegativeConeT`](crate::solver::SupportedConeT::NonnegativeConeT)
+//! constraints as inequalities `a'x <= b`, and rows of
+//! [`BoxConeT`](crate::solver::SupportedConeT::BoxConeT) constraints as a pair
+//! of inequalities for their finite bounds, with each bound scaled by the
+//! leading row `t = b₀ - a₀'x` of the cone.  All variables are declared free.
+//!
+//! Variables are named `x0, x1, ...` and constraints `c0, c1, ...` after their
+//! (zero based) index in the problem data, so that the output for a given
+//! problem is deterministic and suitable for diffing.
+
+#![allow(non_snake_case)]
+
+use crate::algebra::{CscMatrix, ShapedMatrix};
+use crate::solver::core::cones::SupportedConeAsTag;
+use crate::solver::{SupportedConeT, SupportedConeT::*};
+use std::io::Write;
+use thiserror::Error;
+
+// maximum number of terms written on a single line.  CPLEX
+// limits the length of lines in LP files to 255 characters
+const TERMS_PER_LINE: usize = 6;
+
+/// Error type returned by [`write_lp`].
+#[derive(Error, Debug)]
+pub enum LpError {
+    #[error("IO error writing LP data")]
+    Io(#[from] std::io::Error),
+    #[error("Cones not expressible in LP format: {}", .0.join(", "))]
+    UnsupportedCones(Vec<&'static str>),
+    #[error("Incompatible problem data dimensions")]
+    IncompatibleDimensions,
+}
+
+/// Write an LP or QP problem in CPLEX LP format.
+///
+/// Takes problem data `(P, q, A, b, cones)` in the same form as
+/// [`DefaultSolver::new`](crate::solver::DefaultSolver::new), with `P` upper
+/// triangular.   Only zero, nonnegative and box cones are supported, and an
+/// [`LpError::UnsupportedCones`] listing any other cone types is returned
+/// otherwise.
+pub fn write_lp<W: Write>(
+    mut writer: W,
+    P: &CscMatrix<f64>,
+    q: &[f64],
+    A: &CscMatrix<f64>,
+    b: &[f64],
+    cones: &[SupportedConeT<f64>],
+) -> Result<(), LpError> {
+    check_lp_cones(cones)?;
+
+    let n = q.len();
+    let m = b.len();
+    let cone_rows: usize = cones.iter().map(|cone| cone.nvars()).sum();
+    if P.size() != (n, n) || A.size() != (m, n) || cone_rows != m {
+        return Err(LpError::IncompatibleDimensions);
+    }
+
+    writeln!(writer, "\\ Written by Clarabel.rs v{}", crate::VERSION)?;
+
+    // objective, with the quadratic part as [ x'Px ] / 2
+    writeln!(writer, "Minimize")?;
+    let mut terms: Vec<String> = q
+        .iter()
+        .enumerate()
+        .filter(|(_, &v)| v != 0.)
+        .map(|(j, &v)| linear_term(v, j))
+        .collect();
+
+    let quadterms = quadratic_terms(P);
+    if !quadterms.is_empty() {
+        terms.push("+ [".to_string());
+        terms.extend(quadterms);
+        terms.push("] / 2".to_string());
+    }
+    write_expression(&mut writer, "obj", &terms)?;
+    writeln!(writer)?;
+
+    // constraints, one or two per row of A
+    writeln!(writer, "Subject To")?;
+    let At = A.transpose();
+    let infbound = crate::solver::get_infinity();
+    let mut row = 0;
+
+    for cone in cones {
+        for k in 0..cone.nvars() {
+            let i = row + k;
+            let terms = row_terms(&At, i);
+            let name = format!("c{}", i);
+
+            match cone {
+                ZeroConeT(_) => write_constraint(&mut writer, &name, &terms, "=", b[i])?,
+                NonnegativeConeT(_) => write_constraint(&mut writer, &name, &terms, "<=", b[i])?,
+                // t ≥ 0 for the leading row
+                BoxConeT { .. } if k == 0 => {
+                    write_constraint(&mut writer, &name, &terms, "<=", b[i])?
+                }
+                BoxConeT { lower, upper } => {
+                    // t⋅lower ≤ b - Ax ≤ t⋅upper, with t = b₀ - a₀'x
+                    let (l, u) = (lower[k - 1], upper[k - 1]);
+                    if l.abs() < infbound {
+                        let name = format!("{}_lo", name);
+                        let terms = scaled_row_terms(&At, i, row, l);
+                        write_constraint(&mut writer, &name, &terms, "<=", b[i] - l * b[row])?;
+                    }
+                    if u.abs() < infbound {
+                        let name = format!("{}_hi", name);
+                        let terms = scaled_row_terms(&At, i, row, u);
+                        write_constraint(&mut writer, &name, &terms, ">=", b[i] - u * b[row])?;
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        row += cone.nvars();
+    }
+
+    // LP variables are nonnegative by default
+    writeln!(writer, "Bounds")?;
+    for j in 0..n {
+        writeln!(writer, " x{} free", j)?;
+    }
+    writeln!(writer, "End")?;
+
+    Ok(())
+}
+
+fn check_lp_cones(cones: &[SupportedConeT<f64>]) -> Result<(), LpError> {
+    let mut unsupported = Vec::new();
+
+    for cone in cones {
+        let name = match cone {
+            ZeroConeT(_) | NonnegativeConeT(_) | BoxConeT { .. } => continue,
+            _ => cone.as_tag().as_str(),
+        };
+        if !unsupported.contains(&name) {
+            unsupported.push(name);
+        }
+    }
+
+    if unsupported.is_empty() {
+        Ok(())
+    } else {
+        Err(LpError::UnsupportedCones(unsupported))
+    }
+}
+
+// signed coefficient and variable, e.g. "- 2.0 x3".  Values are
+// written with Debug formatting, which is exact on reading back
+// and uses exponent notation for very large or small values
+fn linear_term(v: f64, j: usize) -> String {
+    format!("{} {:?} x{}", sign(v), v.abs(), j)
+}
+
+// terms of x'Px from the upper triangle of P.  Off
+// diagonal terms appear twice in the symmetric product
+fn quadratic_terms(P: &CscMatrix<f64>) -> Vec<String> {
+    let mut terms = Vec::new();
+
+    for col in 0..P.n {
+        for k in P.colptr[col]..P.colptr[col + 1] {
+            let (row, v) = (P.rowval[k], P.nzval[k]);
+            if v == 0. || row > col {
+                continue;
+            }
+            if row == col {
+                terms.push(format!("{} {:?} x{} ^ 2", sign(v), v.abs(), col));
+            } else {
+                terms.push(format!(
+                    "{} {:?} x{} * x{}",
+                    sign(v),
+                    2. * v.abs(),
+                    row,
+                    col
+                ));
+            }
+        }
+    }
+    terms
+}
+
+// terms of the ith row of A, taken from the ith column of A^T
+fn row_terms(At: &CscMatrix<f64>, i: usize) -> Vec<String> {
+    (At.colptr[i]..At.colptr[i + 1])
+        .filter(|&k| At.nzval[k] != 0.)
+        .map(|k| linear_term(At.nzval[k], At.rowval[k]))
+        .collect()
+}
+
+// terms of the row a_i - c⋅a_i0 of A
+fn scaled_row_terms(At: &CscMatrix<f64>, i: usize, i0: usize, c: f64) -> Vec<String> {
+    let mut entries: Vec<(usize, f64)> = (At.colptr[i]..At.colptr[i + 1])
+        .map(|k| (At.rowval[k], At.nzval[k]))
+        .chain((At.colptr[i0]..At.colptr[i0 + 1]).map(|k| (At.rowval[k], -c * At.nzval[k])))
+        .collect();
+    entries.sort_by_key(|&(j, _)| j);
+    entries.dedup_by(|(j, v), (jprev, vprev)| {
+        let same = j == jprev;
+        if same {
+            *vprev += *v;
+        }
+        same
+    });
+    entries
+        .into_iter()
+        .filter(|&(_, v)| v != 0.)
+        .map(|(j, v)| linear_term(v, j))
+        .collect()
+}
+
+fn sign(v: f64) -> &'static str {
+    if v < 0. {
+        "-"
+    } else {
+        "+"
+    }
+}
+
+fn write_expression<W: Write>(writer: &mut W, name: &str, terms: &[String]) -> std::io::Result<()> {
+    write!(writer, " {}:", name)?;
+
+    // an empty expression still needs a term
+    if terms.is_empty() {
+        write!(writer, " 0 x0")?;
+    }
+    for (k, term) in terms.iter().enumerate() {
+        if k > 0 && k % TERMS_PER_LINE == 0 {
+            write!(writer, "\n   ")?;
+        }
+        write!(writer, " {}", term)?;
+    }
+    Ok(())
+}
+
+fn write_constraint<W: Write>(
+    writer: &mut W,
+    name: &str,
+    terms: &[String],
+    relation: &str,
+    rhs: f64,
+) -> std::io::Result<()> {
+    write_expression(writer, name, terms)?;
+    writeln!(writer, " {} {:?}", relation, rhs)
+}
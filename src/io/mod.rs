@@ -0,0 +1,24 @@
+//! Clarabel file IO module.
+//!
+//! Utilities for reading and writing problem data in standard optimization file formats.
+
+#[cfg(feature = "serde")]
+pub mod jl;
+pub mod lp;
+pub mod mps;
+pub mod osqp;
+#[cfg(feature = "sdp")]
+pub mod sdpa;
+
+use crate::algebra::CscMatrix;
+
+// build a CSC matrix from (row,col,val) triplets, summing duplicates
+pub(crate) fn csc_from_triplets(
+    m: usize,
+    n: usize,
+    triplets: &[(usize, usize, f64)],
+) -> CscMatrix<f64> {
+    let (rows, (cols, vals)): (Vec<_>, (Vec<_>, Vec<_>)) =
+        triplets.iter().map(|&(i, j, v)| (i, (j, v))).unzip();
+    CscMatrix::from_triplets(m, n, &rows, &cols, &vals)
+}
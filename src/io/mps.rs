@@ -0,0 +1,447 @@
+//! Reader for LP and QP problems in (free) MPS format.
+//!
+//! Problems are converted to the standard Clarabel form
+//!
+//! ```text
+//! minimize    (1/2)x'Px + q'x
+//! subject to  Ax + s = b,  s ∈ K
+//! ```
+//!
+//! with all equality constraints collected into a single
+//! [`ZeroConeT`](crate::solver::SupportedConeT::ZeroConeT) followed by all
+//! inequality and variable bound constraints in a single
+//! [`NonnegativeConeT`](crate::solver::SupportedConeT::NonnegativeConeT).
+//!
+//! The `ROWS`, `COLUMNS`, `RHS`, `RANGES` and `BOUNDS` sections are supported,
+//! as well as the `QUADOBJ` and `QMATRIX` extensions for quadratic objectives
+//! and an `OBJSENSE` section.   Integrality markers are ignored, and any
+//! constant objective offset specified in the `RHS` section is discarded.
+
+#![allow(non_snake_case)]
+
+use super::csc_from_triplets;
+use crate::algebra::CscMatrix;
+use crate::solver::{SupportedConeT, SupportedConeT::*};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+use thiserror::Error;
+
+/// Error type returned by [`read_mps`].
+#[derive(Error, Debug)]
+pub enum MpsError {
+    #[error("IO error reading MPS data")]
+    Io(#[from] std::io::Error),
+    #[error("Malformed MPS data at line {0}: {1}")]
+    BadLine(usize, String),
+    #[error("Unrecognized MPS section \"{0}\"")]
+    UnknownSection(String),
+    #[error("Reference to undeclared row \"{0}\"")]
+    UnknownRow(String),
+    #[error("Reference to undeclared column \"{0}\"")]
+    UnknownColumn(String),
+    #[error("No objective row found")]
+    NoObjective,
+}
+
+/// Problem data `(P, q, A, b, cones)` as read from an MPS file.
+pub type MpsProblem = (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+);
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Section {
+    Name,
+    ObjSense,
+    Rows,
+    Columns,
+    Rhs,
+    Ranges,
+    Bounds,
+    QuadObj,
+    QMatrix,
+    EndData,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum RowType {
+    N,
+    L,
+    G,
+    E,
+}
+
+// accumulated problem data, indexed as it appears in the file
+#[derive(Default)]
+struct MpsData {
+    rownames: HashMap<String, usize>,
+    rowtypes: Vec<RowType>,
+    rowcoeffs: Vec<Vec<(usize, f64)>>,
+    rhs: Vec<f64>,
+    ranges: Vec<Option<f64>>,
+    objrow: Option<usize>,
+
+    colnames: HashMap<String, usize>,
+    q: Vec<f64>,
+    lb: Vec<f64>,
+    ub: Vec<f64>,
+
+    // upper triangular entries of P as (row,col,val)
+    Pentries: Vec<(usize, usize, f64)>,
+    maximize: bool,
+}
+
+impl MpsData {
+    fn row(&self, name: &str) -> Result<usize, MpsError> {
+        self.rownames
+            .get(name)
+            .copied()
+            .ok_or_else(|| MpsError::UnknownRow(name.to_string()))
+    }
+
+    fn col(&self, name: &str) -> Result<usize, MpsError> {
+        self.colnames
+            .get(name)
+            .copied()
+            .ok_or_else(|| MpsError::UnknownColumn(name.to_string()))
+    }
+
+    fn add_col(&mut self, name: &str) -> usize {
+        if let Some(&j) = self.colnames.get(name) {
+            return j;
+        }
+        let j = self.q.len();
+        self.colnames.insert(name.to_string(), j);
+        self.q.push(0.);
+        self.lb.push(0.);
+        self.ub.push(f64::INFINITY);
+        j
+    }
+}
+
+/// Read an LP or QP problem in MPS format.
+///
+/// Returns problem data `(P, q, A, b, cones)` suitable for passing directly to
+/// [`DefaultSolver::new`](crate::solver::DefaultSolver::new).  Maximization problems
+/// are converted to minimization problems by negating the objective.
+pub fn read_mps<R: Read>(reader: R) -> Result<MpsProblem, MpsError> {
+    let reader = BufReader::new(reader);
+    let mut data = MpsData::default();
+    let mut section = Section::Name;
+
+    for (lineno, line) in reader.lines().enumerate() {
+        let line = line?;
+        let lineno = lineno + 1;
+
+        // skip comments and blank lines
+        if line.starts_with('*') || line.trim().is_empty() {
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        // section headers start in the first column
+        if !line.starts_with(char::is_whitespace) {
+            section = parse_section(tokens[0])?;
+            if section == Section::ObjSense && tokens.len() > 1 {
+                data.maximize = parse_objsense(tokens[1], lineno)?;
+            }
+            if section == Section::EndData {
+                break;
+            }
+            continue;
+        }
+
+        match section {
+            Section::Name => {
+                return Err(bad_line(lineno, "data found before first section"));
+            }
+            Section::ObjSense => {
+                data.maximize = parse_objsense(tokens[0], lineno)?;
+            }
+            Section::Rows => parse_rows_line(&mut data, &tokens, lineno)?,
+            Section::Columns => parse_columns_line(&mut data, &tokens, lineno)?,
+            Section::Rhs => parse_rhs_line(&mut data, &tokens, lineno, false)?,
+            Section::Ranges => parse_rhs_line(&mut data, &tokens, lineno, true)?,
+            Section::Bounds => parse_bounds_line(&mut data, &tokens, lineno)?,
+            Section::QuadObj => parse_quad_line(&mut data, &tokens, lineno, false)?,
+            Section::QMatrix => parse_quad_line(&mut data, &tokens, lineno, true)?,
+            Section::EndData => unreachable!(),
+        }
+    }
+
+    if data.objrow.is_none() {
+        return Err(MpsError::NoObjective);
+    }
+
+    Ok(make_problem(data))
+}
+
+fn bad_line(lineno: usize, msg: &str) -> MpsError {
+    MpsError::BadLine(lineno, msg.to_string())
+}
+
+fn parse_section(token: &str) -> Result<Section, MpsError> {
+    let section = match token {
+        "NAME" => Section::Name,
+        "OBJSENSE" => Section::ObjSense,
+        "ROWS" => Section::Rows,
+        "COLUMNS" => Section::Columns,
+        "RHS" => Section::Rhs,
+        "RANGES" => Section::Ranges,
+        "BOUNDS" => Section::Bounds,
+        "QUADOBJ" => Section::QuadObj,
+        "QMATRIX" => Section::QMatrix,
+        "ENDATA" => Section::EndData,
+        _ => return Err(MpsError::UnknownSection(token.to_string())),
+    };
+    Ok(section)
+}
+
+fn parse_objsense(token: &str, lineno: usize) -> Result<bool, MpsError> {
+    match token {
+        "MAX" | "MAXIMIZE" => Ok(true),
+        "MIN" | "MINIMIZE" => Ok(false),
+        _ => Err(bad_line(lineno, "unrecognized objective sense")),
+    }
+}
+
+fn parse_value(token: &str, lineno: usize) -> Result<f64, MpsError> {
+    token
+        .parse::<f64>()
+        .map_err(|_| bad_line(lineno, "invalid numeric value"))
+}
+
+fn parse_rows_line(data: &mut MpsData, tokens: &[&str], lineno: usize) -> Result<(), MpsError> {
+    if tokens.len() != 2 {
+        return Err(bad_line(lineno, "expected row type and row name"));
+    }
+    let rowtype = match tokens[0] {
+        "N" => RowType::N,
+        "L" => RowType::L,
+        "G" => RowType::G,
+        "E" => RowType::E,
+        _ => return Err(bad_line(lineno, "unrecognized row type")),
+    };
+
+    let i = data.rowtypes.len();
+    if data.rownames.insert(tokens[1].to_string(), i).is_some() {
+        return Err(bad_line(lineno, "duplicate row name"));
+    }
+
+    // the first free row is the objective.  Others are ignored.
+    if rowtype == RowType::N && data.objrow.is_none() {
+        data.objrow = Some(i);
+    }
+
+    data.rowtypes.push(rowtype);
+    data.rowcoeffs.push(Vec::new());
+    data.rhs.push(0.);
+    data.ranges.push(None);
+    Ok(())
+}
+
+fn parse_columns_line(data: &mut MpsData, tokens: &[&str], lineno: usize) -> Result<(), MpsError> {
+    // integrality markers are ignored
+    if tokens.contains(&"'MARKER'") {
+        return Ok(());
+    }
+    if tokens.len() != 3 && tokens.len() != 5 {
+        return Err(bad_line(lineno, "expected column name and row/value pairs"));
+    }
+
+    let j = data.add_col(tokens[0]);
+
+    for pair in tokens[1..].chunks(2) {
+        let i = data.row(pair[0])?;
+        let v = parse_value(pair[1], lineno)?;
+        if Some(i) == data.objrow {
+            data.q[j] += v;
+        } else {
+            data.rowcoeffs[i].push((j, v));
+        }
+    }
+    Ok(())
+}
+
+// RHS and RANGES lines share a format, with an optional leading set name
+fn parse_rhs_line(
+    data: &mut MpsData,
+    tokens: &[&str],
+    lineno: usize,
+    is_range: bool,
+) -> Result<(), MpsError> {
+    let pairs = match tokens.len() {
+        2 | 4 => tokens,
+        3 | 5 => &tokens[1..],
+        _ => return Err(bad_line(lineno, "expected row/value pairs")),
+    };
+
+    for pair in pairs.chunks(2) {
+        let i = data.row(pair[0])?;
+        let v = parse_value(pair[1], lineno)?;
+        if is_range {
+            data.ranges[i] = Some(v);
+        } else {
+            data.rhs[i] = v;
+        }
+    }
+    Ok(())
+}
+
+fn parse_bounds_line(data: &mut MpsData, tokens: &[&str], lineno: usize) -> Result<(), MpsError> {
+    let bndtype = tokens[0];
+    let needs_value = !matches!(bndtype, "FR" | "MI" | "PL" | "BV");
+
+    // the bound set name is optional
+    let (colname, value) = match (needs_value, tokens.len()) {
+        (true, 3) => (tokens[1], Some(tokens[2])),
+        (true, 4) => (tokens[2], Some(tokens[3])),
+        (false, 2) => (tokens[1], None),
+        (false, 3) => (tokens[2], None),
+        (false, 4) => (tokens[2], Some(tokens[3])),
+        _ => return Err(bad_line(lineno, "wrong number of fields in bound")),
+    };
+
+    let j = data.col(colname)?;
+    let value = value.map(|v| parse_value(v, lineno)).transpose()?;
+
+    match (bndtype, value) {
+        ("UP" | "UI", Some(v)) => {
+            // a negative upper bound with a default lower
+            // bound makes the variable unbounded below
+            if v < 0. && data.lb[j] == 0. {
+                data.lb[j] = f64::NEG_INFINITY;
+            }
+            data.ub[j] = v;
+        }
+        ("LO" | "LI", Some(v)) => data.lb[j] = v,
+        ("FX", Some(v)) => {
+            data.lb[j] = v;
+            data.ub[j] = v;
+        }
+        ("FR", _) => {
+            data.lb[j] = f64::NEG_INFINITY;
+            data.ub[j] = f64::INFINITY;
+        }
+        ("MI", _) => data.lb[j] = f64::NEG_INFINITY,
+        ("PL", _) => data.ub[j] = f64::INFINITY,
+        ("BV", _) => {
+            data.lb[j] = 0.;
+            data.ub[j] = 1.;
+        }
+        _ => return Err(bad_line(lineno, "unrecognized bound type")),
+    }
+    Ok(())
+}
+
+fn parse_quad_line(
+    data: &mut MpsData,
+    tokens: &[&str],
+    lineno: usize,
+    is_full: bool,
+) -> Result<(), MpsError> {
+    if tokens.len() != 3 {
+        return Err(bad_line(lineno, "expected two column names and a value"));
+    }
+    let c1 = data.col(tokens[0])?;
+    let c2 = data.col(tokens[1])?;
+    let v = parse_value(tokens[2], lineno)?;
+
+    // QMATRIX specifies both triangles, while QUADOBJ
+    // specifies only one (which may be either)
+    if is_full {
+        if c1 <= c2 {
+            data.Pentries.push((c1, c2, v));
+        }
+    } else {
+        data.Pentries.push((c1.min(c2), c1.max(c2), v));
+    }
+    Ok(())
+}
+
+// assemble the final problem from the parsed data
+fn make_problem(data: MpsData) -> MpsProblem {
+    let n = data.q.len();
+
+    // rows of [A b] split into equalities and inequalities,
+    // with each row given as (coefficients, sign, rhs)
+    let mut eqrows = Vec::new();
+    let mut ineqrows = Vec::new();
+
+    for (i, &rowtype) in data.rowtypes.iter().enumerate() {
+        let rhs = data.rhs[i];
+        let (lo, hi) = match (rowtype, data.ranges[i]) {
+            (RowType::N, _) => continue,
+            (RowType::L, None) => (f64::NEG_INFINITY, rhs),
+            (RowType::L, Some(r)) => (rhs - r.abs(), rhs),
+            (RowType::G, None) => (rhs, f64::INFINITY),
+            (RowType::G, Some(r)) => (rhs, rhs + r.abs()),
+            (RowType::E, None) => (rhs, rhs),
+            (RowType::E, Some(r)) if r >= 0. => (rhs, rhs + r),
+            (RowType::E, Some(r)) => (rhs + r, rhs),
+        };
+        push_interval(&mut eqrows, &mut ineqrows, &data.rowcoeffs[i], lo, hi);
+    }
+
+    // variable bounds are appended as additional constraints
+    for j in 0..n {
+        let coeffs = [(j, 1.)];
+        push_interval(&mut eqrows, &mut ineqrows, &coeffs, data.lb[j], data.ub[j]);
+    }
+
+    let meq = eqrows.len();
+    let mineq = ineqrows.len();
+
+    let mut triplets = Vec::new();
+    let mut b = Vec::with_capacity(meq + mineq);
+    for (i, (coeffs, sign, rhs)) in eqrows.into_iter().chain(ineqrows).enumerate() {
+        triplets.extend(coeffs.iter().map(|&(j, v)| (i, j, sign * v)));
+        b.push(rhs);
+    }
+    let A = csc_from_triplets(meq + mineq, n, &triplets);
+
+    let mut cones = Vec::new();
+    if meq > 0 {
+        cones.push(ZeroConeT(meq));
+    }
+    if mineq > 0 {
+        cones.push(NonnegativeConeT(mineq));
+    }
+
+    let mut P = csc_from_triplets(n, n, &data.Pentries);
+    let mut q = data.q;
+
+    if data.maximize {
+        P.nzval.iter_mut().for_each(|v| *v = -*v);
+        q.iter_mut().for_each(|v| *v = -*v);
+    }
+
+    (P, q, A, b, cones)
+}
+
+type MpsRow = (Vec<(usize, f64)>, f64, f64);
+
+// add the constraint lo <= a'x <= hi as either a single equality
+// or as up to two inequalities a'x <= hi and -a'x <= -lo
+fn push_interval(
+    eqrows: &mut Vec<MpsRow>,
+    ineqrows: &mut Vec<MpsRow>,
+    coeffs: &[(usize, f64)],
+    lo: f64,
+    hi: f64,
+) {
+    if lo == hi {
+        eqrows.push((coeffs.to_vec(), 1., hi));
+        return;
+    }
+    if hi < f64::INFINITY {
+        ineqrows.push((coeffs.to_vec(), 1., hi));
+    }
+    if lo > f64::NEG_INFINITY {
+        ineqrows.push((coeffs.to_vec(), -1., -lo));
+    }
+}
@@ -0,0 +1,185 @@
+//! Reader for QP problems stored in OSQP's CSC binary format.
+//!
+//! OSQP solves problems of the form
+//!
+//! ```text
+//! minimize    (1/2)x'Px + q'x
+//! subject to  l ≤ Ax ≤ u
+//! ```
+//!
+//! with `P` given in upper triangular form.  The data are stored as a flat
+//! sequence of little-endian values, with integers written as `i64` (OSQP's
+//! `c_int` with `DLONG` enabled) and floats as `f64`, in the order
+//!
+//! ```text
+//! n, m
+//! P as (m, n, nnz, p[n+1], i[nnz], x[nnz])
+//! q[n]
+//! A as (m, n, nnz, p[n+1], i[nnz], x[nnz])
+//! l[m], u[m]
+//! ```
+//!
+//! where each matrix is written as the fields of OSQP's `csc` struct.
+//!
+//! Problems are converted to the standard Clarabel form with all rows
+//! with `l = u` collected into a single
+//! [`ZeroConeT`](crate::solver::SupportedConeT::ZeroConeT), followed by
+//! the remaining rows split into the one-sided constraints `Ax ≤ u` and
+//! `-Ax ≤ -l` in a single
+//! [`NonnegativeConeT`](crate::solver::SupportedConeT::NonnegativeConeT).
+//! Bounds with magnitude at least [`OSQP_INFTY`] are treated as infinite
+//! as in OSQP, and the corresponding side of the constraint is dropped.
+
+#![allow(non_snake_case)]
+
+use super::csc_from_triplets;
+use crate::algebra::CscMatrix;
+use crate::solver::{SupportedConeT, SupportedConeT::*};
+use std::io::{BufReader, Read};
+use thiserror::Error;
+
+/// Bound magnitude at or above which OSQP treats a bound as infinite.
+pub const OSQP_INFTY: f64 = 1e30;
+
+/// Error type returned by [`read_osqp`].
+#[derive(Error, Debug)]
+pub enum OsqpError {
+    #[error("IO error reading OSQP data")]
+    Io(#[from] std::io::Error),
+    #[error("Malformed OSQP data: {0}")]
+    BadFormat(String),
+}
+
+/// Problem data `(P, q, A, b, cones)` as read from an OSQP file.
+pub type OsqpProblem = (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+);
+
+/// Read a QP problem in OSQP's CSC binary format.
+///
+/// Returns problem data `(P, q, A, b, cones)` suitable for passing directly to
+/// [`DefaultSolver::new`](crate::solver::DefaultSolver::new).  Rows with both
+/// bounds infinite are dropped.
+pub fn read_osqp<R: Read>(reader: R) -> Result<OsqpProblem, OsqpError> {
+    let mut reader = BufReader::new(reader);
+
+    let n = read_dim(&mut reader)?;
+    let m = read_dim(&mut reader)?;
+    let P = read_csc(&mut reader, n, n)?;
+    let q = read_floats(&mut reader, n)?;
+    let A = read_csc(&mut reader, m, n)?;
+    let l = read_floats(&mut reader, m)?;
+    let u = read_floats(&mut reader, m)?;
+
+    if let Some(i) = (0..m).find(|&i| l[i] > u[i] || l[i].is_nan() || u[i].is_nan()) {
+        return Err(OsqpError::BadFormat(format!("invalid bounds in row {}", i)));
+    }
+    if !P.is_triu() {
+        return Err(OsqpError::BadFormat(
+            "P is not upper triangular".to_string(),
+        ));
+    }
+
+    Ok(to_clarabel(P, q, &A, &l, &u))
+}
+
+fn to_clarabel(
+    P: CscMatrix<f64>,
+    q: Vec<f64>,
+    A: &CscMatrix<f64>,
+    l: &[f64],
+    u: &[f64],
+) -> OsqpProblem {
+    let m = l.len();
+    let is_finite = |v: f64| v.abs() < OSQP_INFTY;
+
+    // each row of A is mapped to at most two rows of the output, given
+    // as (row index, sign), with equalities first and then inequalities
+    let mut rowmap: Vec<Vec<(usize, f64)>> = vec![Vec::new(); m];
+    let mut b = Vec::new();
+
+    for i in (0..m).filter(|&i| l[i] == u[i] && is_finite(u[i])) {
+        rowmap[i].push((b.len(), 1.));
+        b.push(u[i]);
+    }
+    let meq = b.len();
+
+    for i in (0..m).filter(|&i| !(l[i] == u[i] && is_finite(u[i]))) {
+        if is_finite(u[i]) {
+            rowmap[i].push((b.len(), 1.));
+            b.push(u[i]);
+        }
+        if is_finite(l[i]) {
+            rowmap[i].push((b.len(), -1.));
+            b.push(-l[i]);
+        }
+    }
+    let mineq = b.len() - meq;
+
+    let mut triplets = Vec::with_capacity(2 * A.nnz());
+    for col in 0..A.n {
+        for k in A.colptr[col]..A.colptr[col + 1] {
+            let (row, v) = (A.rowval[k], A.nzval[k]);
+            triplets.extend(rowmap[row].iter().map(|&(i, sign)| (i, col, sign * v)));
+        }
+    }
+    let A = csc_from_triplets(meq + mineq, A.n, &triplets);
+
+    let mut cones = Vec::new();
+    if meq > 0 {
+        cones.push(ZeroConeT(meq));
+    }
+    if mineq > 0 {
+        cones.push(NonnegativeConeT(mineq));
+    }
+
+    (P, q, A, b, cones)
+}
+
+fn read_int<R: Read>(reader: &mut R) -> Result<i64, OsqpError> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(i64::from_le_bytes(buf))
+}
+
+fn read_dim<R: Read>(reader: &mut R) -> Result<usize, OsqpError> {
+    let v = read_int(reader)?;
+    usize::try_from(v).map_err(|_| OsqpError::BadFormat(format!("negative integer {}", v)))
+}
+
+fn read_ints<R: Read>(reader: &mut R, len: usize) -> Result<Vec<usize>, OsqpError> {
+    (0..len).map(|_| read_dim(reader)).collect()
+}
+
+fn read_floats<R: Read>(reader: &mut R, len: usize) -> Result<Vec<f64>, OsqpError> {
+    let mut buf = [0u8; 8];
+    (0..len)
+        .map(|_| {
+            reader.read_exact(&mut buf)?;
+            Ok(f64::from_le_bytes(buf))
+        })
+        .collect()
+}
+
+fn read_csc<R: Read>(reader: &mut R, m: usize, n: usize) -> Result<CscMatrix<f64>, OsqpError> {
+    let (mm, nn) = (read_dim(reader)?, read_dim(reader)?);
+    if (mm, nn) != (m, n) {
+        return Err(OsqpError::BadFormat(format!(
+            "expected a {}x{} matrix, found {}x{}",
+            m, n, mm, nn
+        )));
+    }
+    let nnz = read_dim(reader)?;
+    let colptr = read_ints(reader, n + 1)?;
+    let rowval = read_ints(reader, nnz)?;
+    let nzval = read_floats(reader, nnz)?;
+
+    let M = CscMatrix::new(m, n, colptr, rowval, nzval);
+    M.check_format()
+        .map_err(|e| OsqpError::BadFormat(e.to_string()))?;
+    Ok(M)
+}
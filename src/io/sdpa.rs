@@ -0,0 +1,189 @@
+//! Reader for SDP problems in the SDPA sparse format.
+//!
+//! The SDPA format describes problems of the form
+//!
+//! ```text
+//! minimize    c'x
+//! subject to  F₁x₁ + ... + Fₘxₘ - F₀ ⪰ 0
+//! ```
+//!
+//! with block diagonal symmetric matrices Fᵢ.  This is converted to the standard
+//! Clarabel form with `P = 0`, `q = c`, and one cone per block.   Blocks with
+//! positive size become [`PSDTriangleConeT`](crate::solver::SupportedConeT::PSDTriangleConeT)
+//! constraints in packed triangular form, and blocks with negative size are
+//! diagonal and become [`NonnegativeConeT`](crate::solver::SupportedConeT::NonnegativeConeT)
+//! constraints.
+
+#![allow(non_snake_case)]
+
+use super::csc_from_triplets;
+use crate::algebra::{CscMatrix, FloatT};
+use crate::solver::{SupportedConeT, SupportedConeT::*};
+use std::io::{BufRead, BufReader, Read};
+use thiserror::Error;
+
+/// Error type returned by [`read_sdpa`].
+#[derive(Error, Debug)]
+pub enum SdpaError {
+    #[error("IO error reading SDPA data")]
+    Io(#[from] std::io::Error),
+    #[error("Malformed SDPA data: {0}")]
+    BadFormat(String),
+}
+
+/// Problem data as read from an SDPA file.
+#[derive(Debug, Clone)]
+pub struct SdpaProblem<T: FloatT = f64> {
+    pub P: CscMatrix<T>,
+    pub q: Vec<T>,
+    pub A: CscMatrix<T>,
+    pub b: Vec<T>,
+    pub cones: Vec<SupportedConeT<T>>,
+}
+
+/// Read an SDP problem in SDPA sparse format (i.e. a `.dat-s` file).
+pub fn read_sdpa<R: Read>(reader: R) -> Result<SdpaProblem<f64>, SdpaError> {
+    let reader = BufReader::new(reader);
+
+    // drop leading comment lines.  Punctuation is allowed
+    // as a separator anywhere in the remaining data
+    let mut lines = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if lines.is_empty() && (line.starts_with('"') || line.starts_with('*')) {
+            continue;
+        }
+        lines.push(line.replace([',', '{', '}', '(', ')'], " "));
+    }
+
+    // any text following the numeric data on each line is
+    // treated as a comment, e.g. "2 = mDIM"
+    let mut tokens = lines.iter().flat_map(|l| {
+        l.split_whitespace()
+            .take_while(|t| t.parse::<f64>().is_ok())
+    });
+
+    let mut next_int = |name: &str| -> Result<i64, SdpaError> {
+        tokens
+            .next()
+            .ok_or_else(|| bad_format(&format!("missing {}", name)))
+            .and_then(parse_int)
+    };
+    let mdim = next_int("mDIM")?;
+    let nblock = next_int("nBLOCK")?;
+    if mdim < 0 || nblock < 0 {
+        return Err(bad_format("negative problem dimension"));
+    }
+    let (mdim, nblock) = (mdim as usize, nblock as usize);
+
+    let mut blocksizes = Vec::with_capacity(nblock);
+    for _ in 0..nblock {
+        blocksizes.push(next_int("block size")?);
+    }
+
+    let mut q = Vec::with_capacity(mdim);
+    for _ in 0..mdim {
+        let t = tokens
+            .next()
+            .ok_or_else(|| bad_format("missing objective coefficient"))?;
+        q.push(parse_float(t)?);
+    }
+
+    // row offset of each block in the constraint data, and cones
+    let mut offsets = Vec::with_capacity(nblock);
+    let mut cones = Vec::with_capacity(nblock);
+    let mut m = 0;
+    for &size in blocksizes.iter() {
+        offsets.push(m);
+        let dim = size.unsigned_abs() as usize;
+        if size >= 0 {
+            cones.push(PSDTriangleConeT(dim));
+            m += (dim * (dim + 1)) >> 1;
+        } else {
+            cones.push(NonnegativeConeT(dim));
+            m += dim;
+        }
+    }
+
+    // constraint matrix entries as (matno, blkno, i, j, value)
+    let mut b = vec![0.; m];
+    let mut triplets = Vec::new();
+    let tokens: Vec<&str> = tokens.collect();
+
+    if tokens.len() % 5 != 0 {
+        return Err(bad_format("incomplete matrix entry"));
+    }
+
+    for entry in tokens.chunks(5) {
+        let matno = parse_index(entry[0], mdim + 1)?;
+        let blkno = parse_index(entry[1], nblock + 1)?;
+        let blkno = blkno
+            .checked_sub(1)
+            .ok_or_else(|| bad_format("block index out of range"))?;
+        let size = blocksizes[blkno];
+        let dim = size.unsigned_abs() as usize;
+
+        let i = parse_index(entry[2], dim + 1)?;
+        let j = parse_index(entry[3], dim + 1)?;
+        if i == 0 || j == 0 {
+            return Err(bad_format("matrix index out of range"));
+        }
+        let (i, j) = (usize::min(i, j) - 1, usize::max(i, j) - 1);
+        let mut value = parse_float(entry[4])?;
+
+        let row = {
+            if size >= 0 {
+                // packed upper triangle, with off diagonal
+                // terms scaled to preserve inner products
+                if i != j {
+                    value *= std::f64::consts::SQRT_2;
+                }
+                offsets[blkno] + ((j * (j + 1)) >> 1) + i
+            } else if i == j {
+                offsets[blkno] + i
+            } else {
+                return Err(bad_format("off-diagonal entry in diagonal block"));
+            }
+        };
+
+        // Fᵢxᵢ - F₀ = s  ⟹  -Fᵢxᵢ + s = -F₀
+        if matno == 0 {
+            b[row] -= value;
+        } else {
+            triplets.push((row, matno - 1, -value));
+        }
+    }
+
+    let A = csc_from_triplets(m, mdim, &triplets);
+    let P = CscMatrix::zeros((mdim, mdim));
+
+    Ok(SdpaProblem { P, q, A, b, cones })
+}
+
+fn bad_format(msg: &str) -> SdpaError {
+    SdpaError::BadFormat(msg.to_string())
+}
+
+fn parse_int(token: &str) -> Result<i64, SdpaError> {
+    // integers are sometimes written in floating point form
+    let v = parse_float(token)?;
+    if v.fract() != 0. {
+        return Err(bad_format("expected an integer"));
+    }
+    Ok(v as i64)
+}
+
+fn parse_float(token: &str) -> Result<f64, SdpaError> {
+    token
+        .parse::<f64>()
+        .map_err(|_| bad_format(&format!("invalid numeric value \"{}\"", token)))
+}
+
+// parse a nonnegative index that must be strictly less than `bound`
+fn parse_index(token: &str, bound: usize) -> Result<usize, SdpaError> {
+    let v = parse_int(token)?;
+    if v < 0 || v as usize >= bound {
+        return Err(bad_format("index out of range"));
+    }
+    Ok(v as usize)
+}
@@ -59,6 +59,7 @@
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub mod algebra;
+pub mod io;
 pub mod qdldl;
 pub mod solver;
 pub(crate) mod stdio;
@@ -69,3 +70,9 @@ pub mod python;
 
 #[cfg(feature = "julia")]
 pub mod julia;
+
+#[cfg(feature = "c")]
+pub mod clarabel_c;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -147,6 +147,40 @@ impl PyPSDTriangleConeT {
     }
 }
 
+#[pyclass(name = "PSDTriangleComplexConeT")]
+pub struct PyPSDTriangleComplexConeT {
+    #[pyo3(get)]
+    pub dim: usize,
+}
+#[pymethods]
+impl PyPSDTriangleComplexConeT {
+    #[new]
+    pub fn new(dim: usize) -> Self {
+        Self { dim }
+    }
+    pub fn __repr__(&self) -> String {
+        __repr__cone("PSDTriangleComplexConeT", self.dim)
+    }
+}
+
+#[pyclass(name = "BoxConeT")]
+pub struct PyBoxConeT {
+    #[pyo3(get)]
+    pub lower: Vec<f64>,
+    #[pyo3(get)]
+    pub upper: Vec<f64>,
+}
+#[pymethods]
+impl PyBoxConeT {
+    #[new]
+    pub fn new(lower: Vec<f64>, upper: Vec<f64>) -> Self {
+        Self { lower, upper }
+    }
+    pub fn __repr__(&self) -> String {
+        __repr__cone("BoxConeT", self.lower.len() + 1)
+    }
+}
+
 // We can't implement the foreign trait FromPyObject directly on
 // SupportedCone<f64> since both are defined outside the crate, so
 // put a dummy wrapper around it here.
@@ -191,6 +225,15 @@ impl<'a> FromPyObject<'a> for PySupportedCone {
                 let dim: usize = obj.getattr("dim")?.extract()?;
                 Ok(PySupportedCone(PSDTriangleConeT(dim)))
             }
+            "PSDTriangleComplexConeT" => {
+                let dim: usize = obj.getattr("dim")?.extract()?;
+                Ok(PySupportedCone(PSDTriangleComplexConeT(dim)))
+            }
+            "BoxConeT" => {
+                let lower: Vec<f64> = obj.getattr("lower")?.extract()?;
+                let upper: Vec<f64> = obj.getattr("upper")?.extract()?;
+                Ok(PySupportedCone(BoxConeT { lower, upper }))
+            }
             _ => {
                 let mut errmsg = String::new();
                 write!(errmsg, "Unrecognized cone type : {}", thetype).unwrap();
@@ -209,3 +252,26 @@ pub(crate) fn _py_to_native_cones(cones: Vec<PySupportedCone>) -> Vec<SupportedC
     }
     out
 }
+
+pub(crate) fn _native_cones_to_f32(cones: &[SupportedConeT<f64>]) -> Vec<SupportedConeT<f32>> {
+    //single precision copies of native cones, for the f32 solver
+    let tof32 = |v: &Vec<f64>| v.iter().map(|&x| x as f32).collect::<Vec<f32>>();
+
+    cones
+        .iter()
+        .map(|cone| match cone {
+            ZeroConeT(dim) => ZeroConeT(*dim),
+            NonnegativeConeT(dim) => NonnegativeConeT(*dim),
+            SecondOrderConeT(dim) => SecondOrderConeT(*dim),
+            ExponentialConeT() => ExponentialConeT(),
+            PowerConeT(α) => PowerConeT(*α as f32),
+            GenPowerConeT(α, dim2) => GenPowerConeT(tof32(α), *dim2),
+            PSDTriangleConeT(dim) => PSDTriangleConeT(*dim),
+            PSDTriangleComplexConeT(dim) => PSDTriangleComplexConeT(*dim),
+            BoxConeT { lower, upper } => BoxConeT {
+                lower: tof32(lower),
+                upper: tof32(upper),
+            },
+        })
+        .collect()
+}
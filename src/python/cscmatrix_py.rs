@@ -1,10 +1,11 @@
 #![allow(unused)]
 #![allow(non_snake_case)]
 
+use super::*;
 use crate::algebra::CscMatrix;
 use core::ops::Deref;
 use pyo3::exceptions::PyTypeError;
-use pyo3::{prelude::*, PyClass};
+use pyo3::{prelude::*, types::IntoPyDict, PyClass};
 use std::{cmp::Ordering, fmt::Write, io};
 
 //We can't implement the foreign trait FromPyObject directly on CscMatrix
@@ -23,8 +24,72 @@ impl From<PyCscMatrix> for CscMatrix<f64> {
     }
 }
 
+impl From<CscMatrix<f64>> for PyCscMatrix {
+    fn from(mat: CscMatrix<f64>) -> Self {
+        Self(mat)
+    }
+}
+
+impl PyCscMatrix {
+    // single precision copy, for use with the f32 solver.
+    // scipy data of any float type is read as f64 on extraction
+    pub(crate) fn to_f32(&self) -> CscMatrix<f32> {
+        let nzval = self.0.nzval.iter().map(|&v| v as f32).collect();
+        CscMatrix::new(
+            self.0.m,
+            self.0.n,
+            self.0.colptr.clone(),
+            self.0.rowval.clone(),
+            nzval,
+        )
+    }
+
+    // construct from data in coordinate (COO) format, with
+    // entries at the same position summed
+    pub(crate) fn from_coo(
+        rows: &[usize],
+        cols: &[usize],
+        vals: &[f64],
+        shape: (usize, usize),
+    ) -> PyResult<Self> {
+        let (m, n) = shape;
+        if rows.len() != vals.len() || cols.len() != vals.len() {
+            return Err(DimensionMismatchError::new_err(
+                "COO row, column and value arrays must have the same length",
+            ));
+        }
+        if rows.iter().any(|&i| i >= m) || cols.iter().any(|&j| j >= n) {
+            return Err(DimensionMismatchError::new_err(format!(
+                "COO index out of bounds for a matrix of shape ({}, {})",
+                m, n
+            )));
+        }
+        let mat = CscMatrix::from_triplets(m, n, rows, cols, vals);
+        Ok(PyCscMatrix(mat))
+    }
+
+    fn extract_coo(obj: &PyAny) -> PyResult<Self> {
+        let rows: Vec<usize> = obj.getattr("row")?.extract()?;
+        let cols: Vec<usize> = obj.getattr("col")?.extract()?;
+        let vals: Vec<f64> = obj.getattr("data")?.extract()?;
+        let shape: (usize, usize) = obj.getattr("shape")?.extract()?;
+        Self::from_coo(&rows, &cols, &vals, shape)
+    }
+}
+
 impl<'a> FromPyObject<'a> for PyCscMatrix {
     fn extract(obj: &'a PyAny) -> PyResult<Self> {
+        // scipy matrices in formats other than CSC are read through
+        // COO, so that duplicate entries are summed
+        if let Ok(format) = obj.getattr("format") {
+            let format: &str = format.extract()?;
+            match format {
+                "csc" => {}
+                "coo" => return Self::extract_coo(obj),
+                _ => return Self::extract_coo(obj.call_method0("tocoo")?),
+            }
+        }
+
         let nzval: Vec<f64> = obj.getattr("data")?.extract()?;
         let rowval: Vec<usize> = obj.getattr("indices")?.extract()?;
         let colptr: Vec<usize> = obj.getattr("indptr")?.extract()?;
@@ -36,3 +101,30 @@ impl<'a> FromPyObject<'a> for PyCscMatrix {
         Ok(PyCscMatrix(mat))
     }
 }
+
+impl IntoPy<PyObject> for PyCscMatrix {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        // scipy is already a required import for the BLAS/LAPACK
+        // function pointers, so it should always be available here
+        let sparse = py
+            .import("scipy.sparse")
+            .expect("Failed to import scipy.sparse");
+
+        let CscMatrix {
+            m,
+            n,
+            colptr,
+            rowval,
+            nzval,
+        } = self.0;
+
+        let args = ((nzval, rowval, colptr),);
+        let kwargs = [("shape", (m, n))].into_py_dict(py);
+
+        sparse
+            .getattr("csc_matrix")
+            .and_then(|ctor| ctor.call(args, Some(kwargs)))
+            .expect("Failed to construct scipy.sparse.csc_matrix")
+            .into()
+    }
+}
@@ -0,0 +1,38 @@
+// Python exception types raised by the Clarabel interface.
+//
+// All are derived from ClarabelError, which is itself derived from
+// ValueError so that code catching ValueError continues to work.
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+
+create_exception!(
+    clarabel,
+    ClarabelError,
+    PyValueError,
+    "Base class for errors raised by Clarabel."
+);
+create_exception!(
+    clarabel,
+    InvalidSettingsError,
+    ClarabelError,
+    "Raised for settings that are malformed or have unrecognized values."
+);
+create_exception!(
+    clarabel,
+    DimensionMismatchError,
+    ClarabelError,
+    "Raised when problem data, cones or arrays have incompatible dimensions."
+);
+create_exception!(
+    clarabel,
+    InvalidDataError,
+    ClarabelError,
+    "Raised for problem data containing NaN or infinite values."
+);
+create_exception!(
+    clarabel,
+    NumericalError,
+    ClarabelError,
+    "Raised when the solver fails for numerical reasons."
+);
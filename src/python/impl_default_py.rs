@@ -4,17 +4,26 @@
 #![allow(non_snake_case)]
 
 use super::*;
+use crate::algebra::{AsFloatT, CscMatrix, FloatT, ShapedMatrix};
 use crate::solver::{
     core::{
+        cones::{validate_cones, ConeError},
         traits::{InfoPrint, Settings},
-        IPSolver, SolverStatus,
+        IPSolver, SolveError, SolverStatus, WarmStartError, WarmStartMode,
     },
     implementations::default::*,
 };
 use num_derive::ToPrimitive;
 use num_traits::ToPrimitive;
-use pyo3::prelude::*;
+use pyo3::{
+    exceptions::{PyKeyError, PyRuntimeError},
+    prelude::*,
+    types::IntoPyDict,
+};
 use std::fmt::Write;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
 
 //Here we end up repeating several datatypes defined internally
 //in the Clarabel default implementation.   We would prefer
@@ -55,25 +64,58 @@ pub struct PyDefaultSolution {
     pub r_prim: f64,
     #[pyo3(get)]
     pub r_dual: f64,
+    #[pyo3(get)]
+    pub total_refinement_iters: usize,
+    #[pyo3(get)]
+    pub final_refinement_residual: f64,
+    #[pyo3(get)]
+    pub equilibration_iters: u32,
+    #[pyo3(get)]
+    pub mu_hist: Vec<f64>,
+    #[pyo3(get)]
+    pub step_length_primal: f64,
+    #[pyo3(get)]
+    pub step_length_dual: f64,
+    #[pyo3(get)]
+    pub cone_residuals: Vec<(String, f64, f64)>,
 }
 
 impl PyDefaultSolution {
-    pub(crate) fn new_from_internal(result: &DefaultSolution<f64>) -> Self {
-        let x = result.x.clone();
-        let s = result.s.clone();
-        let z = result.z.clone();
+    pub(crate) fn new_from_internal<T: FloatT>(
+        result: &DefaultSolution<T>,
+        info: &DefaultInfo<T>,
+    ) -> Self {
+        // solutions are always reported in f64 on the python side
+        let x = _vec_to_f64(&result.x);
+        let s = _vec_to_f64(&result.s);
+        let z = _vec_to_f64(&result.z);
         let status = PySolverStatus::new_from_internal(&result.status);
         Self {
             x,
             s,
             z,
-            obj_val: result.obj_val,
-            obj_val_dual: result.obj_val_dual,
+            obj_val: result.obj_val.to_f64().unwrap(),
+            obj_val_dual: result.obj_val_dual.to_f64().unwrap(),
             status,
             solve_time: result.solve_time,
             iterations: result.iterations,
-            r_prim: result.r_prim,
-            r_dual: result.r_dual,
+            r_prim: result.r_prim.to_f64().unwrap(),
+            r_dual: result.r_dual.to_f64().unwrap(),
+            total_refinement_iters: result.total_refinement_iters,
+            final_refinement_residual: result.final_refinement_residual.to_f64().unwrap(),
+            equilibration_iters: result.equilibration_iters,
+            mu_hist: _vec_to_f64(&result.mu_hist),
+            step_length_primal: result.step_length_primal.to_f64().unwrap(),
+            step_length_dual: result.step_length_dual.to_f64().unwrap(),
+            // (cone name, primal residual, dual residual) for each cone
+            cone_residuals: info
+                .per_cone_residuals()
+                .into_iter()
+                .map(|(tag, rp, rd)| {
+                    let (rp, rd) = (rp.to_f64().unwrap(), rd.to_f64().unwrap());
+                    (tag.as_str().to_string(), rp, rd)
+                })
+                .collect(),
         }
     }
 }
@@ -103,6 +145,8 @@ pub enum PySolverStatus {
     MaxTime,
     NumericalError,
     InsufficientProgress,
+    CallbackTerminated,
+    InsufficientMemory,
 }
 
 impl PySolverStatus {
@@ -119,6 +163,8 @@ impl PySolverStatus {
             SolverStatus::MaxTime => PySolverStatus::MaxTime,
             SolverStatus::NumericalError => PySolverStatus::NumericalError,
             SolverStatus::InsufficientProgress => PySolverStatus::InsufficientProgress,
+            SolverStatus::CallbackTerminated => PySolverStatus::CallbackTerminated,
+            SolverStatus::InsufficientMemory => PySolverStatus::InsufficientMemory,
         }
     }
 }
@@ -138,6 +184,8 @@ impl PySolverStatus {
             PySolverStatus::MaxTime => "MaxTime",
             PySolverStatus::NumericalError => "NumericalError",
             PySolverStatus::InsufficientProgress => "InsufficientProgress",
+            PySolverStatus::CallbackTerminated => "CallbackTerminated",
+            PySolverStatus::InsufficientMemory => "InsufficientMemory",
         }
         .to_string()
     }
@@ -162,6 +210,12 @@ pub struct PyDefaultSettings {
     #[pyo3(get, set)]
     pub verbose: bool,
     #[pyo3(get, set)]
+    pub print_stride: u32,
+    #[pyo3(get, set)]
+    pub collect_timings: bool,
+    #[pyo3(get, set)]
+    pub time_limit_hard: bool,
+    #[pyo3(get, set)]
     pub max_step_fraction: f64,
 
     //full accuracy solution tolerances
@@ -191,6 +245,14 @@ pub struct PyDefaultSettings {
     pub reduced_tol_infeas_rel: f64,
     #[pyo3(get, set)]
     pub reduced_tol_ktratio: f64,
+    #[pyo3(get, set)]
+    pub termination_mode: String,
+    #[pyo3(get, set)]
+    pub tol_normalize: bool,
+    #[pyo3(get, set)]
+    pub insufficient_progress_window: u32,
+    #[pyo3(get, set)]
+    pub insufficient_progress_tol: f64,
 
     // data equilibration
     #[pyo3(get, set)]
@@ -201,6 +263,12 @@ pub struct PyDefaultSettings {
     pub equilibrate_min_scaling: f64,
     #[pyo3(get, set)]
     pub equilibrate_max_scaling: f64,
+    #[pyo3(get, set)]
+    pub equilibration_method: String,
+    #[pyo3(get, set)]
+    pub objective_scaling: Option<f64>,
+    #[pyo3(get, set)]
+    pub init_strategy: String,
 
     //step size settings
     #[pyo3(get, set)]
@@ -209,12 +277,26 @@ pub struct PyDefaultSettings {
     pub min_switch_step_length: f64,
     #[pyo3(get, set)]
     pub min_terminate_step_length: f64,
+    #[pyo3(get, set)]
+    pub max_centrality_corrections: u32,
 
     // KKT settings incomplete
     #[pyo3(get, set)]
     pub direct_kkt_solver: bool,
     #[pyo3(get, set)]
     pub direct_solve_method: String,
+    #[pyo3(get, set)]
+    pub kkt_form: String,
+    #[pyo3(get, set)]
+    pub dense_kkt_threshold: usize,
+    #[pyo3(get, set)]
+    pub indirect_stop_metric: String,
+    #[pyo3(get, set)]
+    pub direct_solve_cache_symbolic: bool,
+    #[pyo3(get, set)]
+    pub max_kkt_nnz: Option<usize>,
+    #[pyo3(get, set)]
+    pub random_seed: Option<u64>,
 
     // static regularization parameters
     #[pyo3(get, set)]
@@ -231,6 +313,10 @@ pub struct PyDefaultSettings {
     pub dynamic_regularization_eps: f64,
     #[pyo3(get, set)]
     pub dynamic_regularization_delta: f64,
+    #[pyo3(get, set)]
+    pub factorization_max_retries: u32,
+    #[pyo3(get, set)]
+    pub factorization_retry_factor: f64,
 
     // iterative refinement (for QDLDL)
     #[pyo3(get, set)]
@@ -247,6 +333,22 @@ pub struct PyDefaultSettings {
     // preprocessing
     #[pyo3(get, set)]
     pub presolve_enable: bool,
+    #[pyo3(get, set)]
+    pub crossover: bool,
+
+    // iterate history
+    #[pyo3(get, set)]
+    pub save_iterates: bool,
+    #[pyo3(get, set)]
+    pub save_iterates_max: Option<u32>,
+    #[pyo3(get, set)]
+    pub collect_iteration_records: bool,
+    #[pyo3(get, set)]
+    pub collect_mu_history: bool,
+    #[pyo3(get, set)]
+    pub return_scaled: bool,
+    #[pyo3(get, set)]
+    pub dump_on_failure: Option<String>,
 }
 
 #[pymethods]
@@ -267,6 +369,126 @@ impl PyDefaultSettings {
         write!(s, "{:#?}", self).unwrap();
         s
     }
+
+    /// Get the value of a setting by name.  Raises `KeyError`
+    /// if there is no setting with that name.
+    pub fn get(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        self.get_by_name(py, name)
+    }
+
+    /// Set the value of a setting by name.  Raises `KeyError` if
+    /// there is no setting with that name, or `TypeError` if the
+    /// value is not of the setting's type.
+    pub fn set(&mut self, name: &str, value: &PyAny) -> PyResult<()> {
+        self.set_by_name(name, value)
+    }
+
+    /// Serialize the settings to a JSON string.
+    pub fn to_json(&self) -> String {
+        self.to_internal::<f64>().to_json()
+    }
+
+    /// Read settings from a JSON string.   Unrecognized fields are
+    /// ignored and missing fields take their default values.   Raises
+    /// `ValueError` if the string is not valid settings JSON.
+    #[staticmethod]
+    pub fn from_json(s: &str) -> PyResult<Self> {
+        let settings = DefaultSettings::<f64>::from_json(s).map_err(_py_settings_error)?;
+        Ok(PyDefaultSettings::new_from_internal(&settings))
+    }
+}
+
+// Generates dynamic get/set dispatch over the named settings fields.
+// Every field of PyDefaultSettings should appear in the invocation below.
+macro_rules! impl_settings_by_name {
+    ($($field:ident),* $(,)?) => {
+        impl PyDefaultSettings {
+            fn get_by_name(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+                match name {
+                    $(stringify!($field) => Ok(self.$field.clone().into_py(py)),)*
+                    _ => Err(_py_unknown_setting(name)),
+                }
+            }
+
+            fn set_by_name(&mut self, name: &str, value: &PyAny) -> PyResult<()> {
+                match name {
+                    $(stringify!($field) => self.$field = value.extract()?,)*
+                    _ => return Err(_py_unknown_setting(name)),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_settings_by_name!(
+    max_iter,
+    time_limit,
+    verbose,
+    print_stride,
+    collect_timings,
+    time_limit_hard,
+    max_step_fraction,
+    tol_gap_abs,
+    tol_gap_rel,
+    tol_feas,
+    tol_infeas_abs,
+    tol_infeas_rel,
+    tol_ktratio,
+    reduced_tol_gap_abs,
+    reduced_tol_gap_rel,
+    reduced_tol_feas,
+    reduced_tol_infeas_abs,
+    reduced_tol_infeas_rel,
+    reduced_tol_ktratio,
+    termination_mode,
+    tol_normalize,
+    insufficient_progress_window,
+    insufficient_progress_tol,
+    equilibrate_enable,
+    equilibrate_max_iter,
+    equilibrate_min_scaling,
+    equilibrate_max_scaling,
+    equilibration_method,
+    objective_scaling,
+    init_strategy,
+    linesearch_backtrack_step,
+    min_switch_step_length,
+    min_terminate_step_length,
+    max_centrality_corrections,
+    direct_kkt_solver,
+    direct_solve_method,
+    kkt_form,
+    dense_kkt_threshold,
+    indirect_stop_metric,
+    direct_solve_cache_symbolic,
+    max_kkt_nnz,
+    random_seed,
+    static_regularization_enable,
+    static_regularization_constant,
+    static_regularization_proportional,
+    dynamic_regularization_enable,
+    dynamic_regularization_eps,
+    dynamic_regularization_delta,
+    factorization_max_retries,
+    factorization_retry_factor,
+    iterative_refinement_enable,
+    iterative_refinement_reltol,
+    iterative_refinement_abstol,
+    iterative_refinement_max_iter,
+    iterative_refinement_stop_ratio,
+    presolve_enable,
+    crossover,
+    save_iterates,
+    save_iterates_max,
+    collect_iteration_records,
+    collect_mu_history,
+    return_scaled,
+    dump_on_failure,
+);
+
+fn _py_unknown_setting(name: &str) -> PyErr {
+    PyKeyError::new_err(format!("Unrecognized setting: {}", name))
 }
 
 //Default not really necessary, but keeps clippy happy....
@@ -282,6 +504,9 @@ impl PyDefaultSettings {
             max_iter: set.max_iter,
             time_limit: set.time_limit,
             verbose: set.verbose,
+            print_stride: set.print_stride,
+            collect_timings: set.collect_timings,
+            time_limit_hard: set.time_limit_hard,
             tol_gap_abs: set.tol_gap_abs,
             tol_gap_rel: set.tol_gap_rel,
             tol_feas: set.tol_feas,
@@ -294,72 +519,124 @@ impl PyDefaultSettings {
             reduced_tol_infeas_abs: set.reduced_tol_infeas_abs,
             reduced_tol_infeas_rel: set.reduced_tol_infeas_rel,
             reduced_tol_ktratio: set.reduced_tol_ktratio,
+            termination_mode: set.termination_mode.clone(),
+            tol_normalize: set.tol_normalize,
+            insufficient_progress_window: set.insufficient_progress_window,
+            insufficient_progress_tol: set.insufficient_progress_tol,
             max_step_fraction: set.max_step_fraction,
             equilibrate_enable: set.equilibrate_enable,
             equilibrate_max_iter: set.equilibrate_max_iter,
             equilibrate_min_scaling: set.equilibrate_min_scaling,
             equilibrate_max_scaling: set.equilibrate_max_scaling,
+            equilibration_method: set.equilibration_method.clone(),
+            objective_scaling: set.objective_scaling,
+            init_strategy: set.init_strategy.clone(),
             linesearch_backtrack_step: set.linesearch_backtrack_step,
             min_switch_step_length: set.min_switch_step_length,
             min_terminate_step_length: set.min_terminate_step_length,
+            max_centrality_corrections: set.max_centrality_corrections,
             direct_kkt_solver: set.direct_kkt_solver,
             direct_solve_method: set.direct_solve_method.clone(),
+            kkt_form: set.kkt_form.clone(),
+            dense_kkt_threshold: set.dense_kkt_threshold,
+            indirect_stop_metric: set.indirect_stop_metric.clone(),
+            direct_solve_cache_symbolic: set.direct_solve_cache_symbolic,
+            max_kkt_nnz: set.max_kkt_nnz,
+            random_seed: set.random_seed,
             static_regularization_enable: set.static_regularization_enable,
             static_regularization_constant: set.static_regularization_constant,
             static_regularization_proportional: set.static_regularization_proportional,
             dynamic_regularization_enable: set.dynamic_regularization_enable,
             dynamic_regularization_eps: set.dynamic_regularization_eps,
             dynamic_regularization_delta: set.dynamic_regularization_delta,
+            factorization_max_retries: set.factorization_max_retries,
+            factorization_retry_factor: set.factorization_retry_factor,
             iterative_refinement_enable: set.iterative_refinement_enable,
             iterative_refinement_reltol: set.iterative_refinement_reltol,
             iterative_refinement_abstol: set.iterative_refinement_abstol,
             iterative_refinement_max_iter: set.iterative_refinement_max_iter,
             iterative_refinement_stop_ratio: set.iterative_refinement_stop_ratio,
             presolve_enable: set.presolve_enable,
+            crossover: set.crossover,
+            save_iterates: set.save_iterates,
+            save_iterates_max: set.save_iterates_max,
+            collect_iteration_records: set.collect_iteration_records,
+            collect_mu_history: set.collect_mu_history,
+            return_scaled: set.return_scaled,
+            dump_on_failure: set
+                .dump_on_failure
+                .as_ref()
+                .map(|p| p.to_string_lossy().into_owned()),
         }
     }
 
-    pub(crate) fn to_internal(&self) -> DefaultSettings<f64> {
+    pub(crate) fn to_internal<T: FloatT>(&self) -> DefaultSettings<T> {
         // convert python settings -> Rust
 
-        DefaultSettings::<f64> {
+        DefaultSettings::<T> {
             max_iter: self.max_iter,
             time_limit: self.time_limit,
             verbose: self.verbose,
-            tol_gap_abs: self.tol_gap_abs,
-            tol_gap_rel: self.tol_gap_rel,
-            tol_feas: self.tol_feas,
-            tol_infeas_abs: self.tol_infeas_abs,
-            tol_infeas_rel: self.tol_infeas_rel,
-            tol_ktratio: self.tol_ktratio,
-            reduced_tol_gap_abs: self.reduced_tol_gap_abs,
-            reduced_tol_gap_rel: self.reduced_tol_gap_rel,
-            reduced_tol_feas: self.reduced_tol_feas,
-            reduced_tol_infeas_abs: self.reduced_tol_infeas_abs,
-            reduced_tol_infeas_rel: self.reduced_tol_infeas_rel,
-            reduced_tol_ktratio: self.reduced_tol_ktratio,
-            max_step_fraction: self.max_step_fraction,
+            print_stride: self.print_stride,
+            collect_timings: self.collect_timings,
+            time_limit_hard: self.time_limit_hard,
+            tol_gap_abs: self.tol_gap_abs.as_T(),
+            tol_gap_rel: self.tol_gap_rel.as_T(),
+            tol_feas: self.tol_feas.as_T(),
+            tol_infeas_abs: self.tol_infeas_abs.as_T(),
+            tol_infeas_rel: self.tol_infeas_rel.as_T(),
+            tol_ktratio: self.tol_ktratio.as_T(),
+            reduced_tol_gap_abs: self.reduced_tol_gap_abs.as_T(),
+            reduced_tol_gap_rel: self.reduced_tol_gap_rel.as_T(),
+            reduced_tol_feas: self.reduced_tol_feas.as_T(),
+            reduced_tol_infeas_abs: self.reduced_tol_infeas_abs.as_T(),
+            reduced_tol_infeas_rel: self.reduced_tol_infeas_rel.as_T(),
+            reduced_tol_ktratio: self.reduced_tol_ktratio.as_T(),
+            termination_mode: self.termination_mode.clone(),
+            tol_normalize: self.tol_normalize,
+            insufficient_progress_window: self.insufficient_progress_window,
+            insufficient_progress_tol: self.insufficient_progress_tol.as_T(),
+            max_step_fraction: self.max_step_fraction.as_T(),
             equilibrate_enable: self.equilibrate_enable,
             equilibrate_max_iter: self.equilibrate_max_iter,
-            equilibrate_min_scaling: self.equilibrate_min_scaling,
-            equilibrate_max_scaling: self.equilibrate_max_scaling,
-            linesearch_backtrack_step: self.linesearch_backtrack_step,
-            min_switch_step_length: self.min_switch_step_length,
-            min_terminate_step_length: self.min_terminate_step_length,
+            equilibrate_min_scaling: self.equilibrate_min_scaling.as_T(),
+            equilibrate_max_scaling: self.equilibrate_max_scaling.as_T(),
+            equilibration_method: self.equilibration_method.clone(),
+            objective_scaling: self.objective_scaling.map(|c| c.as_T()),
+            init_strategy: self.init_strategy.clone(),
+            linesearch_backtrack_step: self.linesearch_backtrack_step.as_T(),
+            min_switch_step_length: self.min_switch_step_length.as_T(),
+            min_terminate_step_length: self.min_terminate_step_length.as_T(),
+            max_centrality_corrections: self.max_centrality_corrections,
             direct_kkt_solver: self.direct_kkt_solver,
             direct_solve_method: self.direct_solve_method.clone(),
+            kkt_form: self.kkt_form.clone(),
+            dense_kkt_threshold: self.dense_kkt_threshold,
+            indirect_stop_metric: self.indirect_stop_metric.clone(),
+            direct_solve_cache_symbolic: self.direct_solve_cache_symbolic,
+            max_kkt_nnz: self.max_kkt_nnz,
+            random_seed: self.random_seed,
             static_regularization_enable: self.static_regularization_enable,
-            static_regularization_constant: self.static_regularization_constant,
-            static_regularization_proportional: self.static_regularization_proportional,
+            static_regularization_constant: self.static_regularization_constant.as_T(),
+            static_regularization_proportional: self.static_regularization_proportional.as_T(),
             dynamic_regularization_enable: self.dynamic_regularization_enable,
-            dynamic_regularization_eps: self.dynamic_regularization_eps,
-            dynamic_regularization_delta: self.dynamic_regularization_delta,
+            dynamic_regularization_eps: self.dynamic_regularization_eps.as_T(),
+            dynamic_regularization_delta: self.dynamic_regularization_delta.as_T(),
+            factorization_max_retries: self.factorization_max_retries,
+            factorization_retry_factor: self.factorization_retry_factor.as_T(),
             iterative_refinement_enable: self.iterative_refinement_enable,
-            iterative_refinement_reltol: self.iterative_refinement_reltol,
-            iterative_refinement_abstol: self.iterative_refinement_abstol,
+            iterative_refinement_reltol: self.iterative_refinement_reltol.as_T(),
+            iterative_refinement_abstol: self.iterative_refinement_abstol.as_T(),
             iterative_refinement_max_iter: self.iterative_refinement_max_iter,
-            iterative_refinement_stop_ratio: self.iterative_refinement_stop_ratio,
+            iterative_refinement_stop_ratio: self.iterative_refinement_stop_ratio.as_T(),
             presolve_enable: self.presolve_enable,
+            crossover: self.crossover,
+            save_iterates: self.save_iterates,
+            save_iterates_max: self.save_iterates_max,
+            collect_iteration_records: self.collect_iteration_records,
+            collect_mu_history: self.collect_mu_history,
+            return_scaled: self.return_scaled,
+            dump_on_failure: self.dump_on_failure.as_ref().map(PathBuf::from),
         }
     }
 }
@@ -370,7 +647,18 @@ impl PyDefaultSettings {
 
 #[pyclass(name = "DefaultSolver")]
 pub struct PyDefaultSolver {
-    inner: DefaultSolver<f64>,
+    // None once the solver has been freed
+    inner: Option<DefaultSolver<f64>>,
+}
+
+impl PyDefaultSolver {
+    fn solver(&self) -> PyResult<&DefaultSolver<f64>> {
+        self.inner.as_ref().ok_or_else(_py_freed_error)
+    }
+
+    fn solver_mut(&mut self) -> PyResult<&mut DefaultSolver<f64>> {
+        self.inner.as_mut().ok_or_else(_py_freed_error)
+    }
 }
 
 #[pymethods]
@@ -383,42 +671,433 @@ impl PyDefaultSolver {
         b: Vec<f64>,
         cones: Vec<PySupportedCone>,
         settings: PyDefaultSettings,
-    ) -> Self {
+    ) -> PyResult<Self> {
         let cones = _py_to_native_cones(cones);
+        _py_check_dimensions(&P, &q, &A, &b)?;
+        validate_data(&P, &q, &A, &b).map_err(_py_data_error)?;
+        validate_cones(&cones, b.len()).map_err(_py_cone_error)?;
         let settings = settings.to_internal();
+        _py_check_settings(&settings)?;
         let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
 
-        Self { inner: solver }
+        Ok(Self {
+            inner: Some(solver),
+        })
+    }
+
+    fn solve(&mut self) -> PyResult<PyDefaultSolution> {
+        let solver = self.solver_mut()?;
+        solver.solve();
+        Ok(PyDefaultSolution::new_from_internal(
+            &solver.solution,
+            &solver.info,
+        ))
     }
 
-    fn solve(&mut self) -> PyDefaultSolution {
-        self.inner.solve();
-        PyDefaultSolution::new_from_internal(&self.inner.solution)
+    /// Solve as `solve`, but raise `NumericalError` if the solver terminates
+    /// for numerical reasons or through lack of progress, and `ClarabelError`
+    /// if the problem is too large for the `max_kkt_nnz` setting.
+    fn try_solve(&mut self) -> PyResult<PyDefaultSolution> {
+        let solver = self.solver_mut()?;
+        solver.try_solve().map_err(_py_solve_error)?;
+        Ok(PyDefaultSolution::new_from_internal(
+            &solver.solution,
+            &solver.info,
+        ))
+    }
+
+    /// Solve and write the primal variables, slacks and duals into the
+    /// existing arrays `x`, `s` and `z`, e.g. preallocated numpy arrays,
+    /// which must have the dimensions of the problem.   No new solution
+    /// object is created.   Returns the solver status.
+    fn solve_into(&mut self, x: &PyAny, s: &PyAny, z: &PyAny) -> PyResult<PySolverStatus> {
+        let solver = self.solver_mut()?;
+        solver.solve();
+        let solution = solver.solution_view();
+
+        _copy_into_array(x, solution.x)?;
+        _copy_into_array(s, solution.s)?;
+        _copy_into_array(z, solution.z)?;
+        Ok(PySolverStatus::new_from_internal(&solution.status))
+    }
+
+    /// Solve from guesses of the primal variables, slacks and duals.
+    ///
+    /// `mode` selects the warm start strategy: 0 to use the full guess,
+    /// 1 to use the primal guesses only, or 2 to blend the guess with
+    /// the default starting point using the weight `lambda` in [0,1].
+    #[pyo3(signature = (x, s, z, mode=None, lambda=None))]
+    fn solve_warm(
+        &mut self,
+        x: Vec<f64>,
+        s: Vec<f64>,
+        z: Vec<f64>,
+        mode: Option<i32>,
+        lambda: Option<f64>,
+    ) -> PyResult<PyDefaultSolution> {
+        let mode = match mode {
+            Some(mode) => WarmStartMode::try_from(mode).map_err(_py_warm_start_error)?,
+            None => WarmStartMode::default(),
+        };
+        let lambda = lambda.unwrap_or(1.0);
+
+        let solver = self.solver_mut()?;
+        solver
+            .solve_warm(&x, &s, &z, mode, lambda)
+            .map_err(_py_warm_start_error)?;
+        Ok(PyDefaultSolution::new_from_internal(
+            &solver.solution,
+            &solver.info,
+        ))
     }
 
     pub fn __repr__(&self) -> String {
         "Clarabel model with Float precision: f64".to_string()
     }
 
-    fn print_configuration(&mut self) {
+    /// The settings in use by the solver, after any out of range
+    /// values supplied at construction have been adjusted.
+    #[getter]
+    fn settings(&self) -> PyResult<PyDefaultSettings> {
+        let settings = self.solver()?.effective_settings();
+        Ok(PyDefaultSettings::new_from_internal(settings))
+    }
+
+    fn print_configuration(&mut self) -> PyResult<()> {
+        let solver = self.solver_mut()?;
+
         // force a print of the configuration regardless
         // of the verbosity settings.   Save them here first.
-        let verbose = self.inner.settings.core().verbose;
+        let verbose = solver.settings.core().verbose;
 
-        self.inner.settings.core_mut().verbose = true;
-        self.inner
+        solver.settings.core_mut().verbose = true;
+        solver
             .info
-            .print_configuration(&self.inner.settings, &self.inner.data, &self.inner.cones)
+            .print_configuration(&solver.settings, &solver.data, &solver.cones)
             .unwrap();
 
         // revert back to user option
-        self.inner.settings.core_mut().verbose = verbose;
+        solver.settings.core_mut().verbose = verbose;
+        Ok(())
+    }
+
+    /// The assembled KKT matrix as a full symmetric scipy CSC matrix.
+    ///
+    /// Entries in the cone scaling blocks are updated at every iteration,
+    /// so after solving this reflects the scalings of the final iterate.
+    fn get_kkt_matrix(&self) -> PyResult<PyCscMatrix> {
+        let K = self.solver()?.kkt_matrix();
+        Ok(_triu_to_symmetric(K).into())
     }
 
-    fn print_timers(&self) {
-        match &self.inner.timers {
+    /// Per-iteration progress collected with `collect_iteration_records`,
+    /// as a list of dicts with one entry per iteration.
+    fn get_iteration_records(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let records = &self.solver()?.info.iteration_records;
+        Ok(records
+            .iter()
+            .map(|r| {
+                [
+                    ("iteration", r.iteration.into_py(py)),
+                    ("cost_primal", r.cost_primal.into_py(py)),
+                    ("cost_dual", r.cost_dual.into_py(py)),
+                    ("gap_abs", r.gap_abs.into_py(py)),
+                    ("gap_rel", r.gap_rel.into_py(py)),
+                    ("res_primal", r.res_primal.into_py(py)),
+                    ("res_dual", r.res_dual.into_py(py)),
+                    ("ktratio", r.ktratio.into_py(py)),
+                    ("mu", r.mu.into_py(py)),
+                    ("step_length", r.step_length.into_py(py)),
+                    ("step_length_primal", r.step_length_primal.into_py(py)),
+                    ("step_length_dual", r.step_length_dual.into_py(py)),
+                ]
+                .into_py_dict(py)
+                .into()
+            })
+            .collect())
+    }
+
+    /// Writes the iterate history saved with `save_iterates` to a CSV
+    /// file at `path`, with one row per iterate.  The columns are the
+    /// iteration index and the entries of `x`, followed by those of `s`
+    /// and `z` if `full` is true.
+    #[pyo3(signature = (path, full=false))]
+    fn write_iterate_history_csv(&self, path: &str, full: bool) -> PyResult<()> {
+        let solution = &self.solver()?.solution;
+        let file = BufWriter::new(File::create(path)?);
+        if full {
+            solution.write_full_iterate_history_csv(file)?;
+        } else {
+            solution.write_iterate_history_csv(file)?;
+        }
+        Ok(())
+    }
+
+    fn print_timers(&self) -> PyResult<()> {
+        match &self.solver()?.timers {
             Some(timers) => timers.print(),
             None => println!("no timers enabled"),
         };
+        Ok(())
     }
+
+    /// Drops the solver and its problem data immediately, rather than
+    /// when the object is garbage collected.   Any further use of the
+    /// solver raises a RuntimeError.
+    fn free(&mut self) {
+        self.inner = None;
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(&mut self, _exc_type: &PyAny, _exc_value: &PyAny, _traceback: &PyAny) -> bool {
+        self.free();
+        false
+    }
+}
+
+// ----------------------------------
+// Solver (single precision)
+// ----------------------------------
+
+/// A solver operating in single precision.   Problem data and
+/// settings are supplied in f64 and converted internally, and the
+/// solution is reported back in f64.   Tolerances should be loosened
+/// from their defaults to values attainable in f32.
+#[pyclass(name = "DefaultSolverF32")]
+pub struct PyDefaultSolverF32 {
+    // None once the solver has been freed
+    inner: Option<DefaultSolver<f32>>,
+}
+
+impl PyDefaultSolverF32 {
+    fn solver(&self) -> PyResult<&DefaultSolver<f32>> {
+        self.inner.as_ref().ok_or_else(_py_freed_error)
+    }
+
+    fn solver_mut(&mut self) -> PyResult<&mut DefaultSolver<f32>> {
+        self.inner.as_mut().ok_or_else(_py_freed_error)
+    }
+}
+
+#[pymethods]
+impl PyDefaultSolverF32 {
+    #[new]
+    fn new(
+        P: PyCscMatrix,
+        q: Vec<f64>,
+        A: PyCscMatrix,
+        b: Vec<f64>,
+        cones: Vec<PySupportedCone>,
+        settings: PyDefaultSettings,
+    ) -> PyResult<Self> {
+        let (P, A) = (P.to_f32(), A.to_f32());
+        let (q, b) = (_vec_to_f32(&q), _vec_to_f32(&b));
+        let cones = _native_cones_to_f32(&_py_to_native_cones(cones));
+        _py_check_dimensions(&P, &q, &A, &b)?;
+        validate_data(&P, &q, &A, &b).map_err(_py_data_error)?;
+        validate_cones(&cones, b.len()).map_err(_py_cone_error)?;
+        let settings = settings.to_internal();
+        _py_check_settings(&settings)?;
+        let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+
+        Ok(Self {
+            inner: Some(solver),
+        })
+    }
+
+    fn solve(&mut self) -> PyResult<PyDefaultSolution> {
+        let solver = self.solver_mut()?;
+        solver.solve();
+        Ok(PyDefaultSolution::new_from_internal(
+            &solver.solution,
+            &solver.info,
+        ))
+    }
+
+    pub fn __repr__(&self) -> String {
+        "Clarabel model with Float precision: f32".to_string()
+    }
+
+    fn print_timers(&self) -> PyResult<()> {
+        match &self.solver()?.timers {
+            Some(timers) => timers.print(),
+            None => println!("no timers enabled"),
+        };
+        Ok(())
+    }
+
+    /// Drops the solver and its problem data immediately, rather than
+    /// when the object is garbage collected.   Any further use of the
+    /// solver raises a RuntimeError.
+    fn free(&mut self) {
+        self.inner = None;
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(&mut self, _exc_type: &PyAny, _exc_value: &PyAny, _traceback: &PyAny) -> bool {
+        self.free();
+        false
+    }
+}
+
+fn _vec_to_f64<T: FloatT>(v: &[T]) -> Vec<f64> {
+    v.iter().map(|x| x.to_f64().unwrap()).collect()
+}
+
+fn _vec_to_f32(v: &[f64]) -> Vec<f32> {
+    v.iter().map(|&x| x as f32).collect()
+}
+
+// copy elementwise into an existing python sequence of the same
+// length as v.  The buffer protocol is not available in the stable
+// ABI, so numpy arrays are updated in place through item assignment
+fn _copy_into_array(obj: &PyAny, v: &[f64]) -> PyResult<()> {
+    if obj.len()? != v.len() {
+        return Err(DimensionMismatchError::new_err(format!(
+            "expected an array of length {}, found {}",
+            v.len(),
+            obj.len()?
+        )));
+    }
+    for (i, &vi) in v.iter().enumerate() {
+        obj.set_item(i, vi)?;
+    }
+    Ok(())
+}
+
+fn _py_cone_error(err: ConeError) -> PyErr {
+    match err {
+        ConeError::TotalDimension(..) => DimensionMismatchError::new_err(err.to_string()),
+        _ => ClarabelError::new_err(err.to_string()),
+    }
+}
+
+fn _py_data_error(err: DataError) -> PyErr {
+    InvalidDataError::new_err(err.to_string())
+}
+
+fn _py_settings_error(err: SettingsError) -> PyErr {
+    InvalidSettingsError::new_err(err.to_string())
+}
+
+fn _py_warm_start_error(err: WarmStartError) -> PyErr {
+    match err {
+        WarmStartError::IncompatibleDimension => DimensionMismatchError::new_err(err.to_string()),
+        _ => ClarabelError::new_err(err.to_string()),
+    }
+}
+
+fn _py_solve_error(err: SolveError) -> PyErr {
+    match err {
+        SolveError::InsufficientMemory => ClarabelError::new_err(err.to_string()),
+        _ => NumericalError::new_err(err.to_string()),
+    }
+}
+
+// checks made before constructing a solver, which would otherwise
+// panic on data of incompatible dimensions
+fn _py_check_dimensions<T: FloatT>(
+    P: &CscMatrix<T>,
+    q: &[T],
+    A: &CscMatrix<T>,
+    b: &[T],
+) -> PyResult<()> {
+    let (m, n) = (b.len(), q.len());
+    if P.size() != (n, n) {
+        return Err(DimensionMismatchError::new_err(format!(
+            "P must be {}x{} to match the length of q, found {}x{}",
+            n, n, P.m, P.n
+        )));
+    }
+    if A.size() != (m, n) {
+        return Err(DimensionMismatchError::new_err(format!(
+            "A must be {}x{} to match the lengths of b and q, found {}x{}",
+            m, n, A.m, A.n
+        )));
+    }
+    Ok(())
+}
+
+// string-valued settings are otherwise only checked when they are
+// used, and the solver panics on an unrecognized value
+fn _py_check_settings<T: FloatT>(settings: &DefaultSettings<T>) -> PyResult<()> {
+    let options: [(&str, &str, &[&str]); 6] = [
+        (
+            "direct_solve_method",
+            &settings.direct_solve_method,
+            &["qdldl"],
+        ),
+        ("kkt_form", &settings.kkt_form, &["full", "reduced"]),
+        (
+            "equilibration_method",
+            &settings.equilibration_method,
+            &["ruiz", "l2", "geometric"],
+        ),
+        (
+            "init_strategy",
+            &settings.init_strategy,
+            &["default", "equality_project"],
+        ),
+        (
+            "termination_mode",
+            &settings.termination_mode,
+            &["standard", "gap_priority"],
+        ),
+        (
+            "indirect_stop_metric",
+            &settings.indirect_stop_metric,
+            &["absolute", "relative", "preconditioned"],
+        ),
+    ];
+    for (name, value, allowed) in options {
+        if !allowed.contains(&value) {
+            return Err(InvalidSettingsError::new_err(format!(
+                "Unrecognized value \"{}\" for setting {}.  Expected one of: {}",
+                value,
+                name,
+                allowed.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn _py_freed_error() -> PyErr {
+    PyRuntimeError::new_err("solver has been freed")
+}
+
+// mirror an upper triangular matrix into a full symmetric one
+fn _triu_to_symmetric(K: &CscMatrix<f64>) -> CscMatrix<f64> {
+    let Kt = K.transpose();
+    let n = K.n;
+
+    let mut colptr = Vec::with_capacity(n + 1);
+    let mut rowval = Vec::with_capacity(2 * K.nnz());
+    let mut nzval = Vec::with_capacity(2 * K.nnz());
+    colptr.push(0);
+
+    for col in 0..n {
+        // rows on or above the diagonal come from K,
+        // and rows below the diagonal from K^T
+        let upper = K.colptr[col]..K.colptr[col + 1];
+        let lower = Kt.colptr[col]..Kt.colptr[col + 1];
+
+        for k in upper {
+            rowval.push(K.rowval[k]);
+            nzval.push(K.nzval[k]);
+        }
+        for k in lower.filter(|&k| Kt.rowval[k] > col) {
+            rowval.push(Kt.rowval[k]);
+            nzval.push(Kt.nzval[k]);
+        }
+        colptr.push(rowval.len());
+    }
+
+    CscMatrix::new(n, n, colptr, rowval, nzval)
 }
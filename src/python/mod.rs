@@ -10,6 +10,7 @@
 
 mod cones_py;
 mod cscmatrix_py;
+mod exceptions_py;
 mod impl_default_py;
 pub(crate) mod io;
 mod module_py;
@@ -21,4 +22,5 @@ pub(crate) mod pyblas;
 
 pub(crate) use cones_py::*;
 pub(crate) use cscmatrix_py::*;
+pub(crate) use exceptions_py::*;
 pub(crate) use impl_default_py::*;
@@ -21,10 +21,17 @@ fn default_infinity_py() {
     crate::solver::default_infinity();
 }
 
+// convert a scipy sparse matrix in any format to CSC,
+// summing any duplicate entries
+#[pyfunction(name = "as_csc")]
+fn as_csc_py(matrix: PyCscMatrix) -> PyCscMatrix {
+    matrix
+}
+
 // Python module and registry, which includes registration of the
 // data types defined in the other files in this rust module
 #[pymodule]
-fn clarabel(_py: Python, m: &PyModule) -> PyResult<()> {
+fn clarabel(py: Python, m: &PyModule) -> PyResult<()> {
     //module version
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
 
@@ -39,6 +46,7 @@ fn clarabel(_py: Python, m: &PyModule) -> PyResult<()> {
         .unwrap();
     m.add_function(wrap_pyfunction!(default_infinity_py, m)?)
         .unwrap();
+    m.add_function(wrap_pyfunction!(as_csc_py, m)?).unwrap();
 
     // API Cone types
     m.add_class::<PyZeroConeT>()?;
@@ -48,14 +56,30 @@ fn clarabel(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyPowerConeT>()?;
     m.add_class::<PyGenPowerConeT>()?;
     m.add_class::<PyPSDTriangleConeT>()?;
+    m.add_class::<PyPSDTriangleComplexConeT>()?;
+    m.add_class::<PyBoxConeT>()?;
 
     //other API data types
     m.add_class::<PySolverStatus>()?;
     m.add_class::<PyDefaultSolution>()?;
     m.add_class::<PyDefaultSettings>()?;
 
+    // exception types
+    m.add("ClarabelError", py.get_type::<ClarabelError>())?;
+    m.add(
+        "InvalidSettingsError",
+        py.get_type::<InvalidSettingsError>(),
+    )?;
+    m.add(
+        "DimensionMismatchError",
+        py.get_type::<DimensionMismatchError>(),
+    )?;
+    m.add("InvalidDataError", py.get_type::<InvalidDataError>())?;
+    m.add("NumericalError", py.get_type::<NumericalError>())?;
+
     // Main solver object
     m.add_class::<PyDefaultSolver>()?;
+    m.add_class::<PyDefaultSolverF32>()?;
 
     Ok(())
 }
@@ -19,6 +19,8 @@ pub enum QDLDLError {
     ZeroPivot,
     #[error("Invalid permutation vector")]
     InvalidPermutation,
+    #[error("Matrix factorization was aborted")]
+    Aborted,
 }
 
 /// Required settings for [`QDLDLFactorisation`](QDLDLFactorisation)
@@ -52,7 +54,7 @@ where
 
 /// Performs $LDL^T$ factorization of a symmetric quasidefinite matrix
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct QDLDLFactorisation<T = f64> {
     // permutation vector
     pub perm: Vec<usize>,
@@ -89,6 +91,12 @@ where
     pub fn regularize_count(&self) -> usize {
         self.workspace.regularize_count
     }
+    pub fn regularize_eps(&self) -> T {
+        self.workspace.regularize_eps
+    }
+    pub fn set_regularize_eps(&mut self, eps: T) {
+        self.workspace.regularize_eps = eps;
+    }
 
     // Solves Ax = b using LDL factors for A.
     // Solves in place (x replaces b)
@@ -147,6 +155,17 @@ where
     }
 
     pub fn refactor(&mut self) -> Result<(), QDLDLError> {
+        self.refactor_inner(None)
+    }
+
+    /// As [`refactor`](Self::refactor), but polls `abort` periodically during
+    /// the factorization and stops with [`QDLDLError::Aborted`] if it returns
+    /// true.  The factors are invalid after an aborted factorization.
+    pub fn refactor_with_abort(&mut self, abort: &dyn Fn() -> bool) -> Result<(), QDLDLError> {
+        self.refactor_inner(Some(abort))
+    }
+
+    fn refactor_inner(&mut self, abort: Option<&dyn Fn() -> bool>) -> Result<(), QDLDLError> {
         // It never makes sense to call refactor for a logical
         // factorization since it will always be the same.  Calling
         // this function implies that we want a numerical factorization
@@ -157,6 +176,7 @@ where
             &mut self.Dinv,
             &mut self.workspace,
             self.is_logical,
+            abort,
         )
     }
 }
@@ -232,7 +252,14 @@ fn _qdldl_new<T: FloatT>(
     let mut Dinv = vec![T::zero(); n];
 
     // factor the matrix into A = LDL^T
-    _factor(&mut L, &mut D, &mut Dinv, &mut workspace, opts.logical)?;
+    _factor(
+        &mut L,
+        &mut D,
+        &mut Dinv,
+        &mut workspace,
+        opts.logical,
+        None,
+    )?;
 
     Ok(QDLDLFactorisation {
         perm,
@@ -245,7 +272,7 @@ fn _qdldl_new<T: FloatT>(
     })
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct QDLDLWorkspace<T> {
     // internal workspace data
     etree: Vec<usize>,
@@ -336,6 +363,7 @@ fn _factor<T: FloatT>(
     Dinv: &mut [T],
     workspace: &mut QDLDLWorkspace<T>,
     logical: bool,
+    abort: Option<&dyn Fn() -> bool>,
 ) -> Result<(), QDLDLError> {
     if logical {
         L.nzval.fill(T::zero());
@@ -367,6 +395,7 @@ fn _factor<T: FloatT>(
         workspace.regularize_eps,
         workspace.regularize_delta,
         &mut workspace.regularize_count,
+        abort,
     )?;
 
     workspace.positive_inertia = pos_d_count;
@@ -374,6 +403,9 @@ fn _factor<T: FloatT>(
     Ok(())
 }
 
+// number of columns factored between polls of an abort function
+const QDLDL_ABORT_INTERVAL: usize = 256;
+
 const QDLDL_UNKNOWN: usize = usize::MAX;
 const QDLDL_USED: bool = true;
 const QDLDL_UNUSED: bool = false;
@@ -438,6 +470,7 @@ fn _factor_inner<T: FloatT>(
     regularize_eps: T,
     regularize_delta: T,
     regularize_count: &mut usize,
+    abort: Option<&dyn Fn() -> bool>,
 ) -> Result<usize, QDLDLError> {
     *regularize_count = 0;
     let mut positiveValuesInD = 0;
@@ -487,6 +520,10 @@ fn _factor_inner<T: FloatT>(
     // Start from second row (k=1) here. The upper LH corner is trivially 0
     // in L b/c we are only computing the subdiagonal elements
     for k in 1..n {
+        if k % QDLDL_ABORT_INTERVAL == 0 && abort.map_or(false, |abort| abort()) {
+            return Err(QDLDLError::Aborted);
+        }
+
         // NB : For each k, we compute a solution to
         // y = L(0:(k-1),0:k-1))\b, where b is the kth
         // column of A that sits above the diagonal.
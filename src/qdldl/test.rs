@@ -265,6 +265,43 @@ fn test_solve_logical_refactor() {
     assert!(inf_norm_diff(&x, &b) <= 1e-8);
 }
 
+#[test]
+fn test_refactor_with_abort() {
+    use std::cell::Cell;
+
+    // a diagonal matrix, large enough for the abort
+    // function to be polled a few times
+    let n = 1000;
+    let A = CscMatrix::from_triplets(
+        n,
+        n,
+        &(0..n).collect::<Vec<_>>(),
+        &(0..n).collect::<Vec<_>>(),
+        &vec![2.; n],
+    );
+    let mut factors = QDLDLFactorisation::new(&A, None).unwrap();
+
+    let npolls = Cell::new(0);
+    let never = || {
+        npolls.set(npolls.get() + 1);
+        false
+    };
+    assert!(factors.refactor_with_abort(&never).is_ok());
+    assert_eq!(npolls.get(), (n - 1) / 256);
+    let mut b = vec![4.; n];
+    factors.solve(&mut b);
+    assert!(inf_norm_diff(&b, &vec![2.; n]) <= 1e-12);
+
+    let always = || true;
+    assert!(matches!(
+        factors.refactor_with_abort(&always),
+        Err(QDLDLError::Aborted)
+    ));
+
+    // an aborted factorization can be recomputed
+    assert!(factors.refactor().is_ok());
+}
+
 #[test]
 fn test_bad_numeric_pivot() {
     //Disable regularization to force an exact zero pivot
@@ -0,0 +1,589 @@
+use super::*;
+use crate::{
+    algebra::*,
+    solver::{core::ScalingStrategy, get_infinity, CoreSettings},
+};
+use itertools::izip;
+
+// -------------------------------------
+// Box Cone
+// -------------------------------------
+//
+// The cone K = {(t,s) : t ≥ 0, t⋅l ≤ s ≤ t⋅u}, with barrier
+//
+//     f(t,s) = -log(t) - Σ log(s - t⋅l) - Σ log(t⋅u - s)
+//
+// where the sums are over the finite bounds only.  The Hessian of f
+// is an arrowhead matrix, so its inverse is diagonal plus a rank one
+// term.  We use dual scaling only, with Hs = μ∇²f*(z) = μ(∇²f(x))⁻¹
+// at the point x = -∇f*(z), represented as Hs = μ(D + pp' - qq') with
+// q nonzero in the t entry only so that the diagonal D is positive.
+
+#[derive(Clone)]
+pub struct BoxConeData<T> {
+    // gradient of the dual barrier at z
+    grad: Vec<T>,
+
+    // central path parameter
+    pub μ: T,
+
+    // diagonal and vectors for the rank 2 update representation of Hs,
+    // where q is a scalar giving the t entry
+    pub d: Vec<T>,
+    pub p: Vec<T>,
+    pub q: T,
+
+    //work vector length dim, e.g. for line searches
+    work: Vec<T>,
+    //work vector exclusively for computing the dual barrier function.
+    work_db: Vec<T>,
+}
+
+impl<T> BoxConeData<T>
+where
+    T: FloatT,
+{
+    pub fn new(dim: usize) -> Self {
+        Self {
+            grad: vec![T::zero(); dim],
+            μ: T::one(),
+            d: vec![T::zero(); dim],
+            p: vec![T::zero(); dim],
+            q: T::zero(),
+            work: vec![T::zero(); dim],
+            work_db: vec![T::zero(); dim],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BoxCone<T> {
+    pub lower: Vec<T>,
+    pub upper: Vec<T>,
+    // bounds below the infinity bound in magnitude.
+    // NB: bounds are validated on solver construction
+    // so that at least one of each pair is finite
+    has_lower: Vec<bool>,
+    has_upper: Vec<bool>,
+    pub data: Box<BoxConeData<T>>, // Boxed so that the enum_dispatch variant isn't huge
+}
+
+impl<T> BoxCone<T>
+where
+    T: FloatT,
+{
+    pub fn new(lower: Vec<T>, upper: Vec<T>) -> Self {
+        let infbound: T = get_infinity().as_T();
+        let has_lower = lower.iter().map(|&l| l > -infbound).collect();
+        let has_upper = upper.iter().map(|&u| u < infbound).collect();
+        let data = Box::new(BoxConeData::<T>::new(lower.len() + 1));
+        Self {
+            lower,
+            upper,
+            has_lower,
+            has_upper,
+            data,
+        }
+    }
+
+    // number of bounded variables, excluding t
+    pub fn nbounded(&self) -> usize {
+        self.lower.len()
+    }
+}
+
+impl<T> Cone<T> for BoxCone<T>
+where
+    T: FloatT,
+{
+    fn degree(&self) -> usize {
+        let nlower = self.has_lower.iter().filter(|&&b| b).count();
+        let nupper = self.has_upper.iter().filter(|&&b| b).count();
+        1 + nlower + nupper
+    }
+
+    fn numel(&self) -> usize {
+        self.nbounded() + 1
+    }
+
+    fn is_symmetric(&self) -> bool {
+        false
+    }
+
+    fn is_sparse_expandable(&self) -> bool {
+        true
+    }
+
+    fn allows_primal_dual_scaling(&self) -> bool {
+        false
+    }
+
+    fn rectify_equilibration(&self, δ: &mut [T], e: &[T]) -> bool {
+        // t and s must be scaled equally to preserve the bounds
+        δ.copy_from(e).recip().scale(e.mean());
+        true // scalar equilibration
+    }
+
+    fn margins(&mut self, _z: &mut [T], _pd: PrimalOrDualCone) -> (T, T) {
+        // We should never end up shifting to this cone, since
+        // asymmetric problems should always use unit_initialization
+        unreachable!();
+    }
+
+    fn project(&self, _v: &mut [T]) -> bool {
+        // no closed form projection for nonsymmetric cones
+        false
+    }
+
+    fn scaled_unit_shift(&self, _z: &mut [T], _α: T, _pd: PrimalOrDualCone) {
+        // We should never end up shifting to this cone, since
+        // asymmetric problems should always use unit_initialization
+        unreachable!();
+    }
+
+    fn unit_initialization(&self, z: &mut [T], s: &mut [T]) {
+        // s is the midpoint of the box at t = 1, or is unit distance from
+        // the finite bound if there is only one, and z = -∇f(s) so that
+        // the pair is on the central path with μ = 1
+        s[0] = T::one();
+        let (z0, zs) = z.split_at_mut(1);
+        z0[0] = T::one();
+
+        for (zi, si, &l, &u, &hasl, &hasu) in izip!(
+            zs,
+            &mut s[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        ) {
+            *si = match (hasl, hasu) {
+                (true, true) => (l + u) * (0.5).as_T(),
+                (true, false) => l + T::one(),
+                _ => u - T::one(),
+            };
+            *zi = T::zero();
+            if hasl {
+                let a = *si - l;
+                *zi += a.recip();
+                z0[0] -= l / a;
+            }
+            if hasu {
+                let b = u - *si;
+                *zi -= b.recip();
+                z0[0] += u / b;
+            }
+        }
+    }
+
+    fn set_identity_scaling(&mut self) {
+        // We should never use identity scaling because
+        // we never want to allow symmetric initialization
+        unreachable!();
+    }
+
+    fn update_scaling(
+        &mut self,
+        _s: &[T],
+        z: &[T],
+        μ: T,
+        _scaling_strategy: ScalingStrategy,
+    ) -> bool {
+        // update both gradient and Hessian for function f*(z) at the point z
+        self.update_dual_grad_H(z);
+        self.data.μ = μ;
+
+        true
+    }
+
+    fn Hs_is_diagonal(&self) -> bool {
+        true
+    }
+
+    fn get_Hs(&self, Hsblock: &mut [T]) {
+        // we are returning here the diagonal D block
+        let data = &self.data;
+        Hsblock.scalarop_from(|d| data.μ * d, &data.d);
+    }
+
+    fn mul_Hs(&mut self, y: &mut [T], x: &[T], _work: &mut [T]) {
+        // Hs = μ*(D + pp' - qq')
+        let data = &self.data;
+        let coef_p = data.p.dot(x);
+
+        for (y, &x, &d, &p) in izip!(y.iter_mut(), x, &data.d, &data.p) {
+            *y = d * x + coef_p * p;
+        }
+        y[0] -= data.q * data.q * x[0];
+        y.scale(data.μ);
+    }
+
+    fn affine_ds(&self, ds: &mut [T], s: &[T]) {
+        ds.copy_from(s);
+    }
+
+    fn combined_ds_shift(
+        &mut self, shift: &mut [T], _step_z: &mut [T], _step_s: &mut [T], σμ: T
+    ) {
+        // No 3rd order correction at present
+        shift.scalarop_from(|g| g * σμ, &self.data.grad);
+    }
+
+    fn Δs_from_Δz_offset(&mut self, out: &mut [T], ds: &[T], _work: &mut [T], _z: &[T]) {
+        out.copy_from(ds);
+    }
+
+    fn step_length(
+        &mut self,
+        dz: &[T],
+        ds: &[T],
+        z: &[T],
+        s: &[T],
+        _settings: &CoreSettings<T>,
+        αmax: T,
+    ) -> (T, T) {
+        // Both cones are polyhedral, so the steps to their boundaries
+        // are found exactly rather than with a backtracking search
+        let αz = self.step_length_dual(dz, z, αmax);
+        let αs = self.step_length_primal(ds, s, αmax);
+
+        (αz, αs)
+    }
+
+    fn compute_barrier(&mut self, z: &[T], s: &[T], dz: &[T], ds: &[T], α: T) -> T {
+        let mut barrier = T::zero();
+        let mut work = std::mem::take(&mut self.data.work);
+
+        work.waxpby(T::one(), s, α, ds);
+        barrier += self.barrier_primal(&work);
+
+        work.waxpby(T::one(), z, α, dz);
+        barrier += self.barrier_dual(&work);
+
+        self.data.work = work;
+
+        barrier
+    }
+}
+
+impl<T> NonsymmetricCone<T> for BoxCone<T>
+where
+    T: FloatT,
+{
+    // Returns true if s is primal feasible
+    fn is_primal_feasible(&self, s: &[T]) -> bool
+    where
+        T: FloatT,
+    {
+        let t = s[0];
+        if t <= T::zero() {
+            return false;
+        }
+        izip!(
+            &s[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        )
+        .all(|(&si, &l, &u, &hasl, &hasu)| {
+            (!hasl || si - t * l > T::zero()) && (!hasu || t * u - si > T::zero())
+        })
+    }
+
+    // Returns true if z is dual feasible
+    fn is_dual_feasible(&self, z: &[T]) -> bool
+    where
+        T: FloatT,
+    {
+        self.dual_margin(z) > T::zero()
+    }
+
+    fn barrier_primal(&mut self, s: &[T]) -> T
+    where
+        T: FloatT,
+    {
+        let t = s[0];
+        let mut barrier = -t.logsafe();
+
+        for (&si, &l, &u, &hasl, &hasu) in izip!(
+            &s[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        ) {
+            if hasl {
+                barrier -= (si - t * l).logsafe();
+            }
+            if hasu {
+                barrier -= (t * u - si).logsafe();
+            }
+        }
+        barrier
+    }
+
+    fn barrier_dual(&mut self, z: &[T]) -> T
+    where
+        T: FloatT,
+    {
+        // Dual barrier: f*(z) = -ν - f(x) at x = -∇f*(z)
+        if !self.is_dual_feasible(z) {
+            return T::infinity();
+        }
+        let mut x = std::mem::take(&mut self.data.work_db);
+        self.dual_to_primal(&mut x, z);
+        let ν: T = self.degree().as_T();
+        let out = -ν - self.barrier_primal(&x);
+        self.data.work_db = x;
+
+        out
+    }
+
+    fn higher_correction(&mut self, _η: &mut [T], _ds: &[T], _v: &[T]) {
+        unimplemented!()
+    }
+
+    fn update_dual_grad_H(&mut self, z: &[T]) {
+        // the gradient of f* at z is -x, with x = (t,s)
+        let mut x = std::mem::take(&mut self.data.grad);
+        self.dual_to_primal(&mut x, z);
+
+        // The primal Hessian at x is [h c'; c diag(δ)], with inverse
+        // diag(0,1/δ) + vv'/σ for v = [1; -c./δ] and the Schur
+        // complement σ = h - Σ cᵢ²/δᵢ.  Each pair of finite bounds
+        // contributes (u-l)²/(a²+b²) to σ, and single bounds nothing.
+        let data = &mut self.data;
+        let t = x[0];
+        let mut σ = (t * t).recip();
+
+        for (d, p, &si, &l, &u, &hasl, &hasu) in izip!(
+            &mut data.d[1..],
+            &mut data.p[1..],
+            &x[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        ) {
+            match (hasl, hasu) {
+                (true, true) => {
+                    let (a, b) = (si - t * l, t * u - si);
+                    let (a2, b2) = (a * a, b * b);
+                    *d = a2 * b2 / (a2 + b2);
+                    *p = (l * b2 + u * a2) / (a2 + b2);
+                    σ += (u - l) * (u - l) / (a2 + b2);
+                }
+                (true, false) => {
+                    let a = si - t * l;
+                    *d = a * a;
+                    *p = l;
+                }
+                _ => {
+                    let b = t * u - si;
+                    *d = b * b;
+                    *p = u;
+                }
+            }
+        }
+
+        // scale v to p = v/√σ, and set D = diag(1/σ,1/δ) and q = 1/√σ
+        // so that the t entry of D + pp' - qq' is 1/σ
+        let sqrtσinv = σ.sqrt().recip();
+        data.p[0] = T::one();
+        data.p.scale(sqrtσinv);
+        data.d[0] = σ.recip();
+        data.q = sqrtσinv;
+
+        x.negate();
+        self.data.grad = x;
+    }
+}
+
+// ----------------------------------------------
+//  internal operations for box cones
+
+impl<T> BoxCone<T>
+where
+    T: FloatT,
+{
+    // Returns λ + Σ mᵢ(yᵢ) for z = (λ,y), where mᵢ(yᵢ) is the minimum of
+    // yᵢsᵢ over lᵢ ≤ sᵢ ≤ uᵢ.  This is positive if and only if z is in the
+    // interior of the dual cone, and is -∞ if a minimum is unbounded.
+    fn dual_margin(&self, z: &[T]) -> T {
+        let mut margin = z[0];
+
+        for (&y, &l, &u, &hasl, &hasu) in izip!(
+            &z[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        ) {
+            margin += match (hasl, hasu) {
+                (true, true) if y >= T::zero() => y * l,
+                (true, true) => y * u,
+                (true, false) if y > T::zero() => y * l,
+                (false, true) if y < T::zero() => y * u,
+                _ => return -T::infinity(),
+            };
+        }
+        margin
+    }
+
+    // Largest step α ≤ αmax with s + α⋅ds in the primal cone, from a ratio
+    // test on the linear constraints t ≥ 0, s - t⋅l ≥ 0 and t⋅u - s ≥ 0
+    fn step_length_primal(&self, ds: &[T], s: &[T], αmax: T) -> T {
+        let ratio = |g: T, dg: T| if dg < T::zero() { -g / dg } else { αmax };
+
+        let (t, dt) = (s[0], ds[0]);
+        let mut α = T::min(αmax, ratio(t, dt));
+
+        for (&si, &dsi, &l, &u, &hasl, &hasu) in izip!(
+            &s[1..],
+            &ds[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        ) {
+            if hasl {
+                α = T::min(α, ratio(si - t * l, dsi - dt * l));
+            }
+            if hasu {
+                α = T::min(α, ratio(t * u - si, dt * u - dsi));
+            }
+        }
+        α
+    }
+
+    // Largest step α ≤ αmax with z + α⋅dz in the dual cone.  The dual
+    // margin along the step is concave and piecewise linear, with a
+    // breakpoint wherever y changes sign in a row with both bounds finite,
+    // and rows with a single finite bound also require y to keep its sign.
+    fn step_length_dual(&self, dz: &[T], z: &[T], αmax: T) -> T {
+        let mut αcap = αmax;
+        let mut slope = dz[0];
+        let mut breaks: Vec<(T, T)> = Vec::new();
+
+        for (&y, &dy, &l, &u, &hasl, &hasu) in izip!(
+            &z[1..],
+            &dz[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        ) {
+            match (hasl, hasu) {
+                (true, true) => {
+                    // the minimizing bound just after α = 0
+                    let at_lower = y > T::zero() || (y == T::zero() && dy >= T::zero());
+                    slope += dy * if at_lower { l } else { u };
+
+                    // y changes sign, switching to the other bound
+                    if y != T::zero() && (y > T::zero()) != (dy > T::zero()) && dy != T::zero() {
+                        let change = if at_lower { dy * (u - l) } else { dy * (l - u) };
+                        breaks.push((-y / dy, change));
+                    }
+                }
+                (true, false) => {
+                    slope += dy * l;
+                    if dy < T::zero() {
+                        αcap = T::min(αcap, -y / dy);
+                    }
+                }
+                _ => {
+                    slope += dy * u;
+                    if dy > T::zero() {
+                        αcap = T::min(αcap, -y / dy);
+                    }
+                }
+            }
+        }
+
+        breaks.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        // walk the linear segments until the margin reaches zero
+        let (mut α, mut margin) = (T::zero(), self.dual_margin(z));
+        for (αbreak, change) in breaks {
+            if αbreak >= αcap {
+                break;
+            }
+            let next = margin + slope * (αbreak - α);
+            if next <= T::zero() {
+                break;
+            }
+            (α, margin) = (αbreak, next);
+            slope += change;
+        }
+        if slope < T::zero() {
+            αcap = T::min(αcap, α - margin / slope);
+        }
+        αcap
+    }
+
+    // Computes x = -∇f*(z), i.e. the point x with -∇f(x) = z, for z
+    // in the interior of the dual cone.
+    //
+    // For rows with both bounds finite and w = u - l, the smaller of the
+    // distances a = s - t⋅l and b = t⋅u - s to the bounds is t⋅w⋅ρ/(1+ρ)
+    // with ρ = ρ(|y|⋅w⋅t), where ρ(v) = 2/(v + √(v²+4)), and otherwise a
+    // single finite bound is at distance 1/|y|.  The t entry of -∇f(x) = z
+    // then reduces to the scalar equation
+    //
+    //     margin⋅t = 1 + k + Σ ρ(|yᵢ|⋅wᵢ⋅t)
+    //
+    // over the k rows with both bounds finite.   The left hand side is
+    // increasing and the right decreasing in t, and we solve with
+    // Newton-Raphson from the lower bound t = (1+k)/margin.
+    fn dual_to_primal(&self, x: &mut [T], z: &[T]) {
+        let two: T = (2.).as_T();
+        let four: T = (4.).as_T();
+        let ρ = |v: T| two / (v + T::sqrt(v * v + four));
+
+        let margin = self.dual_margin(z);
+        assert!(margin > T::zero());
+
+        // |y|⋅w for the rows with both bounds finite
+        let bounds = izip!(&self.lower, &self.upper, &self.has_lower, &self.has_upper);
+        let c: Vec<T> = izip!(&z[1..], bounds)
+            .filter(|(_, (_, _, &hasl, &hasu))| hasl && hasu)
+            .map(|(&y, (&l, &u, _, _))| y.abs() * (u - l))
+            .collect();
+
+        let k1: T = (c.len() + 1).as_T();
+        let f0 = |t: T| -> T { c.iter().fold(margin * t - k1, |f, &ci| f - ρ(ci * t)) };
+        let f1 = |t: T| -> T {
+            c.iter().fold(margin, |f, &ci| {
+                let v = ci * t;
+                f + ci * ρ(v) / T::sqrt(v * v + four)
+            })
+        };
+        let t = newton_raphson_onesided(k1 / margin, f0, f1);
+
+        x[0] = t;
+        for (xi, &y, &l, &u, &hasl, &hasu) in izip!(
+            &mut x[1..],
+            &z[1..],
+            &self.lower,
+            &self.upper,
+            &self.has_lower,
+            &self.has_upper
+        ) {
+            *xi = match (hasl, hasu) {
+                (true, true) => {
+                    let w = u - l;
+                    let ρi = ρ(y.abs() * w * t);
+                    let dist = t * w * ρi / (T::one() + ρi);
+                    if y >= T::zero() {
+                        t * l + dist
+                    } else {
+                        t * u - dist
+                    }
+                }
+                (true, false) => t * l + y.recip(),
+                _ => t * u + y.recip(),
+            };
+        }
+    }
+}
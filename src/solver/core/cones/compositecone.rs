@@ -1,5 +1,5 @@
 use super::*;
-use crate::algebra::triangular_number;
+use crate::algebra::{triangular_number, AsFloatT, VectorMath};
 use crate::solver::CoreSettings;
 use std::collections::HashMap;
 use std::iter::zip;
@@ -9,6 +9,17 @@ use std::ops::Range;
 // default composite cone type
 // -------------------------------------
 
+/// Lightweight description of one of the cones within a [`CompositeCone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConeDescriptor {
+    /// the type of the cone
+    pub kind: SupportedConeTag,
+    /// the number of elements in the cone
+    pub dim: usize,
+}
+
+/// The collection of cones over which a problem is defined.
+#[derive(Clone)]
 pub struct CompositeCone<T: FloatT = f64> {
     cones: Vec<SupportedCone<T>>,
 
@@ -157,6 +168,17 @@ where
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, SupportedCone<T>> {
         self.cones.iter_mut()
     }
+    /// Type and dimension of each of the constituent cones, in order.
+    pub fn descriptors(&self) -> impl Iterator<Item = ConeDescriptor> + '_ {
+        self.cones.iter().map(|cone| ConeDescriptor {
+            kind: cone.as_tag(),
+            dim: cone.numel(),
+        })
+    }
+    /// Total degree of the constituent cones.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
     pub(crate) fn get_type_count(&self, tag: SupportedConeTag) -> usize {
         if self.type_counts.contains_key(&tag) {
             self.type_counts[&tag]
@@ -164,6 +186,107 @@ where
             0
         }
     }
+
+    /// Euclidean projection of `v` onto the composite cone, in place.
+    ///
+    /// Each block of `v` is projected onto its own cone.  Projections are
+    /// supported for the zero, nonnegative and second order cones only, and
+    /// an error for the first unsupported cone is returned otherwise, with
+    /// `v` left unchanged.
+    pub fn project(&self, v: &mut [T]) -> Result<(), ConeError> {
+        assert_eq!(v.len(), self.numel, "v has incompatible dimension.");
+
+        let unsupported = self.cones.iter().position(|cone| {
+            !matches!(
+                cone,
+                SupportedCone::ZeroCone(_)
+                    | SupportedCone::NonnegativeCone(_)
+                    | SupportedCone::SecondOrderCone(_)
+            )
+        });
+        if let Some(i) = unsupported {
+            return Err(ConeError::NoProjection(i, self.cones[i].as_tag()));
+        }
+
+        for (cone, rng) in zip(&self.cones, &self.rng_cones) {
+            cone.project(&mut v[rng.clone()]);
+        }
+        Ok(())
+    }
+
+    // Violation of membership in the primal or dual cone for each of the
+    // constituent cones, measured as the smallest t ≥ 0 such that v + t⋅e
+    // is in the cone, with e the cone's central point.  For the primal
+    // zero cone it is instead the largest magnitude entry.
+    pub(crate) fn membership_violations(&mut self, v: &[T], pd: PrimalOrDualCone) -> Vec<T> {
+        zip(&mut self.cones, &self.rng_cones)
+            .map(|(cone, rng)| {
+                let vi = &v[rng.clone()];
+                match cone {
+                    SupportedCone::ZeroCone(_) => match pd {
+                        PrimalOrDualCone::PrimalCone => vi.norm_inf(),
+                        PrimalOrDualCone::DualCone => T::zero(),
+                    },
+                    SupportedCone::ExponentialCone(c) => _nonsymmetric_violation(c, vi, pd),
+                    SupportedCone::PowerCone(c) => _nonsymmetric_violation(c, vi, pd),
+                    SupportedCone::GenPowerCone(c) => _nonsymmetric_violation(c, vi, pd),
+                    SupportedCone::BoxCone(c) => _nonsymmetric_violation(c, vi, pd),
+                    // margins of the symmetric cones are measured along e
+                    _ => {
+                        let (α, _) = cone.margins(&mut vi.to_vec(), pd);
+                        T::max(-α, T::zero())
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+// nonsymmetric cones check membership of the cone interior
+// only, so find the smallest shift along e by bisection.  The
+// primal and dual parts of the unit initialization are taken
+// as e for the primal and dual cones respectively.
+fn _nonsymmetric_violation<T, C>(cone: &C, v: &[T], pd: PrimalOrDualCone) -> T
+where
+    T: FloatT,
+    C: Cone<T> + NonsymmetricCone<T>,
+{
+    let (mut e, mut work) = (vec![T::zero(); v.len()], vec![T::zero(); v.len()]);
+    match pd {
+        PrimalOrDualCone::PrimalCone => cone.unit_initialization(&mut work, &mut e),
+        PrimalOrDualCone::DualCone => cone.unit_initialization(&mut e, &mut work),
+    }
+
+    let mut is_interior = |t: T| {
+        work.waxpby(T::one(), v, t, &e);
+        match pd {
+            PrimalOrDualCone::PrimalCone => cone.is_primal_feasible(&work),
+            PrimalOrDualCone::DualCone => cone.is_dual_feasible(&work),
+        }
+    };
+
+    if is_interior(T::zero()) {
+        return T::zero();
+    }
+
+    let mut hi = T::one();
+    while !is_interior(hi) {
+        hi *= (2.).as_T();
+        if !hi.is_finite() {
+            return T::infinity();
+        }
+    }
+
+    let mut lo = T::zero();
+    for _ in 0..64 {
+        let mid = (lo + hi) * (0.5).as_T();
+        if is_interior(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
 }
 
 impl<T> Cone<T> for CompositeCone<T>
@@ -219,6 +342,10 @@ where
         (α, β)
     }
 
+    fn project(&self, v: &mut [T]) -> bool {
+        CompositeCone::project(self, v).is_ok()
+    }
+
     fn scaled_unit_shift(&self, z: &mut [T], α: T, pd: PrimalOrDualCone) {
         for (cone, rng) in zip(&self.cones, &self.rng_cones) {
             cone.scaled_unit_shift(&mut z[rng.clone()], α, pd);
@@ -8,6 +8,7 @@ use crate::{
 // Exponential Cone
 // -------------------------------------
 
+#[derive(Clone)]
 pub struct ExponentialCone<T> {
     // Hessian of the dual barrier at z
     H_dual: DenseMatrixSym3<T>,
@@ -72,6 +73,11 @@ where
         unreachable!();
     }
 
+    fn project(&self, _v: &mut [T]) -> bool {
+        // no closed form projection for nonsymmetric cones
+        false
+    }
+
     fn scaled_unit_shift(&self, _z: &mut [T], _α: T, _pd: PrimalOrDualCone) {
         // We should never end up shifting to this cone, since
         // asymmetric problems should always use unit_initialization
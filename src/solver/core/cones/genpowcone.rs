@@ -10,6 +10,7 @@ use std::iter::zip;
 // Generalized Power Cone
 // -------------------------------------
 
+#[derive(Clone)]
 pub struct GenPowerConeData<T> {
     // gradient of the dual barrier at z
     grad: Vec<T>,
@@ -44,10 +45,8 @@ where
         let dim1 = α.len();
         let dim = dim1 + dim2;
 
-        //PJG : these checks belong elsewhere
-        assert!(α.iter().all(|r| *r > T::zero())); // check all powers are greater than 0
-        assert!((T::one() - α.sum()).abs() < (T::epsilon() * α.len().as_T() * (0.5).as_T()));
-
+        // NB: the powers α are validated against the user
+        // problem data on solver construction
         Self {
             grad: vec![T::zero(); dim],
             z: vec![T::zero(); dim],
@@ -64,6 +63,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct GenPowerCone<T> {
     pub α: Vec<T>,                      // power defining the cone.  length determines dim1
     dim2: usize,                        // dimension of w
@@ -126,6 +126,11 @@ where
         // asymmetric problems should always use unit_initialization
         unreachable!();
     }
+    fn project(&self, _v: &mut [T]) -> bool {
+        // no closed form projection for nonsymmetric cones
+        false
+    }
+
     fn scaled_unit_shift(&self, _z: &mut [T], _α: T, _pd: PrimalOrDualCone) {
         // We should never end up shifting to this cone, since
         // asymmetric problems should always use unit_initialization
@@ -9,6 +9,7 @@ use enum_dispatch::*;
 mod compositecone;
 mod supportedcone;
 // primitive cone types
+mod boxcone;
 mod expcone;
 mod genpowcone;
 mod nonnegativecone;
@@ -22,8 +23,8 @@ mod symmetric_common;
 //re-export everything to appear as one module
 use nonsymmetric_common::*;
 pub use {
-    compositecone::*, expcone::*, genpowcone::*, nonnegativecone::*, powcone::*, socone::*,
-    supportedcone::*, symmetric_common::*, zerocone::*,
+    boxcone::*, compositecone::*, expcone::*, genpowcone::*, nonnegativecone::*, powcone::*,
+    socone::*, supportedcone::*, symmetric_common::*, zerocone::*,
 };
 
 // only use PSD cones with SDP/Blas enabled
@@ -72,6 +73,10 @@ where
     // the positive margin terms.
     fn margins(&mut self, z: &mut [T], pd: PrimalOrDualCone) -> (T, T);
 
+    // Euclidean projection of v onto the cone, in place.  Returns
+    // false and leaves v unchanged if there is no closed form
+    fn project(&self, v: &mut [T]) -> bool;
+
     // functions relating to unit vectors and cone initialization
     fn scaled_unit_shift(&self, z: &mut [T], α: T, pd: PrimalOrDualCone);
     fn unit_initialization(&self, z: &mut [T], s: &mut [T]);
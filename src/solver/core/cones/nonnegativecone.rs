@@ -10,6 +10,7 @@ use std::iter::zip;
 // Nonnegative Cone
 // -------------------------------------
 
+#[derive(Clone)]
 pub struct NonnegativeCone<T> {
     dim: usize,
     w: Vec<T>,
@@ -64,6 +65,11 @@ where
         (α, β)
     }
 
+    fn project(&self, v: &mut [T]) -> bool {
+        v.scalarop(|x| T::max(x, T::zero()));
+        true
+    }
+
     fn scaled_unit_shift(&self, z: &mut [T], α: T, _pd: PrimalOrDualCone) {
         z.translate(α);
     }
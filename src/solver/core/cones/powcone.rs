@@ -8,6 +8,7 @@ use crate::{
 // Power Cone
 // -------------------------------------
 
+#[derive(Clone)]
 pub struct PowerCone<T> {
     // power defining the cone
     α: T,
@@ -73,6 +74,11 @@ where
         // asymmetric problems should always use unit_initialization
         unreachable!();
     }
+    fn project(&self, _v: &mut [T]) -> bool {
+        // no closed form projection for nonsymmetric cones
+        false
+    }
+
     fn scaled_unit_shift(&self, _z: &mut [T], _α: T, _pd: PrimalOrDualCone) {
         // We should never end up shifting to this cone, since
         // asymmetric problems should always use unit_initialization
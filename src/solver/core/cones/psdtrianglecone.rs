@@ -8,6 +8,7 @@ use crate::{
 // Positive Semidefinite Cone (Scaled triangular form)
 // ------------------------------------
 
+#[derive(Clone)]
 pub struct PSDConeData<T> {
     chol1: CholeskyEngine<T>,
     chol2: CholeskyEngine<T>,
@@ -58,6 +59,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct PSDTriangleCone<T> {
     n: usize,                  // matrix dimension, i.e. matrix is n × n
     numel: usize,              // total number of elements in (lower triangle of) the matrix
@@ -127,6 +129,12 @@ where
         (α, β)
     }
 
+    fn project(&self, _v: &mut [T]) -> bool {
+        // requires an eigendecomposition, which is
+        // not available without mutable workspace
+        false
+    }
+
     fn scaled_unit_shift(&self, z: &mut [T], α: T, _pd: PrimalOrDualCone) {
         //adds αI to the vectorized triangle,
         //at elements [1,3,6....n(n+1)/2]
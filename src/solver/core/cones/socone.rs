@@ -9,6 +9,7 @@ use itertools::izip;
 // Second order Cone
 // -------------------------------------
 
+#[derive(Clone)]
 pub struct SecondOrderConeSparseData<T> {
     //vectors for rank 2 update representation of W^2
     pub u: Vec<T>,
@@ -31,6 +32,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct SecondOrderCone<T> {
     pub dim: usize,
     //internal working variables for W and its products
@@ -110,6 +112,23 @@ where
         (α, β)
     }
 
+    fn project(&self, v: &mut [T]) -> bool {
+        let t = v[0];
+        let normx = v[1..].norm();
+
+        if normx <= t {
+            // already in the cone
+        } else if normx <= -t {
+            v.fill(T::zero());
+        } else {
+            // nearest point on the boundary ray through (‖x‖, x)
+            let a = (t + normx) * (0.5).as_T();
+            v[0] = a;
+            v[1..].scale(a / normx);
+        }
+        true
+    }
+
     fn scaled_unit_shift(&self, z: &mut [T], α: T, _pd: PrimalOrDualCone) {
         z[0] += α;
     }
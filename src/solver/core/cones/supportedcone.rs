@@ -1,9 +1,13 @@
 use super::*;
-use crate::algebra::FloatT;
+use crate::algebra::{AsFloatT, FloatT, VectorMath};
+use thiserror::Error;
 
 #[cfg(feature = "sdp")]
 use crate::algebra::triangular_number;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // ---------------------------------------------------
 // We define some machinery here for enumerating the
 // different cone types that can live in the composite cone
@@ -12,6 +16,7 @@ use crate::algebra::triangular_number;
 /// API type describing the type of a conic constraint.
 ///  
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SupportedConeT<T> {
     /// The zero cone (used for equality constraints).
     ///
@@ -41,12 +46,34 @@ pub enum SupportedConeT<T> {
     /// constraint.   The "alpha" terms must sum to 1.
     GenPowerConeT(Vec<T>, usize),
 
+    /// The box cone {(t,s) : t ≥ 0, t⋅lower ≤ s ≤ t⋅upper}.
+    ///
+    /// The bounds must be of equal length n, and the cone has dimension
+    /// n + 1, with the scaling variable t first.   Bounds may be infinite,
+    /// but not both bounds for the same element, and finite bounds must
+    /// satisfy lower < upper.   The constraint `lower ≤ b - Ax ≤ upper` is
+    /// given by placing a row with zero A and b = 1 before the rows of
+    /// `b - Ax`, so that t = 1.
+    BoxConeT { lower: Vec<T>, upper: Vec<T> },
+
     /// The positive semidefinite cone in triangular form.
     ///
     /// The parameter indicates the matrix dimension, i.e. size = n
     /// means that the variable is the upper triangle of an nxn matrix.
     #[cfg(feature = "sdp")]
     PSDTriangleConeT(usize),
+
+    /// The positive semidefinite cone of complex Hermitian matrices in
+    /// triangular form.
+    ///
+    /// The parameter indicates the matrix dimension n.  The variable is the
+    /// upper triangle of an nxn Hermitian matrix H taken columnwise, with
+    /// each diagonal entry H_jj given by a single element and each off-diagonal
+    /// entry H_ij, i < j, by the pair of elements √2·Re(H_ij), √2·Im(H_ij).  The
+    /// cone has n^2 elements, and is implemented internally as a real PSD
+    /// cone of dimension 2n.
+    #[cfg(feature = "sdp")]
+    PSDTriangleComplexConeT(usize),
 }
 
 impl<T> SupportedConeT<T> {
@@ -63,11 +90,111 @@ impl<T> SupportedConeT<T> {
             SupportedConeT::PowerConeT(_) => 3,
             #[cfg(feature = "sdp")]
             SupportedConeT::PSDTriangleConeT(dim) => triangular_number(*dim),
+            #[cfg(feature = "sdp")]
+            SupportedConeT::PSDTriangleComplexConeT(dim) => *dim * *dim,
             SupportedConeT::GenPowerConeT(α, dim2) => α.len() + *dim2,
+            SupportedConeT::BoxConeT { lower, .. } => lower.len() + 1,
+        }
+    }
+
+    // true for cones that the presolver rewrites in terms of
+    // other cones with a different number of slack variables
+    pub(crate) fn is_expanded_by_presolve(&self) -> bool {
+        match self {
+            #[cfg(feature = "sdp")]
+            SupportedConeT::PSDTriangleComplexConeT(_) => true,
+            _ => false,
         }
     }
 }
 
+/// Error type returned by [`validate_cones`], describing the first
+/// invalid cone found, and by [`CompositeCone::project`].   Cone
+/// positions are zero based.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ConeError {
+    #[error(
+        "SecondOrderConeT at position {0} has dimension {1}, but must have dimension at least 2"
+    )]
+    BadSecondOrderDimension(usize, usize),
+    #[error("PowerConeT at position {0} has a power outside of (0,1)")]
+    BadPower(usize),
+    #[error("GenPowerConeT at position {0} has no powers")]
+    EmptyGenPowers(usize),
+    #[error("GenPowerConeT at position {0} has powers that are not all positive")]
+    NonpositiveGenPowers(usize),
+    #[error("GenPowerConeT at position {0} has powers that do not sum to 1")]
+    BadGenPowersSum(usize),
+    #[error("BoxConeT at position {0} has lower and upper bounds of different lengths")]
+    BoxBoundsLength(usize),
+    #[error("BoxConeT at position {0} has a lower bound not less than its upper bound")]
+    BoxBoundsOrder(usize),
+    #[error("BoxConeT at position {0} has an element with both bounds infinite")]
+    BoxBoundsInfinite(usize),
+    #[error("Cones have total dimension {0}, but the constraints have {1} rows")]
+    TotalDimension(usize, usize),
+    #[error("{} at position {0} has no closed form projection", .1.as_str())]
+    NoProjection(usize, SupportedConeTag),
+}
+
+/// Checks the user specification of a collection of cones for a problem
+/// with `m` constraint rows.
+///
+/// Returns an error describing the first cone with invalid dimensions or
+/// parameters, or an error if the total dimension of the cones is not `m`.
+/// This check is also made when constructing a solver, which panics if it
+/// fails.
+pub fn validate_cones<T: FloatT>(cones: &[SupportedConeT<T>], m: usize) -> Result<(), ConeError> {
+    for (i, cone) in cones.iter().enumerate() {
+        match cone {
+            SupportedConeT::SecondOrderConeT(dim) if *dim < 2 => {
+                return Err(ConeError::BadSecondOrderDimension(i, *dim));
+            }
+            SupportedConeT::PowerConeT(α) if !(*α > T::zero() && *α < T::one()) => {
+                return Err(ConeError::BadPower(i));
+            }
+            SupportedConeT::GenPowerConeT(α, _) => {
+                if α.is_empty() {
+                    return Err(ConeError::EmptyGenPowers(i));
+                }
+                if !α.iter().all(|r| *r > T::zero()) {
+                    return Err(ConeError::NonpositiveGenPowers(i));
+                }
+                let tol = T::epsilon() * α.len().as_T() * (0.5).as_T();
+                if (T::one() - α.sum()).abs() >= tol {
+                    return Err(ConeError::BadGenPowersSum(i));
+                }
+            }
+            SupportedConeT::BoxConeT { lower, upper } => {
+                if lower.len() != upper.len() {
+                    return Err(ConeError::BoxBoundsLength(i));
+                }
+                if !lower.iter().zip(upper).all(|(l, u)| l < u) {
+                    return Err(ConeError::BoxBoundsOrder(i));
+                }
+                let infbound: T = crate::solver::get_infinity().as_T();
+                if lower
+                    .iter()
+                    .zip(upper)
+                    .any(|(&l, &u)| l <= -infbound && u >= infbound)
+                {
+                    return Err(ConeError::BoxBoundsInfinite(i));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // NB: the PSDTriangleConeT parameter is the matrix dimension, so
+    // its number of variables is always a triangular number.
+    let total = cones.iter().map(|cone| cone.nvars()).sum();
+    if total != m {
+        return Err(ConeError::TotalDimension(total, m));
+    }
+
+    Ok(())
+}
+
 impl<T> std::fmt::Display for SupportedConeT<T>
 where
     T: FloatT,
@@ -93,6 +220,13 @@ pub fn make_cone<T: FloatT>(cone: &SupportedConeT<T>) -> SupportedCone<T> {
         }
         #[cfg(feature = "sdp")]
         SupportedConeT::PSDTriangleConeT(dim) => PSDTriangleCone::<T>::new(*dim).into(),
+        // the presolver embeds complex PSD constraints
+        // into real PSD constraints of twice the size
+        #[cfg(feature = "sdp")]
+        SupportedConeT::PSDTriangleComplexConeT(dim) => PSDTriangleCone::<T>::new(2 * *dim).into(),
+        SupportedConeT::BoxConeT { lower, upper } => {
+            BoxCone::<T>::new((*lower).clone(), (*upper).clone()).into()
+        }
     }
 }
 
@@ -102,6 +236,7 @@ pub fn make_cone<T: FloatT>(cone: &SupportedConeT<T>) -> SupportedCone<T> {
 // all of our internal cone types
 // -------------------------------------
 
+#[derive(Clone)]
 #[allow(clippy::enum_variant_names)]
 #[enum_dispatch(Cone<T>)]
 pub enum SupportedCone<T>
@@ -114,6 +249,7 @@ where
     ExponentialCone(ExponentialCone<T>),
     PowerCone(PowerCone<T>),
     GenPowerCone(GenPowerCone<T>),
+    BoxCone(BoxCone<T>),
     #[cfg(feature = "sdp")]
     PSDTriangleCone(PSDTriangleCone<T>),
 }
@@ -129,16 +265,18 @@ where
 // https://rust-lang.github.io/rfcs/2363-arbitrary-enum-discriminant.html
 // -------------------------------------
 
+/// Cone types, without any dimension or parameter data.
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
-pub(crate) enum SupportedConeTag {
+pub enum SupportedConeTag {
     ZeroCone = 0,
     NonnegativeCone,
     SecondOrderCone,
     ExponentialCone,
     PowerCone,
     GenPowerCone,
+    BoxCone,
     #[cfg(feature = "sdp")]
     PSDTriangleCone,
 }
@@ -158,7 +296,10 @@ impl<T> SupportedConeAsTag for SupportedConeT<T> {
             SupportedConeT::PowerConeT(_) => SupportedConeTag::PowerCone,
             #[cfg(feature = "sdp")]
             SupportedConeT::PSDTriangleConeT(_) => SupportedConeTag::PSDTriangleCone,
+            #[cfg(feature = "sdp")]
+            SupportedConeT::PSDTriangleComplexConeT(_) => SupportedConeTag::PSDTriangleCone,
             SupportedConeT::GenPowerConeT(_, _) => SupportedConeTag::GenPowerCone,
+            SupportedConeT::BoxConeT { .. } => SupportedConeTag::BoxCone,
         }
     }
 }
@@ -175,6 +316,7 @@ impl<T: FloatT> SupportedConeAsTag for SupportedCone<T> {
             #[cfg(feature = "sdp")]
             SupportedCone::PSDTriangleCone(_) => SupportedConeTag::PSDTriangleCone,
             SupportedCone::GenPowerCone(_) => SupportedConeTag::GenPowerCone,
+            SupportedCone::BoxCone(_) => SupportedConeTag::BoxCone,
         }
     }
 }
@@ -191,6 +333,7 @@ impl SupportedConeTag {
             #[cfg(feature = "sdp")]
             SupportedConeTag::PSDTriangleCone => "PSDTriangleCone",
             SupportedConeTag::GenPowerCone => "GenPowerCone",
+            SupportedConeTag::BoxCone => "BoxCone",
         }
     }
 }
@@ -9,6 +9,7 @@ use core::marker::PhantomData;
 // Zero Cone
 // -------------------------------------
 
+#[derive(Clone)]
 pub struct ZeroCone<T> {
     dim: usize,
     phantom: PhantomData<T>,
@@ -63,6 +64,11 @@ where
         // out regardless of the applied shift anway
         (T::max_value(), T::zero())
     }
+    fn project(&self, v: &mut [T]) -> bool {
+        v.fill(T::zero());
+        true
+    }
+
     fn scaled_unit_shift(&self, z: &mut [T], _α: T, pd: PrimalOrDualCone) {
         if pd == PrimalOrDualCone::PrimalCone {
             z.fill(T::zero());
@@ -4,19 +4,24 @@ use super::*;
 use crate::solver::core::cones::*;
 use enum_dispatch::*;
 
+#[derive(Clone)]
 #[enum_dispatch(SparseExpansionMapTrait)]
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum SparseExpansionMap {
     SOCExpansionMap(SOCExpansionMap),
     GenPowExpansionMap(GenPowExpansionMap),
+    BoxExpansionMap(BoxExpansionMap),
 }
 
 #[enum_dispatch(SparseExpansionConeTrait<T>)]
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum SparseExpansionCone<'a, T>
 where
     T: FloatT,
 {
     SecondOrderCone(&'a SecondOrderCone<T>),
     GenPowerCone(&'a GenPowerCone<T>),
+    BoxCone(&'a BoxCone<T>),
 }
 
 impl<'a, T> SupportedCone<T>
@@ -27,6 +32,7 @@ where
         match self {
             SupportedCone::SecondOrderCone(sc) => Some(SparseExpansionCone::SecondOrderCone(sc)),
             SupportedCone::GenPowerCone(sc) => Some(SparseExpansionCone::GenPowerCone(sc)),
+            SupportedCone::BoxCone(sc) => Some(SparseExpansionCone::BoxCone(sc)),
             _ => None,
         }
     }
@@ -51,8 +57,10 @@ impl SparseExpansionMapTrait for Vec<SparseExpansionMap> {
     }
 }
 
-type UpdateFcn<T> = fn(&mut BoxedDirectLDLSolver<T>, &mut CscMatrix<T>, &[usize], &[T]) -> ();
-type ScaleFcn<T> = fn(&mut BoxedDirectLDLSolver<T>, &mut CscMatrix<T>, &[usize], T) -> ();
+// updates to KKT entries, applied both to the matrix K and to the data
+// held by a linear solver of type S, e.g. a direct LDL solver
+pub(crate) type UpdateFcn<S, T> = fn(&mut S, &mut CscMatrix<T>, &[usize], &[T]) -> ();
+pub(crate) type ScaleFcn<S, T> = fn(&mut S, &mut CscMatrix<T>, &[usize], T) -> ();
 
 #[enum_dispatch]
 pub(crate) trait SparseExpansionConeTrait<T>
@@ -76,13 +84,13 @@ where
         col: usize,
         shape: MatrixTriangle,
     );
-    fn csc_update_sparsecone(
+    fn csc_update_sparsecone<S>(
         &self,
         map: &SparseExpansionMap,
-        ldl: &mut BoxedDirectLDLSolver<T>,
+        ldl: &mut S,
         K: &mut CscMatrix<T>,
-        updateFcn: UpdateFcn<T>,
-        scaleFcn: ScaleFcn<T>,
+        updateFcn: UpdateFcn<S, T>,
+        scaleFcn: ScaleFcn<S, T>,
     );
 }
 
@@ -109,6 +117,7 @@ macro_rules! impl_map_recover {
 // Second order cone data map
 //--------------------------------------
 
+#[derive(Clone)]
 pub(crate) struct SOCExpansionMap {
     u: Vec<usize>, //off diag dense columns u
     v: Vec<usize>, //off diag dense columns v
@@ -196,13 +205,13 @@ where
         K.fill_diag(&mut map.D, col, pdim);
     }
 
-    fn csc_update_sparsecone(
+    fn csc_update_sparsecone<S>(
         &self,
         map: &SparseExpansionMap,
-        ldl: &mut BoxedDirectLDLSolver<T>,
+        ldl: &mut S,
         K: &mut CscMatrix<T>,
-        updateFcn: UpdateFcn<T>,
-        scaleFcn: ScaleFcn<T>,
+        updateFcn: UpdateFcn<S, T>,
+        scaleFcn: ScaleFcn<S, T>,
     ) {
         let sparse_data = self.sparse_data.as_ref().unwrap();
 
@@ -224,6 +233,7 @@ where
 // Generalized power cone data map
 //--------------------------------------
 
+#[derive(Clone)]
 pub(crate) struct GenPowExpansionMap {
     p: Vec<usize>, //off diag dense columns p
     q: Vec<usize>, //off diag dense columns q
@@ -318,13 +328,13 @@ where
         K.fill_diag(&mut map.D, col, pdim);
     }
 
-    fn csc_update_sparsecone(
+    fn csc_update_sparsecone<S>(
         &self,
         map: &SparseExpansionMap,
-        ldl: &mut BoxedDirectLDLSolver<T>,
+        ldl: &mut S,
         K: &mut CscMatrix<T>,
-        updateFcn: UpdateFcn<T>,
-        scaleFcn: ScaleFcn<T>,
+        updateFcn: UpdateFcn<S, T>,
+        scaleFcn: ScaleFcn<S, T>,
     ) {
         let map = self.recover_map(map);
         let data = &self.data;
@@ -343,10 +353,129 @@ where
     }
 }
 
+//--------------------------------------
+// Box cone data map
+//--------------------------------------
+
+#[derive(Clone)]
+pub(crate) struct BoxExpansionMap {
+    p: Vec<usize>, //off diag dense columns p
+    q: Vec<usize>, //off diag entry q, in the t row only
+    D: [usize; 2], //diag D
+}
+
+impl BoxExpansionMap {
+    pub fn new<T: FloatT>(cone: &BoxCone<T>) -> Self {
+        let p = vec![0; cone.numel()];
+        let q = vec![0; 1];
+        let D = [0; 2];
+        Self { p, q, D }
+    }
+}
+
+impl SparseExpansionMapTrait for BoxExpansionMap {
+    fn pdim(&self) -> usize {
+        2
+    }
+    fn nnz_vec(&self) -> usize {
+        self.p.len() + self.q.len()
+    }
+    fn Dsigns(&self) -> &[i8] {
+        &[-1, 1]
+    }
+}
+
+impl_map_recover!(BoxCone, BoxExpansionMap);
+
+impl<T> SparseExpansionConeTrait<T> for &BoxCone<T>
+where
+    T: FloatT,
+{
+    fn expansion_map(&self) -> SparseExpansionMap {
+        SparseExpansionMap::BoxExpansionMap(BoxExpansionMap::new(self))
+    }
+
+    fn csc_colcount_sparsecone(
+        &self,
+        map: &SparseExpansionMap,
+        K: &mut CscMatrix<T>,
+        row: usize,
+        col: usize,
+        shape: MatrixTriangle,
+    ) {
+        let map = self.recover_map(map);
+        let nvars = self.numel();
+
+        match shape {
+            MatrixTriangle::Triu => {
+                K.colcount_colvec(1, row, col); //q column
+                K.colcount_colvec(nvars, row, col + 1); //p column
+            }
+            MatrixTriangle::Tril => {
+                K.colcount_rowvec(1, col, row); //q row
+                K.colcount_rowvec(nvars, col + 1, row); //p row
+            }
+        }
+        K.colcount_diag(col, map.pdim());
+    }
+
+    fn csc_fill_sparsecone(
+        &self,
+        map: &mut SparseExpansionMap,
+        K: &mut CscMatrix<T>,
+        row: usize,
+        col: usize,
+        shape: MatrixTriangle,
+    ) {
+        let map = self.recover_map_mut(map);
+
+        match shape {
+            MatrixTriangle::Triu => {
+                K.fill_colvec(&mut map.q, row, col); //q column
+                K.fill_colvec(&mut map.p, row, col + 1); //p column
+            }
+            MatrixTriangle::Tril => {
+                K.fill_rowvec(&mut map.q, col, row); //q row
+                K.fill_rowvec(&mut map.p, col + 1, row); //p row
+            }
+        }
+        let pdim = map.pdim();
+        K.fill_diag(&mut map.D, col, pdim);
+    }
+
+    fn csc_update_sparsecone<S>(
+        &self,
+        map: &SparseExpansionMap,
+        ldl: &mut S,
+        K: &mut CscMatrix<T>,
+        updateFcn: UpdateFcn<S, T>,
+        scaleFcn: ScaleFcn<S, T>,
+    ) {
+        let map = self.recover_map(map);
+        let data = &self.data;
+
+        // The terms pp' and qq' can be very large relative to μD, so
+        // the extended rows/cols are scaled to have diagonals of size
+        // μ‖p‖² and μq², making regularization of them negligible.
+        let μ = data.μ;
+        let (normp, q) = (data.p.norm(), data.q);
+
+        //&off diagonal columns (or rows)
+        updateFcn(ldl, K, &map.q, &[q]);
+        updateFcn(ldl, K, &map.p, &data.p);
+        scaleFcn(ldl, K, &map.q, -μ * q);
+        scaleFcn(ldl, K, &map.p, -μ * normp);
+
+        //&set diagonal to μ*(-q²,‖p‖²) in the extended rows/cols
+        updateFcn(ldl, K, &map.D, &[-μ * q * q, μ * normp * normp]);
+    }
+}
+
 //--------------------------------------
 // LDL Data Map
 //--------------------------------------
 
+#[derive(Clone)]
 pub(crate) struct LDLDataMap {
     pub P: Vec<usize>,
     pub A: Vec<usize>,
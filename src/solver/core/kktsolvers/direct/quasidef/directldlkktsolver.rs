@@ -1,9 +1,12 @@
 #![allow(non_snake_case)]
 
+use super::ldlsolvers::dense::*;
 use super::ldlsolvers::qdldl::*;
 use super::*;
-use crate::solver::core::kktsolvers::KKTSolver;
+use crate::qdldl::QDLDLError;
+use crate::solver::core::kktsolvers::{KKTInfo, KKTSolver};
 use crate::solver::core::{cones::*, CoreSettings};
+use std::convert::Infallible;
 use std::iter::zip;
 
 // -------------------------------------
@@ -15,7 +18,14 @@ use std::iter::zip;
 
 pub(crate) type BoxedDirectLDLSolver<T> = Box<dyn DirectLDLSolver<T> + Send>;
 
-pub struct DirectLDLKKTSolver<T> {
+impl<T: FloatT> Clone for BoxedDirectLDLSolver<T> {
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
+}
+
+#[derive(Clone)]
+pub struct DirectLDLKKTSolver<T: FloatT> {
     // problem dimensions
     m: usize,
     n: usize,
@@ -48,6 +58,18 @@ pub struct DirectLDLKKTSolver<T> {
 
     // the diagonal regularizer currently applied
     diagonal_regularizer: T,
+
+    // running count of iterative refinement steps
+    refinement_count: usize,
+
+    // residual norm after the most recent refinement
+    refinement_error: T,
+
+    // number of fill-reducing orderings computed
+    symbolic_count: usize,
+
+    // running count of factorization retries
+    retry_count: usize,
 }
 
 impl<T> DirectLDLKKTSolver<T>
@@ -62,6 +84,71 @@ where
         n: usize,
         settings: &CoreSettings<T>,
     ) -> Self {
+        Self::new_with_ordering(P, A, cones, m, n, settings, None)
+    }
+
+    /// Creates a KKT solver as for [`new`](Self::new), but reusing the
+    /// fill-reducing ordering in `previous`, given together with the KKT
+    /// matrix it was computed for, if the newly assembled KKT matrix has
+    /// the same sparsity pattern.
+    pub fn new_with_ordering(
+        P: &CscMatrix<T>,
+        A: &CscMatrix<T>,
+        cones: &CompositeCone<T>,
+        m: usize,
+        n: usize,
+        settings: &CoreSettings<T>,
+        previous: Option<(&CscMatrix<T>, &[usize])>,
+    ) -> Self {
+        // reuse the previous ordering only if the sparsity matches,
+        // and never for KKT matrices small enough to factor densely
+        let get_perm = |KKT: &CscMatrix<T>| -> Result<_, Infallible> {
+            Ok(previous
+                .filter(|_| !_use_dense_ldl(KKT, settings))
+                .filter(|(K, _)| K.colptr == KKT.colptr && K.rowval == KKT.rowval)
+                .map(|(_, perm)| perm))
+        };
+        Self::_new(P, A, cones, m, n, settings, get_perm).unwrap()
+    }
+
+    /// Creates a KKT solver as for [`new`](Self::new), but factoring the KKT
+    /// matrix with the user-supplied fill-reducing ordering `perm` in place
+    /// of the default AMD ordering.
+    ///
+    /// The KKT dimension is `n + m + p`, where `p` is the number of extra
+    /// variables introduced by sparse expansion of some cones.  Returns an
+    /// error if `perm` is not a permutation of `0..n + m + p`.
+    pub fn new_with_permutation(
+        P: &CscMatrix<T>,
+        A: &CscMatrix<T>,
+        cones: &CompositeCone<T>,
+        m: usize,
+        n: usize,
+        settings: &CoreSettings<T>,
+        perm: &[usize],
+    ) -> Result<Self, QDLDLError> {
+        let get_perm = |KKT: &CscMatrix<T>| {
+            if _is_permutation(perm, KKT.nrows()) {
+                Ok(Some(perm))
+            } else {
+                Err(QDLDLError::InvalidPermutation)
+            }
+        };
+        Self::_new(P, A, cones, m, n, settings, get_perm)
+    }
+
+    // `get_perm` supplies the fill-reducing ordering, if any, to use for
+    // the assembled KKT matrix.  AMD is used otherwise, unless the KKT
+    // matrix is small enough to be factored densely.
+    fn _new<'a, E>(
+        P: &CscMatrix<T>,
+        A: &CscMatrix<T>,
+        cones: &CompositeCone<T>,
+        m: usize,
+        n: usize,
+        settings: &CoreSettings<T>,
+        get_perm: impl FnOnce(&CscMatrix<T>) -> Result<Option<&'a [usize]>, E>,
+    ) -> Result<Self, E> {
         // get a constructor for the LDL solver we should use,
         // and also the matrix shape it requires
         let (kktshape, ldl_ctor) = _get_ldlsolver_config(settings);
@@ -88,10 +175,22 @@ where
 
         let diagonal_regularizer = T::zero();
 
+        let perm = get_perm(&KKT)?;
+        let is_dense = perm.is_none()
+            && _use_dense_ldl(&KKT, settings)
+            && kktshape == DenseDirectLDLSolver::<T>::required_matrix_shape();
+        let symbolic_count = if perm.is_some() || is_dense { 0 } else { 1 };
+
         // now make the LDL linear solver engine
-        let ldlsolver = ldl_ctor(&KKT, &dsigns, settings);
+        let ldlsolver: BoxedDirectLDLSolver<T> = {
+            if is_dense {
+                Box::new(DenseDirectLDLSolver::<T>::new(&KKT, &dsigns, settings))
+            } else {
+                ldl_ctor(&KKT, &dsigns, settings, perm)
+            }
+        };
 
-        Self {
+        Ok(Self {
             m,
             n,
             p,
@@ -105,7 +204,11 @@ where
             KKT,
             ldlsolver,
             diagonal_regularizer,
-        }
+            refinement_count: 0,
+            refinement_error: T::nan(),
+            symbolic_count,
+            retry_count: 0,
+        })
     }
 }
 
@@ -114,6 +217,15 @@ where
     T: FloatT,
 {
     fn update(&mut self, cones: &CompositeCone<T>, settings: &CoreSettings<T>) -> bool {
+        self.update_with_abort(cones, settings, &|| false)
+    }
+
+    fn update_with_abort(
+        &mut self,
+        cones: &CompositeCone<T>,
+        settings: &CoreSettings<T>,
+        abort: &dyn Fn() -> bool,
+    ) -> bool {
         let map = &self.map;
 
         // Set the elements the W^tW blocks in the KKT matrix.
@@ -136,7 +248,7 @@ where
             }
         }
 
-        self.regularize_and_refactor(settings)
+        self.regularize_and_refactor(settings, abort)
     }
 
     fn setrhs(&mut self, rhsx: &[T], rhsz: &[T]) {
@@ -159,6 +271,7 @@ where
             if settings.iterative_refinement_enable {
                 self.iterative_refinement(settings)
             } else {
+                self.refinement_error = T::nan();
                 self.x.is_finite()
             }
         };
@@ -177,6 +290,32 @@ where
     fn update_A(&mut self, A: &CscMatrix<T>) {
         _update_values(&mut self.ldlsolver, &mut self.KKT, &self.map.A, &A.nzval);
     }
+
+    fn kkt_info(&self) -> KKTInfo {
+        KKTInfo {
+            nnz_kkt: self.KKT.nnz(),
+            nnz_L: self.ldlsolver.nnz_L(),
+            num_dynamic_regularizations: self.ldlsolver.regularize_count(),
+            num_refinement_steps: self.refinement_count,
+            final_refinement_residual: self.refinement_error.to_f64().unwrap(),
+            num_symbolic_factorizations: self.symbolic_count,
+            num_factorization_retries: self.retry_count,
+            num_iterative_solver_iters: 0,
+            is_reduced: false,
+        }
+    }
+
+    fn kkt_matrix(&self) -> &CscMatrix<T> {
+        &self.KKT
+    }
+
+    fn ordering(&self) -> Option<&[usize]> {
+        Some(self.ldlsolver.ordering())
+    }
+
+    fn try_clone(&self) -> Option<Box<dyn KKTSolver<T> + Send>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 impl<T> DirectLDLKKTSolver<T>
@@ -196,7 +335,11 @@ where
         }
     }
 
-    fn regularize_and_refactor(&mut self, settings: &CoreSettings<T>) -> bool {
+    fn regularize_and_refactor(
+        &mut self,
+        settings: &CoreSettings<T>,
+        abort: &dyn Fn() -> bool,
+    ) -> bool {
         let map = &self.map;
         let KKT = &mut self.KKT;
         let dsigns = &self.dsigns;
@@ -231,8 +374,23 @@ where
             self.diagonal_regularizer = eps;
         }
 
-        //refactor with new data
-        let is_success = self.ldlsolver.refactor(KKT);
+        //refactor with new data, retrying with a larger dynamic
+        //regularization threshold if the factorization fails.
+        //The KKT assembly is complete, so stop here if aborted
+        let mut is_success = !abort() && self.ldlsolver.refactor_with_abort(KKT, abort);
+
+        let regularize_eps = self.ldlsolver.regularize_eps();
+        for _ in 0..settings.factorization_max_retries {
+            if is_success || abort() {
+                break;
+            }
+            let eps = self.ldlsolver.regularize_eps() * settings.factorization_retry_factor;
+            self.ldlsolver.set_regularize_eps(T::max(eps, T::epsilon()));
+            self.retry_count += 1;
+            is_success = self.ldlsolver.refactor_with_abort(KKT, abort);
+        }
+        // later factorizations start again from the configured threshold
+        self.ldlsolver.set_regularize_eps(regularize_eps);
 
         if settings.static_regularization_enable {
             // put our internal copy of the KKT matrix back the way
@@ -275,6 +433,7 @@ where
 
             //make a refinement
             self.ldlsolver.solve(dx, e);
+            self.refinement_count += 1;
 
             //prospective solution is x + dx.  Use dx space to
             // hold it for a check before applying to x
@@ -291,17 +450,36 @@ where
                 //insufficient improvement.  Exit
                 if improved_ratio > T::one() {
                     std::mem::swap(x, dx);
+                } else {
+                    norme = lastnorme;
                 }
                 break;
             }
             std::mem::swap(x, dx);
         }
+        self.refinement_error = norme;
+
         //NB: "success" means only that we had a finite valued result
         true
     }
 }
 
-fn _compute_regularizer<T: FloatT>(diag_kkt: &[T], settings: &CoreSettings<T>) -> T {
+// true if the KKT matrix should be factored with a dense LDL
+// in place of the sparse solver given by direct_solve_method
+pub(crate) fn _use_dense_ldl<T: FloatT>(KKT: &CscMatrix<T>, settings: &CoreSettings<T>) -> bool {
+    KKT.nrows() < settings.dense_kkt_threshold
+}
+
+// true if `perm` is a permutation of 0..n
+fn _is_permutation(perm: &[usize], n: usize) -> bool {
+    let mut seen = vec![false; n];
+    perm.len() == n
+        && perm
+            .iter()
+            .all(|&i| i < n && !std::mem::replace(&mut seen[i], true))
+}
+
+pub(crate) fn _compute_regularizer<T: FloatT>(diag_kkt: &[T], settings: &CoreSettings<T>) -> T {
     let maxdiag = diag_kkt.norm_inf();
 
     // Compute a new regularizer
@@ -311,7 +489,12 @@ fn _compute_regularizer<T: FloatT>(diag_kkt: &[T], settings: &CoreSettings<T>) -
 //  computes e = b - Kξ, overwriting the first argument
 //  and returning its norm
 
-fn _get_refine_error<T: FloatT>(e: &mut [T], b: &[T], K: &CscMatrix<T>, ξ: &mut [T]) -> T {
+pub(crate) fn _get_refine_error<T: FloatT>(
+    e: &mut [T],
+    b: &[T],
+    K: &CscMatrix<T>,
+    ξ: &mut [T],
+) -> T {
     // Note that K is only triu data, so need to
     // be careful when computing the residual here
 
@@ -322,9 +505,12 @@ fn _get_refine_error<T: FloatT>(e: &mut [T], b: &[T], K: &CscMatrix<T>, ξ: &mut
     e.norm_inf()
 }
 
-type LDLConstructor<T> = fn(&CscMatrix<T>, &[i8], &CoreSettings<T>) -> BoxedDirectLDLSolver<T>;
+pub(crate) type LDLConstructor<T> =
+    fn(&CscMatrix<T>, &[i8], &CoreSettings<T>, Option<&[usize]>) -> BoxedDirectLDLSolver<T>;
 
-fn _get_ldlsolver_config<T>(settings: &CoreSettings<T>) -> (MatrixTriangle, LDLConstructor<T>)
+pub(crate) fn _get_ldlsolver_config<T>(
+    settings: &CoreSettings<T>,
+) -> (MatrixTriangle, LDLConstructor<T>)
 where
     T: FloatT,
 {
@@ -343,7 +529,7 @@ where
     match settings.direct_solve_method.as_str() {
         "qdldl" => {
             kktshape = QDLDLDirectLDLSolver::<T>::required_matrix_shape();
-            ldlptr = |M, D, S| Box::new(QDLDLDirectLDLSolver::<T>::new(M, D, S));
+            ldlptr = |M, D, S, p| Box::new(QDLDLDirectLDLSolver::<T>::new(M, D, S, p));
         }
         "custom" => {
             unimplemented!();
@@ -372,7 +558,7 @@ fn _update_values<T: FloatT>(
     ldlsolver.update_values(index, values);
 }
 
-fn _update_values_KKT<T: FloatT>(KKT: &mut CscMatrix<T>, index: &[usize], values: &[T]) {
+pub(crate) fn _update_values_KKT<T: FloatT>(KKT: &mut CscMatrix<T>, index: &[usize], values: &[T]) {
     for (idx, v) in zip(index, values) {
         KKT.nzval[*idx] = *v;
     }
@@ -392,13 +578,13 @@ fn _scale_values<T: FloatT>(
 }
 
 //scales KKT matrix values
-fn _scale_values_KKT<T: FloatT>(KKT: &mut CscMatrix<T>, index: &[usize], scale: T) {
+pub(crate) fn _scale_values_KKT<T: FloatT>(KKT: &mut CscMatrix<T>, index: &[usize], scale: T) {
     for idx in index.iter() {
         KKT.nzval[*idx] *= scale;
     }
 }
 
-fn _fill_signs(signs: &mut [i8], m: usize, n: usize, map: &LDLDataMap) {
+pub(crate) fn _fill_signs(signs: &mut [i8], m: usize, n: usize, map: &LDLDataMap) {
     signs.fill(1);
 
     //flip expected negative signs of D in LDL
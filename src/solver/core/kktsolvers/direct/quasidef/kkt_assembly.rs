@@ -49,6 +49,29 @@ pub(crate) fn assemble_kkt_matrix<T: FloatT>(
 
     (K, map)
 }
+// number of nonzeros in the KKT matrix that would be assembled
+// from P, A and cones, computed without allocating the matrix itself
+pub(crate) fn predicted_kkt_nnz<T: FloatT>(
+    P: &CscMatrix<T>,
+    A: &CscMatrix<T>,
+    cones: &CompositeCone<T>,
+) -> usize {
+    let n = A.n;
+
+    // total entries in the Hs blocks
+    let nnz_Hsblocks = cones.rng_blocks.last().map_or(0, |rng| rng.end);
+
+    // extra entries for sparse cone expansions
+    let (mut p, mut nnz_sparse) = (0, 0);
+    for cone in cones.iter().filter(|cone| cone.is_sparse_expandable()) {
+        let map = cone.to_sparse_expansion().unwrap().expansion_map();
+        p += map.pdim();
+        nnz_sparse += map.nnz_vec();
+    }
+
+    P.nnz() + n - P.count_diagonal_entries() + A.nnz() + nnz_Hsblocks + nnz_sparse + p
+}
+
 fn _kkt_assemble_colcounts<T: FloatT>(
     K: &mut CscMatrix<T>,
     P: &CscMatrix<T>,
@@ -264,3 +287,38 @@ fn test_kkt_assembly_upper_lower() {
     }
     assert_eq!(Kl, Kl_true_dense);
 }
+
+#[test]
+fn test_predicted_kkt_nnz() {
+    let P = CscMatrix::from(&[
+        [1., 2., 0.], //
+        [0., 0., 5.], //
+        [0., 0., 6.], //
+    ]);
+    let A = CscMatrix::from(&[
+        [7., 0., 8.],  //
+        [0., 9., 10.], //
+        [1., 2., 3.],  //
+        [1., 0., 0.],  //
+        [0., 1., 0.],  //
+        [0., 0., 1.],  //
+        [1., 1., 1.],  //
+        [0., 1., 0.],  //
+        [0., 0., 1.],  //
+        [1., 0., 1.],  //
+        [0., 1., 1.],  //
+        [1., 1., 0.],  //
+    ]);
+
+    // diagonal, dense and sparse expanded Hs blocks
+    let cones = CompositeCone::new(&[
+        SupportedConeT::NonnegativeConeT(3),
+        SupportedConeT::ExponentialConeT(),
+        SupportedConeT::SecondOrderConeT(6),
+    ]);
+
+    for shape in [MatrixTriangle::Triu, MatrixTriangle::Tril] {
+        let (K, _) = assemble_kkt_matrix(&P, &A, &cones, shape);
+        assert_eq!(predicted_kkt_nnz(&P, &A, &cones), K.nnz());
+    }
+}
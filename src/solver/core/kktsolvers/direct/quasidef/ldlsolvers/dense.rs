@@ -0,0 +1,167 @@
+#![allow(non_snake_case)]
+use crate::algebra::*;
+use crate::solver::core::kktsolvers::direct::DirectLDLSolver;
+use crate::solver::core::CoreSettings;
+
+// -------------------------------------
+// Dense LDL factorization for very small KKT systems, where
+// the overhead of a sparse ordering and factorization dominates
+// -------------------------------------
+
+#[derive(Clone)]
+pub struct DenseDirectLDLSolver<T> {
+    dim: usize,
+
+    // unit lower triangular factor L, stored densely by rows
+    L: Vec<T>,
+    D: Vec<T>,
+    Dinv: Vec<T>,
+
+    // the expected signs of D
+    Dsigns: Vec<i8>,
+
+    // dynamic regularization parameters, as for QDLDL
+    regularize_eps: T,
+    regularize_delta: T,
+    regularize_count: usize,
+
+    // the identity ordering, since no reordering is applied
+    perm: Vec<usize>,
+}
+
+impl<T> DenseDirectLDLSolver<T>
+where
+    T: FloatT,
+{
+    pub fn new(KKT: &CscMatrix<T>, Dsigns: &[i8], settings: &CoreSettings<T>) -> Self {
+        let dim = KKT.nrows();
+
+        assert!(dim == KKT.ncols(), "KKT matrix is not square");
+
+        Self {
+            dim,
+            L: vec![T::zero(); dim * dim],
+            D: vec![T::zero(); dim],
+            Dinv: vec![T::zero(); dim],
+            Dsigns: Dsigns.to_vec(),
+            regularize_eps: settings.dynamic_regularization_eps,
+            regularize_delta: settings.dynamic_regularization_delta,
+            regularize_count: 0,
+            perm: (0..dim).collect(),
+        }
+    }
+
+    // factors the matrix already copied into the lower triangle of L,
+    // overwriting it with the factor L.  Pivots with the wrong sign or
+    // too close to zero are replaced by ±regularize_delta
+    fn factor(&mut self) -> bool {
+        let n = self.dim;
+        let (L, D) = (&mut self.L, &mut self.D);
+        self.regularize_count = 0;
+
+        for k in 0..n {
+            let mut d = L[k * n + k];
+            for j in 0..k {
+                d -= L[k * n + j] * L[k * n + j] * D[j];
+            }
+
+            let sign = T::from_i8(self.Dsigns[k]).unwrap();
+            if d * sign < self.regularize_eps {
+                d = self.regularize_delta * sign;
+                self.regularize_count += 1;
+            }
+            if d == T::zero() {
+                return false;
+            }
+            D[k] = d;
+            L[k * n + k] = T::one();
+
+            for i in (k + 1)..n {
+                let mut v = L[i * n + k];
+                for j in 0..k {
+                    v -= L[i * n + j] * L[k * n + j] * D[j];
+                }
+                L[i * n + k] = v / d;
+            }
+        }
+
+        for (dinv, &d) in self.Dinv.iter_mut().zip(self.D.iter()) {
+            *dinv = T::recip(d);
+        }
+        self.Dinv.is_finite() && self.L.is_finite()
+    }
+}
+
+impl<T> DirectLDLSolver<T> for DenseDirectLDLSolver<T>
+where
+    T: FloatT,
+{
+    // the KKT matrix is copied in full on each refactor,
+    // so no internal copy needs to be maintained here
+    fn update_values(&mut self, _index: &[usize], _values: &[T]) {}
+
+    fn scale_values(&mut self, _index: &[usize], _scale: T) {}
+
+    fn offset_values(&mut self, _index: &[usize], _offset: T, _signs: &[i8]) {}
+
+    fn solve(&mut self, x: &mut [T], b: &[T]) {
+        let n = self.dim;
+        let L = &self.L;
+        x.copy_from(b);
+
+        // forward solve with L, scale by D⁻¹, then back solve with Lᵀ
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= L[i * n + j] * x[j];
+            }
+        }
+        x.hadamard(&self.Dinv);
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] -= L[j * n + i] * x[j];
+            }
+        }
+    }
+
+    fn refactor(&mut self, kkt: &CscMatrix<T>) -> bool {
+        // copy the upper triangle of the KKT matrix into
+        // the lower triangle of the dense workspace
+        let n = self.dim;
+        self.L.fill(T::zero());
+        for col in 0..n {
+            for k in kkt.colptr[col]..kkt.colptr[col + 1] {
+                let row = kkt.rowval[k];
+                self.L[col * n + row] = kkt.nzval[k];
+            }
+        }
+        self.factor()
+    }
+
+    fn nnz_L(&self) -> usize {
+        self.dim * self.dim.saturating_sub(1) / 2
+    }
+
+    fn regularize_count(&self) -> usize {
+        self.regularize_count
+    }
+
+    fn regularize_eps(&self) -> T {
+        self.regularize_eps
+    }
+
+    fn set_regularize_eps(&mut self, eps: T) {
+        self.regularize_eps = eps;
+    }
+
+    fn ordering(&self) -> &[usize] {
+        &self.perm
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DirectLDLSolver<T> + Send> {
+        Box::new(self.clone())
+    }
+
+    fn required_matrix_shape() -> MatrixTriangle {
+        MatrixTriangle::Triu
+    }
+}
@@ -1 +1,2 @@
+pub mod dense;
 pub mod qdldl;
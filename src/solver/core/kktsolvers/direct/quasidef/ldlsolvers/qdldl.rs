@@ -4,6 +4,7 @@ use crate::qdldl::*;
 use crate::solver::core::kktsolvers::direct::DirectLDLSolver;
 use crate::solver::core::CoreSettings;
 
+#[derive(Clone)]
 pub struct QDLDLDirectLDLSolver<T> {
     //KKT matrix and its QDLDL factorization
     factors: QDLDLFactorisation<T>,
@@ -13,7 +14,12 @@ impl<T> QDLDLDirectLDLSolver<T>
 where
     T: FloatT,
 {
-    pub fn new(KKT: &CscMatrix<T>, Dsigns: &[i8], settings: &CoreSettings<T>) -> Self {
+    pub fn new(
+        KKT: &CscMatrix<T>,
+        Dsigns: &[i8],
+        settings: &CoreSettings<T>,
+        perm: Option<&[usize]>,
+    ) -> Self {
         let dim = KKT.nrows();
 
         assert!(dim == KKT.ncols(), "KKT matrix is not square");
@@ -27,15 +33,19 @@ where
 
         //make a logical factorization to fix memory allocations
 
-        let opts = QDLDLSettingsBuilder::default()
-            .logical(true) //allocate memory only on init
+        let mut opts = QDLDLSettingsBuilder::default();
+        opts.logical(true) //allocate memory only on init
             .Dsigns(Dsigns.to_vec())
             .regularize_enable(true)
             .regularize_eps(settings.dynamic_regularization_eps)
             .regularize_delta(settings.dynamic_regularization_delta)
-            .amd_dense_scale(1.5)
-            .build()
-            .unwrap();
+            .amd_dense_scale(1.5);
+
+        // skip the AMD ordering if one is provided
+        if let Some(perm) = perm {
+            opts.perm(perm.to_vec());
+        }
+        let opts = opts.build().unwrap();
 
         let factors = QDLDLFactorisation::<T>::new(KKT, Some(opts)).unwrap();
 
@@ -71,8 +81,35 @@ where
         //QDLDL has maintained its own version of the permuted
         //KKT matrix through custom update/scale/offset methods,
         //so we ignore the KKT matrix provided by the caller
-        self.factors.refactor().unwrap();
-        self.factors.Dinv.is_finite()
+        self.factors.refactor().is_ok() && self.factors.Dinv.is_finite()
+    }
+
+    fn refactor_with_abort(&mut self, _kkt: &CscMatrix<T>, abort: &dyn Fn() -> bool) -> bool {
+        self.factors.refactor_with_abort(abort).is_ok() && self.factors.Dinv.is_finite()
+    }
+
+    fn nnz_L(&self) -> usize {
+        self.factors.L.nnz()
+    }
+
+    fn regularize_count(&self) -> usize {
+        self.factors.regularize_count()
+    }
+
+    fn regularize_eps(&self) -> T {
+        self.factors.regularize_eps()
+    }
+
+    fn set_regularize_eps(&mut self, eps: T) {
+        self.factors.set_regularize_eps(eps);
+    }
+
+    fn ordering(&self) -> &[usize] {
+        &self.factors.perm
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DirectLDLSolver<T> + Send> {
+        Box::new(self.clone())
     }
 
     fn required_matrix_shape() -> MatrixTriangle {
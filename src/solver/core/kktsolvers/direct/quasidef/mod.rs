@@ -7,9 +7,11 @@ pub mod ldlsolvers;
 mod datamaps;
 mod directldlkktsolver;
 mod kkt_assembly;
-use datamaps::*;
+mod reducedldlkktsolver;
+pub(crate) use datamaps::*;
 pub use directldlkktsolver::*;
-use kkt_assembly::*;
+pub(crate) use kkt_assembly::*;
+pub use reducedldlkktsolver::*;
 
 pub trait DirectLDLSolver<T: FloatT> {
     fn update_values(&mut self, index: &[usize], values: &[T]);
@@ -17,6 +19,21 @@ pub trait DirectLDLSolver<T: FloatT> {
     fn offset_values(&mut self, index: &[usize], offset: T, signs: &[i8]);
     fn solve(&mut self, x: &mut [T], b: &[T]);
     fn refactor(&mut self, kkt: &CscMatrix<T>) -> bool;
+
+    /// As [`refactor`](DirectLDLSolver::refactor), but may stop early and
+    /// return false if `abort` returns true.  The default implementation
+    /// never stops early.
+    fn refactor_with_abort(&mut self, kkt: &CscMatrix<T>, _abort: &dyn Fn() -> bool) -> bool {
+        self.refactor(kkt)
+    }
+    fn nnz_L(&self) -> usize;
+    fn regularize_count(&self) -> usize;
+    fn regularize_eps(&self) -> T;
+    fn set_regularize_eps(&mut self, eps: T);
+    fn ordering(&self) -> &[usize];
+
+    /// A deep copy of the solver and its factorization.
+    fn clone_boxed(&self) -> Box<dyn DirectLDLSolver<T> + Send>;
     fn required_matrix_shape() -> MatrixTriangle
     where
         Self: Sized;
@@ -0,0 +1,551 @@
+#![allow(non_snake_case)]
+
+use super::ldlsolvers::dense::*;
+use super::*;
+use crate::solver::core::kktsolvers::{KKTInfo, KKTSolver};
+use crate::solver::core::{cones::*, CoreSettings};
+use std::iter::zip;
+
+// -------------------------------------
+// KKTSolver using direct LDL factorisation of the reduced
+// (normal equations) system.
+//
+// For problems with only nonnegative cones, the Hs block of the
+// KKT matrix [P A'; A -Hs] is diagonal and positive, and z can be
+// eliminated to leave the positive definite system
+//
+//     (P + A'Hs⁻¹A) x = rx + A'Hs⁻¹rz,    z = Hs⁻¹(Ax - rz)
+//
+// which is of dimension n rather than n + m.
+// -------------------------------------
+
+#[derive(Clone)]
+pub struct ReducedLDLKKTSolver<T: FloatT> {
+    // copies of the problem data, upper triangular P
+    P: CscMatrix<T>,
+    A: CscMatrix<T>,
+
+    // entries of A by row, as (column, index into A.nzval)
+    Arowptr: Vec<usize>,
+    Arowidx: Vec<(usize, usize)>,
+
+    // index into M.nzval of each entry of P, of the diagonal
+    // of M, and of each pair of entries within each row of A
+    // in the order visited in `assemble`
+    Pmap: Vec<usize>,
+    diagmap: Vec<usize>,
+    Amap: Vec<usize>,
+
+    // the diagonal of Hs and its inverse
+    Hs: Vec<T>,
+    Hsinv: Vec<T>,
+
+    // right hand side of the full KKT system
+    bx: Vec<T>,
+    bz: Vec<T>,
+
+    // solution of the full KKT system
+    x: Vec<T>,
+    z: Vec<T>,
+
+    // internal workspace for solves and the IR scheme
+    workx: Vec<T>,
+    ex: Vec<T>,
+    ez: Vec<T>,
+    dx: Vec<T>,
+    dz: Vec<T>,
+
+    // the reduced matrix P + A'Hs⁻¹A, upper triangular
+    M: CscMatrix<T>,
+
+    // index of every entry of M, for updates to the LDL solver
+    allmap: Vec<usize>,
+
+    // the direct linear LDL solver
+    ldlsolver: BoxedDirectLDLSolver<T>,
+
+    // the diagonal regularizer currently applied
+    diagonal_regularizer: T,
+
+    // running count of iterative refinement steps
+    refinement_count: usize,
+
+    // residual norm after the most recent refinement
+    refinement_error: T,
+
+    // number of fill-reducing orderings computed
+    symbolic_count: usize,
+
+    // running count of factorization retries
+    retry_count: usize,
+}
+
+impl<T> ReducedLDLKKTSolver<T>
+where
+    T: FloatT,
+{
+    /// True if the KKT system for the given cones can be solved in the
+    /// reduced form, i.e. if every cone is a nonnegative cone.
+    pub fn is_supported(cones: &CompositeCone<T>) -> bool {
+        cones.get_type_count(SupportedConeTag::NonnegativeCone) == cones.len()
+    }
+
+    /// Creates a KKT solver for the reduced system, reusing the fill-reducing
+    /// ordering in `previous`, given together with the matrix it was computed
+    /// for, if the reduced matrix has the same sparsity pattern.
+    ///
+    /// # Panics
+    /// Panics if the cones are not supported.  See [`is_supported`](Self::is_supported).
+    pub fn new(
+        P: &CscMatrix<T>,
+        A: &CscMatrix<T>,
+        cones: &CompositeCone<T>,
+        m: usize,
+        n: usize,
+        settings: &CoreSettings<T>,
+        previous: Option<(&CscMatrix<T>, &[usize])>,
+    ) -> Self {
+        assert!(
+            Self::is_supported(cones),
+            "Reduced KKT form requires nonnegative cones only"
+        );
+
+        let (Arowptr, Arowidx) = _row_entries(A);
+        let (M, Pmap, diagmap, Amap) = _assemble_reduced_pattern(P, n, &Arowptr, &Arowidx);
+        let allmap = (0..M.nnz()).collect();
+
+        // the reduced matrix is positive definite
+        let dsigns = vec![1_i8; n];
+
+        let (kktshape, ldl_ctor) = _get_ldlsolver_config(settings);
+        let perm = previous
+            .filter(|_| !_use_dense_ldl(&M, settings))
+            .filter(|(K, _)| K.colptr == M.colptr && K.rowval == M.rowval)
+            .map(|(_, perm)| perm);
+        let is_dense = perm.is_none()
+            && _use_dense_ldl(&M, settings)
+            && kktshape == DenseDirectLDLSolver::<T>::required_matrix_shape();
+        let symbolic_count = if perm.is_some() || is_dense { 0 } else { 1 };
+
+        let ldlsolver: BoxedDirectLDLSolver<T> = {
+            if is_dense {
+                Box::new(DenseDirectLDLSolver::<T>::new(&M, &dsigns, settings))
+            } else {
+                ldl_ctor(&M, &dsigns, settings, perm)
+            }
+        };
+
+        Self {
+            P: P.clone(),
+            A: A.clone(),
+            Arowptr,
+            Arowidx,
+            Pmap,
+            diagmap,
+            Amap,
+            Hs: vec![T::zero(); m],
+            Hsinv: vec![T::zero(); m],
+            bx: vec![T::zero(); n],
+            bz: vec![T::zero(); m],
+            x: vec![T::zero(); n],
+            z: vec![T::zero(); m],
+            workx: vec![T::zero(); n],
+            ex: vec![T::zero(); n],
+            ez: vec![T::zero(); m],
+            dx: vec![T::zero(); n],
+            dz: vec![T::zero(); m],
+            M,
+            allmap,
+            ldlsolver,
+            diagonal_regularizer: T::zero(),
+            refinement_count: 0,
+            refinement_error: T::nan(),
+            symbolic_count,
+            retry_count: 0,
+        }
+    }
+}
+
+impl<T> KKTSolver<T> for ReducedLDLKKTSolver<T>
+where
+    T: FloatT,
+{
+    fn update(&mut self, cones: &CompositeCone<T>, settings: &CoreSettings<T>) -> bool {
+        self.update_with_abort(cones, settings, &|| false)
+    }
+
+    fn update_with_abort(
+        &mut self,
+        cones: &CompositeCone<T>,
+        settings: &CoreSettings<T>,
+        abort: &dyn Fn() -> bool,
+    ) -> bool {
+        // the Hs blocks are the diagonal of Hs for nonnegative cones
+        cones.get_Hs(&mut self.Hs);
+        for (hinv, &h) in zip(&mut self.Hsinv, &self.Hs) {
+            *hinv = T::recip(h);
+        }
+
+        self.assemble();
+        self.regularize_and_refactor(settings, abort)
+    }
+
+    fn setrhs(&mut self, rhsx: &[T], rhsz: &[T]) {
+        self.bx.copy_from(rhsx);
+        self.bz.copy_from(rhsz);
+    }
+
+    fn solve(
+        &mut self,
+        lhsx: Option<&mut [T]>,
+        lhsz: Option<&mut [T]>,
+        settings: &CoreSettings<T>,
+    ) -> bool {
+        _solve_reduced(
+            &mut *self.ldlsolver,
+            &self.A,
+            &self.Hsinv,
+            &self.bx,
+            &self.bz,
+            &mut self.x,
+            &mut self.z,
+            &mut self.workx,
+        );
+
+        let is_success = {
+            if settings.iterative_refinement_enable {
+                self.iterative_refinement(settings)
+            } else {
+                self.refinement_error = T::nan();
+                self.x.is_finite() && self.z.is_finite()
+            }
+        };
+
+        if is_success {
+            if let Some(v) = lhsx {
+                v.copy_from(&self.x);
+            }
+            if let Some(v) = lhsz {
+                v.copy_from(&self.z);
+            }
+        }
+
+        is_success
+    }
+
+    fn update_P(&mut self, P: &CscMatrix<T>) {
+        self.P.nzval.copy_from(&P.nzval);
+    }
+
+    fn update_A(&mut self, A: &CscMatrix<T>) {
+        self.A.nzval.copy_from(&A.nzval);
+    }
+
+    fn kkt_info(&self) -> KKTInfo {
+        KKTInfo {
+            nnz_kkt: self.M.nnz(),
+            nnz_L: self.ldlsolver.nnz_L(),
+            num_dynamic_regularizations: self.ldlsolver.regularize_count(),
+            num_refinement_steps: self.refinement_count,
+            final_refinement_residual: self.refinement_error.to_f64().unwrap(),
+            num_symbolic_factorizations: self.symbolic_count,
+            num_factorization_retries: self.retry_count,
+            num_iterative_solver_iters: 0,
+            is_reduced: true,
+        }
+    }
+
+    fn kkt_matrix(&self) -> &CscMatrix<T> {
+        &self.M
+    }
+
+    fn ordering(&self) -> Option<&[usize]> {
+        Some(self.ldlsolver.ordering())
+    }
+
+    fn try_clone(&self) -> Option<Box<dyn KKTSolver<T> + Send>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl<T> ReducedLDLKKTSolver<T>
+where
+    T: FloatT,
+{
+    // fills M = P + A'Hs⁻¹A using the maps computed on construction
+    fn assemble(&mut self) {
+        let M = &mut self.M;
+        M.nzval.fill(T::zero());
+
+        for (&idx, &v) in zip(&self.Pmap, &self.P.nzval) {
+            M.nzval[idx] += v;
+        }
+
+        let mut Amap = self.Amap.iter();
+        for (row, &hinv) in self.Hsinv.iter().enumerate() {
+            let entries = &self.Arowidx[self.Arowptr[row]..self.Arowptr[row + 1]];
+            for (k, &(_, idx2)) in entries.iter().enumerate() {
+                let v2 = self.A.nzval[idx2] * hinv;
+                for &(_, idx1) in &entries[..=k] {
+                    M.nzval[*Amap.next().unwrap()] += self.A.nzval[idx1] * v2;
+                }
+            }
+        }
+    }
+
+    fn regularize_and_refactor(
+        &mut self,
+        settings: &CoreSettings<T>,
+        abort: &dyn Fn() -> bool,
+    ) -> bool {
+        let M = &mut self.M;
+
+        if settings.static_regularization_enable {
+            let diag: Vec<T> = self.diagmap.iter().map(|&idx| M.nzval[idx]).collect();
+            let eps = _compute_regularizer(&diag, settings);
+            for &idx in &self.diagmap {
+                M.nzval[idx] += eps;
+            }
+            self.diagonal_regularizer = eps;
+        }
+
+        self.ldlsolver.update_values(&self.allmap, &M.nzval);
+
+        //refactor with new data, retrying with a larger dynamic
+        //regularization threshold if the factorization fails.
+        //The assembly of M is complete, so stop here if aborted
+        let mut is_success = !abort() && self.ldlsolver.refactor_with_abort(M, abort);
+
+        let regularize_eps = self.ldlsolver.regularize_eps();
+        for _ in 0..settings.factorization_max_retries {
+            if is_success || abort() {
+                break;
+            }
+            let eps = self.ldlsolver.regularize_eps() * settings.factorization_retry_factor;
+            self.ldlsolver.set_regularize_eps(T::max(eps, T::epsilon()));
+            self.retry_count += 1;
+            is_success = self.ldlsolver.refactor_with_abort(M, abort);
+        }
+        // later factorizations start again from the configured threshold
+        self.ldlsolver.set_regularize_eps(regularize_eps);
+
+        if settings.static_regularization_enable {
+            // remove the regularizer from our copy of M, which is
+            // not used in the refinement but is visible through
+            // `kkt_matrix`
+            for &idx in &self.diagmap {
+                M.nzval[idx] -= self.diagonal_regularizer;
+            }
+        }
+
+        is_success
+    }
+
+    // refinement against the full (unregularized) KKT system, with
+    // corrections computed from the reduced system
+    fn iterative_refinement(&mut self, settings: &CoreSettings<T>) -> bool {
+        let reltol = settings.iterative_refinement_reltol;
+        let abstol = settings.iterative_refinement_abstol;
+        let maxiter = settings.iterative_refinement_max_iter;
+        let stopratio = settings.iterative_refinement_stop_ratio;
+
+        let normb = T::max(self.bx.norm_inf(), self.bz.norm_inf());
+
+        //compute the initial error
+        let mut norme = self.refine_error(false);
+
+        if !norme.is_finite() {
+            return false;
+        }
+
+        for _ in 0..maxiter {
+            if norme <= (abstol + reltol * normb) {
+                //within tolerance.  Exit
+                break;
+            }
+
+            let lastnorme = norme;
+
+            //make a refinement
+            _solve_reduced(
+                &mut *self.ldlsolver,
+                &self.A,
+                &self.Hsinv,
+                &self.ex,
+                &self.ez,
+                &mut self.dx,
+                &mut self.dz,
+                &mut self.workx,
+            );
+            self.refinement_count += 1;
+
+            //prospective solution is x + dx.  Use dx space to
+            // hold it for a check before applying to x
+            self.dx.axpby(T::one(), &self.x, T::one());
+            self.dz.axpby(T::one(), &self.z, T::one());
+
+            norme = self.refine_error(true);
+
+            if !norme.is_finite() {
+                return false;
+            }
+
+            let improved_ratio = lastnorme / norme;
+            if improved_ratio < stopratio {
+                //insufficient improvement.  Exit
+                if improved_ratio > T::one() {
+                    std::mem::swap(&mut self.x, &mut self.dx);
+                    std::mem::swap(&mut self.z, &mut self.dz);
+                } else {
+                    norme = lastnorme;
+                }
+                break;
+            }
+            std::mem::swap(&mut self.x, &mut self.dx);
+            std::mem::swap(&mut self.z, &mut self.dz);
+        }
+        self.refinement_error = norme;
+
+        //NB: "success" means only that we had a finite valued result
+        true
+    }
+
+    // computes the error (ex, ez) = b - K(x, z) of the full KKT system,
+    // at the prospective solution (dx, dz) if `prospective` is set and
+    // otherwise at (x, z), returning its norm
+    fn refine_error(&mut self, prospective: bool) -> T {
+        let (x, z) = match prospective {
+            true => (&self.dx, &self.dz),
+            false => (&self.x, &self.z),
+        };
+
+        // ex = bx - Px - A'z
+        self.ex.copy_from(&self.bx);
+        self.P.sym().symv(&mut self.ex, x, -T::one(), T::one());
+        _csc_axpby_T(&self.A, &mut self.ex, z, -T::one(), T::one());
+
+        // ez = bz - Ax + Hs z
+        self.ez.copy_from(&self.bz);
+        _csc_axpby_N(&self.A, &mut self.ez, x, -T::one(), T::one());
+        for (e, (&h, &zi)) in zip(&mut self.ez, zip(&self.Hs, z)) {
+            *e += h * zi;
+        }
+
+        T::max(self.ex.norm_inf(), self.ez.norm_inf())
+    }
+}
+
+// solves the full KKT system with right hand side (bx, bz)
+// through the factored reduced system
+#[allow(clippy::too_many_arguments)]
+fn _solve_reduced<T: FloatT>(
+    ldlsolver: &mut dyn DirectLDLSolver<T>,
+    A: &CscMatrix<T>,
+    Hsinv: &[T],
+    bx: &[T],
+    bz: &[T],
+    x: &mut [T],
+    z: &mut [T],
+    work: &mut [T],
+) {
+    // work = bx + A'Hs⁻¹bz, using z as scratch
+    for (zi, (&hinv, &b)) in zip(&mut *z, zip(Hsinv, bz)) {
+        *zi = hinv * b;
+    }
+    work.copy_from(bx);
+    _csc_axpby_T(A, work, z, T::one(), T::one());
+
+    ldlsolver.solve(x, work);
+
+    // z = Hs⁻¹(Ax - bz)
+    z.copy_from(bz);
+    _csc_axpby_N(A, z, x, T::one(), -T::one());
+    z.hadamard(Hsinv);
+}
+
+// entries of A grouped by row, in increasing column order, as
+// pairs (column, index into A.nzval), with pointers to the start
+// of each row
+fn _row_entries<T: FloatT>(A: &CscMatrix<T>) -> (Vec<usize>, Vec<(usize, usize)>) {
+    let mut rowptr = vec![0; A.m + 1];
+    for &row in &A.rowval {
+        rowptr[row + 1] += 1;
+    }
+    for i in 0..A.m {
+        rowptr[i + 1] += rowptr[i];
+    }
+
+    let mut next = rowptr.clone();
+    let mut entries = vec![(0, 0); A.nnz()];
+    for col in 0..A.n {
+        for idx in A.colptr[col]..A.colptr[col + 1] {
+            let row = A.rowval[idx];
+            entries[next[row]] = (col, idx);
+            next[row] += 1;
+        }
+    }
+    (rowptr, entries)
+}
+
+// the sparsity pattern of the upper triangle of P + A'A, including
+// the full diagonal, together with maps from P, the diagonal and
+// the pairs of entries in each row of A into its values
+#[allow(clippy::type_complexity)]
+fn _assemble_reduced_pattern<T: FloatT>(
+    P: &CscMatrix<T>,
+    n: usize,
+    Arowptr: &[usize],
+    Arowidx: &[(usize, usize)],
+) -> (CscMatrix<T>, Vec<usize>, Vec<usize>, Vec<usize>) {
+    // every (row, col) pair in the upper triangle
+    let mut coords = Vec::with_capacity(P.nnz() + n);
+    for col in 0..P.n {
+        for idx in P.colptr[col]..P.colptr[col + 1] {
+            coords.push((col, P.rowval[idx]));
+        }
+    }
+    coords.extend((0..n).map(|i| (i, i)));
+    for row in 0..Arowptr.len() - 1 {
+        let entries = &Arowidx[Arowptr[row]..Arowptr[row + 1]];
+        for (k, &(col2, _)) in entries.iter().enumerate() {
+            coords.extend(entries[..=k].iter().map(|&(col1, _)| (col2, col1)));
+        }
+    }
+    coords.sort_unstable();
+    coords.dedup();
+
+    let mut colptr = vec![0; n + 1];
+    for &(col, _) in &coords {
+        colptr[col + 1] += 1;
+    }
+    for i in 0..n {
+        colptr[i + 1] += colptr[i];
+    }
+    let rowval: Vec<usize> = coords.iter().map(|&(_, row)| row).collect();
+    let nnz = rowval.len();
+    let M = CscMatrix::new(n, n, colptr, rowval, vec![T::zero(); nnz]);
+
+    let find = |row: usize, col: usize| {
+        let rng = M.colptr[col]..M.colptr[col + 1];
+        rng.start + M.rowval[rng].binary_search(&row).unwrap()
+    };
+
+    let mut Pmap = Vec::with_capacity(P.nnz());
+    for col in 0..P.n {
+        for idx in P.colptr[col]..P.colptr[col + 1] {
+            Pmap.push(find(P.rowval[idx], col));
+        }
+    }
+
+    let diagmap = (0..n).map(|i| find(i, i)).collect();
+
+    let mut Amap = vec![];
+    for row in 0..Arowptr.len() - 1 {
+        let entries = &Arowidx[Arowptr[row]..Arowptr[row + 1]];
+        for (k, &(col2, _)) in entries.iter().enumerate() {
+            Amap.extend(entries[..=k].iter().map(|&(col1, _)| find(col1, col2)));
+        }
+    }
+
+    (M, Pmap, diagmap, Amap)
+}
@@ -0,0 +1,127 @@
+#![allow(non_snake_case)]
+
+use crate::algebra::*;
+use itertools::izip;
+
+// -------------------------------------
+// Preconditioned MINRES for symmetric (indefinite) systems
+// -------------------------------------
+
+#[derive(Clone)]
+pub(crate) struct MINRESWorkspace<T> {
+    r1: Vec<T>,
+    r2: Vec<T>,
+    y: Vec<T>,
+    v: Vec<T>,
+    w: Vec<T>,
+    w1: Vec<T>,
+    w2: Vec<T>,
+}
+
+impl<T> MINRESWorkspace<T>
+where
+    T: FloatT,
+{
+    pub(crate) fn new(dim: usize) -> Self {
+        Self {
+            r1: vec![T::zero(); dim],
+            r2: vec![T::zero(); dim],
+            y: vec![T::zero(); dim],
+            v: vec![T::zero(); dim],
+            w: vec![T::zero(); dim],
+            w1: vec![T::zero(); dim],
+            w2: vec![T::zero(); dim],
+        }
+    }
+
+    // Solves Kx = b starting from x = 0, with K applied as mulK(y,x) : y = Kx
+    // and a positive definite diagonal preconditioner M given by Minv = M⁻¹.
+    // Stops once the preconditioned residual norm falls below abstol + reltol
+    // times the preconditioned norm of b, with tol = (abstol, reltol), or
+    // after maxiter iterations.
+    // Returns the number of iterations taken.
+    //
+    // Follows the Paige-Saunders formulation, as in e.g. scipy.sparse.linalg.minres
+
+    pub(crate) fn solve<F>(
+        &mut self,
+        mut mulK: F,
+        x: &mut [T],
+        b: &[T],
+        Minv: &[T],
+        tol: (T, T),
+        maxiter: usize,
+    ) -> usize
+    where
+        F: FnMut(&mut [T], &[T]),
+    {
+        let (r1, r2, y, v) = (&mut self.r1, &mut self.r2, &mut self.y, &mut self.v);
+        let (w, w1, w2) = (&mut self.w, &mut self.w1, &mut self.w2);
+
+        x.fill(T::zero());
+        r1.copy_from(b);
+        y.copy_from(r1).hadamard(Minv);
+
+        let beta1 = T::sqrt(r1.dot(y));
+        if beta1 == T::zero() || !beta1.is_finite() {
+            return 0;
+        }
+
+        let tol = tol.0 + tol.1 * beta1;
+
+        r2.copy_from(r1);
+        w.fill(T::zero());
+        w2.fill(T::zero());
+
+        let mut oldb = T::zero();
+        let mut beta = beta1;
+        let mut dbar = T::zero();
+        let mut epsln = T::zero();
+        let mut phibar = beta1;
+        let mut cs = -T::one();
+        let mut sn = T::zero();
+
+        for itn in 1..=maxiter {
+            // Lanczos step
+            v.copy_from(y).scale(T::recip(beta));
+            mulK(y, v);
+            if itn >= 2 {
+                y.axpby(-beta / oldb, r1, T::one());
+            }
+            let alfa = v.dot(y);
+            y.axpby(-alfa / beta, r2, T::one());
+            std::mem::swap(r1, r2);
+            r2.copy_from(y);
+            y.copy_from(r2).hadamard(Minv);
+            oldb = beta;
+            beta = T::sqrt(T::max(r2.dot(y), T::zero()));
+
+            // apply the previous rotation, then compute and
+            // apply a new one to eliminate beta
+            let oldeps = epsln;
+            let delta = cs * dbar + sn * alfa;
+            let gbar = sn * dbar - cs * alfa;
+            epsln = sn * beta;
+            dbar = -cs * beta;
+
+            let gamma = T::max(T::hypot(gbar, beta), T::epsilon());
+            cs = gbar / gamma;
+            sn = beta / gamma;
+            let phi = cs * phibar;
+            phibar *= sn;
+
+            // update the search direction and solution
+            std::mem::swap(w1, w2);
+            std::mem::swap(w2, w);
+            for (wi, &vi, &w1i, &w2i) in izip!(w.iter_mut(), v.iter(), w1.iter(), w2.iter()) {
+                *wi = (vi - oldeps * w1i - delta * w2i) / gamma;
+            }
+            x.axpby(phi, w, T::one());
+
+            if phibar <= tol || beta == T::zero() || !phibar.is_finite() {
+                return itn;
+            }
+        }
+        maxiter
+    }
+}
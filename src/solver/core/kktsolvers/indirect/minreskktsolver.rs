@@ -0,0 +1,372 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::algebra::*;
+use crate::solver::core::kktsolvers::direct::*;
+use crate::solver::core::kktsolvers::{KKTInfo, KKTSolver};
+use crate::solver::core::{cones::*, CoreSettings};
+use std::iter::zip;
+
+// -------------------------------------
+// KKTSolver using the MINRES iterative method
+// -------------------------------------
+
+// MINRES iterations allowed per solve, as a multiple of the KKT dimension
+const MINRES_MAX_ITER_RATIO: usize = 5;
+
+#[derive(Clone)]
+pub struct IndirectMINRESKKTSolver<T> {
+    // problem dimensions
+    m: usize,
+    n: usize,
+    p: usize,
+
+    // Left and right hand sides for solves
+    x: Vec<T>,
+    b: Vec<T>,
+
+    // internal workspace for IR scheme
+    work1: Vec<T>,
+    work2: Vec<T>,
+
+    // KKT mapping from problem data to KKT
+    map: LDLDataMap,
+
+    // the expected signs of the KKT diagonal blocks
+    dsigns: Vec<i8>,
+
+    // a vector for storing the entries of Hs blocks
+    // on the KKT matrix block diagonal
+    Hsblocks: Vec<T>,
+
+    // unregularized KKT matrix (triu)
+    KKT: CscMatrix<T>,
+
+    // signed static regularization applied to the KKT diagonal
+    // within MINRES, and the inverse of the diagonal preconditioner
+    regularizer: Vec<T>,
+    Minv: Vec<T>,
+
+    // MINRES workspace
+    minres: MINRESWorkspace<T>,
+
+    // running count of iterative refinement steps
+    refinement_count: usize,
+
+    // running count of MINRES iterations
+    minres_count: usize,
+
+    // residual norm after the most recent refinement
+    refinement_error: T,
+}
+
+impl<T> IndirectMINRESKKTSolver<T>
+where
+    T: FloatT,
+{
+    pub fn new(
+        P: &CscMatrix<T>,
+        A: &CscMatrix<T>,
+        cones: &CompositeCone<T>,
+        m: usize,
+        n: usize,
+        _settings: &CoreSettings<T>,
+    ) -> Self {
+        //construct a KKT matrix.  Only the upper triangle is stored
+        let (KKT, map) = assemble_kkt_matrix(P, A, cones, MatrixTriangle::Triu);
+
+        //Need this many extra variables for sparse cones
+        let p = map.sparse_maps.pdim();
+        let dim = n + m + p;
+
+        // LHS/RHS/work for iterative refinement
+        let x = vec![T::zero(); dim];
+        let b = vec![T::zero(); dim];
+        let work1 = vec![T::zero(); dim];
+        let work2 = vec![T::zero(); dim];
+
+        // the expected signs of the diagonal blocks
+        let mut dsigns = vec![1_i8; dim];
+        _fill_signs(&mut dsigns, m, n, &map);
+
+        // updates to the diagonal of KKT will be
+        // assigned here before updating matrix entries
+        let Hsblocks = allocate_kkt_Hsblocks::<T, T>(cones);
+
+        let regularizer = vec![T::zero(); dim];
+        let Minv = vec![T::one(); dim];
+        let minres = MINRESWorkspace::<T>::new(dim);
+
+        Self {
+            m,
+            n,
+            p,
+            x,
+            b,
+            work1,
+            work2,
+            map,
+            dsigns,
+            Hsblocks,
+            KKT,
+            regularizer,
+            Minv,
+            minres,
+            refinement_count: 0,
+            minres_count: 0,
+            refinement_error: T::nan(),
+        }
+    }
+}
+
+impl<T> KKTSolver<T> for IndirectMINRESKKTSolver<T>
+where
+    T: FloatT,
+{
+    fn update(&mut self, cones: &CompositeCone<T>, settings: &CoreSettings<T>) -> bool {
+        let map = &self.map;
+
+        // Set the elements the W^tW blocks in the KKT matrix.
+        cones.get_Hs(&mut self.Hsblocks);
+
+        let (values, index) = (&mut self.Hsblocks, &map.Hsblocks);
+        // change signs to get -W^TW
+        values.negate();
+        _update_values_KKT(&mut self.KKT, index, values);
+
+        let mut sparse_map_iter = map.sparse_maps.iter();
+        let KKT = &mut self.KKT;
+
+        for cone in cones.iter() {
+            if cone.is_sparse_expandable() {
+                let sc = cone.to_sparse_expansion().unwrap();
+                let thismap = sparse_map_iter.next().unwrap();
+                sc.csc_update_sparsecone(thismap, &mut (), KKT, _update_values, _scale_values);
+            }
+        }
+
+        self.regularize_and_precondition(settings)
+    }
+
+    fn setrhs(&mut self, rhsx: &[T], rhsz: &[T]) {
+        let (m, n, p) = (self.m, self.n, self.p);
+
+        self.b[0..n].copy_from(rhsx);
+        self.b[n..(n + m)].copy_from(rhsz);
+        self.b[n + m..(n + m + p)].fill(T::zero());
+    }
+
+    fn solve(
+        &mut self,
+        lhsx: Option<&mut [T]>,
+        lhsz: Option<&mut [T]>,
+        settings: &CoreSettings<T>,
+    ) -> bool {
+        let normb = self.b.norm_inf();
+        self.minres_solve(normb, settings);
+
+        let is_success = {
+            if settings.iterative_refinement_enable {
+                self.iterative_refinement(settings)
+            } else {
+                self.refinement_error = T::nan();
+                self.x.is_finite()
+            }
+        };
+
+        if is_success {
+            self.getlhs(lhsx, lhsz);
+        }
+
+        is_success
+    }
+
+    fn update_P(&mut self, P: &CscMatrix<T>) {
+        _update_values_KKT(&mut self.KKT, &self.map.P, &P.nzval);
+    }
+
+    fn update_A(&mut self, A: &CscMatrix<T>) {
+        _update_values_KKT(&mut self.KKT, &self.map.A, &A.nzval);
+    }
+
+    fn kkt_info(&self) -> KKTInfo {
+        KKTInfo {
+            nnz_kkt: self.KKT.nnz(),
+            nnz_L: 0,
+            num_dynamic_regularizations: 0,
+            num_refinement_steps: self.refinement_count,
+            final_refinement_residual: self.refinement_error.to_f64().unwrap(),
+            num_symbolic_factorizations: 0,
+            num_factorization_retries: 0,
+            num_iterative_solver_iters: self.minres_count,
+            is_reduced: false,
+        }
+    }
+
+    fn kkt_matrix(&self) -> &CscMatrix<T> {
+        &self.KKT
+    }
+
+    fn try_clone(&self) -> Option<Box<dyn KKTSolver<T> + Send>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl<T> IndirectMINRESKKTSolver<T>
+where
+    T: FloatT,
+{
+    // extra helper functions, not required for KKTSolver trait
+    fn getlhs(&self, lhsx: Option<&mut [T]>, lhsz: Option<&mut [T]>) {
+        let x = &self.x;
+        let (m, n) = (self.m, self.n);
+
+        if let Some(v) = lhsx {
+            v.copy_from(&x[0..n]);
+        }
+        if let Some(v) = lhsz {
+            v.copy_from(&x[n..(n + m)]);
+        }
+    }
+
+    fn regularize_and_precondition(&mut self, settings: &CoreSettings<T>) -> bool {
+        let map = &self.map;
+        let KKT = &self.KKT;
+        let diag_kkt = &mut self.work1;
+
+        // diag_kkt .= KKT.nzval[map.diag_full];
+        for (d, idx) in zip(&mut *diag_kkt, &map.diag_full) {
+            *d = KKT.nzval[*idx];
+        }
+
+        let eps = {
+            if settings.static_regularization_enable {
+                _compute_regularizer(diag_kkt, settings)
+            } else {
+                T::zero()
+            }
+        };
+
+        // static regularization with the expected signs of each block
+        zip(&mut self.regularizer, &self.dsigns).for_each(|(r, &sign)| {
+            *r = if sign == 1 { eps } else { -eps };
+        });
+
+        // block diagonal preconditioner from the regularized diagonal,
+        // bounded away from zero in case no regularization is applied
+        let floor = T::max(eps, T::epsilon());
+        zip(&mut self.Minv, &*diag_kkt).for_each(|(minv, &d)| {
+            *minv = T::recip(T::max(T::abs(d) + eps, floor));
+        });
+
+        self.Minv.is_finite()
+    }
+
+    // solves the regularized system for x from the current b
+    fn minres_solve(&mut self, normb: T, settings: &CoreSettings<T>) {
+        let (x, b) = (&mut self.x, &self.b);
+        let tol = _minres_tolerances(normb, settings);
+        let maxiter = MINRES_MAX_ITER_RATIO * x.len();
+
+        let (K, reg) = (&self.KKT, &self.regularizer);
+        let mulK = |y: &mut [T], v: &[T]| _mul_regularized(y, K, reg, v);
+        self.minres_count += self.minres.solve(mulK, x, b, &self.Minv, tol, maxiter);
+    }
+
+    fn iterative_refinement(&mut self, settings: &CoreSettings<T>) -> bool {
+        let (x, b) = (&mut self.x, &self.b);
+        let (e, dx) = (&mut self.work1, &mut self.work2);
+
+        // iterative refinement params
+        let reltol = settings.iterative_refinement_reltol;
+        let abstol = settings.iterative_refinement_abstol;
+        let maxiter = settings.iterative_refinement_max_iter;
+        let stopratio = settings.iterative_refinement_stop_ratio;
+
+        let K = &self.KKT;
+        let normb = b.norm_inf();
+
+        //compute the initial error
+        let mut norme = _get_refine_error(e, b, K, x);
+
+        if !norme.is_finite() {
+            return false;
+        }
+
+        let (reg, Minv) = (&self.regularizer, &self.Minv);
+        let minres_maxiter = MINRES_MAX_ITER_RATIO * x.len();
+
+        for _ in 0..maxiter {
+            if norme <= (abstol + reltol * normb) {
+                //within tolerance.  Exit
+                break;
+            }
+
+            let lastnorme = norme;
+
+            //make a refinement
+            let tol = _minres_tolerances(norme, settings);
+            let mulK = |y: &mut [T], v: &[T]| _mul_regularized(y, K, reg, v);
+            self.minres_count += self.minres.solve(mulK, dx, e, Minv, tol, minres_maxiter);
+            self.refinement_count += 1;
+
+            //prospective solution is x + dx.  Use dx space to
+            // hold it for a check before applying to x
+            dx.axpby(T::one(), x, T::one());
+
+            norme = _get_refine_error(e, b, K, dx);
+
+            if !norme.is_finite() {
+                return false;
+            }
+
+            let improved_ratio = lastnorme / norme;
+            if improved_ratio < stopratio {
+                //insufficient improvement.  Exit
+                if improved_ratio > T::one() {
+                    std::mem::swap(x, dx);
+                } else {
+                    norme = lastnorme;
+                }
+                break;
+            }
+            std::mem::swap(x, dx);
+        }
+        self.refinement_error = norme;
+
+        //NB: "success" means only that we had a finite valued result
+        true
+    }
+}
+
+// absolute and relative MINRES tolerances for a solve with right hand
+// side of norm normb.  The relative tolerance applies to the norm of the
+// right hand side in the preconditioner metric, and is only used for the
+// "preconditioned" stopping metric
+fn _minres_tolerances<T: FloatT>(normb: T, settings: &CoreSettings<T>) -> (T, T) {
+    let abstol = settings.iterative_refinement_abstol;
+    let reltol = settings.iterative_refinement_reltol;
+
+    match settings.indirect_stop_metric.as_str() {
+        "absolute" => (abstol, T::zero()),
+        "relative" => (abstol + reltol * normb, T::zero()),
+        "preconditioned" => (abstol, reltol),
+        _ => panic!("Unrecognized indirect stopping metric"),
+    }
+}
+
+// y = (K + diag(reg))v, with K triu data treated as symmetric
+fn _mul_regularized<T: FloatT>(y: &mut [T], K: &CscMatrix<T>, reg: &[T], v: &[T]) {
+    y.copy_from(v).hadamard(reg);
+    K.sym().symv(y, v, T::one(), T::one());
+}
+
+// there is no separate linear solver holding a copy of the
+// KKT data here, so updates are applied to the KKT matrix only
+fn _update_values<T: FloatT>(_: &mut (), KKT: &mut CscMatrix<T>, index: &[usize], values: &[T]) {
+    _update_values_KKT(KKT, index, values);
+}
+
+fn _scale_values<T: FloatT>(_: &mut (), KKT: &mut CscMatrix<T>, index: &[usize], scale: T) {
+    _scale_values_KKT(KKT, index, scale);
+}
@@ -0,0 +1,5 @@
+//flatten indirect KKT module structure
+mod minres;
+mod minreskktsolver;
+use minres::*;
+pub use minreskktsolver::*;
@@ -3,9 +3,53 @@ use super::{cones::CompositeCone, CoreSettings};
 use crate::algebra::*;
 
 pub mod direct;
+pub mod indirect;
+
+/// Summary information about an assembled KKT system and its factorization
+
+#[derive(Default, Debug, Clone)]
+pub struct KKTInfo {
+    /// Number of structural nonzeros in the assembled (triangular) KKT matrix
+    pub nnz_kkt: usize,
+    /// Number of nonzeros in the strictly lower triangular factor L
+    pub nnz_L: usize,
+    /// Number of dynamic regularizations applied in the most recent factorization
+    pub num_dynamic_regularizations: usize,
+    /// Total number of iterative refinement steps applied since initialization
+    pub num_refinement_steps: usize,
+    /// Norm of the KKT residual after the most recent iterative refinement,
+    /// or NaN if refinement was not applied in the most recent solve
+    pub final_refinement_residual: f64,
+    /// Number of symbolic factorizations (fill-reducing orderings) computed
+    /// since initialization.  Zero if the ordering was reused from a previous
+    /// KKT system or if the solver does not factor the KKT matrix.
+    pub num_symbolic_factorizations: usize,
+    /// Total number of factorization retries with increased dynamic
+    /// regularization since initialization
+    pub num_factorization_retries: usize,
+    /// Total number of iterations of the iterative linear solver since
+    /// initialization.  Zero if the solver factors the KKT matrix.
+    pub num_iterative_solver_iters: usize,
+    /// True if the solver factors the reduced (normal equations) form of
+    /// the KKT system, as selected by the `kkt_form` setting
+    pub is_reduced: bool,
+}
 
 pub trait KKTSolver<T: FloatT> {
     fn update(&mut self, cones: &CompositeCone<T>, settings: &CoreSettings<T>) -> bool;
+
+    /// As [`update`](KKTSolver::update), but may stop early and return
+    /// false if `abort` returns true, e.g. during a long factorization.
+    /// The default implementation never stops early.
+    fn update_with_abort(
+        &mut self,
+        cones: &CompositeCone<T>,
+        settings: &CoreSettings<T>,
+        _abort: &dyn Fn() -> bool,
+    ) -> bool {
+        self.update(cones, settings)
+    }
+
     fn setrhs(&mut self, x: &[T], z: &[T]);
     fn solve(
         &mut self,
@@ -15,4 +59,47 @@ pub trait KKTSolver<T: FloatT> {
     ) -> bool;
     fn update_P(&mut self, P: &CscMatrix<T>);
     fn update_A(&mut self, A: &CscMatrix<T>);
+    fn kkt_info(&self) -> KKTInfo;
+    fn kkt_matrix(&self) -> &CscMatrix<T>;
+
+    /// The fill-reducing ordering of [`kkt_matrix`](KKTSolver::kkt_matrix)
+    /// used by the factorization, if any.
+    fn ordering(&self) -> Option<&[usize]> {
+        None
+    }
+
+    /// A deep copy of the solver, including its current factorization,
+    /// or `None` if the solver cannot be copied.
+    ///
+    /// Used by [`DefaultSolver::fork`](crate::solver::DefaultSolver::fork).
+    /// The default implementation returns `None`.
+    fn try_clone(&self) -> Option<Box<dyn KKTSolver<T> + Send>> {
+        None
+    }
+
+    /// Solves the KKT system for each right hand side `(x, z)` in `rhs`,
+    /// writing the solutions to the corresponding entries of `out`.
+    ///
+    /// All solves use the current factorization, i.e. the one made in the
+    /// most recent [`update`](KKTSolver::update).   The default implementation
+    /// calls [`setrhs`](KKTSolver::setrhs) and [`solve`](KKTSolver::solve) for
+    /// each right hand side in turn, and returns false if any of them fails.
+    ///
+    /// # Panics
+    /// Panics if `rhs` and `out` have different lengths.
+    fn solve_multi(
+        &mut self,
+        rhs: &[(&[T], &[T])],
+        out: &mut [(&mut [T], &mut [T])],
+        settings: &CoreSettings<T>,
+    ) -> bool {
+        assert_eq!(rhs.len(), out.len());
+
+        let mut is_success = true;
+        for ((rhsx, rhsz), (lhsx, lhsz)) in rhs.iter().zip(out.iter_mut()) {
+            self.setrhs(rhsx, rhsz);
+            is_success &= self.solve(Some(lhsx), Some(lhsz), settings);
+        }
+        is_success
+    }
 }
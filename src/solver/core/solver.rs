@@ -1,10 +1,12 @@
 use self::internal::*;
 use super::cones::Cone;
 use super::traits::*;
+use super::CoreSettings;
 use crate::algebra::*;
 use crate::stdio;
 use crate::timers::*;
 use std::io::Write;
+use thiserror::Error;
 
 // ---------------------------------
 // Solver status type
@@ -37,6 +39,10 @@ pub enum SolverStatus {
     NumericalError,
     /// Solver terminated due to lack of progress.
     InsufficientProgress,
+    /// Solver terminated at the request of a user-supplied iteration callback.
+    CallbackTerminated,
+    /// Problem is too large for the limit set by `max_kkt_nnz`.  The solver does not run.
+    InsufficientMemory,
 }
 
 impl SolverStatus {
@@ -74,6 +80,77 @@ pub enum ScalingStrategy {
     Dual,
 }
 
+/// Action requested by a user-supplied iteration callback.
+/// See [`Solver::set_iteration_callback`].
+#[repr(u32)]
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum CallbackAction {
+    /// Continue with the next iteration
+    Continue,
+    /// Stop the solver and report [`SolverStatus::CallbackTerminated`]
+    Terminate,
+}
+
+/// Strategy for initializing the solver from a user-supplied guess.
+/// See [`IPSolver::solve_from`].
+#[repr(u32)]
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum WarmStartMode {
+    /// Use the primal and dual guesses, shifted into the cone interior if needed
+    FullGuess,
+    /// Use the primal guesses only, with the default starting point for the duals
+    PrimalOnly,
+    /// Blend the guesses with the default starting point, with weight λ ∈ [0,1]
+    /// applied to the guesses
+    Scaled,
+}
+
+impl Default for WarmStartMode {
+    fn default() -> Self {
+        WarmStartMode::FullGuess
+    }
+}
+
+impl TryFrom<i32> for WarmStartMode {
+    type Error = WarmStartError;
+
+    fn try_from(mode: i32) -> Result<Self, Self::Error> {
+        match mode {
+            0 => Ok(WarmStartMode::FullGuess),
+            1 => Ok(WarmStartMode::PrimalOnly),
+            2 => Ok(WarmStartMode::Scaled),
+            _ => Err(WarmStartError::InvalidMode(mode)),
+        }
+    }
+}
+
+/// Error type returned when a warm start guess is rejected.
+#[derive(Error, Debug)]
+pub enum WarmStartError {
+    #[error("Invalid warm start mode: {0}")]
+    InvalidMode(i32),
+    #[error("Warm start weight must lie in the interval [0,1]")]
+    InvalidLambda,
+    #[error("Warm start guess has incompatible dimensions")]
+    IncompatibleDimension,
+    #[error("Warm start solution is a certificate of infeasibility")]
+    InfeasibleSolution,
+    #[error("Warm start is not supported for problems with nonsymmetric cones")]
+    NonsymmetricCones,
+}
+
+/// Error type returned by [`DefaultSolver::try_solve`](crate::solver::DefaultSolver::try_solve)
+/// when the solver terminates without a solution or infeasibility certificate.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    #[error("Solver terminated with a numerical error")]
+    NumericalError,
+    #[error("Solver terminated due to lack of progress")]
+    InsufficientProgress,
+    #[error("Problem is too large for the limit set by max_kkt_nnz")]
+    InsufficientMemory,
+}
+
 /// An enum for reporting strategy checkpointing
 #[repr(u32)]
 #[derive(PartialEq, Eq, Clone, Debug, Copy)]
@@ -99,6 +176,14 @@ impl Default for SolverStatus {
 // top level solver container type
 // ---------------------------------
 
+/// Boxed form of a user-supplied iteration callback.   The callback
+/// is required to be `Send` so that the solver remains `Send`.
+pub type IterationCallback<I, V> = Box<dyn FnMut(&I, &V) -> CallbackAction + Send>;
+
+// Internal observer of the solver information, called once per pass
+// through the main loop.  Used to stream progress to a receiver.
+pub(crate) type ProgressObserver<I> = Box<dyn FnMut(&I) + Send>;
+
 // The top-level solver.
 
 // This trait is defined with a collection of mutually interacting associated types.
@@ -113,10 +198,49 @@ pub struct Solver<D, V, R, K, C, I, SO, SE> {
     pub step_lhs: V,
     pub step_rhs: V,
     pub prev_vars: V,
+    // step direction saved while trying a centrality correction
+    pub(crate) step_work: V,
     pub info: I,
     pub solution: SO,
     pub settings: SE,
     pub timers: Option<Timers>,
+    pub(crate) iteration_callback: Option<IterationCallback<I, V>>,
+    pub(crate) progress_observer: Option<ProgressObserver<I>>,
+    // status reported by every solve if setup could not be completed
+    pub(crate) setup_failure: Option<SolverStatus>,
+    // progress of a solve made with IPSolver::step
+    pub(crate) solve_state: Option<SolveState>,
+}
+
+impl<D, V, R, K, C, I, SO, SE> Solver<D, V, R, K, C, I, SO, SE> {
+    /// Register a callback to be called once per interior point iteration,
+    /// immediately after the solver's own termination checks.   Returning
+    /// [`CallbackAction::Terminate`] stops the solver with status
+    /// [`SolverStatus::CallbackTerminated`].  The solution is still
+    /// populated from the most recent iterate in that case.
+    ///
+    /// The callback receives the solver information and the current
+    /// iterate.  Note that the iterate is in the solver's internal
+    /// (equilibrated and homogenized) form.
+    ///
+    /// The callback is called synchronously from whichever thread calls
+    /// `solve`, and is never called concurrently.   It must be `Send`
+    /// so that the solver itself can be moved between threads.
+    pub fn set_iteration_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(&I, &V) -> CallbackAction + Send + 'static,
+    {
+        self.iteration_callback = Some(Box::new(callback));
+    }
+
+    /// Remove any previously registered iteration callback.
+    pub fn unset_iteration_callback(&mut self) {
+        self.iteration_callback = None;
+    }
+}
+
+fn _is_hard_time_limit_exceeded<T: FloatT>(settings: &CoreSettings<T>, timers: &Timers) -> bool {
+    settings.time_limit_hard && timers.total_time_now().as_secs_f64() > settings.time_limit
 }
 
 fn _print_banner(is_verbose: bool) -> std::io::Result<()> {
@@ -163,7 +287,47 @@ fn _print_banner(is_verbose: bool) -> std::io::Result<()> {
 
 pub trait IPSolver<T, D, V, R, K, C, I, SO, SE> {
     /// Run the solver
-    fn solve(&mut self);
+    fn solve(&mut self) {
+        self.solve_from(None);
+    }
+
+    /// Run the solver, optionally starting from a guess of the variables.
+    ///
+    /// The guess is supplied in the solver's internal (equilibrated)
+    /// variable format, along with the [`WarmStartMode`] and a weight `λ`
+    /// used by [`WarmStartMode::Scaled`].  With `None` this is the same
+    /// as [`solve`](IPSolver::solve).
+    fn solve_from(&mut self, guess: Option<(&V, WarmStartMode, T)>);
+
+    /// Run at most `n` further interior point iterations, returning true
+    /// once the solver has terminated.
+    ///
+    /// The first call starts a new solve, and later calls resume it from
+    /// where the previous one stopped.   Between calls the solver state,
+    /// e.g. the iterate and the solver information, can be inspected.
+    /// Convergence checks and iteration and time limits are applied as for
+    /// [`solve`](IPSolver::solve), and calling `step` until it returns true
+    /// gives the same result.   The solution is only populated on
+    /// termination, after which the next call starts a new solve.
+    ///
+    /// Time spent between calls is not counted towards the solve time.
+    fn step(&mut self, n: u32) -> bool;
+}
+
+// The progress of a solve, kept between calls to step.  Scalars are
+// stored as f64 since the solver type is not generic over the float type
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SolveState {
+    iter: u32,
+    σ: f64,
+    α: f64,
+    μ: f64,
+    scaling: ScalingStrategy,
+    // termination checks have been made for the current iterate
+    is_checked: bool,
+    // KKT counters when the solve started
+    refinement_steps_start: usize,
+    factorization_retries_start: usize,
 }
 
 impl<T, D, V, R, K, C, I, SO, SE> IPSolver<T, D, V, R, K, C, I, SO, SE>
@@ -179,212 +343,33 @@ where
     SO: Solution<T, D = D, V = V, I = I>,
     SE: Settings<T>,
 {
-    fn solve(&mut self) {
-        // various initializations
-        let mut iter: u32 = 0;
-        let mut σ = T::one();
-        let mut α = T::zero();
-        let mut μ;
-
+    fn solve_from(&mut self, guess: Option<(&V, WarmStartMode, T)>) {
         //timers is stored as an option so that
         //we can swap it out here and avoid
         //borrow conflicts with other fields.
         let mut timers = self.timers.take().unwrap();
 
-        // solver release info, solver config
-        // problem dimensions, cone types etc
-        notimeit! {timers; {
-            _print_banner(self.settings.core().verbose).unwrap();
-            self.info.print_configuration(&self.settings, &self.data, &self.cones).unwrap();
-            self.info.print_status_header(&self.settings).unwrap();
-        }}
-
-        self.info.reset(&mut timers);
-
-        timeit! {timers => "solve"; {
-
-        // initialize variables to some reasonable starting point
-        timeit!{timers => "default start"; {
-            self.default_start();
-        }}
-
-        timeit!{timers => "IP iteration"; {
-
-        // ----------
-        // main loop
-        // ----------
-
-        let mut scaling = {
-            if self.cones.allows_primal_dual_scaling() {ScalingStrategy::PrimalDual}
-            else {ScalingStrategy::Dual}
-        };
-
-        loop {
-
-            //update the residuals
-            //--------------
-            self.residuals.update(&self.variables, &self.data);
-
-            //calculate duality gap (scaled)
-            //--------------
-            μ = self.variables.calc_mu(&self.residuals, &self.cones);
-
-            // record scalar values from most recent iteration.
-            // This captures μ at iteration zero.
-            self.info.save_scalars(μ, α, σ, iter);
-
-            // convergence check and printing
-            // --------------
-            self.info.update(
-                &mut self.data,
-                &self.variables,
-                &self.residuals,&timers);
-
-            notimeit!{timers; {
-                self.info.print_status(&self.settings).unwrap();
-            }}
-
-            let isdone = self.info.check_termination(&self.residuals, &self.settings, iter);
-
-            // check for termination due to slow progress and update strategy
-            if isdone{
-                    match self.strategy_checkpoint_insufficient_progress(scaling){
-                        StrategyCheckpoint::NoUpdate | StrategyCheckpoint::Fail => {break}
-                        StrategyCheckpoint::Update(s) => {scaling = s; continue}
-                    }
-            }  // allows continuation if new strategy provided
-
-
-            // update the scalings
-            // --------------
-            let is_scaling_success = self.variables.scale_cones(&mut self.cones,μ,scaling);
-            // check whether variables are interior points
-            match self.strategy_checkpoint_is_scaling_success(is_scaling_success,scaling){
-                StrategyCheckpoint::Fail => {break}
-                StrategyCheckpoint::NoUpdate => {} // we only expect NoUpdate or Fail here
-                StrategyCheckpoint::Update(_) => {unreachable!()}
-            }
-
-            //increment counter here because we only count
-            //iterations that produce a KKT update
-            iter += 1;
-
-            // Update the KKT system and the constant parts of its solution.
-            // Keep track of the success of each step that calls KKT
-            // --------------
-            //PJG: This should be a Result in Rust, but needs changes down
-            //into the KKT solvers to do that.
-            let mut is_kkt_solve_success : bool;
-            timeit!{timers => "kkt update"; {
-                is_kkt_solve_success = self.kktsystem.update(&self.data, &self.cones, &self.settings);
-            }} // end "kkt update" timer
-
-            // calculate the affine step
-            // --------------
-            self.step_rhs
-                .affine_step_rhs(&self.residuals, &self.variables, &self.cones);
-
-            timeit!{timers => "kkt solve"; {
-                is_kkt_solve_success = is_kkt_solve_success &&
-                self.kktsystem.solve(
-                    &mut self.step_lhs,
-                    &self.step_rhs,
-                    &self.data,
-                    &self.variables,
-                    &mut self.cones,
-                    StepDirection::Affine,
-                    &self.settings,
-                );
-            }}  //end "kkt solve affine" timer
-
-            // combined step only on affine step success
-            if is_kkt_solve_success {
-
-                //calculate step length and centering parameter
-                // --------------
-                α = self.get_step_length(StepDirection::Affine, scaling);
-                σ = self.centering_parameter(α);
-
-                // make a reduced Mehrotra correction in the first iteration
-                // to accommodate badly centred starting points
-                let m = if iter > 1 {T::one()} else {α};
-
-                // calculate the combined step and length
-                // --------------
-                self.step_rhs.combined_step_rhs(
-                    &self.residuals,
-                    &self.variables,
-                    &mut self.cones,
-                    &mut self.step_lhs,
-                    σ,
-                    μ,
-                    m
-                );
-
-                timeit!{timers => "kkt solve" ; {
-                    is_kkt_solve_success =
-                    self.kktsystem.solve(
-                        &mut self.step_lhs,
-                        &self.step_rhs,
-                        &self.data,
-                        &self.variables,
-                        &mut self.cones,
-                        StepDirection::Combined,
-                        &self.settings,
-                    );
-                }} //end "kkt solve"
-            }
-
-            // check for numerical failure and update strategy
-            match self.strategy_checkpoint_numerical_error(is_kkt_solve_success,scaling) {
-                StrategyCheckpoint::NoUpdate => {}
-                StrategyCheckpoint::Update(s) => {α = T::zero(); scaling = s; continue}
-                StrategyCheckpoint::Fail => {α = T::zero(); break}
-            }
-
-
-            // compute final step length and update the current iterate
-            // --------------
-            α = self.get_step_length(StepDirection::Combined,scaling);
-
-            // check for undersized step and update strategy
-            match self.strategy_checkpoint_small_step(α, scaling) {
-                StrategyCheckpoint::NoUpdate => {}
-                StrategyCheckpoint::Update(s) => {α = T::zero(); scaling = s; continue}
-                StrategyCheckpoint::Fail => {α = T::zero(); break}
-            }
-
-            // Copy previous iterate in case the next one is a dud
-            self.info.save_prev_iterate(&self.variables,&mut self.prev_vars);
-
-            self.variables.add_step(&self.step_lhs, α);
+        if self.start_solve(guess, &mut timers) {
+            self.run_iterations(None, &mut timers);
+            self.finish_solve(&mut timers);
+        }
 
-        } //end loop
-        // ----------
-        // ----------
+        //stow the timers back into Option in the solver struct
+        self.timers.replace(timers);
+    }
 
-        }} //end "IP iteration" timer
+    fn step(&mut self, n: u32) -> bool {
+        let mut timers = self.timers.take().unwrap();
 
-        }} // end "solve" timer
+        let is_started = self.solve_state.is_some() || self.start_solve(None, &mut timers);
 
-        // Check we if actually took a final step.  If not, we need
-        // to recapture the scalars and print one last line
-        if α == T::zero() {
-            self.info.save_scalars(μ, α, σ, iter);
-            notimeit! {timers; {self.info.print_status(&self.settings).unwrap();}}
+        let is_done = !is_started || self.run_iterations(Some(n), &mut timers);
+        if is_started && is_done {
+            self.finish_solve(&mut timers);
         }
 
-        //store final solution, timing etc
-        self.info
-            .finalize(&self.residuals, &self.settings, &mut timers);
-
-        self.solution
-            .finalize(&self.data, &self.variables, &self.info);
-
-        self.info.print_footer(&self.settings).unwrap();
-
-        //stow the timers back into Option in the solver struct
         self.timers.replace(timers);
+        is_done
     }
 }
 
@@ -396,6 +381,19 @@ mod internal {
     use super::*;
 
     pub(super) trait IPSolverInternals<T, D, V, R, K, C, I, SO, SE> {
+        /// Start a new solve.  Returns false if there is nothing to solve
+        fn start_solve(
+            &mut self,
+            guess: Option<(&V, WarmStartMode, T)>,
+            timers: &mut Timers,
+        ) -> bool;
+
+        /// Run the main loop.  Returns true on termination
+        fn run_iterations(&mut self, limit: Option<u32>, timers: &mut Timers) -> bool;
+
+        /// Finalize the information and solution of a terminated solve
+        fn finish_solve(&mut self, timers: &mut Timers);
+
         /// Find an initial condition
         fn default_start(&mut self);
 
@@ -409,6 +407,16 @@ mod internal {
         /// backtrack a step direction to the barrier
         fn backtrack_step_to_barrier(&mut self, αinit: T) -> T;
 
+        /// repeated correction of the combined step direction
+        fn centrality_corrections(
+            &mut self,
+            σ: T,
+            μ: T,
+            m: T,
+            scaling: ScalingStrategy,
+            timers: &mut Timers,
+        );
+
         /// Scaling strategy checkpointing functions
         fn strategy_checkpoint_insufficient_progress(
             &mut self,
@@ -447,6 +455,351 @@ mod internal {
         SO: Solution<T, D = D, V = V, I = I>,
         SE: Settings<T>,
     {
+        // Prints the solver configuration, resets the solver information and
+        // finds the starting point.  Returns false, with the solver information
+        // and solution finalized, if there is nothing to solve
+        fn start_solve(
+            &mut self,
+            guess: Option<(&V, WarmStartMode, T)>,
+            timers: &mut Timers,
+        ) -> bool {
+            self.solve_state = None;
+
+            // solver release info, solver config
+            // problem dimensions, cone types etc
+            notimeit! {timers; {
+                _print_banner(self.settings.core().verbose).unwrap();
+                self.info.print_configuration(&self.settings, &self.data, &self.cones).unwrap();
+                self.info.print_status_header(&self.settings).unwrap();
+            }}
+
+            self.info.reset(timers);
+            self.solution.reset();
+
+            // refinement steps and factorization retries are
+            // counted since initialization
+            let kktinfo = self.kktsystem.kkt_info();
+
+            // nothing to solve if setup failed
+            if let Some(status) = self.setup_failure {
+                self.info.set_status(status);
+                self.info.finalize(&self.residuals, &self.settings, timers);
+                self.solution.finalize(
+                    &self.data,
+                    &self.variables,
+                    &self.info,
+                    self.settings.core(),
+                );
+                self.info.print_footer(&self.settings).unwrap();
+                return false;
+            }
+
+            timeit! {timers => "solve"; {
+
+            // initialize variables to some reasonable starting point
+            timeit!{timers => "default start"; {
+                self.default_start();
+                if let Some((guess, mode, λ)) = guess {
+                    self.variables.warm_start(guess, mode, λ, &mut self.cones);
+                }
+            }}
+
+            }} // end "solve" timer
+
+            let scaling = {
+                if self.cones.allows_primal_dual_scaling() {
+                    ScalingStrategy::PrimalDual
+                } else {
+                    ScalingStrategy::Dual
+                }
+            };
+
+            self.solve_state = Some(SolveState {
+                iter: 0,
+                σ: 1.,
+                α: 0.,
+                μ: 0.,
+                scaling,
+                is_checked: false,
+                refinement_steps_start: kktinfo.num_refinement_steps,
+                factorization_retries_start: kktinfo.num_factorization_retries,
+            });
+            true
+        }
+
+        // Runs the main loop until termination, or until `limit` steps have
+        // been taken.   Returns true on termination
+        fn run_iterations(&mut self, limit: Option<u32>, timers: &mut Timers) -> bool {
+            let state = self.solve_state.unwrap();
+            let mut iter = state.iter;
+            let mut σ: T = state.σ.as_T();
+            let mut α: T = state.α.as_T();
+            let mut μ: T = state.μ.as_T();
+            let mut scaling = state.scaling;
+            let mut is_checked = state.is_checked;
+            let mut steps: u32 = 0;
+
+            timeit! {timers => "solve"; {
+            timeit!{timers => "IP iteration"; {
+
+            // ----------
+            // main loop
+            // ----------
+
+            loop {
+
+                // the termination checks have already been made for
+                // the current iterate if resuming from a pause
+                if !is_checked {
+                    //update the residuals
+                    //--------------
+                    self.residuals.update(&self.variables, &self.data);
+
+                    //calculate duality gap (scaled)
+                    //--------------
+                    μ = self.variables.calc_mu(&self.residuals, &self.cones);
+
+                    // record scalar values from most recent iteration.
+                    // This captures μ at iteration zero.
+                    self.info.save_scalars(μ, α, σ, iter);
+
+                    // convergence check and printing
+                    // --------------
+                    self.info.update(
+                        &mut self.data,
+                        &self.variables,
+                        &self.residuals,timers);
+                    self.info.save_iteration_record(&self.settings);
+
+                    notimeit!{timers; {
+                        self.info.print_status(&self.settings).unwrap();
+                    }}
+
+                    if let Some(observer) = self.progress_observer.as_mut() {
+                        observer(&self.info);
+                    }
+
+                    let isdone = self.info.check_termination(&self.residuals, &self.settings, iter);
+
+                    // check for termination due to slow progress and update strategy
+                    if isdone{
+                            match self.strategy_checkpoint_insufficient_progress(scaling){
+                                StrategyCheckpoint::NoUpdate | StrategyCheckpoint::Fail => {break}
+                                StrategyCheckpoint::Update(s) => {scaling = s; continue}
+                            }
+                    }  // allows continuation if new strategy provided
+
+                    // user-supplied callback, which may request termination
+                    if let Some(callback) = self.iteration_callback.as_mut() {
+                        if callback(&self.info, &self.variables) == CallbackAction::Terminate {
+                            self.info.set_status(SolverStatus::CallbackTerminated);
+                            break;
+                        }
+                    }
+                }
+                is_checked = false;
+
+                // pause before taking more than the requested steps
+                if limit.map_or(false, |n| steps >= n) {
+                    is_checked = true;
+                    break;
+                }
+
+                // update the scalings
+                // --------------
+                let is_scaling_success = self.variables.scale_cones(&mut self.cones,μ,scaling);
+                // check whether variables are interior points
+                match self.strategy_checkpoint_is_scaling_success(is_scaling_success,scaling){
+                    StrategyCheckpoint::Fail => {break}
+                    StrategyCheckpoint::NoUpdate => {} // we only expect NoUpdate or Fail here
+                    StrategyCheckpoint::Update(_) => {unreachable!()}
+                }
+
+                //increment counter here because we only count
+                //iterations that produce a KKT update
+                iter += 1;
+                steps += 1;
+
+                // Update the KKT system and the constant parts of its solution.
+                // Keep track of the success of each step that calls KKT
+                // --------------
+                //PJG: This should be a Result in Rust, but needs changes down
+                //into the KKT solvers to do that.
+                let mut is_kkt_solve_success : bool;
+                timeit!{timers => "kkt update"; {
+                    // with a hard time limit, the factorization
+                    // stops early once the limit is exceeded
+                    let is_expired = || _is_hard_time_limit_exceeded(self.settings.core(), timers);
+                    is_kkt_solve_success = self.kktsystem.update_with_abort(
+                        &self.data, &self.cones, &self.settings, &is_expired
+                    );
+                }} // end "kkt update" timer
+
+                // stop immediately if a hard time limit is exceeded.  The
+                // current iterate is untouched and used for the solution
+                if _is_hard_time_limit_exceeded(self.settings.core(), timers) {
+                    self.info.set_status(SolverStatus::MaxTime);
+                    α = T::zero();
+                    break;
+                }
+
+                // calculate the affine step
+                // --------------
+                self.step_rhs
+                    .affine_step_rhs(&self.residuals, &self.variables, &self.cones);
+
+                timeit!{timers => "kkt solve"; {
+                    is_kkt_solve_success = is_kkt_solve_success &&
+                    self.kktsystem.solve(
+                        &mut self.step_lhs,
+                        &self.step_rhs,
+                        &self.data,
+                        &self.variables,
+                        &mut self.cones,
+                        StepDirection::Affine,
+                        &self.settings,
+                    );
+                }}  //end "kkt solve affine" timer
+
+                // combined step only on affine step success
+                if is_kkt_solve_success {
+
+                    //calculate step length and centering parameter
+                    // --------------
+                    α = self.get_step_length(StepDirection::Affine, scaling);
+                    σ = self.centering_parameter(α);
+
+                    // make a reduced Mehrotra correction in the first iteration
+                    // to accommodate badly centred starting points
+                    let m = if iter > 1 {T::one()} else {α};
+
+                    // calculate the combined step and length
+                    // --------------
+                    self.step_rhs.combined_step_rhs(
+                        &self.residuals,
+                        &self.variables,
+                        &mut self.cones,
+                        &mut self.step_lhs,
+                        σ,
+                        μ,
+                        m
+                    );
+
+                    timeit!{timers => "kkt solve" ; {
+                        is_kkt_solve_success =
+                        self.kktsystem.solve(
+                            &mut self.step_lhs,
+                            &self.step_rhs,
+                            &self.data,
+                            &self.variables,
+                            &mut self.cones,
+                            StepDirection::Combined,
+                            &self.settings,
+                        );
+                    }} //end "kkt solve"
+
+                    // higher order corrections, each recomputing the combined
+                    // step with the second order term from the latest direction
+                    // --------------
+                    if is_kkt_solve_success {
+                        self.centrality_corrections(σ, μ, m, scaling, timers);
+                    }
+                }
+
+                if _is_hard_time_limit_exceeded(self.settings.core(), timers) {
+                    self.info.set_status(SolverStatus::MaxTime);
+                    α = T::zero();
+                    break;
+                }
+
+                // check for numerical failure and update strategy
+                match self.strategy_checkpoint_numerical_error(is_kkt_solve_success,scaling) {
+                    StrategyCheckpoint::NoUpdate => {}
+                    StrategyCheckpoint::Update(s) => {α = T::zero(); scaling = s; continue}
+                    StrategyCheckpoint::Fail => {α = T::zero(); break}
+                }
+
+
+                // compute final step length and update the current iterate
+                // --------------
+                α = self.get_step_length(StepDirection::Combined,scaling);
+
+                // check for undersized step and update strategy
+                match self.strategy_checkpoint_small_step(α, scaling) {
+                    StrategyCheckpoint::NoUpdate => {}
+                    StrategyCheckpoint::Update(s) => {α = T::zero(); scaling = s; continue}
+                    StrategyCheckpoint::Fail => {α = T::zero(); break}
+                }
+
+                // Copy previous iterate in case the next one is a dud
+                self.info.save_prev_iterate(&self.variables,&mut self.prev_vars);
+                self.solution.save_prev_iterate(&self.data,&self.variables,self.settings.core());
+
+                self.variables.add_step(&self.step_lhs, α);
+
+            } //end loop
+            // ----------
+            // ----------
+
+            }} //end "IP iteration" timer
+            }} // end "solve" timer
+
+            self.solve_state = Some(SolveState {
+                iter,
+                σ: σ.to_f64().unwrap(),
+                α: α.to_f64().unwrap(),
+                μ: μ.to_f64().unwrap(),
+                scaling,
+                is_checked,
+                ..state
+            });
+            !is_checked
+        }
+
+        // Finalizes the solver information and solution of a terminated solve
+        fn finish_solve(&mut self, timers: &mut Timers) {
+            let state = self.solve_state.take().unwrap();
+            let σ: T = state.σ.as_T();
+            let α: T = state.α.as_T();
+            let μ: T = state.μ.as_T();
+
+            timeit! {timers => "solve"; {
+            if self.info.get_status() == SolverStatus::Solved {
+                self.variables.crossover(&self.data, &self.cones, &self.settings);
+            }
+            if self.info.get_status() == SolverStatus::NumericalError {
+                self.kktsystem.dump_on_failure(&self.cones, &self.settings);
+            }
+            }} // end "solve" timer
+
+            // Check we if actually took a final step.  If not, we need
+            // to recapture the scalars and print one last line
+            if α == T::zero() {
+                self.info.save_scalars(μ, α, σ, state.iter);
+                notimeit! {timers; {self.info.print_status(&self.settings).unwrap();}}
+            }
+
+            //store final solution, timing etc
+            let kktinfo = self.kktsystem.kkt_info();
+            self.info.save_refinement_stats(
+                kktinfo.num_refinement_steps - state.refinement_steps_start,
+                kktinfo.final_refinement_residual.as_T(),
+            );
+            self.info.save_factorization_retries(
+                kktinfo.num_factorization_retries - state.factorization_retries_start,
+            );
+            self.info.finalize(&self.residuals, &self.settings, timers);
+
+            self.solution.finalize(
+                &self.data,
+                &self.variables,
+                &self.info,
+                self.settings.core(),
+            );
+
+            self.info.print_footer(&self.settings).unwrap();
+        }
+
         fn default_start(&mut self) {
             if self.cones.is_symmetric() {
                 // set all scalings to identity (or zero for the zero cone)
@@ -474,13 +827,19 @@ mod internal {
             step_direction: StepDirection,
             scaling: ScalingStrategy,
         ) -> T {
-            //step length to stay within the cones
-            let mut α = self.variables.calc_step_length(
+            //step length to stay within the cones.  A common
+            //step is taken in the primal and dual variables
+            let (α_primal, α_dual) = self.variables.calc_step_length(
                 &self.step_lhs,
                 &mut self.cones,
                 &self.settings,
                 step_direction,
             );
+            let mut α = T::min(α_primal, α_dual);
+
+            if step_direction == StepDirection::Combined {
+                self.info.save_step_lengths(α_primal, α_dual);
+            }
 
             // additional barrier function limits for asymmetric cones
             if !self.cones.is_symmetric()
@@ -508,6 +867,56 @@ mod internal {
             α
         }
 
+        fn centrality_corrections(
+            &mut self,
+            σ: T,
+            μ: T,
+            m: T,
+            scaling: ScalingStrategy,
+            timers: &mut Timers,
+        ) {
+            let ncorrections = self.settings.core().max_centrality_corrections;
+
+            for _ in 0..ncorrections {
+                let α = self.get_step_length(StepDirection::Combined, scaling);
+                self.step_work.copy_from(&self.step_lhs);
+
+                // the combined RHS is built on the affine one
+                self.step_rhs
+                    .affine_step_rhs(&self.residuals, &self.variables, &self.cones);
+                self.step_rhs.combined_step_rhs(
+                    &self.residuals,
+                    &self.variables,
+                    &mut self.cones,
+                    &mut self.step_lhs,
+                    σ,
+                    μ,
+                    m,
+                );
+
+                let is_kkt_solve_success;
+                timeit! {timers => "kkt solve"; {
+                    is_kkt_solve_success = self.kktsystem.solve(
+                        &mut self.step_lhs,
+                        &self.step_rhs,
+                        &self.data,
+                        &self.variables,
+                        &mut self.cones,
+                        StepDirection::Combined,
+                        &self.settings,
+                    );
+                }}
+
+                // keep the previous direction unless the step is longer
+                if !is_kkt_solve_success
+                    || self.get_step_length(StepDirection::Combined, scaling) <= α
+                {
+                    self.step_lhs.copy_from(&self.step_work);
+                    break;
+                }
+            }
+        }
+
         fn strategy_checkpoint_insufficient_progress(
             &mut self,
             scaling: ScalingStrategy,
@@ -10,8 +10,8 @@
 //!  which collectively implement support for the problem format described in the top
 //! level crate documentation.
 
-use super::{cones::Cone, CoreSettings, ScalingStrategy};
-use super::{SolverStatus, StepDirection};
+use super::{cones::Cone, kktsolvers::KKTInfo, CoreSettings, ScalingStrategy};
+use super::{SolverStatus, StepDirection, WarmStartMode};
 use crate::algebra::*;
 use crate::timers::*;
 
@@ -56,8 +56,9 @@ pub trait Variables<T: FloatT> {
         m: T,
     );
 
-    /// Compute the maximum step length possible in the given
-    /// step direction without violating a cone boundary.
+    /// Compute the maximum step lengths possible in the given
+    /// step direction without violating a cone boundary, as a
+    /// pair of primal and dual step lengths.
 
     fn calc_step_length(
         &self,
@@ -65,7 +66,7 @@ pub trait Variables<T: FloatT> {
         cones: &mut Self::C,
         settings: &Self::SE,
         step_direction: StepDirection,
-    ) -> T;
+    ) -> (T, T);
 
     /// Update the variables in the given step direction, scaled by `α`.
     fn add_step(&mut self, step_lhs: &Self, α: T);
@@ -76,6 +77,11 @@ pub trait Variables<T: FloatT> {
     /// Initialize all conic variables to unit values.
     fn unit_initialization(&mut self, cones: &Self::C);
 
+    /// Overwrite default initial values with those from a user guess,
+    /// according to the warm start mode.   The result must lie in the
+    /// interior of the cone constraints.
+    fn warm_start(&mut self, guess: &Self, mode: WarmStartMode, λ: T, cones: &mut Self::C);
+
     /// Overwrite values with those from another object
     fn copy_from(&mut self, src: &Self);
 
@@ -87,6 +93,11 @@ pub trait Variables<T: FloatT> {
 
     fn barrier(&self, step: &Self, α: T, cones: &mut Self::C) -> T;
 
+    /// Move a solved iterate to a vertex of the feasible set, e.g. for
+    /// a linear program.   Called only for problems that have been solved.
+    /// The default implementation does nothing.
+    fn crossover(&mut self, _data: &Self::D, _cones: &Self::C, _settings: &Self::SE) {}
+
     /// Rescale variables, e.g. to renormalize iterates
     /// in a homogeneous embedding
 
@@ -117,6 +128,18 @@ pub trait KKTSystem<T: FloatT> {
 
     fn update(&mut self, data: &Self::D, cones: &Self::C, settings: &Self::SE) -> bool;
 
+    /// As [`update`](KKTSystem::update), but may stop early and return false
+    /// if `abort` returns true.  The default implementation never stops early.
+    fn update_with_abort(
+        &mut self,
+        data: &Self::D,
+        cones: &Self::C,
+        settings: &Self::SE,
+        _abort: &dyn Fn() -> bool,
+    ) -> bool {
+        self.update(data, cones, settings)
+    }
+
     /// Solve the KKT system for the given RHS.
 
     #[allow(clippy::too_many_arguments)]
@@ -139,6 +162,14 @@ pub trait KKTSystem<T: FloatT> {
         data: &Self::D,
         settings: &Self::SE,
     ) -> bool;
+
+    /// Summary information about the KKT system and its factorization
+    fn kkt_info(&self) -> KKTInfo;
+
+    /// Record the state of the KKT system after a solve has stopped
+    /// for numerical reasons, e.g. for offline analysis.
+    /// The default implementation does nothing.
+    fn dump_on_failure(&self, _cones: &Self::C, _settings: &Self::SE) {}
 }
 
 /// Printing functions for the solver's Info
@@ -207,6 +238,21 @@ where
     /// `σ = ` multiplier for the updated centering parameter.
     fn save_scalars(&mut self, μ: T, α: T, σ: T, iter: u32);
 
+    /// Record the primal and dual step lengths computed for the most
+    /// recent combined step direction.
+    fn save_step_lengths(&mut self, α_primal: T, α_dual: T);
+
+    /// Record the number of iterative refinement steps taken during
+    /// the solve, and the KKT residual after the final refinement.
+    fn save_refinement_stats(&mut self, iters: usize, residual: T);
+
+    /// Record the progress of the most recent iteration, if enabled in
+    /// the settings.  Called once per iteration after `update`.
+    fn save_iteration_record(&mut self, settings: &Self::SE);
+
+    /// Record the number of KKT factorization retries made during the solve.
+    fn save_factorization_retries(&mut self, retries: usize);
+
     /// Report or update termination status
     fn get_status(&self) -> SolverStatus;
     fn set_status(&mut self, status: SolverStatus);
@@ -220,7 +266,25 @@ pub trait Solution<T: FloatT> {
     type I: Info<T>;
 
     /// Compute solution from the Variables at solver termination
-    fn finalize(&mut self, data: &Self::D, variables: &Self::V, info: &Self::I);
+    fn finalize(
+        &mut self,
+        data: &Self::D,
+        variables: &Self::V,
+        info: &Self::I,
+        settings: &CoreSettings<T>,
+    );
+
+    /// Clear any iterate history from a previous solve
+    fn reset(&mut self);
+
+    /// Record the current iterate before a step is taken, if
+    /// iterate history is enabled in the settings.
+    fn save_prev_iterate(
+        &mut self,
+        data: &Self::D,
+        variables: &Self::V,
+        settings: &CoreSettings<T>,
+    );
 }
 
 /// Settings for a conic optimization problem.
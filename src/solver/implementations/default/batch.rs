@@ -0,0 +1,21 @@
+use super::*;
+use crate::algebra::*;
+use crate::solver::core::IPSolver;
+use rayon::prelude::*;
+
+/// Solves a batch of independent problems in parallel on the global
+/// [rayon](https://docs.rs/rayon) thread pool, returning their solutions
+/// in the same order as `solvers`.
+///
+/// Each solver holds its own problem data and workspace, so the results are
+/// the same as solving each one in turn.  Verbose output from the solvers
+/// may be interleaved, so it is best disabled in the settings.  To run the
+/// solves on a particular pool, call this function within
+/// `ThreadPool::install`.
+pub fn solve_batch<T>(solvers: &mut [DefaultSolver<T>]) -> Vec<&DefaultSolution<T>>
+where
+    T: FloatT,
+{
+    solvers.par_iter_mut().for_each(|solver| solver.solve());
+    solvers.iter().map(|solver| &solver.solution).collect()
+}
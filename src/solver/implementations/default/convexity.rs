@@ -0,0 +1,161 @@
+#![allow(non_snake_case)]
+use super::*;
+use crate::algebra::*;
+use std::fmt;
+
+// power iteration steps taken when looking for negative curvature
+const POWER_ITERATIONS: usize = 50;
+
+/// Definiteness of the quadratic term `P` of the objective, as far
+/// as it could be determined by [`DefaultSolver::verify_convexity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Definiteness {
+    /// `P` is positive semidefinite
+    PositiveSemidefinite,
+    /// `P` is not positive semidefinite, so the problem is not convex
+    Indefinite,
+    /// neither could be established by the checks made
+    Unknown,
+}
+
+/// Result of the checks made by [`DefaultSolver::verify_convexity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvexityCheck {
+    /// false if `P` was supplied as a full matrix that is not symmetric.
+    /// Only the upper triangle of `P` is used by the solver in that case.
+    pub symmetric: bool,
+    /// definiteness of the symmetric matrix with the upper triangle of `P`
+    pub definiteness: Definiteness,
+}
+
+impl ConvexityCheck {
+    /// True if `P` is symmetric and known to be positive semidefinite.
+    pub fn is_verified(&self) -> bool {
+        self.symmetric && self.definiteness == Definiteness::PositiveSemidefinite
+    }
+}
+
+impl fmt::Display for ConvexityCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.symmetric {
+            writeln!(f, "P is not symmetric, and only its upper triangle is used")?;
+        }
+        match self.definiteness {
+            Definiteness::PositiveSemidefinite => {}
+            Definiteness::Indefinite => {
+                writeln!(
+                    f,
+                    "P is not positive semidefinite, so the problem is not convex"
+                )?;
+            }
+            Definiteness::Unknown => {
+                writeln!(f, "P could not be verified to be positive semidefinite")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> DefaultSolver<T>
+where
+    T: FloatT,
+{
+    /// Checks that the quadratic term `P` of the objective is symmetric
+    /// and positive semidefinite, as assumed by the solver.
+    ///
+    /// The definiteness checks are cheap heuristics.  `P` is reported as
+    /// positive semidefinite if it is diagonally dominant with a nonnegative
+    /// diagonal, and as indefinite if it has a negative diagonal entry or
+    /// 2×2 principal minor, or if a few steps of power iteration find a
+    /// direction of negative curvature.   The result is otherwise
+    /// [`Unknown`](Definiteness::Unknown).
+    ///
+    /// The checks are advisory only, and do not prevent the solver from
+    /// running.  They are made on the equilibrated data, which has the same
+    /// definiteness as the `P` supplied.
+    pub fn verify_convexity(&self) -> ConvexityCheck {
+        ConvexityCheck {
+            symmetric: self.data.P_is_symmetric,
+            definiteness: _definiteness(&self.data.P),
+        }
+    }
+}
+
+// definiteness of the symmetric matrix with upper triangle P
+fn _definiteness<T: FloatT>(P: &CscMatrix<T>) -> Definiteness {
+    let n = P.n;
+    let Pmax = P.nzval.norm_inf();
+    if Pmax == T::zero() {
+        return Definiteness::PositiveSemidefinite;
+    }
+    let tol = T::epsilon().sqrt() * Pmax;
+
+    // diagonal entries, and off-diagonal absolute row sums
+    let mut diag = vec![T::zero(); n];
+    let mut offsum = vec![T::zero(); n];
+    for col in 0..n {
+        for k in P.colptr[col]..P.colptr[col + 1] {
+            let (row, v) = (P.rowval[k], P.nzval[k]);
+            if row == col {
+                diag[col] = v;
+            } else {
+                offsum[row] += v.abs();
+                offsum[col] += v.abs();
+            }
+        }
+    }
+
+    if diag.iter().any(|&d| d < -tol) {
+        return Definiteness::Indefinite;
+    }
+
+    // negative 2×2 principal minors
+    for col in 0..n {
+        for k in P.colptr[col]..P.colptr[col + 1] {
+            let (row, v) = (P.rowval[k], P.nzval[k]);
+            if row != col && v * v > diag[row] * diag[col] + tol * Pmax {
+                return Definiteness::Indefinite;
+            }
+        }
+    }
+
+    if diag.iter().zip(&offsum).all(|(&d, &r)| d >= r) {
+        return Definiteness::PositiveSemidefinite;
+    }
+
+    // power iteration on σI - P, with σ a Gershgorin bound on the
+    // largest eigenvalue of P, converges towards the eigenvector of
+    // the smallest eigenvalue of P
+    let σ = diag
+        .iter()
+        .zip(&offsum)
+        .fold(T::zero(), |acc, (&d, &r)| T::max(acc, d + r));
+
+    // a start vector unlikely to be orthogonal to that eigenvector
+    let mut x: Vec<T> = (0..n)
+        .map(|i| {
+            let v = T::one() + T::from_usize(i).unwrap() / T::from_usize(n).unwrap();
+            if i % 2 == 0 {
+                v
+            } else {
+                -v
+            }
+        })
+        .collect();
+    let mut y = vec![T::zero(); n];
+
+    for _ in 0..POWER_ITERATIONS {
+        let xnorm = x.norm();
+        x.scale(T::recip(xnorm));
+
+        // y = σx - Px, so that xᵀPx = σ - xᵀy
+        y.copy_from(&x);
+        P.sym().symv(&mut y, &x, -T::one(), σ);
+        if σ - x.dot(&y) < -tol {
+            return Definiteness::Indefinite;
+        }
+        std::mem::swap(&mut x, &mut y);
+    }
+
+    Definiteness::Unknown
+}
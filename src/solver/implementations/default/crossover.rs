@@ -0,0 +1,234 @@
+#![allow(non_snake_case)]
+use super::*;
+use crate::algebra::*;
+use crate::solver::core::cones::{CompositeCone, SupportedConeTag};
+
+// -------------------------------------
+// Crossover of an interior LP solution to a vertex.
+//
+// Starting from an optimal point, we repeatedly move along a direction
+// in the null space of the active constraints until another constraint
+// becomes active.  Every such direction leaves the objective unchanged,
+// since moving along it in either direction remains feasible for some
+// distance.  The process ends at a vertex once the active constraints
+// have full column rank, after at most n steps.   Dense elimination is
+// used for the active constraints, so this is intended for problems of
+// small to moderate size, and is skipped for problems with more than
+// CROSSOVER_MAX_DIM variables or constraints.
+//
+// All computations are made in the scaled space of the solver, which
+// has the same vertices as the original problem.  The dual variables
+// are unchanged, since constraints active at the interior solution
+// remain active throughout.
+// -------------------------------------
+
+// Each step eliminates the active rows densely, so the cost of a
+// crossover grows as O(n³⋅m).  Larger problems keep the interior solution
+pub(crate) const CROSSOVER_MAX_DIM: usize = 200;
+
+pub(crate) fn lp_crossover<T: FloatT>(
+    variables: &mut DefaultVariables<T>,
+    data: &DefaultProblemData<T>,
+    cones: &CompositeCone<T>,
+    settings: &DefaultSettings<T>,
+) {
+    if data.n > CROSSOVER_MAX_DIM || data.m > CROSSOVER_MAX_DIM {
+        return;
+    }
+
+    // only linear programs with zero and nonnegative cones
+    if data.P.nzval.iter().any(|&v| v != T::zero()) {
+        return;
+    }
+    let mut is_eq = Vec::with_capacity(data.m);
+    for cone in cones.descriptors() {
+        let eq = match cone.kind {
+            SupportedConeTag::ZeroCone => true,
+            SupportedConeTag::NonnegativeCone => false,
+            _ => return,
+        };
+        is_eq.extend(std::iter::repeat(eq).take(cone.dim));
+    }
+
+    let (A, b, q) = (&data.A, &data.b, &data.q);
+    let (m, n) = (data.m, data.n);
+    let At = A.transpose();
+
+    // the solution of the homogeneous embedding
+    let τinv = T::recip(variables.τ);
+    let mut x = variables.x.clone();
+    x.scale(τinv);
+
+    // slacks recomputed from x, and the constraints active there
+    let mut s = b.clone();
+    A.gemv(&mut s, &x, -T::one(), T::one(), MatrixShape::N);
+    let mut active: Vec<bool> = (0..m)
+        .map(|i| is_eq[i] || s[i] <= settings.tol_feas * (T::one() + b[i].abs()))
+        .collect();
+
+    let mut Ad = vec![T::zero(); m];
+
+    for _ in 0..=n {
+        let rows: Vec<usize> = (0..m).filter(|&i| active[i]).collect();
+        let mut d = match _null_vector(&At, &rows, n) {
+            Some(d) => d,
+            None => break, // at a vertex
+        };
+
+        // move in a direction that does not increase the objective
+        if q.dot(&d) > T::zero() {
+            d.negate();
+        }
+        A.gemv(&mut Ad, &d, T::one(), T::zero(), MatrixShape::N);
+
+        // the first inactive constraint reached in either direction,
+        // preferring the one chosen above
+        let mut step = _ratio_test(&s, &Ad, &active);
+        if step.is_none() {
+            d.negate();
+            Ad.negate();
+            step = _ratio_test(&s, &Ad, &active);
+        }
+        let (t, hit) = match step {
+            Some(step) => step,
+            None => break, // the feasible set contains a line
+        };
+
+        x.axpby(t, &d, T::one());
+        s.axpby(-t, &Ad, T::one());
+        s[hit] = T::zero();
+        active[hit] = true;
+    }
+
+    // remove any residual slack in the active constraints, unless
+    // they are too badly conditioned for this to be a small correction
+    let rows: Vec<usize> = (0..m).filter(|&i| active[i]).collect();
+    if let Some(v) = _vertex(&At, b, &rows, n) {
+        if v.dist(&x) <= settings.tol_feas * (T::one() + x.norm_inf()) {
+            x.copy_from(&v);
+        }
+    }
+
+    // final slacks, on the cone boundary where active
+    s.copy_from(b);
+    A.gemv(&mut s, &x, -T::one(), T::one(), MatrixShape::N);
+    for (si, &eq) in s.iter_mut().zip(is_eq.iter()) {
+        *si = if eq {
+            T::zero()
+        } else {
+            T::max(*si, T::zero())
+        };
+    }
+
+    variables.x.copy_from(&x);
+    variables.s.copy_from(&s);
+    variables.z.scale(τinv);
+    variables.κ *= τinv;
+    variables.τ = T::one();
+}
+
+// largest step t ≥ 0 along a direction with change Ad in the slacks
+// before an inactive slack reaches zero, and the index of that slack
+fn _ratio_test<T: FloatT>(s: &[T], Ad: &[T], active: &[bool]) -> Option<(T, usize)> {
+    let tol = T::epsilon().sqrt() * Ad.norm_inf();
+    let mut step: Option<(T, usize)> = None;
+
+    for (i, (&si, &adi)) in s.iter().zip(Ad.iter()).enumerate() {
+        if active[i] || adi <= tol {
+            continue;
+        }
+        let t = T::max(si, T::zero()) / adi;
+        if step.map_or(true, |(tmin, _)| t < tmin) {
+            step = Some((t, i));
+        }
+    }
+    step
+}
+
+// dense copy of the given rows of A, i.e. columns of At
+fn _dense_rows<T: FloatT>(At: &CscMatrix<T>, rows: &[usize], n: usize) -> Vec<Vec<T>> {
+    rows.iter()
+        .map(|&i| {
+            let mut row = vec![T::zero(); n];
+            for k in At.colptr[i]..At.colptr[i + 1] {
+                row[At.rowval[k]] = At.nzval[k];
+            }
+            row
+        })
+        .collect()
+}
+
+// Gauss-Jordan elimination with partial pivoting of the rows M and
+// right hand side r, stopping at the first column without a pivot.
+// Returns the pivot column of each reduced row, and the free column
+fn _eliminate<T: FloatT>(M: &mut [Vec<T>], r: &mut [T], n: usize) -> (Vec<usize>, Option<usize>) {
+    let Mmax = M
+        .iter()
+        .fold(T::zero(), |acc, row| T::max(acc, row.norm_inf()));
+    let tol = T::epsilon().sqrt() * T::max(Mmax, T::one());
+
+    let mut pivots: Vec<usize> = Vec::new();
+
+    for col in 0..n {
+        let k = pivots.len();
+
+        let best =
+            (k..M.len()).max_by(|&i, &j| M[i][col].abs().partial_cmp(&M[j][col].abs()).unwrap());
+        match best {
+            Some(p) if M[p][col].abs() > tol => {
+                M.swap(k, p);
+                r.swap(k, p);
+                let pivot = M[k][col];
+                M[k].scale(T::recip(pivot));
+                r[k] /= pivot;
+                let (prow, pr) = (M[k].clone(), r[k]);
+                for (i, (row, ri)) in M.iter_mut().zip(r.iter_mut()).enumerate() {
+                    if i != k && row[col] != T::zero() {
+                        let f = row[col];
+                        row.axpby(-f, &prow, T::one());
+                        *ri -= f * pr;
+                    }
+                }
+                pivots.push(col);
+            }
+            _ => return (pivots, Some(col)),
+        }
+    }
+    (pivots, None)
+}
+
+// a nonzero vector orthogonal to the given rows of A, or None if
+// those rows have full column rank
+fn _null_vector<T: FloatT>(At: &CscMatrix<T>, rows: &[usize], n: usize) -> Option<Vec<T>> {
+    let mut M = _dense_rows(At, rows, n);
+    let mut r = vec![T::zero(); rows.len()];
+    let (pivots, free) = _eliminate(&mut M, &mut r, n);
+
+    // d is one in the free column and zero in all later columns.
+    // Its entries in the pivot columns are fixed by the reduced rows
+    let f = free?;
+    let mut d = vec![T::zero(); n];
+    d[f] = T::one();
+    for (k, &col) in pivots.iter().enumerate() {
+        d[col] = -M[k][f];
+    }
+    let dnorm = d.norm_inf();
+    d.scale(T::recip(dnorm));
+    Some(d)
+}
+
+// the point at which n linearly independent rows among the given
+// rows of Ax = b hold with equality, or None if there are no such rows
+fn _vertex<T: FloatT>(At: &CscMatrix<T>, b: &[T], rows: &[usize], n: usize) -> Option<Vec<T>> {
+    let mut M = _dense_rows(At, rows, n);
+    let mut r: Vec<T> = rows.iter().map(|&i| b[i]).collect();
+    let (pivots, free) = _eliminate(&mut M, &mut r, n);
+    if free.is_some() {
+        return None;
+    }
+    let mut x = vec![T::zero(); n];
+    for (k, &col) in pivots.iter().enumerate() {
+        x[col] = r[k];
+    }
+    Some(x)
+}
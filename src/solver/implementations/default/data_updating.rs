@@ -3,6 +3,7 @@ use super::DefaultSolver;
 use crate::algebra::*;
 use core::iter::Zip;
 use core::slice::Iter;
+use std::iter::zip;
 use thiserror::Error;
 
 /// Error type returned by user data update utilities, e.g. [`check_format`](crate::algebra::CscMatrix::check_format) utility.
@@ -10,23 +11,44 @@ use thiserror::Error;
 pub enum DataUpdateError {
     #[error("Data updates are not allowed when presolve is enabled")]
     PresolveEnabled,
+    #[error("Updates to A and b are not allowed for problems with complex PSD cones")]
+    ComplexPSDConesPresent,
     #[error("Data formatting error")]
     BadFormat(#[from] SparseFormatError),
 }
 
-// Trait for updating P and A matrices from various data types
+/// Error type returned by selective updates of problem data, e.g.
+/// [`update_q_entries`](DefaultSolver::update_q_entries).
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SparseUpdateError {
+    #[error("Data updates are not allowed when presolve is enabled")]
+    PresolveEnabled,
+    #[error("Update has {0} indices but {1} values")]
+    LengthMismatch(usize, usize),
+    #[error("Update index {0} is out of bounds")]
+    IndexOutOfBounds(usize),
+    #[error("Update has {0} values but the matrix has {1} stored entries")]
+    NnzMismatch(usize, usize),
+    #[error("Updates to A and b are not allowed for problems with complex PSD cones")]
+    ComplexPSDConesPresent,
+}
+
+// Trait for updating P and A matrices from various data types.  Updated
+// entries are scaled by `lscale` and `rscale` and then by `cscale`
 pub trait MatrixProblemDataUpdate<T: FloatT> {
     fn update_matrix(
         &self,
         M: &mut CscMatrix<T>,
         lscale: &[T],
         rscale: &[T],
+        cscale: T,
     ) -> Result<(), SparseFormatError>;
 }
 
-// Trait for updating q and b vectors from various data types
+// Trait for updating q and b vectors from various data types.  Updated
+// entries are scaled elementwise by `scale` and then by `cscale`
 pub trait VectorProblemDataUpdate<T: FloatT> {
-    fn update_vector(&self, v: &mut [T], scale: &[T]) -> Result<(), SparseFormatError>;
+    fn update_vector(&self, v: &mut [T], scale: &[T], cscale: T) -> Result<(), SparseFormatError>;
 }
 
 impl<T> DefaultSolver<T>
@@ -66,13 +88,14 @@ where
     ///
     /// - an empty vector, in which case no action is taken.
     ///
-    pub fn update_P<Data: MatrixProblemDataUpdate<T>>(
+    pub fn update_P<Data: MatrixProblemDataUpdate<T> + ?Sized>(
         &mut self,
         data: &Data,
     ) -> Result<(), DataUpdateError> {
         self.check_presolve_disabled()?;
         let d = &self.data.equilibration.d;
-        data.update_matrix(&mut self.data.P, d, d)?;
+        let c = self.data.equilibration.c;
+        data.update_matrix(&mut self.data.P, d, d, c)?;
         // overwrite KKT data
         self.kktsystem.update_P(&self.data.P);
         Ok(())
@@ -88,19 +111,68 @@ where
     ///
     /// - an empty vector, in which case no action is taken.
     ///
-    pub fn update_A<Data: MatrixProblemDataUpdate<T>>(
+    pub fn update_A<Data: MatrixProblemDataUpdate<T> + ?Sized>(
         &mut self,
         data: &Data,
     ) -> Result<(), DataUpdateError> {
         self.check_presolve_disabled()?;
+        self.check_no_expanded_cones()?;
         let d = &self.data.equilibration.d;
         let e = &self.data.equilibration.e;
-        data.update_matrix(&mut self.data.A, e, d)?;
+        data.update_matrix(&mut self.data.A, e, d, T::one())?;
         // overwrite KKT data
         self.kktsystem.update_A(&self.data.A);
         Ok(())
     }
 
+    /// Overwrites the nonzero values of the `P` matrix in an existing solver object,
+    /// preserving its sparsity pattern.   Values should be supplied in the order of
+    /// the upper triangular part of the original `P`.
+    ///
+    /// Unlike [`update_P`](Self::update_P), the input length must always match the
+    /// number of stored entries in `P`, and an empty input is an error unless `P`
+    /// itself has no stored entries.
+    pub fn update_P_values(&mut self, nzval: &[T]) -> Result<(), SparseUpdateError> {
+        if self.settings.presolve_enable {
+            return Err(SparseUpdateError::PresolveEnabled);
+        }
+        if nzval.len() != self.data.P.nnz() {
+            return Err(SparseUpdateError::NnzMismatch(
+                nzval.len(),
+                self.data.P.nnz(),
+            ));
+        }
+
+        // always succeeds after the checks above
+        self.update_P(nzval).unwrap();
+        Ok(())
+    }
+
+    /// Overwrites the nonzero values of the `A` matrix in an existing solver object,
+    /// preserving its sparsity pattern.
+    ///
+    /// Unlike [`update_A`](Self::update_A), the input length must always match the
+    /// number of stored entries in `A`, and an empty input is an error unless `A`
+    /// itself has no stored entries.
+    pub fn update_A_values(&mut self, nzval: &[T]) -> Result<(), SparseUpdateError> {
+        if self.settings.presolve_enable {
+            return Err(SparseUpdateError::PresolveEnabled);
+        }
+        if self.data.presolver.is_expanded() {
+            return Err(SparseUpdateError::ComplexPSDConesPresent);
+        }
+        if nzval.len() != self.data.A.nnz() {
+            return Err(SparseUpdateError::NnzMismatch(
+                nzval.len(),
+                self.data.A.nnz(),
+            ));
+        }
+
+        // always succeeds after the checks above
+        self.update_A(nzval).unwrap();
+        Ok(())
+    }
+
     /// Overwrites the `q` vector data in an existing solver object.  No action is taken if the input is empty.
     pub fn update_q<Data: VectorProblemDataUpdate<T>>(
         &mut self,
@@ -108,10 +180,49 @@ where
     ) -> Result<(), DataUpdateError> {
         self.check_presolve_disabled()?;
         let d = &self.data.equilibration.d;
-        data.update_vector(&mut self.data.q, d)?;
+        let c = self.data.equilibration.c;
+        data.update_vector(&mut self.data.q, d, c)?;
+
+        // flush unscaled norm, and refresh the tolerance scaling
+        self.data.clear_normq();
+        self.info.save_data_norms(&mut self.data);
+
+        Ok(())
+    }
+
+    /// Overwrites selected entries of the `q` vector data in an existing solver
+    /// object, with `q[indices[k]] = values[k]` and all other entries unchanged.
+    ///
+    /// Equivalent to [`update_q`](Self::update_q) with a `zip(&indices,&values)`
+    /// iterator, but with the whole input checked before any entry is updated.
+    pub fn update_q_entries(
+        &mut self,
+        indices: &[usize],
+        values: &[T],
+    ) -> Result<(), SparseUpdateError> {
+        if self.settings.presolve_enable {
+            return Err(SparseUpdateError::PresolveEnabled);
+        }
+        if indices.len() != values.len() {
+            return Err(SparseUpdateError::LengthMismatch(
+                indices.len(),
+                values.len(),
+            ));
+        }
+        if let Some(&idx) = indices.iter().find(|&&idx| idx >= self.data.n) {
+            return Err(SparseUpdateError::IndexOutOfBounds(idx));
+        }
+
+        // always succeeds after the checks above
+        let d = &self.data.equilibration.d;
+        let c = self.data.equilibration.c;
+        zip(indices.iter(), values.iter())
+            .update_vector(&mut self.data.q, d, c)
+            .unwrap();
 
-        // flush unscaled norm. Will be recalculated during solve
+        // flush unscaled norm, and refresh the tolerance scaling
         self.data.clear_normq();
+        self.info.save_data_norms(&mut self.data);
 
         Ok(())
     }
@@ -122,11 +233,38 @@ where
         data: &Data,
     ) -> Result<(), DataUpdateError> {
         self.check_presolve_disabled()?;
+        self.check_no_expanded_cones()?;
         let e = &self.data.equilibration.e;
-        data.update_vector(&mut self.data.b, e)?;
+        data.update_vector(&mut self.data.b, e, T::one())?;
+
+        // flush unscaled norm, and refresh the tolerance scaling
+        self.data.clear_normb();
+        self.info.save_data_norms(&mut self.data);
+
+        Ok(())
+    }
 
-        // flush unscaled norm. Will be recalculated during solve
+    /// Overwrites the `b` vector in the internally equilibrated problem data,
+    /// without applying the equilibration scaling used by [`update_b`](Self::update_b).
+    ///
+    /// __Warning__: `b_scaled` is written directly into the scaled problem, and is
+    /// equivalent to calling `update_b` with `b_scaled` divided elementwise by the
+    /// row scaling `e` of [`equilibration`](Self::equilibration).   Unless that
+    /// scaling is taken into account, the problem solved is not the one given by
+    /// `b_scaled` in the original units.
+    ///
+    /// The input length must always match the number of constraints.
+    pub fn update_b_scaled(&mut self, b_scaled: &[T]) -> Result<(), DataUpdateError> {
+        self.check_presolve_disabled()?;
+        self.check_no_expanded_cones()?;
+        if b_scaled.len() != self.data.b.len() {
+            return Err(SparseFormatError::IncompatibleDimension.into());
+        }
+        self.data.b.copy_from(b_scaled);
+
+        // flush unscaled norm, and refresh the tolerance scaling
         self.data.clear_normb();
+        self.info.save_data_norms(&mut self.data);
 
         Ok(())
     }
@@ -138,6 +276,16 @@ where
             Ok(())
         }
     }
+
+    // complex PSD cone rows are expanded internally,
+    // so A and b data no longer match the user's layout
+    fn check_no_expanded_cones(&self) -> Result<(), DataUpdateError> {
+        if self.data.presolver.is_expanded() {
+            Err(DataUpdateError::ComplexPSDConesPresent)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<T> MatrixProblemDataUpdate<T> for CscMatrix<T>
@@ -149,10 +297,11 @@ where
         M: &mut CscMatrix<T>,
         lscale: &[T],
         rscale: &[T],
+        cscale: T,
     ) -> Result<(), SparseFormatError> {
         self.check_equal_sparsity(M)?;
         let v = &self.nzval;
-        v.update_matrix(M, lscale, rscale)
+        v.update_matrix(M, lscale, rscale, cscale)
     }
 }
 
@@ -165,6 +314,7 @@ where
         M: &mut CscMatrix<T>,
         lscale: &[T],
         rscale: &[T],
+        cscale: T,
     ) -> Result<(), SparseFormatError> {
         let data = self;
         if data.is_empty() {
@@ -179,6 +329,7 @@ where
 
         // reapply original equilibration
         M.lrscale(lscale, rscale);
+        M.scale(cscale);
 
         Ok(())
     }
@@ -190,8 +341,9 @@ impl<T: FloatT> MatrixProblemDataUpdate<T> for Vec<T> {
         M: &mut CscMatrix<T>,
         lscale: &[T],
         rscale: &[T],
+        cscale: T,
     ) -> Result<(), SparseFormatError> {
-        self.as_slice().update_matrix(M, lscale, rscale)
+        self.as_slice().update_matrix(M, lscale, rscale, cscale)
     }
 }
 
@@ -201,6 +353,7 @@ impl<T: FloatT> MatrixProblemDataUpdate<T> for [T; 0] {
         _M: &mut CscMatrix<T>,
         _lscale: &[T],
         _rscale: &[T],
+        _cscale: T,
     ) -> Result<(), SparseFormatError> {
         Ok(())
     }
@@ -219,13 +372,14 @@ where
         M: &mut CscMatrix<T>,
         lscale: &[T],
         rscale: &[T],
+        cscale: T,
     ) -> Result<(), SparseFormatError> {
         for (&idx, &value) in self.clone() {
             if idx >= M.nzval.len() {
                 return Err(SparseFormatError::IncompatibleDimension);
             }
             let (row, col) = M.index_to_coord(idx);
-            M.nzval[idx] = lscale[row] * rscale[col] * value;
+            M.nzval[idx] = lscale[row] * rscale[col] * value * cscale;
         }
         Ok(())
     }
@@ -235,7 +389,7 @@ impl<T> VectorProblemDataUpdate<T> for [T]
 where
     T: FloatT,
 {
-    fn update_vector(&self, v: &mut [T], scale: &[T]) -> Result<(), SparseFormatError> {
+    fn update_vector(&self, v: &mut [T], scale: &[T], cscale: T) -> Result<(), SparseFormatError> {
         let data = self;
         if data.is_empty() {
             return Ok(());
@@ -248,20 +402,25 @@ where
         v.copy_from_slice(data);
 
         //reapply original equilibration
-        v.hadamard(scale);
+        v.hadamard(scale).scale(cscale);
 
         Ok(())
     }
 }
 
 impl<T: FloatT> VectorProblemDataUpdate<T> for Vec<T> {
-    fn update_vector(&self, v: &mut [T], scale: &[T]) -> Result<(), SparseFormatError> {
-        self.as_slice().update_vector(v, scale)
+    fn update_vector(&self, v: &mut [T], scale: &[T], cscale: T) -> Result<(), SparseFormatError> {
+        self.as_slice().update_vector(v, scale, cscale)
     }
 }
 
 impl<T: FloatT> VectorProblemDataUpdate<T> for [T; 0] {
-    fn update_vector(&self, _v: &mut [T], _scale: &[T]) -> Result<(), SparseFormatError> {
+    fn update_vector(
+        &self,
+        _v: &mut [T],
+        _scale: &[T],
+        _cscale: T,
+    ) -> Result<(), SparseFormatError> {
         Ok(())
     }
 }
@@ -274,12 +433,12 @@ impl<'a, T> VectorProblemDataUpdate<T> for Zip<Iter<'a, usize>, Iter<'a, T>>
 where
     T: FloatT,
 {
-    fn update_vector(&self, v: &mut [T], scale: &[T]) -> Result<(), SparseFormatError> {
+    fn update_vector(&self, v: &mut [T], scale: &[T], cscale: T) -> Result<(), SparseFormatError> {
         for (&idx, &value) in self.clone() {
             if idx >= v.len() {
                 return Err(SparseFormatError::IncompatibleDimension);
             }
-            v[idx] = value * scale[idx];
+            v[idx] = value * scale[idx] * cscale;
         }
         Ok(())
     }
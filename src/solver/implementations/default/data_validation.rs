@@ -0,0 +1,39 @@
+#![allow(non_snake_case)]
+use crate::algebra::*;
+use thiserror::Error;
+
+/// Error type returned by [`validate_data`], describing the first
+/// invalid entry found in the problem data.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataError {
+    /// A NaN or infinite value in the field `field`, i.e. one of `"P"`,
+    /// `"q"`, `"A"` or `"b"`.   For `P` and `A`, `index` is the position
+    /// of the value in `nzval`.
+    #[error("Non-finite value in {field} at index {index}")]
+    NonFinite { field: &'static str, index: usize },
+}
+
+/// Checks the problem data `P`, `q`, `A` and `b` for NaN or infinite values.
+///
+/// Entries of `b` equal to `+∞` are allowed, since these mark constraints
+/// with no upper bound that are removed by presolve.   This check is also
+/// made when constructing a solver, which panics if it fails.
+pub fn validate_data<T: FloatT>(
+    P: &CscMatrix<T>,
+    q: &[T],
+    A: &CscMatrix<T>,
+    b: &[T],
+) -> Result<(), DataError> {
+    let fields: [(&'static str, &[T]); 3] = [("P", &P.nzval), ("q", q), ("A", &A.nzval)];
+    for (field, v) in fields {
+        if let Some(index) = v.iter().position(|x| !x.is_finite()) {
+            return Err(DataError::NonFinite { field, index });
+        }
+    }
+
+    if let Some(index) = b.iter().position(|&x| x.is_nan() || x == T::neg_infinity()) {
+        return Err(DataError::NonFinite { field: "b", index });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+#![allow(non_snake_case)]
+use super::*;
+use crate::algebra::*;
+use crate::solver::core::cones::SupportedConeT;
+use std::fmt;
+
+/// Structural issues in the problem data that may indicate a poor
+/// formulation, as returned by [`DefaultSolver::diagnostics`].
+///
+/// Variable indices refer to the columns of `A`.  Constraint indices are
+/// for the constraints as solved, i.e. after any presolve reduction or
+/// expansion of complex PSD cones, and match the user's constraint rows
+/// otherwise.  See [`PresolveReport`] for the mapping between the two.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// variables with an all-zero column in `A` that also do not appear in
+    /// the objective, so that their values are arbitrary
+    pub zero_columns: Vec<usize>,
+    /// zero or nonnegative cone constraints with an all-zero row in `A`
+    /// that hold for any `x`
+    pub redundant_constraints: Vec<usize>,
+    /// variables with an all-zero column in `A` that appear only in the
+    /// objective.  The problem is unbounded if any of these appear only in
+    /// the linear term.
+    pub objective_only: Vec<usize>,
+}
+
+impl Diagnostics {
+    /// True if no issues were found.
+    pub fn is_empty(&self) -> bool {
+        self.zero_columns.is_empty()
+            && self.redundant_constraints.is_empty()
+            && self.objective_only.is_empty()
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.zero_columns.is_empty() {
+            writeln!(
+                f,
+                "variables {:?} do not appear in the constraints or objective",
+                self.zero_columns
+            )?;
+        }
+        if !self.redundant_constraints.is_empty() {
+            writeln!(
+                f,
+                "constraints {:?} have no variables and are redundant",
+                self.redundant_constraints
+            )?;
+        }
+        if !self.objective_only.is_empty() {
+            writeln!(
+                f,
+                "variables {:?} appear only in the objective and are free; \
+                 consider eliminating them or adding bounds",
+                self.objective_only
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> DefaultSolver<T>
+where
+    T: FloatT,
+{
+    /// Checks the problem data for structural issues, i.e. variables that
+    /// are not constrained and constraints that involve no variables.
+    ///
+    /// None of these prevent the solver from running, but free variables in
+    /// particular can make the dual problem unbounded.  See [`Diagnostics`]
+    /// for the issues reported.  Explicitly stored zeros in `P` and `A` are
+    /// ignored.
+    pub fn diagnostics(&self) -> Diagnostics {
+        let data = &self.data;
+        let (P, A) = (&data.P, &data.A);
+
+        // nonzero counts for each row and column of A
+        let mut row_nnz = vec![0usize; data.m];
+        let mut col_nnz = vec![0usize; data.n];
+        for (col, nnz) in col_nnz.iter_mut().enumerate() {
+            for k in A.colptr[col]..A.colptr[col + 1] {
+                if A.nzval[k] != T::zero() {
+                    row_nnz[A.rowval[k]] += 1;
+                    *nnz += 1;
+                }
+            }
+        }
+
+        // variables with a nonzero in P.  P is triu, so a variable
+        // may appear only as a row index of another column
+        let mut in_P = vec![false; data.n];
+        for col in 0..P.n {
+            for k in P.colptr[col]..P.colptr[col + 1] {
+                if P.nzval[k] != T::zero() {
+                    in_P[col] = true;
+                    in_P[P.rowval[k]] = true;
+                }
+            }
+        }
+
+        let mut out = Diagnostics::default();
+
+        for (j, &nnz) in col_nnz.iter().enumerate() {
+            if nnz > 0 {
+                continue;
+            }
+            if in_P[j] || data.q[j] != T::zero() {
+                out.objective_only.push(j);
+            } else {
+                out.zero_columns.push(j);
+            }
+        }
+
+        // zero rows are redundant if b satisfies the cone constraint
+        let mut start = 0;
+        for cone in data.presolver.cone_specs.iter() {
+            let rng = start..start + cone.nvars();
+            start = rng.end;
+
+            let is_redundant: fn(T) -> bool = match cone {
+                SupportedConeT::ZeroConeT(_) => |b| b == T::zero(),
+                SupportedConeT::NonnegativeConeT(_) => |b| b >= T::zero(),
+                _ => continue,
+            };
+            for i in rng {
+                if row_nnz[i] == 0 && is_redundant(data.b[i]) {
+                    out.redundant_constraints.push(i);
+                }
+            }
+        }
+
+        out
+    }
+}
@@ -7,6 +7,7 @@ use crate::algebra::*;
 
 /// Data from the Ruiz equilibration procedure
 
+#[derive(Clone)]
 pub struct DefaultEquilibrationData<T> {
     // scaling matrices for problem data equilibration
     // fields d,e,dinv,einv are vectors of scaling values
@@ -18,6 +19,12 @@ pub struct DefaultEquilibrationData<T> {
 
     // overall scaling for objective function
     pub c: T,
+
+    // number of equilibration iterations performed, and the ratio of
+    // the largest to smallest KKT column measure after equilibration.
+    // The ratio is NaN if equilibration is disabled
+    pub iterations: u32,
+    pub ratio: T,
 }
 
 impl<T> DefaultEquilibrationData<T>
@@ -39,6 +46,44 @@ where
             e,
             einv,
             c,
+            iterations: 0,
+            ratio: T::nan(),
+        }
+    }
+}
+
+/// Borrowed view of the equilibration scaling applied to the problem data.
+///
+/// The problem solved internally has data `c*D*P*D`, `c*D*q`, `E*A*D` and `E*b`,
+/// where `D = diag(d)` and `E = diag(e)`.   If presolve has eliminated
+/// constraints, then the row scalings `e` and `einv` apply only to the
+/// rows that remain.
+
+#[derive(Debug, Clone, Copy)]
+pub struct EquilibrationView<'a, T> {
+    /// column scaling of the problem data
+    pub d: &'a [T],
+    /// inverse of `d`
+    pub dinv: &'a [T],
+    /// row scaling of the constraint data
+    pub e: &'a [T],
+    /// inverse of `e`
+    pub einv: &'a [T],
+    /// overall scaling of the objective
+    pub c: T,
+}
+
+impl<'a, T> From<&'a DefaultEquilibrationData<T>> for EquilibrationView<'a, T>
+where
+    T: FloatT,
+{
+    fn from(equil: &'a DefaultEquilibrationData<T>) -> Self {
+        Self {
+            d: &equil.d,
+            dinv: &equil.dinv,
+            e: &equil.e,
+            einv: &equil.einv,
+            c: equil.c,
         }
     }
 }
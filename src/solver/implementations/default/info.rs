@@ -1,8 +1,13 @@
 use super::*;
 use crate::algebra::*;
-use crate::solver::core::{traits::Info, SolverStatus};
+use crate::solver::core::{
+    cones::{SupportedConeAsTag, SupportedConeTag},
+    traits::Info,
+    SolverStatus,
+};
 use crate::solver::traits::Variables;
 use crate::timers::*;
+use std::iter::zip;
 
 /// Standard-form solver type implementing the [`Info`](crate::solver::core::traits::Info) and [`InfoPrint`](crate::solver::core::traits::InfoPrint) traits
 
@@ -12,6 +17,10 @@ pub struct DefaultInfo<T> {
     pub μ: T,
     pub sigma: T,
     pub step_length: T,
+    // step lengths allowed by the primal and dual cone
+    // constraints, the smaller of which is taken
+    pub step_length_primal: T,
+    pub step_length_dual: T,
     pub iterations: u32,
     pub cost_primal: T,
     pub cost_dual: T,
@@ -23,6 +32,9 @@ pub struct DefaultInfo<T> {
     pub gap_rel: T,
     pub ktratio: T,
 
+    // primal and dual residuals by cone at the most recent iterate
+    cone_residuals: Vec<(SupportedConeTag, T, T)>,
+
     // previous iterate
     prev_cost_primal: T,
     prev_cost_dual: T,
@@ -30,9 +42,74 @@ pub struct DefaultInfo<T> {
     prev_res_dual: T,
     prev_gap_abs: T,
     prev_gap_rel: T,
+    prev_cone_residuals: Vec<(SupportedConeTag, T, T)>,
 
     pub solve_time: f64,
     pub status: SolverStatus,
+
+    // time so far, kept for the time limit even when
+    // collect_timings is false and solve_time is zero
+    elapsed_time: f64,
+
+    // iterative refinement over the whole solve
+    pub total_refinement_iters: usize,
+    pub final_refinement_residual: T,
+
+    // KKT factorization retries with increased regularization
+    pub factorization_retries: usize,
+
+    // equilibration iterations performed during setup, and the ratio
+    // of the largest to smallest KKT column measure afterwards
+    pub equilibration_iters: u32,
+    pub equilibration_ratio: T,
+
+    // max(‖q‖∞, ‖b‖∞) of the unscaled data for normalized
+    // tolerances, fixed at setup or when q or b is updated
+    norm_qb: T,
+
+    // consecutive iterations with insufficient decrease in the residuals
+    stalled_iters: u32,
+
+    // one entry per iteration, populated only if enabled in the settings
+    pub iteration_records: Vec<IterationRecord<T>>,
+
+    // μ at the initial point and each iteration, populated
+    // only if enabled in the settings
+    pub mu_history: Vec<T>,
+}
+
+/// Progress of the solver at a single iteration, with the values shown
+/// in a row of the iteration table printed when `verbose` is set.
+/// Collected in [`DefaultInfo`] when the `collect_iteration_records`
+/// setting is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IterationRecord<T> {
+    /// Iteration count
+    pub iteration: u32,
+    /// Primal objective value
+    pub cost_primal: T,
+    /// Dual objective value
+    pub cost_dual: T,
+    /// Absolute duality gap
+    pub gap_abs: T,
+    /// Relative duality gap
+    pub gap_rel: T,
+    /// Primal residual
+    pub res_primal: T,
+    /// Dual residual
+    pub res_dual: T,
+    /// Ratio κ/τ of the homogeneous embedding
+    pub ktratio: T,
+    /// Normalized complementarity
+    pub mu: T,
+    /// Length of the step taken to reach this iterate
+    pub step_length: T,
+    /// Largest step length allowed by the primal cone constraints,
+    /// scaled by `max_step_fraction`
+    pub step_length_primal: T,
+    /// Largest step length allowed by the dual cone constraints,
+    /// scaled by `max_step_fraction`
+    pub step_length_dual: T,
 }
 
 impl<T> DefaultInfo<T>
@@ -40,7 +117,107 @@ where
     T: FloatT,
 {
     pub fn new() -> Self {
-        Self::default()
+        let mut info = Self::default();
+        info.clear_current();
+        info
+    }
+
+    /// Primal and dual objective values at the most recent iterate.
+    /// Both values are NaN before the first iteration.
+    pub fn current_objective(&self) -> (T, T) {
+        (self.cost_primal, self.cost_dual)
+    }
+
+    /// Primal and dual relative residuals at the most recent iterate.
+    /// Both values are NaN before the first iteration.
+    pub fn current_residuals(&self) -> (T, T) {
+        (self.res_primal, self.res_dual)
+    }
+
+    /// Breakdown of the primal and dual residuals by cone at the most
+    /// recent iterate.  Empty before the first iteration.
+    ///
+    /// Returns the type of each cone with its contributions to
+    /// [`res_primal`](Self::res_primal) and [`res_dual`](Self::res_dual).  The
+    /// primal contributions are the residuals of the cone's rows, and
+    /// combine in the 2-norm to `res_primal`.   The dual residual is defined
+    /// over the variables rather than the constraint rows, so the dual
+    /// contribution of a cone is the (signed) component of its term `Aᵢᵀzᵢ`
+    /// along the dual residual.  These sum to `res_dual` together with the
+    /// component of the `Px + q` term, which belongs to no cone.
+    ///
+    /// Cones are those of the problem as solved, i.e. after any presolve
+    /// reduction or expansion of complex PSD cones into real PSD cones.
+    pub fn per_cone_residuals(&self) -> Vec<(SupportedConeTag, T, T)> {
+        self.cone_residuals.clone()
+    }
+
+    pub(crate) fn save_equilibration(&mut self, equil: &DefaultEquilibrationData<T>) {
+        self.equilibration_iters = equil.iterations;
+        self.equilibration_ratio = equil.ratio;
+    }
+
+    pub(crate) fn save_data_norms(&mut self, data: &mut DefaultProblemData<T>) {
+        self.norm_qb = T::max(data.get_normq(), data.get_normb());
+    }
+
+    // no iterate has been evaluated yet
+    fn clear_current(&mut self) {
+        self.cost_primal = T::nan();
+        self.cost_dual = T::nan();
+        self.res_primal = T::nan();
+        self.res_dual = T::nan();
+        self.cone_residuals.clear();
+    }
+
+    // contributions of each cone to the primal and dual residuals,
+    // given the normalizations applied to the norms of rz and rx
+    fn update_cone_residuals(
+        &mut self,
+        data: &DefaultProblemData<T>,
+        variables: &DefaultVariables<T>,
+        residuals: &DefaultResiduals<T>,
+        scale_primal: T,
+        scale_dual: T,
+    ) {
+        let dinv = &data.equilibration.dinv;
+        let einv = &data.equilibration.einv;
+        let specs = &data.presolver.cone_specs;
+
+        // first row of each cone
+        let mut offsets = Vec::with_capacity(specs.len() + 1);
+        offsets.push(0);
+        for cone in specs {
+            offsets.push(offsets.last().unwrap() + cone.nvars());
+        }
+
+        self.cone_residuals.clear();
+        for (i, cone) in specs.iter().enumerate() {
+            let rng = offsets[i]..offsets[i + 1];
+            let rz = &residuals.rz[rng.clone()];
+            let res_primal = rz.norm_scaled(&einv[rng]) * scale_primal;
+            self.cone_residuals
+                .push((cone.as_tag(), res_primal, T::zero()));
+        }
+
+        // rx includes the term -A'z, so the component of the cone term
+        // Aᵢᵀzᵢ along rx is -⟨rx, Aᵢᵀzᵢ⟩/‖rx‖ in the unscaled norm
+        let normrx = residuals.rx.norm_scaled(dinv);
+        if normrx == T::zero() {
+            return;
+        }
+        let A = &data.A;
+        for (col, (&d, &r)) in zip(dinv, &residuals.rx).enumerate() {
+            let w = d * d * r;
+            for k in A.colptr[col]..A.colptr[col + 1] {
+                let row = A.rowval[k];
+                let i = offsets.partition_point(|&o| o <= row) - 1;
+                self.cone_residuals[i].2 -= w * A.nzval[k] * variables.z[row];
+            }
+        }
+        for res in self.cone_residuals.iter_mut() {
+            res.2 *= scale_dual / normrx;
+        }
     }
 }
 
@@ -55,6 +232,16 @@ where
         self.status = SolverStatus::Unsolved;
         self.iterations = 0;
         self.solve_time = 0f64;
+        self.elapsed_time = 0f64;
+        self.total_refinement_iters = 0;
+        self.final_refinement_residual = T::nan();
+        self.factorization_retries = 0;
+        self.stalled_iters = 0;
+        self.step_length_primal = T::zero();
+        self.step_length_dual = T::zero();
+        self.iteration_records.clear();
+        self.mu_history.clear();
+        self.clear_current();
 
         timers.reset_timer("solve");
     }
@@ -126,10 +313,11 @@ where
         norms *= τinv;
 
         // primal and dual relative residuals.
-        self.res_primal =
-            residuals.rz.norm_scaled(einv) * τinv / T::max(T::one(), normb + normx + norms);
-        self.res_dual =
-            residuals.rx.norm_scaled(dinv) * τinv / T::max(T::one(), normq + normx + normz);
+        let scale_primal = τinv / T::max(T::one(), normb + normx + norms);
+        let scale_dual = τinv / T::max(T::one(), normq + normx + normz);
+        self.res_primal = residuals.rz.norm_scaled(einv) * scale_primal;
+        self.res_dual = residuals.rx.norm_scaled(dinv) * scale_dual;
+        self.update_cone_residuals(data, variables, residuals, scale_primal, scale_dual);
 
         // absolute and relative gaps
         self.gap_abs = T::abs(self.cost_primal - self.cost_dual);
@@ -142,8 +330,11 @@ where
         // κ/τ
         self.ktratio = variables.κ / variables.τ;
 
-        // solve time so far (includes setup)
+        // solve time so far (includes setup).  The time limit is
+        // checked against the elapsed time, which is kept even
+        // when timings are not reported
         self.solve_time = timers.total_time().as_secs_f64();
+        self.elapsed_time = timers.elapsed_time().as_secs_f64();
     }
 
     fn check_termination(
@@ -164,7 +355,7 @@ where
         {
             // Poor progress at high tolerance.
             if self.ktratio < T::epsilon() * (100.).as_T()
-                && (self.prev_gap_abs < settings.tol_gap_abs
+                && (self.prev_gap_abs < settings.tol_gap_abs * self.abs_tol_scale(settings)
                     || self.prev_gap_rel < settings.tol_gap_rel)
             {
                 self.status = SolverStatus::InsufficientProgress;
@@ -180,12 +371,18 @@ where
             }
         }
 
+        // stalled residuals over several iterations
+        // ----------------------
+        if self.status == SolverStatus::Unsolved && iter > 1u32 {
+            self.check_stalled(settings);
+        }
+
         // time or iteration limits
         // ----------------------
         if self.status == SolverStatus::Unsolved {
             if settings.max_iter == self.iterations {
                 self.status = SolverStatus::MaxIterations;
-            } else if self.solve_time > settings.time_limit {
+            } else if self.elapsed_time > settings.time_limit {
                 self.status = SolverStatus::MaxTime;
             }
         }
@@ -201,6 +398,7 @@ where
         self.prev_res_dual = self.res_dual;
         self.prev_gap_abs = self.gap_abs;
         self.prev_gap_rel = self.gap_rel;
+        self.prev_cone_residuals.clone_from(&self.cone_residuals);
 
         prev_variables.copy_from(variables);
     }
@@ -212,6 +410,8 @@ where
         self.res_dual = self.prev_res_dual;
         self.gap_abs = self.prev_gap_abs;
         self.gap_rel = self.prev_gap_rel;
+        self.cone_residuals.clone_from(&self.prev_cone_residuals);
+        self.stalled_iters = 0;
 
         variables.copy_from(prev_variables);
     }
@@ -223,6 +423,55 @@ where
         self.iterations = iter;
     }
 
+    fn save_step_lengths(&mut self, α_primal: T, α_dual: T) {
+        self.step_length_primal = α_primal;
+        self.step_length_dual = α_dual;
+    }
+
+    fn save_refinement_stats(&mut self, iters: usize, residual: T) {
+        self.total_refinement_iters = iters;
+        self.final_refinement_residual = residual;
+    }
+
+    fn save_iteration_record(&mut self, settings: &DefaultSettings<T>) {
+        if settings.collect_mu_history {
+            // an iteration is repeated if the scaling strategy changes
+            self.mu_history.truncate(self.iterations as usize);
+            self.mu_history.push(self.μ);
+        }
+
+        // the initial point is not an iteration
+        if !settings.collect_iteration_records || self.iterations == 0 {
+            return;
+        }
+
+        let record = IterationRecord {
+            iteration: self.iterations,
+            cost_primal: self.cost_primal,
+            cost_dual: self.cost_dual,
+            gap_abs: self.gap_abs,
+            gap_rel: self.gap_rel,
+            res_primal: self.res_primal,
+            res_dual: self.res_dual,
+            ktratio: self.ktratio,
+            mu: self.μ,
+            step_length: self.step_length,
+            step_length_primal: self.step_length_primal,
+            step_length_dual: self.step_length_dual,
+        };
+
+        // an iteration is repeated if the scaling strategy
+        // changes, in which case the later values are kept
+        match self.iteration_records.last_mut() {
+            Some(last) if last.iteration == record.iteration => *last = record,
+            _ => self.iteration_records.push(record),
+        }
+    }
+
+    fn save_factorization_retries(&mut self, retries: usize) {
+        self.factorization_retries = retries;
+    }
+
     fn get_status(&self) -> SolverStatus {
         self.status
     }
@@ -244,10 +493,11 @@ where
         settings: &DefaultSettings<T>,
     ) {
         // "full" tolerances
-        let tol_gap_abs = settings.tol_gap_abs;
+        let scale = self.abs_tol_scale(settings);
+        let tol_gap_abs = settings.tol_gap_abs * scale;
         let tol_gap_rel = settings.tol_gap_rel;
         let tol_feas = settings.tol_feas;
-        let tol_infeas_abs = settings.tol_infeas_abs;
+        let tol_infeas_abs = settings.tol_infeas_abs * scale;
         let tol_infeas_rel = settings.tol_infeas_rel;
         let tol_ktratio = settings.tol_ktratio;
 
@@ -267,6 +517,23 @@ where
             pinf_status,
             dinf_status,
         );
+
+        match settings.termination_mode.as_str() {
+            "standard" => {}
+            "gap_priority" => {
+                // accept a small relative gap at reduced feasibility,
+                // which is reported as a reduced accuracy solution
+                if self.status == SolverStatus::Unsolved
+                    && self.ktratio <= T::one()
+                    && self.gap_rel < tol_gap_rel
+                    && self.res_primal < settings.reduced_tol_feas
+                    && self.res_dual < settings.reduced_tol_feas
+                {
+                    self.status = SolverStatus::AlmostSolved;
+                }
+            }
+            _ => panic!("Unrecognized termination mode"),
+        }
     }
 
     fn check_convergence_almost(
@@ -275,10 +542,11 @@ where
         settings: &DefaultSettings<T>,
     ) {
         // "almost" tolerances
-        let tol_gap_abs = settings.reduced_tol_gap_abs;
+        let scale = self.abs_tol_scale(settings);
+        let tol_gap_abs = settings.reduced_tol_gap_abs * scale;
         let tol_gap_rel = settings.reduced_tol_gap_rel;
         let tol_feas = settings.reduced_tol_feas;
-        let tol_infeas_abs = settings.reduced_tol_infeas_abs;
+        let tol_infeas_abs = settings.reduced_tol_infeas_abs * scale;
         let tol_infeas_rel = settings.reduced_tol_infeas_rel;
         let tol_ktratio = settings.reduced_tol_ktratio;
 
@@ -326,6 +594,36 @@ where
         }
     }
 
+    fn check_stalled(&mut self, settings: &DefaultSettings<T>) {
+        let window = settings.insufficient_progress_window;
+        if window == 0 {
+            return;
+        }
+
+        let res = T::max(self.res_primal, self.res_dual);
+        let prev_res = T::max(self.prev_res_primal, self.prev_res_dual);
+        if res > prev_res * (T::one() - settings.insufficient_progress_tol) {
+            self.stalled_iters += 1;
+        } else {
+            self.stalled_iters = 0;
+        }
+
+        if self.stalled_iters >= window {
+            self.status = SolverStatus::InsufficientProgress;
+        }
+    }
+
+    // scaling applied to the absolute gap and infeasibility tolerances.
+    // The feasibility residuals are already relative to the data norms,
+    // so tol_feas is left as it is
+    fn abs_tol_scale(&self, settings: &DefaultSettings<T>) -> T {
+        if settings.tol_normalize {
+            T::max(T::one(), self.norm_qb)
+        } else {
+            T::one()
+        }
+    }
+
     fn is_solved(&self, tol_gap_abs: T, tol_gap_rel: T, tol_feas: T) -> bool {
         ((self.gap_abs < tol_gap_abs) || (self.gap_rel < tol_gap_rel))
             && (self.res_primal < tol_feas)
@@ -63,6 +63,7 @@ where
         _print_conedims_by_type(cones, SupportedConeTag::ExponentialCone)?;
         _print_conedims_by_type(cones, SupportedConeTag::PowerCone)?;
         _print_conedims_by_type(cones, SupportedConeTag::GenPowerCone)?;
+        _print_conedims_by_type(cones, SupportedConeTag::BoxCone)?;
         #[cfg(feature = "sdp")]
         _print_conedims_by_type(cones, SupportedConeTag::PSDTriangleCone)?;
 
@@ -103,27 +104,12 @@ where
             return std::io::Result::Ok(());
         }
 
-        let mut out = stdio::stdout();
-
-        write!(out, "{:>3}  ", self.iterations)?;
-        write!(out, "{}  ", expformat!("{:+8.4e}", self.cost_primal))?;
-        write!(out, "{}  ", expformat!("{:+8.4e}", self.cost_dual))?;
-        let gapprint = T::min(self.gap_abs, self.gap_rel);
-        write!(out, "{}  ", expformat!("{:6.2e}", gapprint))?;
-        write!(out, "{}  ", expformat!("{:6.2e}", self.res_primal))?;
-        write!(out, "{}  ", expformat!("{:6.2e}", self.res_dual))?;
-        write!(out, "{}  ", expformat!("{:6.2e}", self.ktratio))?;
-        write!(out, "{}  ", expformat!("{:6.2e}", self.μ))?;
-
-        if self.iterations > 0 {
-            write!(out, "{}  ", expformat!("{:>.2e}", self.step_length))?;
-        } else {
-            write!(out, " ------   ")?; //info.step_length
+        // skipped rows other than the first are caught up by the footer
+        if self.iterations % _print_stride(settings) != 0 {
+            return std::io::Result::Ok(());
         }
 
-        writeln!(out, )?;
-
-        std::io::Result::Ok(())
+        self.print_status_row()
     }
 
     fn print_footer(&self, settings: &DefaultSettings<T>) -> std::io::Result<()> {
@@ -131,6 +117,11 @@ where
             return std::io::Result::Ok(());
         }
 
+        // the final iteration, if it was skipped by print_status
+        if self.iterations % _print_stride(settings) != 0 {
+            self.print_status_row()?;
+        }
+
         let mut out = stdio::stdout();
 
         writeln!(out, 
@@ -148,6 +139,40 @@ where
     }
 }
 
+impl<T> DefaultInfo<T>
+where
+    T: FloatT,
+{
+    // one row of the iteration table
+    fn print_status_row(&self) -> std::io::Result<()> {
+        let mut out = stdio::stdout();
+
+        write!(out, "{:>3}  ", self.iterations)?;
+        write!(out, "{}  ", expformat!("{:+8.4e}", self.cost_primal))?;
+        write!(out, "{}  ", expformat!("{:+8.4e}", self.cost_dual))?;
+        let gapprint = T::min(self.gap_abs, self.gap_rel);
+        write!(out, "{}  ", expformat!("{:6.2e}", gapprint))?;
+        write!(out, "{}  ", expformat!("{:6.2e}", self.res_primal))?;
+        write!(out, "{}  ", expformat!("{:6.2e}", self.res_dual))?;
+        write!(out, "{}  ", expformat!("{:6.2e}", self.ktratio))?;
+        write!(out, "{}  ", expformat!("{:6.2e}", self.μ))?;
+
+        if self.iterations > 0 {
+            write!(out, "{}  ", expformat!("{:>.2e}", self.step_length))?;
+        } else {
+            write!(out, " ------   ")?; //info.step_length
+        }
+
+        writeln!(out, )?;
+
+        std::io::Result::Ok(())
+    }
+}
+
+fn _print_stride<T: FloatT>(settings: &DefaultSettings<T>) -> u32 {
+    u32::max(settings.print_stride, 1)
+}
+
 fn _bool_on_off(v: bool) -> &'static str {
     match v {
         true => "on",
@@ -167,11 +192,19 @@ fn _print_settings<T: FloatT>(settings: &DefaultSettings<T>) -> std::io::Result<
             set.direct_solve_method,
             _get_precision_string::<T>()
         )?;
+    } else {
+        writeln!(out, 
+            "  linear algebra: indirect / minres ({} stopping), precision: {} bit",
+            set.indirect_stop_metric,
+            _get_precision_string::<T>()
+        )?;
     }
 
     let time_lim_str = {
         if set.time_limit.is_infinite() {
             "Inf".to_string()
+        } else if set.time_limit_hard {
+            format!("{:?} (hard)", set.time_limit)
         } else {
             format!("{:?}", set.time_limit)
         }
@@ -219,7 +252,10 @@ fn _print_settings<T: FloatT>(settings: &DefaultSettings<T>) -> std::io::Result<
         set.equilibrate_max_scaling
     )?;
 
-    writeln!(out, "               max iter = {}", set.equilibrate_max_iter,)?;
+    writeln!(out, 
+        "               max iter = {}, method = {}",
+        set.equilibrate_max_iter, set.equilibration_method,
+    )?;
 
     std::io::Result::Ok(())
 }
@@ -0,0 +1,195 @@
+#![allow(non_snake_case)]
+use super::*;
+use crate::algebra::*;
+use crate::solver::core::cones::SupportedConeT;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use thiserror::Error;
+
+// Serialized form of a problem.   The problem data is written
+// after presolve, but with equilibration scaling removed, so that
+// it describes a problem equivalent to the one originally supplied.
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "T: Serialize + DeserializeOwned")]
+struct JsonProblemData<T: FloatT> {
+    pub P: CscMatrix<T>,
+    pub q: Vec<T>,
+    pub A: CscMatrix<T>,
+    pub b: Vec<T>,
+    pub cones: Vec<SupportedConeT<T>>,
+    pub settings: DefaultSettings<T>,
+    // not present in files from older versions
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+/// Read and write problem data in JSON format, e.g. to share reproducers.
+pub trait SolverJSONReadWrite<T>: Sized
+where
+    T: FloatT,
+{
+    /// Write the problem data, settings and any metadata to a file
+    fn write_to_file(&self, file: &mut File) -> Result<(), std::io::Error>;
+
+    /// Read a problem from a file and construct a new solver.   Settings
+    /// saved in the file are used unless `settings` is supplied.
+    fn read_from_file(
+        file: &mut File,
+        settings: Option<DefaultSettings<T>>,
+    ) -> Result<Self, std::io::Error>;
+}
+
+impl<T> SolverJSONReadWrite<T> for DefaultSolver<T>
+where
+    T: FloatT + Serialize + DeserializeOwned,
+{
+    fn write_to_file(&self, file: &mut File) -> Result<(), std::io::Error> {
+        let data = &self.data;
+        let equil = &data.equilibration;
+        let (dinv, einv) = (&equil.dinv, &equil.einv);
+        let cinv = T::recip(equil.c);
+
+        // undo the equilibration scaling
+        let mut P = data.P.clone();
+        let mut q = data.q.clone();
+        let mut A = data.A.clone();
+        let mut b = data.b.clone();
+
+        P.lrscale(dinv, dinv);
+        P.scale(cinv);
+        q.hadamard(dinv).scale(cinv);
+        A.lrscale(einv, dinv);
+        b.hadamard(einv);
+
+        let settings = _settings_to_json_form(&self.settings);
+
+        let json_data = JsonProblemData {
+            P,
+            q,
+            A,
+            b,
+            cones: data.presolver.cone_specs.clone(),
+            settings,
+            metadata: data.metadata.clone(),
+        };
+
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, &json_data)?;
+        writer.flush()
+    }
+
+    fn read_from_file(
+        file: &mut File,
+        settings: Option<DefaultSettings<T>>,
+    ) -> Result<Self, std::io::Error> {
+        let reader = BufReader::new(file);
+        let mut json_data: JsonProblemData<T> = serde_json::from_reader(reader)?;
+
+        let settings =
+            settings.unwrap_or_else(|| _settings_from_json_form(json_data.settings.clone()));
+
+        let mut solver = DefaultSolver::new(
+            &json_data.P,
+            &json_data.q,
+            &json_data.A,
+            &json_data.b,
+            &json_data.cones,
+            settings,
+        );
+        std::mem::swap(&mut solver.data.metadata, &mut json_data.metadata);
+
+        Ok(solver)
+    }
+}
+
+/// Error type returned by [`DefaultSettings::from_json`].
+#[derive(Error, Debug)]
+pub enum SettingsError {
+    #[error("Error parsing settings JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl<T> DefaultSettings<T>
+where
+    T: FloatT + Serialize + DeserializeOwned,
+{
+    /// Serialize the settings to a JSON string, e.g. to reuse a set
+    /// of tuned settings across problems.
+    pub fn to_json(&self) -> String {
+        let settings = _settings_to_json_form(self);
+        serde_json::to_string(&settings).unwrap()
+    }
+
+    /// Read settings from a JSON string, as produced by [`to_json`](Self::to_json).
+    ///
+    /// Unrecognized fields are ignored, and any missing fields take their
+    /// default values, so that settings saved by other versions can be read.
+    pub fn from_json(s: &str) -> Result<Self, SettingsError> {
+        let settings: DefaultSettings<T> = serde_json::from_str(s)?;
+        Ok(_settings_from_json_form(settings))
+    }
+}
+
+// JSON has no infinite values, so an unlimited time is stored as f64::MAX
+fn _settings_to_json_form<T: FloatT>(settings: &DefaultSettings<T>) -> DefaultSettings<T> {
+    let mut settings = settings.clone();
+    if settings.time_limit == f64::INFINITY {
+        settings.time_limit = f64::MAX;
+    }
+    settings
+}
+
+fn _settings_from_json_form<T: FloatT>(mut settings: DefaultSettings<T>) -> DefaultSettings<T> {
+    if settings.time_limit == f64::MAX {
+        settings.time_limit = f64::INFINITY;
+    }
+    settings
+}
+
+/// State of the KKT system when a solve stops with
+/// [`NumericalError`](crate::solver::SolverStatus::NumericalError), as written
+/// by the `dump_on_failure` setting.  Values are stored in double precision.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KKTDump {
+    /// The upper triangular part of the assembled KKT matrix
+    pub kkt: CscMatrix<f64>,
+    /// The right hand side `[x; z]` of the failed KKT solve, or `None` if
+    /// the failure was elsewhere, e.g. in the factorization of the KKT matrix
+    pub rhs: Option<Vec<f64>>,
+    /// The cone scaling blocks, packed as for the KKT assembly
+    pub Hs: Vec<f64>,
+}
+
+impl KKTDump {
+    pub(crate) fn new<T: FloatT>(kkt: &CscMatrix<T>, rhs: Option<&[T]>, Hs: &[T]) -> Self {
+        let to_f64 = |v: &[T]| v.iter().map(|x| x.to_f64().unwrap()).collect::<Vec<f64>>();
+        let kkt = CscMatrix::new(
+            kkt.m,
+            kkt.n,
+            kkt.colptr.clone(),
+            kkt.rowval.clone(),
+            to_f64(&kkt.nzval),
+        );
+        Self {
+            kkt,
+            rhs: rhs.map(to_f64),
+            Hs: to_f64(Hs),
+        }
+    }
+
+    /// Write the KKT state to a file
+    pub fn write_to_file(&self, file: &mut File) -> Result<(), std::io::Error> {
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()
+    }
+
+    /// Read the KKT state from a file written by [`write_to_file`](Self::write_to_file)
+    pub fn read_from_file(file: &mut File) -> Result<Self, std::io::Error> {
+        let reader = BufReader::new(file);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
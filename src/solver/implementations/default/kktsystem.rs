@@ -1,17 +1,36 @@
 use super::*;
 use crate::solver::core::{
-    cones::{CompositeCone, Cone},
-    kktsolvers::{direct::*, *},
+    cones::{CompositeCone, Cone, SupportedConeT},
+    kktsolvers::{direct::ldlsolvers::dense::DenseDirectLDLSolver, direct::*, indirect::*, *},
     traits::{KKTSystem, Settings},
-    StepDirection,
+    CoreSettings, StepDirection,
 };
 
 use crate::algebra::*;
+use crate::stdio;
+use std::io::Write;
+use std::iter::zip;
 
 // We require Send here to allow pyo3 builds to share
 // solver objects between threads.
 
-type BoxedKKTSolver<T> = Box<dyn KKTSolver<T> + Send>;
+pub type BoxedKKTSolver<T> = Box<dyn KKTSolver<T> + Send>;
+
+/// Constructor for a user supplied [`KKTSolver`], for use with
+/// [`DefaultSolver::new_with_kkt_solver`](crate::solver::DefaultSolver::new_with_kkt_solver).
+///
+/// The constructor receives the problem data `P` (upper triangular) and `A`
+/// after equilibration and presolve, the problem cones and the solver
+/// settings.   The KKT matrix to be solved has the sparsity pattern of
+/// `[P+εI A'; A -Hs]`, with `Hs` the block diagonal cone scaling terms.
+pub type KKTSolverFactory<T> = Box<
+    dyn FnOnce(
+        &CscMatrix<T>,
+        &CscMatrix<T>,
+        &CompositeCone<T>,
+        &CoreSettings<T>,
+    ) -> BoxedKKTSolver<T>,
+>;
 
 /// Standard-form solver type implementing the [`KKTSystem`](crate::solver::core::traits::KKTSystem) trait
 
@@ -30,6 +49,10 @@ pub struct DefaultKKTSystem<T> {
     workx: Vec<T>,
     workz: Vec<T>,
     work_conic: Vec<T>,
+
+    // right hand side [x; z] of a failed linear solve since
+    // the last update, kept for the dump_on_failure setting
+    failed_rhs: Option<Vec<T>>,
 }
 
 impl<T> DefaultKKTSystem<T>
@@ -40,14 +63,52 @@ where
         data: &DefaultProblemData<T>,
         cones: &CompositeCone<T>,
         settings: &DefaultSettings<T>,
+    ) -> Self {
+        Self::new_reusing_ordering(data, cones, settings, None)
+    }
+
+    // as for `new`, but reuses the fill-reducing ordering of a previous
+    // system's KKT matrix if the new KKT matrix has the same sparsity
+    pub(crate) fn new_reusing_ordering(
+        data: &DefaultProblemData<T>,
+        cones: &CompositeCone<T>,
+        settings: &DefaultSettings<T>,
+        previous: Option<&Self>,
     ) -> Self {
         let (m, n) = (data.m, data.n);
 
         //here we allow scope for different KKT solvers, e.g.
         //direct vs indirect, different QR based direct methods
-        //etc.   For now, we only have direct / LDL based
-        let kktsolver = if settings.direct_kkt_solver {
-            Box::new(DirectLDLKKTSolver::<T>::new(
+        //etc.   For now, we have direct / LDL based and indirect
+        //MINRES based solvers
+        let kktsolver: BoxedKKTSolver<T> = if settings.direct_kkt_solver {
+            let previous = previous.and_then(|kkt| {
+                let K = kkt.kktsolver.kkt_matrix();
+                kkt.kktsolver.ordering().map(|perm| (K, perm))
+            });
+            if _use_reduced_kkt(cones, settings) {
+                Box::new(ReducedLDLKKTSolver::<T>::new(
+                    &data.P,
+                    &data.A,
+                    cones,
+                    m,
+                    n,
+                    settings.core(),
+                    previous,
+                ))
+            } else {
+                Box::new(DirectLDLKKTSolver::<T>::new_with_ordering(
+                    &data.P,
+                    &data.A,
+                    cones,
+                    m,
+                    n,
+                    settings.core(),
+                    previous,
+                ))
+            }
+        } else {
+            Box::new(IndirectMINRESKKTSolver::<T>::new(
                 &data.P,
                 &data.A,
                 cones,
@@ -55,10 +116,21 @@ where
                 n,
                 settings.core(),
             ))
-        } else {
-            panic!("Indirect and other solve strategies not yet supported.");
         };
 
+        Self::new_with_kkt_solver(data, kktsolver)
+    }
+
+    // A KKT system with no KKT matrix, for use when setup is abandoned
+    // because the problem is too large.   Every solve fails.
+    pub(crate) fn new_unassembled(data: &DefaultProblemData<T>) -> Self {
+        Self::new_with_kkt_solver(data, Box::new(UnassembledKKTSolver::new()))
+    }
+
+    /// Creates a KKT system using the given solver for the KKT equations.
+    pub fn new_with_kkt_solver(data: &DefaultProblemData<T>, kktsolver: BoxedKKTSolver<T>) -> Self {
+        let (m, n) = (data.m, data.n);
+
         //the LHS constant part of the reduced solve
         let x1 = vec![T::zero(); n];
         let z1 = vec![T::zero(); m];
@@ -83,10 +155,31 @@ where
             workx,
             workz,
             work_conic,
+            failed_rhs: None,
         }
     }
 }
 
+impl<T> DefaultKKTSystem<T>
+where
+    T: FloatT,
+{
+    /// Summary information about the KKT system and its factorization
+    pub fn kkt_info(&self) -> KKTInfo {
+        self.kktsolver.kkt_info()
+    }
+
+    /// The upper triangular part of the assembled KKT matrix
+    pub fn kkt_matrix(&self) -> &CscMatrix<T> {
+        self.kktsolver.kkt_matrix()
+    }
+
+    /// The fill-reducing ordering used to factor the KKT matrix, if any
+    pub fn kkt_ordering(&self) -> Option<&[usize]> {
+        self.kktsolver.ordering()
+    }
+}
+
 impl<T> KKTSystem<T> for DefaultKKTSystem<T>
 where
     T: FloatT,
@@ -102,8 +195,22 @@ where
         cones: &CompositeCone<T>,
         settings: &DefaultSettings<T>,
     ) -> bool {
-        // update the linear solver with new cones
-        let is_success = self.kktsolver.update(cones, settings.core());
+        self.update_with_abort(data, cones, settings, &|| false)
+    }
+
+    fn update_with_abort(
+        &mut self,
+        data: &DefaultProblemData<T>,
+        cones: &CompositeCone<T>,
+        settings: &DefaultSettings<T>,
+        abort: &dyn Fn() -> bool,
+    ) -> bool {
+        // update the linear solver with new cones.  A failed
+        // factorization leaves no right hand side to report
+        self.failed_rhs = None;
+        let is_success = self
+            .kktsolver
+            .update_with_abort(cones, settings.core(), abort);
 
         if !is_success {
             return is_success;
@@ -153,6 +260,7 @@ where
         self.kktsolver.setrhs(workx, workz);
         let is_success = self.kktsolver.solve(Some(x1), Some(z1), settings.core());
         if !is_success {
+            self.failed_rhs = Some(_concat_rhs(workx, workz));
             return false;
         }
 
@@ -205,6 +313,7 @@ where
         settings: &DefaultSettings<T>,
     ) -> bool {
         let mut is_success;
+        let equality_project = _use_equality_project(settings);
 
         if data.P.nnz() == 0 {
             // LP initialization
@@ -221,6 +330,7 @@ where
             variables.s.negate();
 
             if !is_success {
+                self.failed_rhs = Some(_concat_rhs(&self.workx, &self.workz));
                 return is_success;
             }
 
@@ -244,9 +354,38 @@ where
                 settings.core(),
             );
             variables.s.scalarop_from(|z| -z, &variables.z);
+
+            if is_success && equality_project {
+                _equality_project_initial_point(variables, data, settings);
+            }
+        }
+
+        if !is_success {
+            self.failed_rhs = Some(_concat_rhs(&self.workx, &self.workz));
         }
         is_success
     }
+
+    fn kkt_info(&self) -> KKTInfo {
+        self.kktsolver.kkt_info()
+    }
+
+    #[allow(unused_variables)]
+    fn dump_on_failure(&self, cones: &CompositeCone<T>, settings: &DefaultSettings<T>) {
+        #[cfg(feature = "serde")]
+        if let Some(path) = &settings.dump_on_failure {
+            let mut Hs = allocate_kkt_Hsblocks::<T, T>(cones);
+            cones.get_Hs(&mut Hs);
+            let rhs = self.failed_rhs.as_deref();
+            let dump = KKTDump::new(self.kkt_matrix(), rhs, &Hs);
+
+            let result = std::fs::File::create(path).and_then(|mut f| dump.write_to_file(&mut f));
+            if let (Err(e), true) = (result, settings.verbose) {
+                let mut out = stdio::stdout();
+                writeln!(out, "WARNING: failed to write KKT dump: {}", e).unwrap();
+            }
+        }
+    }
 }
 
 impl<T> DefaultKKTSystem<T>
@@ -259,11 +398,15 @@ where
         settings: &DefaultSettings<T>,
     ) -> bool {
         self.workx.axpby(-T::one(), &data.q, T::zero()); //workx .= -q
-        self.kktsolver.setrhs(&self.workx, &data.b);
+        self.workz.copy_from(&data.b);
+        self.kktsolver.setrhs(&self.workx, &self.workz);
         let is_success =
             self.kktsolver
                 .solve(Some(&mut self.x2), Some(&mut self.z2), settings.core());
 
+        if !is_success {
+            self.failed_rhs = Some(_concat_rhs(&self.workx, &self.workz));
+        }
         is_success
     }
 
@@ -274,4 +417,197 @@ where
     pub(crate) fn update_A(&mut self, A: &CscMatrix<T>) {
         self.kktsolver.update_A(A);
     }
+
+    // a deep copy of the system, or None if the KKT solver
+    // does not support copying
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            kktsolver: self.kktsolver.try_clone()?,
+            x1: self.x1.clone(),
+            z1: self.z1.clone(),
+            x2: self.x2.clone(),
+            z2: self.z2.clone(),
+            workx: self.workx.clone(),
+            workz: self.workz.clone(),
+            work_conic: self.work_conic.clone(),
+            failed_rhs: self.failed_rhs.clone(),
+        })
+    }
+}
+
+fn _concat_rhs<T: FloatT>(x: &[T], z: &[T]) -> Vec<T> {
+    [x, z].concat()
+}
+
+// true if the direct solver should factor the reduced form of the KKT
+// system.  Falls back to the full form, with a warning if verbose, if
+// the reduced form is requested but not supported by the cones
+fn _use_reduced_kkt<T: FloatT>(cones: &CompositeCone<T>, settings: &DefaultSettings<T>) -> bool {
+    match settings.kkt_form.as_str() {
+        "full" => false,
+        "reduced" => {
+            let is_supported = ReducedLDLKKTSolver::is_supported(cones);
+            if !is_supported && settings.verbose {
+                let mut out = stdio::stdout();
+                writeln!(
+                    out,
+                    "warning: kkt_form = \"reduced\" requires nonnegative cones only. Using \"full\"."
+                )
+                .unwrap();
+            }
+            is_supported
+        }
+        _ => panic!("Unrecognized KKT form"),
+    }
+}
+
+// placeholder for a KKT solver that could not be constructed
+#[derive(Clone)]
+struct UnassembledKKTSolver<T> {
+    KKT: CscMatrix<T>,
+}
+
+impl<T> UnassembledKKTSolver<T>
+where
+    T: FloatT,
+{
+    fn new() -> Self {
+        Self {
+            KKT: CscMatrix::zeros((0, 0)),
+        }
+    }
+}
+
+impl<T> KKTSolver<T> for UnassembledKKTSolver<T>
+where
+    T: FloatT,
+{
+    fn update(&mut self, _cones: &CompositeCone<T>, _settings: &CoreSettings<T>) -> bool {
+        false
+    }
+    fn setrhs(&mut self, _x: &[T], _z: &[T]) {}
+    fn solve(
+        &mut self,
+        _x: Option<&mut [T]>,
+        _z: Option<&mut [T]>,
+        _settings: &CoreSettings<T>,
+    ) -> bool {
+        false
+    }
+    fn update_P(&mut self, _P: &CscMatrix<T>) {}
+    fn update_A(&mut self, _A: &CscMatrix<T>) {}
+    fn kkt_info(&self) -> KKTInfo {
+        KKTInfo::default()
+    }
+    fn kkt_matrix(&self) -> &CscMatrix<T> {
+        &self.KKT
+    }
+    fn try_clone(&self) -> Option<BoxedKKTSolver<T>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+fn _use_equality_project<T: FloatT>(settings: &DefaultSettings<T>) -> bool {
+    match settings.init_strategy.as_str() {
+        "default" => false,
+        "equality_project" => true,
+        _ => panic!("Unrecognized initialization strategy"),
+    }
+}
+
+// Overwrites an initial point with one found from the solution of
+//
+//    min ½x'Px + q'x  s.t.  A_eq x = b_eq
+//
+// i.e. with the constraints other than zero cones dropped.  The
+// primal variables x are set to the minimizer, the slacks to b - Ax,
+// and the duals of the zero cone rows to the equality multipliers,
+// with the remaining duals zeroed.  The point is brought into the
+// cone interior by the usual symmetric initialization.   The point
+// is left unchanged if there are no zero cones or the factorization
+// fails.
+fn _equality_project_initial_point<T: FloatT>(
+    variables: &mut DefaultVariables<T>,
+    data: &DefaultProblemData<T>,
+    settings: &DefaultSettings<T>,
+) {
+    let (m, n) = (data.m, data.n);
+
+    let mut is_eq = vec![false; m];
+    let mut row = 0;
+    for cone in data.presolver.cone_specs.iter() {
+        let dim = cone.nvars();
+        if matches!(cone, SupportedConeT::ZeroConeT(_)) {
+            is_eq[row..row + dim].fill(true);
+        }
+        row += dim;
+    }
+    let eqrows: Vec<usize> = (0..m).filter(|&i| is_eq[i]).collect();
+    let meq = eqrows.len();
+    if meq == 0 {
+        return;
+    }
+
+    // the upper triangle of the regularized system [P+εI A_eq'; A_eq -εI]
+    let eps = settings.static_regularization_constant;
+    let Aeq = data.A.select_rows(&is_eq);
+    let (mut rows, mut cols, mut vals) = (Vec::new(), Vec::new(), Vec::new());
+    for (M, coloff) in [(&data.P, 0), (&Aeq.transpose(), n)] {
+        for col in 0..M.n {
+            for k in M.colptr[col]..M.colptr[col + 1] {
+                rows.push(M.rowval[k]);
+                cols.push(col + coloff);
+                vals.push(M.nzval[k]);
+            }
+        }
+    }
+    for i in 0..(n + meq) {
+        rows.push(i);
+        cols.push(i);
+        vals.push(if i < n { eps } else { -eps });
+    }
+    let mut K = CscMatrix::from_triplets(n + meq, n + meq, &rows, &cols, &vals);
+
+    // factor with the same LDL solver as the KKT system
+    let (kktshape, ldl_ctor) = _get_ldlsolver_config(settings.core());
+    if kktshape == MatrixTriangle::Tril {
+        K = K.transpose();
+    }
+    let mut dsigns = vec![1_i8; n + meq];
+    dsigns[n..].fill(-1);
+    let mut ldlsolver: BoxedDirectLDLSolver<T> = {
+        if _use_dense_ldl(&K, settings.core())
+            && kktshape == DenseDirectLDLSolver::<T>::required_matrix_shape()
+        {
+            Box::new(DenseDirectLDLSolver::<T>::new(&K, &dsigns, settings.core()))
+        } else {
+            ldl_ctor(&K, &dsigns, settings.core(), None)
+        }
+    };
+    if !ldlsolver.refactor(&K) {
+        return;
+    }
+
+    // solve with [-q; b_eq] as a RHS
+    let mut rhs: Vec<T> = data.q.iter().map(|&q| -q).collect();
+    rhs.extend(eqrows.iter().map(|&i| data.b[i]));
+    let mut xy = vec![T::zero(); n + meq];
+    ldlsolver.solve(&mut xy, &rhs);
+    if !xy.iter().all(|v| v.is_finite()) {
+        return;
+    }
+
+    variables.x.copy_from(&xy[..n]);
+    variables.s.copy_from(&data.b);
+    data.A.gemv(
+        &mut variables.s,
+        &variables.x,
+        -T::one(),
+        T::one(),
+        MatrixShape::N,
+    );
+    variables.z.fill(T::zero());
+    for (&i, &y) in zip(&eqrows, &xy[n..]) {
+        variables.z[i] = y;
+    }
 }
@@ -3,29 +3,50 @@
 
 #![allow(non_snake_case)]
 
+#[cfg(feature = "rayon")]
+mod batch;
+mod convexity;
+mod crossover;
 mod data_updating;
+mod data_validation;
+mod diagnostics;
 mod equilibration;
 mod info;
 mod info_print;
+#[cfg(feature = "serde")]
+mod json;
 mod kktsystem;
 mod presolver;
 mod problemdata;
+mod progress;
 mod residuals;
 mod settings;
 mod solution;
+mod solution_check;
 mod solver;
 mod variables;
+mod warm_start;
 
 // export flattened
+#[cfg(feature = "rayon")]
+pub use batch::*;
+pub use convexity::*;
+pub(crate) use crossover::*;
 pub use data_updating::*;
+pub use data_validation::*;
+pub use diagnostics::*;
 pub use equilibration::*;
 pub use info::*;
 pub use info_print::*;
+#[cfg(feature = "serde")]
+pub use json::*;
 pub use kktsystem::*;
 pub use presolver::*;
 pub use problemdata::*;
+pub use progress::*;
 pub use residuals::*;
 pub use settings::*;
 pub use solution::*;
+pub use solution_check::*;
 pub use solver::*;
 pub use variables::*;
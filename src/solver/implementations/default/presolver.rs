@@ -2,12 +2,13 @@
 use super::*;
 use crate::algebra::*;
 use crate::solver::SupportedConeT;
+use std::iter::zip;
 
 // ---------------
 // Data type for default problem presolver
 // ---------------
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct PresolverRowReductionIndex {
     // vector of length = original RHS.   Entries are false
     // for those rows that should be eliminated before solve
@@ -20,18 +21,33 @@ pub(crate) struct PresolverRowReductionIndex {
     pub keep_index: Vec<usize>,
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct PresolverRowExpansionIndex<T> {
+    // vectors of length = expanded RHS.   Each expanded row
+    // is taken from the original row at the same position in
+    // `rows` and multiplied by `scale` (one of 0 or ±1).  Rows
+    // not in complex PSD cones are copied without change
+    pub rows: Vec<usize>,
+    pub scale: Vec<T>,
+}
+
 /// Presolver data for the standard solver implementation
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Presolver<T> {
     // possibly reduced internal copy of user cone specification
     pub(crate) cone_specs: Vec<SupportedConeT<T>>,
 
-    //record of reduced constraints for NN cones with inf bounds
+    //record of expanded constraints for complex PSD cones
+    pub(crate) expand_map: Option<PresolverRowExpansionIndex<T>>,
+
+    //record of reduced constraints for NN cones with inf bounds.
+    //Row indices refer to the RHS after complex PSD cone expansion
     pub(crate) reduce_map: Option<PresolverRowReductionIndex>,
 
-    // size of original and reduced RHS, respectively
+    // size of original, expanded and reduced RHS, respectively
     pub(crate) mfull: usize,
+    pub(crate) mexpanded: usize,
     pub(crate) mreduced: usize,
 
     // inf bound that was taken from the module level
@@ -57,18 +73,28 @@ where
         let mut cone_specs = cone_specs.to_vec();
         let mfull = b.len();
 
+        // complex PSD cones are always expanded, regardless of settings
+        let expand_map = expand_cones(&mut cone_specs);
+        let b = match expand_map.as_ref() {
+            Some(map) => map.expand_vector(b),
+            None => b.to_vec(),
+        };
+        let mexpanded = b.len();
+
         let (reduce_map, mreduced) = {
             if settings.presolve_enable {
-                reduce_cones(&mut cone_specs, b, infbound.as_T())
+                reduce_cones(&mut cone_specs, &b, infbound.as_T())
             } else {
-                (None, mfull)
+                (None, mexpanded)
             }
         };
 
         Self {
             cone_specs,
+            expand_map,
             reduce_map,
             mfull,
+            mexpanded,
             mreduced,
             infbound,
         }
@@ -78,8 +104,241 @@ where
         self.reduce_map.is_some()
     }
     pub fn count_reduced(&self) -> usize {
-        self.mfull - self.mreduced
+        self.mexpanded - self.mreduced
+    }
+    pub fn is_expanded(&self) -> bool {
+        self.expand_map.is_some()
+    }
+
+    /// Summary of the changes made to the constraints by the presolver.
+    pub fn report(&self) -> PresolveReport {
+        let expanded_rows: Vec<usize> = match self.expand_map.as_ref() {
+            Some(map) => map.rows.clone(),
+            None => (0..self.mexpanded).collect(),
+        };
+        let row_map = match self.reduce_map.as_ref() {
+            Some(map) => map.keep_index.iter().map(|&k| expanded_rows[k]).collect(),
+            None => expanded_rows,
+        };
+
+        PresolveReport {
+            rows_original: self.mfull,
+            rows_expanded: self.mexpanded,
+            rows_removed: self.count_reduced(),
+            row_map,
+        }
+    }
+}
+
+/// Summary of the constraints removed by the presolver.
+///
+/// Complex PSD cone constraints are expanded into real PSD cone constraints
+/// of twice the matrix dimension before presolve, and row counts after
+/// expansion include the rows of the real embedding.  The presolver removes
+/// constraint rows only, and never fixes variables.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresolveReport {
+    /// Number of constraint rows in the problem as supplied
+    pub rows_original: usize,
+    /// Number of constraint rows after expansion of complex PSD cones
+    pub rows_expanded: usize,
+    /// Number of expanded constraint rows removed as redundant
+    pub rows_removed: usize,
+    /// Index of the original row for each constraint row in the problem as solved.
+    /// Rows of complex PSD cones that are always zero map to a diagonal entry
+    pub row_map: Vec<usize>,
+}
+
+impl<T> PresolverRowExpansionIndex<T>
+where
+    T: FloatT,
+{
+    // expanded RHS
+    pub(crate) fn expand_vector(&self, b: &[T]) -> Vec<T> {
+        zip(&self.rows, &self.scale)
+            .map(|(&i, &scale)| scale * b[i])
+            .collect()
+    }
+
+    // expanded constraint matrix, with rows copied, negated or zeroed
+    pub(crate) fn expand_matrix(&self, A: &CscMatrix<T>) -> CscMatrix<T> {
+        // expanded rows generated by each original row
+        let mut targets = vec![Vec::new(); A.m];
+        for (k, &i) in self.rows.iter().enumerate() {
+            if self.scale[k] != T::zero() {
+                targets[i].push(k);
+            }
+        }
+
+        let mut colptr = Vec::with_capacity(A.n + 1);
+        let mut rowval = Vec::with_capacity(2 * A.nnz());
+        let mut nzval = Vec::with_capacity(2 * A.nnz());
+        let mut entries = Vec::new();
+        colptr.push(0);
+
+        for col in 0..A.n {
+            entries.clear();
+            for j in A.colptr[col]..A.colptr[col + 1] {
+                let (i, v) = (A.rowval[j], A.nzval[j]);
+                for &k in targets[i].iter() {
+                    entries.push((k, self.scale[k] * v));
+                }
+            }
+            entries.sort_by_key(|&(k, _)| k);
+            for &(k, v) in entries.iter() {
+                rowval.push(k);
+                nzval.push(v);
+            }
+            colptr.push(rowval.len());
+        }
+
+        CscMatrix::new(self.rows.len(), A.n, colptr, rowval, nzval)
+    }
+
+    // expanded slacks and duals from those for the original constraints,
+    // i.e. the reverse of `collapse`.   Duals are shared equally between
+    // the copies of their row
+    pub(crate) fn expand_iterate(&self, s: &[T], z: &[T]) -> (Vec<T>, Vec<T>) {
+        let mut sexp = Vec::with_capacity(self.rows.len());
+        let mut zexp = Vec::with_capacity(self.rows.len());
+
+        let mut copies = vec![0usize; s.len()];
+        for (&i, &scale) in zip(&self.rows, &self.scale) {
+            if scale != T::zero() {
+                copies[i] += 1;
+            }
+        }
+
+        for (&i, &scale) in zip(&self.rows, &self.scale) {
+            sexp.push(scale * s[i]);
+            if scale == T::zero() {
+                zexp.push(T::zero());
+            } else {
+                zexp.push(scale * z[i] / copies[i].as_T());
+            }
+        }
+        (sexp, zexp)
+    }
+
+    // maps slacks and duals for the expanded constraints back to
+    // the original ones.   Slacks are recovered from the first copy
+    // of the row that was not eliminated during presolve, and the
+    // duals are the sum over all copies.
+    pub(crate) fn collapse(
+        &self,
+        s: &mut [T],
+        z: &mut [T],
+        sexp: &[T],
+        zexp: &[T],
+        keep: Option<&[bool]>,
+        infbound: T,
+    ) {
+        let mut is_set = vec![false; s.len()];
+        z.fill(T::zero());
+
+        for (k, (&i, &scale)) in zip(&self.rows, &self.scale).enumerate() {
+            if !keep.map_or(true, |keep| keep[k]) || scale == T::zero() {
+                continue;
+            }
+            z[i] += scale * zexp[k];
+            if !is_set[i] {
+                s[i] = scale * sexp[k];
+                is_set[i] = true;
+            }
+        }
+
+        // rows with every copy eliminated are unconstrained
+        for (si, _) in zip(s, is_set).filter(|(_, set)| !set) {
+            *si = infbound;
+        }
+    }
+}
+
+fn expand_cones<T>(cone_specs: &mut [SupportedConeT<T>]) -> Option<PresolverRowExpansionIndex<T>>
+where
+    T: FloatT,
+{
+    if !cone_specs.iter().any(|cone| cone.is_expanded_by_presolve()) {
+        return None;
+    }
+
+    let mfull = cone_specs.iter().fold(0, |acc, cone| acc + cone.nvars());
+    let mut map = PresolverRowExpansionIndex {
+        rows: Vec::with_capacity(mfull),
+        scale: Vec::with_capacity(mfull),
+    };
+    let mut bptr = 0; // index into the original b vector
+
+    for cone in cone_specs.iter_mut() {
+        let numel_cone = cone.nvars();
+
+        match cone {
+            #[cfg(feature = "sdp")]
+            SupportedConeT::PSDTriangleComplexConeT(dim) => {
+                let dim = *dim;
+                for (i, scale) in complex_psd_embedding::<T>(dim) {
+                    map.push(bptr + i, scale);
+                }
+                *cone = SupportedConeT::PSDTriangleConeT(2 * dim);
+            }
+            _ => {
+                for i in bptr..(bptr + numel_cone) {
+                    map.push(i, T::one());
+                }
+            }
+        }
+
+        bptr += numel_cone;
+    }
+
+    Some(map)
+}
+
+impl<T> PresolverRowExpansionIndex<T> {
+    fn push(&mut self, row: usize, scale: T) {
+        self.rows.push(row);
+        self.scale.push(scale);
+    }
+}
+
+// A Hermitian matrix H = X + iY is PSD if and only if the real symmetric
+// matrix [X -Y; Y X] is PSD.  Returns, for each entry of the upper triangle
+// of the real matrix, the row of the complex cone that it is taken from and
+// its sign, or a zero scale for the entries of the diagonal of -Y.
+#[cfg(feature = "sdp")]
+fn complex_psd_embedding<T: FloatT>(n: usize) -> Vec<(usize, T)> {
+    // rows of the complex cone holding H[i,i], and the
+    // real and imaginary parts of H[i,j] for i < j
+    let diag = |i: usize| i * i + 2 * i;
+    let re = |i: usize, j: usize| j * j + 2 * i;
+    let im = |i: usize, j: usize| j * j + 2 * i + 1;
+
+    let mut entries = Vec::with_capacity(n * (2 * n + 1));
+
+    for col in 0..(2 * n) {
+        for row in 0..=col {
+            let entry = if col < n || row >= n {
+                // X blocks on the diagonal
+                let (i, j) = (row % n, col % n);
+                if i == j {
+                    (diag(i), T::one())
+                } else {
+                    (re(i, j), T::one())
+                }
+            } else {
+                // -Y block, with Y antisymmetric
+                let (i, j) = (row, col - n);
+                match i.cmp(&j) {
+                    std::cmp::Ordering::Less => (im(i, j), -T::one()),
+                    std::cmp::Ordering::Greater => (im(j, i), T::one()),
+                    std::cmp::Ordering::Equal => (diag(i), T::zero()),
+                }
+            };
+            entries.push(entry);
+        }
     }
+    entries
 }
 
 fn reduce_cones<T>(
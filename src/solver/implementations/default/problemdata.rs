@@ -1,5 +1,7 @@
 #![allow(non_snake_case)]
 use itertools::izip;
+use std::collections::HashMap;
+use std::iter::zip;
 
 use super::*;
 use crate::algebra::*;
@@ -14,6 +16,7 @@ use crate::solver::core::{
 
 /// Standard-form solver type implementing the [`ProblemData`](crate::solver::core::traits::ProblemData) trait
 
+#[derive(Clone)]
 pub struct DefaultProblemData<T> {
     // the main KKT residuals
     pub P: CscMatrix<T>,
@@ -31,6 +34,32 @@ pub struct DefaultProblemData<T> {
     normb: Option<T>,
 
     pub presolver: Presolver<T>,
+
+    // false if a full P was supplied that is not symmetric,
+    // in which case only its upper triangle is used
+    pub(crate) P_is_symmetric: bool,
+
+    // user supplied descriptive information, e.g.
+    // a problem name.  Saved with the problem data
+    pub(crate) metadata: HashMap<String, String>,
+}
+
+/// Dimensions and nonzero counts of a problem as solved, i.e. after
+/// presolve and the expansion of any complex PSD cones.
+/// Returned by [`DefaultSolver::problem_dimensions`].
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProblemDimensions {
+    /// Number of variables
+    pub n: usize,
+    /// Number of constraint rows
+    pub m: usize,
+    /// Number of nonzeros in the upper triangular part of `P`
+    pub nnz_P: usize,
+    /// Number of nonzeros in `A`
+    pub nnz_A: usize,
+    /// Total degree of the cones
+    pub cone_degree: usize,
 }
 
 impl<T> DefaultProblemData<T>
@@ -47,9 +76,18 @@ where
         // dimension checks will have already been
         // performed during problem setup, so skip here
 
+        let P_is_symmetric = P.is_triu() || P.is_symmetric(_symmetry_tol(P));
         let P = P.to_triu();
         let q = q.to_vec();
 
+        let (A, b) = {
+            if let Some(map) = presolver.expand_map.as_ref() {
+                (map.expand_matrix(A), map.expand_vector(b))
+            } else {
+                (A.clone(), b.to_vec())
+            }
+        };
+
         let (A, mut b) = {
             if let Some(map) = presolver.reduce_map.as_ref() {
                 (
@@ -57,7 +95,7 @@ where
                     b.select(&map.keep_logical),
                 )
             } else {
-                (A.clone(), b.to_vec())
+                (A, b)
             }
         };
 
@@ -84,6 +122,8 @@ where
             normq,
             normb,
             presolver,
+            P_is_symmetric,
+            metadata: HashMap::new(),
         }
     }
 
@@ -130,10 +170,23 @@ where
         let data = self;
         let equil = &mut data.equilibration;
 
+        // the measure of KKT column size to be equalized
+        let kkt_col_measure: KKTColMeasureFn<T> = match settings.equilibration_method.as_str() {
+            "ruiz" => kkt_col_norms,
+            "l2" => kkt_col_norms_l2,
+            "geometric" => kkt_col_geomeans,
+            _ => panic!("Unrecognized equilibration method"),
+        };
+
         // if equilibration is disabled, just return.  Note that
         // the default equilibration structure initializes with
         // identity scaling already.
         if !settings.equilibrate_enable {
+            if let Some(c) = settings.objective_scaling {
+                data.P.scale(c);
+                data.q.scale(c);
+                equil.c = c;
+            }
             return;
         }
 
@@ -153,7 +206,7 @@ where
 
         // perform scaling operations for a fixed number of steps
         for _ in 0..settings.equilibrate_max_iter {
-            kkt_col_norms(P, A, dwork, ework);
+            kkt_col_measure(P, A, dwork, ework);
 
             //zero rows or columns should not get scaled
             dwork.scalarop(|x| if x == T::zero() { T::one() } else { x });
@@ -170,6 +223,12 @@ where
                 *ework = T::clip(ework, scale_min / e, scale_max / e);
             }
 
+            // stop early once the scaling is unchanged
+            if dwork.iter().chain(ework.iter()).all(|&x| x == T::one()) {
+                break;
+            }
+            equil.iterations += 1;
+
             // Scale the problem data and update the
             // equilibration matrices
             scale_data(P, A, q, b, Some(dwork), ework);
@@ -183,7 +242,10 @@ where
             let mean_col_norm_P = dwork.mean();
             let inf_norm_q = q.norm_inf();
 
-            if mean_col_norm_P != T::zero() && inf_norm_q != T::zero() {
+            if settings.objective_scaling.is_none()
+                && mean_col_norm_P != T::zero()
+                && inf_norm_q != T::zero()
+            {
                 let scale_cost = T::max(inf_norm_q, mean_col_norm_P);
                 let ctmp = T::recip(scale_cost);
                 let ctmp = T::clip(&ctmp, scale_min / equil.c, scale_max / equil.c);
@@ -193,7 +255,14 @@ where
                 q.scale(ctmp);
                 equil.c *= ctmp;
             }
-        } //end Ruiz scaling loop
+        } //end scaling loop
+
+        // a fixed objective scaling is applied once at the end
+        if let Some(c) = settings.objective_scaling {
+            P.scale(c);
+            q.scale(c);
+            equil.c = c;
+        }
 
         // fix scalings in cones for which elementwise
         // scaling can't be applied. Rectification should
@@ -206,6 +275,10 @@ where
             e.hadamard(ework);
         }
 
+        // how well the final data is equilibrated
+        kkt_col_measure(P, A, dwork, ework);
+        equil.ratio = _col_measure_ratio(dwork, ework);
+
         // update the inverse scaling data
         equil.dinv.scalarop_from(T::recip, d);
         equil.einv.scalarop_from(T::recip, e);
@@ -216,6 +289,26 @@ where
 // utilities
 // ---------------
 
+// allow for roundoff when P is built as a full matrix
+fn _symmetry_tol<T: FloatT>(P: &CscMatrix<T>) -> T {
+    T::epsilon().sqrt() * P.nzval.norm_inf()
+}
+
+// ratio of the largest to smallest nonzero column measure, or
+// one if there are no nonzero columns
+fn _col_measure_ratio<T: FloatT>(dwork: &[T], ework: &[T]) -> T {
+    let (mut min, mut max) = (T::infinity(), T::zero());
+    for &x in dwork.iter().chain(ework.iter()).filter(|&&x| x > T::zero()) {
+        min = T::min(min, x);
+        max = T::max(max, x);
+    }
+    if max == T::zero() {
+        T::one()
+    } else {
+        max / min
+    }
+}
+
 fn kkt_col_norms<T: FloatT>(
     P: &CscMatrix<T>,
     A: &CscMatrix<T>,
@@ -227,6 +320,90 @@ fn kkt_col_norms<T: FloatT>(
     A.row_norms(norm_RHS); // same as column norms of A'
 }
 
+type KKTColMeasureFn<T> = fn(&CscMatrix<T>, &CscMatrix<T>, &mut [T], &mut [T]);
+
+// Euclidean norms of the columns of the KKT matrix [P A';A 0]
+#[allow(clippy::needless_range_loop)]
+fn kkt_col_norms_l2<T: FloatT>(
+    P: &CscMatrix<T>,
+    A: &CscMatrix<T>,
+    norm_LHS: &mut [T],
+    norm_RHS: &mut [T],
+) {
+    norm_LHS.fill(T::zero());
+    norm_RHS.fill(T::zero());
+
+    // P is triu, so off diagonal terms appear in two columns
+    for col in 0..P.n {
+        for k in P.colptr[col]..P.colptr[col + 1] {
+            let (row, v2) = (P.rowval[k], P.nzval[k] * P.nzval[k]);
+            norm_LHS[col] += v2;
+            if row != col {
+                norm_LHS[row] += v2;
+            }
+        }
+    }
+    for col in 0..A.n {
+        for k in A.colptr[col]..A.colptr[col + 1] {
+            let (row, v2) = (A.rowval[k], A.nzval[k] * A.nzval[k]);
+            norm_LHS[col] += v2;
+            norm_RHS[row] += v2;
+        }
+    }
+
+    norm_LHS.scalarop(T::sqrt);
+    norm_RHS.scalarop(T::sqrt);
+}
+
+// geometric means of the largest and smallest nonzero magnitudes
+// in the columns of the KKT matrix [P A';A 0]
+fn kkt_col_geomeans<T: FloatT>(
+    P: &CscMatrix<T>,
+    A: &CscMatrix<T>,
+    max_LHS: &mut [T],
+    max_RHS: &mut [T],
+) {
+    let mut min_LHS = vec![T::infinity(); max_LHS.len()];
+    let mut min_RHS = vec![T::infinity(); max_RHS.len()];
+    max_LHS.fill(T::zero());
+    max_RHS.fill(T::zero());
+
+    fn update<T: FloatT>(max: &mut T, min: &mut T, v: T) {
+        let v = T::abs(v);
+        if v != T::zero() {
+            *max = T::max(*max, v);
+            *min = T::min(*min, v);
+        }
+    }
+
+    for col in 0..P.n {
+        for k in P.colptr[col]..P.colptr[col + 1] {
+            let (row, v) = (P.rowval[k], P.nzval[k]);
+            update(&mut max_LHS[col], &mut min_LHS[col], v);
+            update(&mut max_LHS[row], &mut min_LHS[row], v);
+        }
+    }
+    for col in 0..A.n {
+        for k in A.colptr[col]..A.colptr[col + 1] {
+            let (row, v) = (A.rowval[k], A.nzval[k]);
+            update(&mut max_LHS[col], &mut min_LHS[col], v);
+            update(&mut max_RHS[row], &mut min_RHS[row], v);
+        }
+    }
+
+    // columns with no nonzeros are left at zero
+    for (max, &min) in zip(max_LHS.iter_mut(), &min_LHS) {
+        if *max != T::zero() {
+            *max = T::sqrt(*max * min);
+        }
+    }
+    for (max, &min) in zip(max_RHS.iter_mut(), &min_RHS) {
+        if *max != T::zero() {
+            *max = T::sqrt(*max * min);
+        }
+    }
+}
+
 fn scale_data<T: FloatT>(
     P: &mut CscMatrix<T>,
     A: &mut CscMatrix<T>,
@@ -0,0 +1,55 @@
+use super::*;
+use crate::algebra::*;
+use std::sync::mpsc::{channel, Receiver};
+
+/// Progress of the solver after an interior point iteration.
+/// See [`DefaultSolver::progress_receiver`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressEvent<T> {
+    /// Iteration count
+    pub iteration: u32,
+    /// Primal residual
+    pub res_primal: T,
+    /// Dual residual
+    pub res_dual: T,
+    /// Relative duality gap
+    pub gap: T,
+}
+
+impl<T> DefaultSolver<T>
+where
+    T: FloatT,
+{
+    /// Returns a channel receiver for streaming the progress of the solver.
+    ///
+    /// One [`ProgressEvent`] is sent for every iteration of each subsequent
+    /// call to `solve`, so that the number of events sent by a solve is equal
+    /// to its reported iteration count.  Events are sent without blocking
+    /// and are discarded if the receiver has been dropped.
+    ///
+    /// Only the most recently created receiver is sent events.
+    pub fn progress_receiver(&mut self) -> Receiver<ProgressEvent<T>> {
+        let (sender, receiver) = channel();
+
+        // the observer is also called at iteration zero, and may be called
+        // more than once per iteration if the scaling strategy changes
+        let mut last_iteration = 0;
+
+        self.progress_observer = Some(Box::new(move |info: &DefaultInfo<T>| {
+            if info.iterations == 0 {
+                last_iteration = 0;
+            }
+            if info.iterations > last_iteration {
+                last_iteration = info.iterations;
+                let _ = sender.send(ProgressEvent {
+                    iteration: info.iterations,
+                    res_primal: info.res_primal,
+                    res_dual: info.res_dual,
+                    gap: info.gap_rel,
+                });
+            }
+        }));
+
+        receiver
+    }
+}
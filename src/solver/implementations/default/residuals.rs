@@ -9,6 +9,7 @@ use crate::solver::core::traits::Residuals;
 
 /// Standard-form solver type implementing the [`Residuals`](crate::solver::core::traits::Residuals) trait
 
+#[derive(Clone)]
 pub struct DefaultResiduals<T> {
     // the main KKT residuals
     pub rx: Vec<T>,
@@ -57,6 +58,20 @@ where
             dot_xPx: T::zero(),
         }
     }
+
+    // resets to the state given by `new`, reusing existing allocations
+    pub(crate) fn resize(&mut self, n: usize, m: usize) {
+        _resize_zeroed(&mut self.rx, n);
+        _resize_zeroed(&mut self.rz, m);
+        _resize_zeroed(&mut self.rx_inf, n);
+        _resize_zeroed(&mut self.rz_inf, m);
+        _resize_zeroed(&mut self.Px, n);
+        self.rτ = T::one();
+        self.dot_qx = T::zero();
+        self.dot_bz = T::zero();
+        self.dot_sz = T::zero();
+        self.dot_xPx = T::zero();
+    }
 }
 
 impl<T> Residuals<T> for DefaultResiduals<T>
@@ -83,13 +98,12 @@ where
 
         //Same as:
         //rx_inf .= -data.A'* variables.z
-        let At = data.A.t();
-        At.gemv(&mut self.rx_inf, &variables.z, -T::one(), T::zero());
+        let A = &data.A;
+        _csc_axpby_T(A, &mut self.rx_inf, &variables.z, -T::one(), T::zero());
 
         //Same as:  residuals.rz_inf .=  data.A * variables.x + variables.s
         self.rz_inf.copy_from(&variables.s);
-        let A = &data.A;
-        A.gemv(&mut self.rz_inf, &variables.x, T::one(), T::one());
+        _csc_axpby_N(A, &mut self.rz_inf, &variables.x, T::one(), T::one());
 
         //complete the residuals
         //rx = rx_inf - Px - qτ
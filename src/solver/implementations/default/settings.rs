@@ -1,14 +1,16 @@
 use crate::algebra::*;
 use crate::solver::core::traits::Settings;
 use derive_builder::Builder;
+use std::path::PathBuf;
 
-#[cfg(feature = "julia")]
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Standard-form solver type implementing the [`Settings`](crate::solver::core::traits::Settings) trait
 
 #[derive(Builder, Debug, Clone)]
-#[cfg_attr(feature = "julia", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct DefaultSettings<T: FloatT> {
     #[builder(default = "200")]
     // Main algorithm settings
@@ -20,6 +22,24 @@ pub struct DefaultSettings<T: FloatT> {
     #[builder(default = "true")]
     pub verbose: bool,
 
+    // print every print_stride-th row of the iteration table,
+    // together with the first and last.  Zero is treated as one
+    #[builder(default = "1")]
+    pub print_stride: u32,
+
+    // when false, solve_time is always zero and no
+    // timings are reported.  time_limit is still enforced
+    #[builder(default = "true")]
+    pub collect_timings: bool,
+
+    // when true, time_limit is also checked within each
+    // iteration, periodically during the KKT factorization
+    // and after the KKT solves, rather than only between
+    // iterations.  Only the QDLDL factorization is polled;
+    // other direct solvers complete their factorization
+    #[builder(default = "false")]
+    pub time_limit_hard: bool,
+
     #[builder(default = "(0.99).as_T()")]
     pub max_step_fraction: T,
 
@@ -61,6 +81,30 @@ pub struct DefaultSettings<T: FloatT> {
     #[builder(default = "(1e-4).as_T()")]
     pub reduced_tol_ktratio: T,
 
+    // one of "standard" or "gap_priority".  With "gap_priority"
+    // the solver also stops once tol_gap_rel is met and the
+    // residuals are within reduced_tol_feas, with status AlmostSolved
+    // unless the standard criteria are also met
+    #[builder(default = r#""standard".to_string()"#)]
+    pub termination_mode: String,
+
+    // scale the absolute gap and infeasibility tolerances by
+    // max(1, ‖q‖∞, ‖b‖∞), computed once at setup, so that termination
+    // is invariant to the overall scale of the problem data.  tol_feas
+    // is unaffected since the feasibility residuals are already relative
+    #[builder(default = "false")]
+    pub tol_normalize: bool,
+
+    // stop with InsufficientProgress after this many consecutive
+    // iterations in which max(res_primal, res_dual) decreases by a
+    // relative amount less than insufficient_progress_tol.  Zero
+    // disables the check
+    #[builder(default = "0")]
+    pub insufficient_progress_window: u32,
+
+    #[builder(default = "(1e-3).as_T()")]
+    pub insufficient_progress_tol: T,
+
     // data equilibration settings
     #[builder(default = "true")]
     pub equilibrate_enable: bool,
@@ -74,6 +118,24 @@ pub struct DefaultSettings<T: FloatT> {
     #[builder(default = "(1e+5).as_T()")]
     pub equilibrate_max_scaling: T,
 
+    // one of "ruiz", "l2" or "geometric"
+    #[builder(default = r#""ruiz".to_string()"#)]
+    pub equilibration_method: String,
+
+    // fixed scaling applied to the objective in place of the one
+    // chosen during equilibration.  Must be positive and finite.
+    // Some(1.0) disables objective scaling
+    #[builder(default = "None")]
+    pub objective_scaling: Option<T>,
+
+    // one of "default" or "equality_project".  With "equality_project"
+    // the initial point is found from the minimizer of the objective
+    // subject to the zero cone (equality) constraints only, at the cost
+    // of one additional factorization.  Ignored for LPs, for problems
+    // without zero cones and for problems with nonsymmetric cones
+    #[builder(default = r#""default".to_string()"#)]
+    pub init_strategy: String,
+
     // Step size settings
     #[builder(default = "(0.8).as_T()")]
     pub linesearch_backtrack_step: T,
@@ -84,12 +146,59 @@ pub struct DefaultSettings<T: FloatT> {
     #[builder(default = "(1e-4).as_T()")]
     pub min_terminate_step_length: T,
 
-    // Linear solver settings
+    // higher order corrections made after the combined step in each
+    // iteration, each taking the second order term from the previous
+    // step direction.  A correction is kept only if it lengthens the step
+    #[builder(default = "0")]
+    pub max_centrality_corrections: u32,
+
+    // Linear solver settings.  Use an indirect (MINRES)
+    // solver for the KKT system if direct_kkt_solver = false
     #[builder(default = "true")]
     pub direct_kkt_solver: bool,
     #[builder(default = r#""qdldl".to_string()"#)]
     pub direct_solve_method: String,
 
+    // form of the KKT system factored by the direct solver, one of
+    // "full" or "reduced".  "reduced" eliminates z to factor the
+    // normal equations P + A'Hs⁻¹A, and is only used if all cones
+    // are nonnegative cones.  The full form is used otherwise
+    #[builder(default = r#""full".to_string()"#)]
+    pub kkt_form: String,
+
+    // KKT systems of dimension less than this are factored with a
+    // dense LDL in place of direct_solve_method, unless a fill-reducing
+    // ordering is supplied.  Zero always uses direct_solve_method
+    #[builder(default = "32")]
+    pub dense_kkt_threshold: usize,
+
+    // stopping criterion for the indirect solver, one of "absolute",
+    // "relative" or "preconditioned".  MINRES stops once its residual
+    // estimate is below iterative_refinement_abstol, plus (if not
+    // "absolute") iterative_refinement_reltol times the norm of the
+    // right hand side, or its preconditioned norm if "preconditioned"
+    #[builder(default = r#""relative".to_string()"#)]
+    pub indirect_stop_metric: String,
+
+    // reuse the fill-reducing ordering of the previous KKT matrix
+    // when the solver is rebuilt for a problem with the same sparsity
+    #[builder(default = "false")]
+    pub direct_solve_cache_symbolic: bool,
+
+    // setup is abandoned, and solve reports InsufficientMemory, if
+    // the KKT matrix would have more than this many nonzeros.
+    // None places no limit on the size of the KKT matrix
+    #[builder(default = "None")]
+    pub max_kkt_nnz: Option<usize>,
+
+    // seed for any randomized choice made during setup, e.g. tie-breaking
+    // or a randomized ordering.  The solver is currently deterministic, so
+    // the same problem and settings always give identical iterates, and
+    // any randomized component added later must preserve this when a seed
+    // is given.  None leaves the choice of seed to the component
+    #[builder(default = "None")]
+    pub random_seed: Option<u64>,
+
     // static regularization parameters
     #[builder(default = "true")]
     pub static_regularization_enable: bool,
@@ -108,6 +217,16 @@ pub struct DefaultSettings<T: FloatT> {
     #[builder(default = "(2e-7).as_T()")]
     pub dynamic_regularization_delta: T,
 
+    // if the KKT factorization fails, retry up to this many times with
+    // dynamic_regularization_eps multiplied by factorization_retry_factor
+    // (or raised to machine epsilon if it is zero) on each attempt.
+    // Later factorizations start again from dynamic_regularization_eps
+    #[builder(default = "3")]
+    pub factorization_max_retries: u32,
+
+    #[builder(default = "(100.).as_T()")]
+    pub factorization_retry_factor: T,
+
     // iterative refinement (for direct solves)
     #[builder(default = "true")]
     pub iterative_refinement_enable: bool,
@@ -127,6 +246,42 @@ pub struct DefaultSettings<T: FloatT> {
     // preprocessing
     #[builder(default = "true")]
     pub presolve_enable: bool,
+
+    // move the solution of a linear program to a vertex of the feasible
+    // set with the same objective value.  Ignored for other problems,
+    // and for problems with more than 200 variables or constraints
+    #[builder(default = "false")]
+    pub crossover: bool,
+
+    // iterate history, ending with the final iterate
+    #[builder(default = "false")]
+    pub save_iterates: bool,
+
+    // keep at most this many of the most recent iterates.
+    // None keeps all of them
+    #[builder(default = "None")]
+    pub save_iterates_max: Option<u32>,
+
+    // collect an IterationRecord for each iteration in the solver info
+    #[builder(default = "false")]
+    pub collect_iteration_records: bool,
+
+    // collect μ at each iteration, including the initial point,
+    // as returned by DefaultSolver::mu_history
+    #[builder(default = "false")]
+    pub collect_mu_history: bool,
+
+    // keep the final iterate in the scaled space of the solver, as
+    // returned by DefaultSolution::scaled_solution
+    #[builder(default = "false")]
+    pub return_scaled: bool,
+
+    // if the solver stops with NumericalError, write the KKT matrix,
+    // the right hand side of the failed KKT solve (if any) and the cone
+    // scaling blocks to this file in JSON format, as read by
+    // KKTDump::read_from_file.  Requires the serde feature
+    #[builder(default = "None")]
+    pub dump_on_failure: Option<PathBuf>,
 }
 
 impl<T> Default for DefaultSettings<T>
@@ -138,6 +293,85 @@ where
     }
 }
 
+impl<T> DefaultSettings<T>
+where
+    T: FloatT,
+{
+    /// Adjusts settings that are outside of their valid range:
+    ///
+    /// - tolerances are raised to at least machine epsilon, since smaller
+    ///   values can never be met
+    /// - `max_step_fraction` is reduced to at most 1
+    /// - `print_stride` is raised to at least 1
+    ///
+    /// This is applied to the settings passed to
+    /// [`DefaultSolver::new`](crate::solver::DefaultSolver::new), and the
+    /// adjusted values are returned by
+    /// [`DefaultSolver::effective_settings`](crate::solver::DefaultSolver::effective_settings).
+    pub fn validate(&mut self) {
+        let eps = T::epsilon();
+        for tol in [
+            &mut self.tol_gap_abs,
+            &mut self.tol_gap_rel,
+            &mut self.tol_feas,
+            &mut self.tol_infeas_abs,
+            &mut self.tol_infeas_rel,
+            &mut self.tol_ktratio,
+            &mut self.reduced_tol_gap_abs,
+            &mut self.reduced_tol_gap_rel,
+            &mut self.reduced_tol_feas,
+            &mut self.reduced_tol_infeas_abs,
+            &mut self.reduced_tol_infeas_rel,
+            &mut self.reduced_tol_ktratio,
+        ] {
+            *tol = T::max(*tol, eps);
+        }
+        self.max_step_fraction = T::min(self.max_step_fraction, T::one());
+        self.print_stride = u32::max(self.print_stride, 1);
+    }
+}
+
+impl<T> DefaultSettingsBuilder<T>
+where
+    T: FloatT,
+{
+    /// Sets the full accuracy tolerances from an absolute tolerance `abs`
+    /// and a relative tolerance `rel`:
+    ///
+    /// - `tol_gap_abs = abs`, `tol_gap_rel = rel`
+    /// - `tol_feas = rel`
+    /// - `tol_infeas_abs = abs`, `tol_infeas_rel = rel`
+    /// - `tol_ktratio = 100 * rel`
+    ///
+    /// The default tolerances are those given by `abs = rel = 1e-8`.
+    pub fn tolerances(&mut self, abs: T, rel: T) -> &mut Self {
+        self.tol_gap_abs(abs)
+            .tol_gap_rel(rel)
+            .tol_feas(rel)
+            .tol_infeas_abs(abs)
+            .tol_infeas_rel(rel)
+            .tol_ktratio(rel * (100.).as_T())
+    }
+
+    /// Sets the reduced accuracy tolerances from an absolute tolerance `abs`
+    /// and a relative tolerance `rel`:
+    ///
+    /// - `reduced_tol_gap_abs = abs`, `reduced_tol_gap_rel = rel`
+    /// - `reduced_tol_feas = 2 * rel`
+    /// - `reduced_tol_infeas_abs = abs`, `reduced_tol_infeas_rel = rel`
+    /// - `reduced_tol_ktratio = 2 * rel`
+    ///
+    /// The default reduced tolerances are those given by `abs = rel = 5e-5`.
+    pub fn reduced_tolerances(&mut self, abs: T, rel: T) -> &mut Self {
+        self.reduced_tol_gap_abs(abs)
+            .reduced_tol_gap_rel(rel)
+            .reduced_tol_feas(rel * (2.).as_T())
+            .reduced_tol_infeas_abs(abs)
+            .reduced_tol_infeas_rel(rel)
+            .reduced_tol_ktratio(rel * (2.).as_T())
+    }
+}
+
 impl<T> Settings<T> for DefaultSettings<T>
 where
     T: FloatT,
@@ -1,13 +1,16 @@
 use super::*;
 use crate::{
     algebra::*,
-    solver::core::{traits::Solution, SolverStatus},
+    solver::core::{cones::SupportedConeT, traits::Solution, SolverStatus},
 };
 use itertools::izip;
+use std::collections::VecDeque;
+use std::io::{self, Write};
 use std::iter::zip;
 
 /// Standard-form solver type implementing the [`Solution`](crate::solver::core::traits::Solution) trait
 
+#[derive(Debug, Clone)]
 pub struct DefaultSolution<T> {
     pub x: Vec<T>,
     pub z: Vec<T>,
@@ -19,6 +22,28 @@ pub struct DefaultSolution<T> {
     pub iterations: u32,
     pub r_prim: T,
     pub r_dual: T,
+    pub total_refinement_iters: usize,
+    pub final_refinement_residual: T,
+    pub equilibration_iters: u32,
+
+    // μ at the initial point and each iteration, if enabled in the settings
+    pub mu_hist: Vec<T>,
+
+    // primal and dual step lengths for the final iteration
+    pub step_length_primal: T,
+    pub step_length_dual: T,
+
+    // start of each cone supplied to the solver within z and s,
+    // followed by the total length of z and s
+    pub(crate) cone_offsets: Vec<usize>,
+
+    // final iterate in the scaled space, if enabled in the settings
+    pub(crate) scaled: Option<ScaledSolution<T>>,
+
+    // iterate history, populated only if enabled in the settings
+    pub xhist: VecDeque<Vec<T>>,
+    pub zhist: VecDeque<Vec<T>>,
+    pub shist: VecDeque<Vec<T>>,
 }
 
 impl<T> DefaultSolution<T>
@@ -41,6 +66,307 @@ where
             iterations: 0,
             r_prim: T::nan(),
             r_dual: T::nan(),
+            total_refinement_iters: 0,
+            final_refinement_residual: T::nan(),
+            equilibration_iters: 0,
+            mu_hist: vec![],
+            step_length_primal: T::nan(),
+            step_length_dual: T::nan(),
+            cone_offsets: vec![0],
+            scaled: None,
+            xhist: VecDeque::new(),
+            zhist: VecDeque::new(),
+            shist: VecDeque::new(),
+        }
+    }
+
+    // resets to the state given by `new`, reusing existing allocations
+    pub(crate) fn reset(&mut self, m: usize, n: usize) {
+        _resize_zeroed(&mut self.x, n);
+        _resize_zeroed(&mut self.z, m);
+        _resize_zeroed(&mut self.s, m);
+        self.status = SolverStatus::Unsolved;
+        self.obj_val = T::nan();
+        self.obj_val_dual = T::nan();
+        self.solve_time = 0f64;
+        self.iterations = 0;
+        self.r_prim = T::nan();
+        self.r_dual = T::nan();
+        self.total_refinement_iters = 0;
+        self.final_refinement_residual = T::nan();
+        self.equilibration_iters = 0;
+        self.mu_hist.clear();
+        self.step_length_primal = T::nan();
+        self.step_length_dual = T::nan();
+        self.scaled = None;
+        self.xhist.clear();
+        self.zhist.clear();
+        self.shist.clear();
+    }
+
+    // records the layout of the cones supplied to the solver
+    pub(crate) fn set_cone_offsets(&mut self, cones: &[SupportedConeT<T>]) {
+        self.cone_offsets.clear();
+        self.cone_offsets.push(0);
+        let mut offset = 0;
+        for cone in cones {
+            offset += cone.nvars();
+            self.cone_offsets.push(offset);
+        }
+    }
+
+    /// Slices of the dual variables `z`, one for each of the cones supplied
+    /// to the solver and in the same order.
+    pub fn dual_by_cone(&self) -> Vec<&[T]> {
+        _split_by_cone(&self.z, &self.cone_offsets)
+    }
+
+    /// Slices of the slack variables `s`, one for each of the cones supplied
+    /// to the solver and in the same order.
+    pub fn slacks_by_cone(&self) -> Vec<&[T]> {
+        _split_by_cone(&self.s, &self.cone_offsets)
+    }
+
+    /// The final iterate in the scaled space of the solver, if the
+    /// `return_scaled` setting is enabled.  See [`ScaledSolution`].
+    pub fn scaled_solution(&self) -> Option<ScaledSolution<T>> {
+        self.scaled.clone()
+    }
+
+    /// Certificate of infeasibility, if the solver terminated with
+    /// a primal or dual infeasible status (including the reduced
+    /// accuracy variants), and `None` otherwise.
+    pub fn infeasibility_certificate(&self) -> Option<Certificate<T>> {
+        match self.status {
+            SolverStatus::PrimalInfeasible | SolverStatus::AlmostPrimalInfeasible => {
+                Some(Certificate::PrimalInfeasible { z: self.z.clone() })
+            }
+            SolverStatus::DualInfeasible | SolverStatus::AlmostDualInfeasible => {
+                Some(Certificate::DualInfeasible { x: self.x.clone() })
+            }
+            _ => None,
+        }
+    }
+
+    /// Flags the constraint rows that are active at the solution, i.e. those
+    /// whose slacks `s` lie within `tol` of the boundary of their cone.
+    ///
+    /// `cones` should be the cones supplied to the solver.  Equality
+    /// constraints are always active.   Nonnegative and box cone rows are
+    /// flagged individually, with the bounds of a box cone scaled by its
+    /// leading row `t`, and second order cone rows are flagged as a
+    /// block when `s₀ - ‖s₁‖ ≤ tol`.   Rows of other cone types are not
+    /// flagged.
+    pub fn active_constraints(&self, cones: &[SupportedConeT<T>], tol: T) -> Vec<bool> {
+        let mut active = vec![false; self.s.len()];
+        let mut row = 0;
+
+        for cone in cones {
+            let rng = row..row + cone.nvars();
+            let (s, active) = (&self.s[rng.clone()], &mut active[rng]);
+
+            match cone {
+                SupportedConeT::ZeroConeT(_) => active.fill(true),
+                SupportedConeT::NonnegativeConeT(_) => {
+                    zip(active, s).for_each(|(a, &si)| *a = si <= tol);
+                }
+                SupportedConeT::BoxConeT { lower, upper } => {
+                    // bounds are scaled by the leading t row
+                    let t = s[0];
+                    active[0] = t <= tol;
+                    for (a, &si, &l, &u) in izip!(&mut active[1..], &s[1..], lower, upper) {
+                        *a = si - t * l <= tol || t * u - si <= tol;
+                    }
+                }
+                SupportedConeT::SecondOrderConeT(_) => {
+                    active.fill(s[0] - s[1..].norm() <= tol);
+                }
+                _ => {}
+            }
+            row += cone.nvars();
+        }
+        active
+    }
+
+    /// Writes the iterate history collected with the `save_iterates` setting
+    /// as CSV, with a header row followed by one row per saved iterate.
+    ///
+    /// Each row starts with the iteration index, numbered from zero for the
+    /// initial point so that indices are unaffected by `save_iterates_max`
+    /// and ending with the final iterate, followed by the entries of `x` in columns
+    /// `x0, x1, ...`.  Use [`write_full_iterate_history_csv`](Self::write_full_iterate_history_csv)
+    /// to also write `s` and `z`.
+    pub fn write_iterate_history_csv<W: Write>(&self, w: W) -> io::Result<()> {
+        self._write_iterate_history_csv(w, false)
+    }
+
+    /// Writes the iterate history as for [`write_iterate_history_csv`](Self::write_iterate_history_csv),
+    /// with additional columns `s0, s1, ...` and `z0, z1, ...` following `x`.
+    pub fn write_full_iterate_history_csv<W: Write>(&self, w: W) -> io::Result<()> {
+        self._write_iterate_history_csv(w, true)
+    }
+
+    fn _write_iterate_history_csv<W: Write>(&self, mut w: W, full: bool) -> io::Result<()> {
+        let (m, n) = (self.s.len(), self.x.len());
+
+        // one iterate is saved per iteration plus the final one, and
+        // only the most recent ones are kept if the history is capped
+        let first = (self.iterations as usize + 1).saturating_sub(self.xhist.len());
+
+        write!(w, "iter")?;
+        for i in 0..n {
+            write!(w, ",x{}", i)?;
+        }
+        if full {
+            for i in 0..m {
+                write!(w, ",s{}", i)?;
+            }
+            for i in 0..m {
+                write!(w, ",z{}", i)?;
+            }
+        }
+        writeln!(w)?;
+
+        for (k, (x, s, z)) in izip!(&self.xhist, &self.shist, &self.zhist).enumerate() {
+            write!(w, "{}", first + k)?;
+            for v in x {
+                write!(w, ",{}", v)?;
+            }
+            if full {
+                for v in s.iter().chain(z) {
+                    write!(w, ",{}", v)?;
+                }
+            }
+            writeln!(w)?;
+        }
+        w.flush()
+    }
+
+    fn push_iterate(&mut self, x: Vec<T>, z: Vec<T>, s: Vec<T>, settings: &DefaultSettings<T>) {
+        // drop the oldest iterates if at capacity.  A cap of
+        // zero is treated as no history at all
+        if let Some(max) = settings.save_iterates_max {
+            let max = max as usize;
+            if max == 0 {
+                return;
+            }
+            while self.xhist.len() >= max {
+                self.xhist.pop_front();
+                self.zhist.pop_front();
+                self.shist.pop_front();
+            }
+        }
+
+        self.xhist.push_back(x);
+        self.zhist.push_back(z);
+        self.shist.push_back(s);
+    }
+}
+
+/// Final iterate of the solver before unscaling, as returned by
+/// [`DefaultSolution::scaled_solution`].
+///
+/// The solver works with equilibrated data `c D P D`, `c D q`, `E A D` and
+/// `E b`, with diagonal scalings `d`, `e` and objective scaling `c` as
+/// given by [`DefaultSolver::equilibration`], and with homogeneous variables
+/// `τ` and `κ`.
+/// The unscaled solution reported in [`DefaultSolution`] is recovered as
+///
+/// ```text
+/// x = d ∘ x̂ / τ
+/// s = e⁻¹ ∘ ŝ / τ
+/// z = e ∘ ẑ / (c τ)
+/// ```
+///
+/// with `κ` in place of `τ` if the problem was found to be infeasible.  The
+/// vectors `ẑ` and `ŝ` are those of the constraints seen by the solver, i.e.
+/// after any presolve reduction of the original constraints.
+#[derive(Debug, Clone)]
+pub struct ScaledSolution<T> {
+    pub x: Vec<T>,
+    pub z: Vec<T>,
+    pub s: Vec<T>,
+    pub τ: T,
+    pub κ: T,
+}
+
+/// Borrowed view of a [`DefaultSolution`], giving access to the solution
+/// vectors without copying them.
+
+#[derive(Debug, Clone, Copy)]
+pub struct SolutionView<'a, T> {
+    pub x: &'a [T],
+    pub z: &'a [T],
+    pub s: &'a [T],
+    pub status: SolverStatus,
+    pub obj_val: T,
+    pub obj_val_dual: T,
+    pub solve_time: f64,
+    pub iterations: u32,
+    pub r_prim: T,
+    pub r_dual: T,
+}
+
+impl<'a, T> From<&'a DefaultSolution<T>> for SolutionView<'a, T>
+where
+    T: FloatT,
+{
+    fn from(solution: &'a DefaultSolution<T>) -> Self {
+        Self {
+            x: &solution.x,
+            z: &solution.z,
+            s: &solution.s,
+            status: solution.status,
+            obj_val: solution.obj_val,
+            obj_val_dual: solution.obj_val_dual,
+            solve_time: solution.solve_time,
+            iterations: solution.iterations,
+            r_prim: solution.r_prim,
+            r_dual: solution.r_dual,
+        }
+    }
+}
+
+/// Certificate of infeasibility recovered from a [`DefaultSolution`].
+
+#[derive(Debug, Clone)]
+pub enum Certificate<T> {
+    /// A vector `z` in the dual cone with `Aᵀz = 0` and `bᵀz < 0`,
+    /// proving that the primal problem is infeasible.
+    PrimalInfeasible { z: Vec<T> },
+    /// A vector `x` with `-Ax` in the cone, `Px = 0` and `qᵀx < 0`,
+    /// proving that the dual problem is infeasible, i.e. that the
+    /// primal problem is unbounded.
+    DualInfeasible { x: Vec<T> },
+}
+
+impl<T> Certificate<T>
+where
+    T: FloatT,
+{
+    /// Checks the certificate conditions against the problem data
+    /// up to a tolerance `tol`, e.g. the `tol_infeas_abs` setting.
+    ///
+    /// A primal certificate is accepted if `bᵀz < -tol` and
+    /// `‖Aᵀz‖∞ ≤ tol |bᵀz|`.  A dual certificate is accepted if
+    /// `qᵀx < -tol` and `‖Px‖∞ ≤ tol |qᵀx|`.   Membership of `z`
+    /// or `-Ax` in the appropriate cone is not checked.
+    ///
+    /// `P` should be upper triangular, as for the solver itself.
+    pub fn is_valid(&self, P: &CscMatrix<T>, q: &[T], A: &CscMatrix<T>, b: &[T], tol: T) -> bool {
+        match self {
+            Certificate::PrimalInfeasible { z } => {
+                let bz = b.dot(z);
+                let mut Atz = vec![T::zero(); A.n];
+                A.gemv(&mut Atz, z, T::one(), T::zero(), MatrixShape::T);
+                bz < -tol && Atz.norm_inf() <= tol * T::abs(bz)
+            }
+            Certificate::DualInfeasible { x } => {
+                let qx = q.dot(x);
+                let mut Px = vec![T::zero(); P.n];
+                P.sym().symv(&mut Px, x, T::one(), T::zero());
+                qx < -tol && Px.norm_inf() <= tol * T::abs(qx)
+            }
         }
     }
 }
@@ -58,6 +384,7 @@ where
         data: &DefaultProblemData<T>,
         variables: &DefaultVariables<T>,
         info: &DefaultInfo<T>,
+        settings: &DefaultSettings<T>,
     ) {
         self.status = info.status;
         self.obj_val = info.cost_primal;
@@ -75,47 +402,137 @@ where
             scaleinv = T::recip(variables.τ);
         }
 
-        // also undo the equilibration
-        let d = &data.equilibration.d;
-        let (e, einv) = (&data.equilibration.e, &data.equilibration.einv);
-        let cscale = data.equilibration.c;
-
-        self.x.copy_from(&variables.x).hadamard(d).scale(scaleinv);
-
-        if let Some(map) = data.presolver.reduce_map.as_ref() {
-            //
-
-            for (&zi, &si, &ei, &einvi, &mapi) in
-                izip!(&variables.z, &variables.s, e, einv, &map.keep_index)
-            {
-                self.z[mapi] = zi * ei * (scaleinv / cscale);
-                self.s[mapi] = si * einvi * scaleinv;
-            }
+        unscale_iterate(
+            data,
+            variables,
+            scaleinv,
+            &mut self.x,
+            &mut self.s,
+            &mut self.z,
+        );
 
-            // eliminated constraints get huge slacks
-            // and are assumed to be nonbinding
-            let infbound = data.presolver.infbound.as_T();
-            let sz = zip(&mut self.s, &mut self.z);
-            zip(sz, &map.keep_logical).for_each(|((si, zi), b)| {
-                if !b {
-                    *si = infbound;
-                    *zi = T::zero();
-                }
-            });
-        } else {
-            self.z
-                .copy_from(&variables.z)
-                .hadamard(e)
-                .scale(scaleinv / cscale);
-            self.s
-                .copy_from(&variables.s)
-                .hadamard(einv)
-                .scale(scaleinv);
+        // the history ends with the final iterate, as returned
+        if settings.save_iterates {
+            self.push_iterate(self.x.clone(), self.z.clone(), self.s.clone(), settings);
         }
 
         self.iterations = info.iterations;
         self.solve_time = info.solve_time;
         self.r_prim = info.res_primal;
         self.r_dual = info.res_dual;
+        self.total_refinement_iters = info.total_refinement_iters;
+        self.final_refinement_residual = info.final_refinement_residual;
+        self.equilibration_iters = info.equilibration_iters;
+        self.mu_hist.clone_from(&info.mu_history);
+        self.step_length_primal = info.step_length_primal;
+        self.step_length_dual = info.step_length_dual;
+
+        self.scaled = None;
+        if settings.return_scaled {
+            self.scaled = Some(ScaledSolution {
+                x: variables.x.clone(),
+                z: variables.z.clone(),
+                s: variables.s.clone(),
+                τ: variables.τ,
+                κ: variables.κ,
+            });
+        }
+    }
+
+    fn reset(&mut self) {
+        self.xhist.clear();
+        self.zhist.clear();
+        self.shist.clear();
+    }
+
+    fn save_prev_iterate(
+        &mut self,
+        data: &DefaultProblemData<T>,
+        variables: &DefaultVariables<T>,
+        settings: &DefaultSettings<T>,
+    ) {
+        if !settings.save_iterates {
+            return;
+        }
+
+        let (m, n) = (self.s.len(), self.x.len());
+        let (mut x, mut z, mut s) = (vec![T::zero(); n], vec![T::zero(); m], vec![T::zero(); m]);
+        let scaleinv = T::recip(variables.τ);
+        unscale_iterate(data, variables, scaleinv, &mut x, &mut s, &mut z);
+
+        self.push_iterate(x, z, s, settings);
     }
 }
+
+// Map the internal variables back to the original problem, undoing
+// the equilibration and any presolve reduction or complex PSD cone expansion.
+
+fn unscale_iterate<T: FloatT>(
+    data: &DefaultProblemData<T>,
+    variables: &DefaultVariables<T>,
+    scaleinv: T,
+    x: &mut [T],
+    s_out: &mut [T],
+    z_out: &mut [T],
+) {
+    // undo the equilibration
+    let d = &data.equilibration.d;
+    let (e, einv) = (&data.equilibration.e, &data.equilibration.einv);
+    let cscale = data.equilibration.c;
+
+    x.copy_from(&variables.x).hadamard(d).scale(scaleinv);
+
+    // slacks and duals for the expanded constraints, if there are
+    // complex PSD cones, and otherwise for the original constraints
+    let presolver = &data.presolver;
+    let (mut sexp, mut zexp) = (Vec::new(), Vec::new());
+    let (s, z) = {
+        if presolver.is_expanded() {
+            sexp.resize(presolver.mexpanded, T::zero());
+            zexp.resize(presolver.mexpanded, T::zero());
+            (&mut sexp[..], &mut zexp[..])
+        } else {
+            (&mut *s_out, &mut *z_out)
+        }
+    };
+
+    if let Some(map) = presolver.reduce_map.as_ref() {
+        //
+
+        for (&zi, &si, &ei, &einvi, &mapi) in
+            izip!(&variables.z, &variables.s, e, einv, &map.keep_index)
+        {
+            z[mapi] = zi * ei * (scaleinv / cscale);
+            s[mapi] = si * einvi * scaleinv;
+        }
+
+        // eliminated constraints get huge slacks
+        // and are assumed to be nonbinding
+        let infbound = presolver.infbound.as_T();
+        let sz = zip(&mut *s, &mut *z);
+        zip(sz, &map.keep_logical).for_each(|((si, zi), b)| {
+            if !b {
+                *si = infbound;
+                *zi = T::zero();
+            }
+        });
+    } else {
+        z.copy_from(&variables.z)
+            .hadamard(e)
+            .scale(scaleinv / cscale);
+        s.copy_from(&variables.s).hadamard(einv).scale(scaleinv);
+    }
+
+    if let Some(map) = presolver.expand_map.as_ref() {
+        let keep = presolver
+            .reduce_map
+            .as_ref()
+            .map(|map| &map.keep_logical[..]);
+        let infbound = presolver.infbound.as_T();
+        map.collapse(s_out, z_out, &sexp, &zexp, keep, infbound);
+    }
+}
+
+fn _split_by_cone<'a, T>(v: &'a [T], offsets: &[usize]) -> Vec<&'a [T]> {
+    offsets.windows(2).map(|w| &v[w[0]..w[1]]).collect()
+}
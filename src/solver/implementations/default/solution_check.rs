@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+use super::*;
+use crate::algebra::*;
+use crate::solver::core::cones::{CompositeCone, PrimalOrDualCone};
+use std::iter::zip;
+
+/// Residuals of the optimality conditions at a given point, as returned
+/// by [`DefaultSolver::check_solution`].
+///
+/// All terms are for the unscaled problem data.   Constraint terms are for
+/// the constraints as solved, i.e. after any presolve reduction or expansion
+/// of complex PSD cones, and match the user's constraint rows otherwise.  See [`PresolveReport`] for the mapping between the two.
+#[derive(Debug, Clone)]
+pub struct ResidualReport<T> {
+    /// primal residual `Ax + s - b`
+    pub primal: Vec<T>,
+    /// dual residual `Px + q + Aᵀz`
+    pub dual: Vec<T>,
+    /// elementwise complementarity `s ∘ z`
+    pub complementarity: Vec<T>,
+    /// violation of primal cone membership by `s`, for each cone
+    pub cone_violation_s: Vec<T>,
+    /// violation of dual cone membership by `z`, for each cone
+    pub cone_violation_z: Vec<T>,
+}
+
+impl<T> ResidualReport<T>
+where
+    T: FloatT,
+{
+    /// Largest magnitude of the primal residual.
+    pub fn primal_norm_inf(&self) -> T {
+        self.primal.norm_inf()
+    }
+    /// Largest magnitude of the dual residual.
+    pub fn dual_norm_inf(&self) -> T {
+        self.dual.norm_inf()
+    }
+    /// Total complementarity `sᵀz`.
+    pub fn gap(&self) -> T {
+        self.complementarity.sum()
+    }
+    /// Largest violation of primal or dual cone membership.
+    pub fn cone_violation(&self) -> T {
+        T::max(
+            self.cone_violation_s.norm_inf(),
+            self.cone_violation_z.norm_inf(),
+        )
+    }
+}
+
+impl<T> DefaultSolver<T>
+where
+    T: FloatT,
+{
+    /// Evaluates the optimality conditions at an arbitrary point `(x, s, z)`.
+    ///
+    /// `x` has one entry per variable, and `s` and `z` one entry per row of
+    /// the constraints supplied by the user, as in [`DefaultSolution`].
+    /// Cone membership violations are measured as the smallest `t ≥ 0` for
+    /// which the shift `v + t⋅e` along the central point `e` of the cone is
+    /// in the cone.   For the zero cone, the violation for `s` is its largest
+    /// magnitude entry.   See [`ResidualReport`] for the other terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the vectors have the wrong dimension.
+    pub fn check_solution(&self, x: &[T], s: &[T], z: &[T]) -> ResidualReport<T> {
+        let data = &self.data;
+        let mfull = data.presolver.mfull;
+        assert!(x.len() == data.n, "x has incompatible dimension.");
+        assert!(s.len() == mfull, "s has incompatible dimension.");
+        assert!(z.len() == mfull, "z has incompatible dimension.");
+
+        let (s, z) = self.presolved_iterate(s, z);
+
+        // the stored data is equilibrated as cDPD, cDq, EAD and Eb
+        let equil = &data.equilibration;
+        let (dinv, einv) = (&equil.dinv, &equil.einv);
+        let cinv = T::recip(equil.c);
+
+        let mut xs = x.to_vec();
+        xs.hadamard(dinv);
+
+        // primal = Ax + s - b
+        let mut primal = vec![T::zero(); data.m];
+        data.A
+            .gemv(&mut primal, &xs, T::one(), T::zero(), MatrixShape::N);
+        primal.axpby(-T::one(), &data.b, T::one()).hadamard(einv);
+        primal.axpby(T::one(), &s, T::one());
+
+        // dual = Px + q + Aᵀz
+        let mut zs = z.clone();
+        zs.hadamard(einv);
+        let mut dual = data.q.clone();
+        data.P.sym().symv(&mut dual, &xs, T::one(), T::one());
+        dual.scale(cinv);
+        data.A
+            .gemv(&mut dual, &zs, T::one(), T::one(), MatrixShape::T);
+        dual.hadamard(dinv);
+
+        let complementarity = zip(&s, &z).map(|(&si, &zi)| si * zi).collect();
+
+        let mut cones = CompositeCone::<T>::new(&data.presolver.cone_specs);
+        let cone_violation_s = cones.membership_violations(&s, PrimalOrDualCone::PrimalCone);
+        let cone_violation_z = cones.membership_violations(&z, PrimalOrDualCone::DualCone);
+
+        ResidualReport {
+            primal,
+            dual,
+            complementarity,
+            cone_violation_s,
+            cone_violation_z,
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use super::*;
 use crate::solver::core::{
-    cones::{CompositeCone, SupportedConeT},
-    traits::ProblemData,
-    Solver,
+    cones::{validate_cones, CompositeCone, ConeError, SupportedConeT},
+    kktsolvers::{direct::predicted_kkt_nnz, KKTInfo},
+    traits::{ProblemData, Settings},
+    IPSolver, SolveError, Solver, SolverStatus,
 };
 
 use crate::algebra::*;
 use crate::timers::*;
+use std::collections::HashMap;
+use thiserror::Error;
 
 /// Solver for problems in standard conic program form
 
@@ -21,10 +24,27 @@ pub type DefaultSolver<T = f64> = Solver<
     DefaultSettings<T>,
 >;
 
+/// Error type returned by [`DefaultSolver::try_new`] when the problem
+/// data can not be used to construct a solver.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SetupError {
+    #[error("{0}")]
+    IncompatibleDimension(&'static str),
+    #[error("{0}")]
+    BadCones(#[from] ConeError),
+    #[error("{0}")]
+    BadData(#[from] DataError),
+}
+
 impl<T> DefaultSolver<T>
 where
     T: FloatT,
 {
+    /// Creates a solver for the given problem data.
+    ///
+    /// Panics if the problem data has incompatible dimensions, invalid cones or
+    /// non-finite values.  See [`try_new`](Self::try_new) for a version returning
+    /// an error.
     pub fn new(
         P: &CscMatrix<T>,
         q: &[T],
@@ -33,48 +53,114 @@ where
         cone_specs: &[SupportedConeT<T>],
         settings: DefaultSettings<T>,
     ) -> Self {
-        //sanity check problem dimensions
-        _check_dimensions(P, q, A, b, cone_specs);
+        let result = Self::new_inner(P, q, A, b, cone_specs, settings, None, None);
+        _unwrap_setup(result)
+    }
+
+    /// Creates a solver for the given problem data, returning an error
+    /// rather than panicking if the data is invalid.
+    pub fn try_new(
+        P: &CscMatrix<T>,
+        q: &[T],
+        A: &CscMatrix<T>,
+        b: &[T],
+        cone_specs: &[SupportedConeT<T>],
+        settings: DefaultSettings<T>,
+    ) -> Result<Self, SetupError> {
+        Self::new_inner(P, q, A, b, cone_specs, settings, None, None)
+    }
+
+    /// Creates a solver that uses a custom [`KKTSolver`](crate::solver::KKTSolver)
+    /// for the linear systems solved at each iteration, in place of the one
+    /// selected by the `direct_kkt_solver` and `direct_solve_method` settings.
+    ///
+    /// The solver is created by calling `kkt_factory` once during setup.
+    /// See [`KKTSolverFactory`] for the data passed to it.
+    pub fn new_with_kkt_solver(
+        P: &CscMatrix<T>,
+        q: &[T],
+        A: &CscMatrix<T>,
+        b: &[T],
+        cone_specs: &[SupportedConeT<T>],
+        settings: DefaultSettings<T>,
+        kkt_factory: KKTSolverFactory<T>,
+    ) -> Self {
+        let result = Self::new_inner(P, q, A, b, cone_specs, settings, Some(kkt_factory), None);
+        _unwrap_setup(result)
+    }
+
+    /// Creates a solver whose timers take their times from `time_source`
+    /// rather than from [`std::time::Instant`].
+    ///
+    /// All reported times, including `solve_time` and the `time_limit`
+    /// check, are then measured with `time_source`.   It is not used when
+    /// the `collect_timings` setting is disabled.
+    pub fn new_with_time_source(
+        P: &CscMatrix<T>,
+        q: &[T],
+        A: &CscMatrix<T>,
+        b: &[T],
+        cone_specs: &[SupportedConeT<T>],
+        settings: DefaultSettings<T>,
+        time_source: Box<dyn TimeSource>,
+    ) -> Self {
+        let result = Self::new_inner(P, q, A, b, cone_specs, settings, None, Some(time_source));
+        _unwrap_setup(result)
+    }
 
-        let mut timers = Timers::default();
+    #[allow(clippy::too_many_arguments)]
+    fn new_inner(
+        P: &CscMatrix<T>,
+        q: &[T],
+        A: &CscMatrix<T>,
+        b: &[T],
+        cone_specs: &[SupportedConeT<T>],
+        mut settings: DefaultSettings<T>,
+        kkt_factory: Option<KKTSolverFactory<T>>,
+        time_source: Option<Box<dyn TimeSource>>,
+    ) -> Result<Self, SetupError> {
+        //sanity check problem dimensions and settings
+        _check_dimensions(P, q, A, b, cone_specs)?;
+        _check_settings(&settings);
+        settings.validate();
+
+        let mut timers = {
+            if settings.collect_timings {
+                match time_source {
+                    Some(clock) => Timers::with_time_source(clock),
+                    None => Timers::default(),
+                }
+            } else {
+                Timers::disabled()
+            }
+        };
         let mut output;
-        let info = DefaultInfo::<T>::new();
+        let mut info = DefaultInfo::<T>::new();
 
         timeit! {timers => "setup"; {
 
-        // reduce the cone sizes.  (A,b) will be reduced
-        // within the problem data constructor.  Also makes
-        // an internal copy of the user cone specification
-        let presolver = Presolver::<T>::new(A,b,cone_specs,&settings);
-
-        let cones = CompositeCone::<T>::new(&presolver.cone_specs);
-        let mut data = DefaultProblemData::<T>::new(P,q,A,b,presolver);
+        let (mut data, cones, kktsystem, setup_failure) =
+            _setup_problem(P, q, A, b, cone_specs, &settings, kkt_factory, None, &mut timers);
+        info.save_equilibration(&data.equilibration);
+        info.save_data_norms(&mut data);
 
         let variables = DefaultVariables::<T>::new(data.n,data.m);
         let residuals = DefaultResiduals::<T>::new(data.n,data.m);
 
-        // equilibrate problem data immediately on setup.
-        // this prevents multiple equlibrations if solve!
-        // is called more than once.
-        timeit!{timers => "equilibration"; {
-            data.equilibrate(&cones,&settings);
-        }}
-
-        let kktsystem;
-        timeit!{timers => "kktinit"; {
-            kktsystem = DefaultKKTSystem::<T>::new(&data,&cones,&settings);
-        }}
-
         // work variables for assembling step direction LHS/RHS
         let step_rhs  = DefaultVariables::<T>::new(data.n,data.m);
         let step_lhs  = DefaultVariables::<T>::new(data.n,data.m);
         let prev_vars = DefaultVariables::<T>::new(data.n,data.m);
+        let step_work = DefaultVariables::<T>::new(data.n,data.m);
 
         // user facing results go here.
-        let solution = DefaultSolution::<T>::new(data.presolver.mfull,data.n);
+        let mut solution = DefaultSolution::<T>::new(data.presolver.mfull,data.n);
+        solution.set_cone_offsets(cone_specs);
 
         output = Self{data,variables,residuals,kktsystem,step_lhs,
-             step_rhs,prev_vars,info,solution,cones,settings,timers: None};
+             step_rhs,prev_vars,step_work,info,solution,cones,settings,timers: None,
+             iteration_callback: None, progress_observer: None, setup_failure,
+             solve_state: None};
 
         }} //end "setup" timer.
 
@@ -82,27 +168,371 @@ where
         //timer object into the solver structure
         output.timers.replace(timers);
 
-        output
+        Ok(output)
+    }
+
+    /// Rebuilds the solver in place for a new problem, which may have
+    /// different dimensions and cones from the original one.
+    ///
+    /// All setup steps of [`new`](Self::new) are repeated for the new data,
+    /// and the results are as for a newly constructed solver.  The vectors
+    /// holding the iterates, step directions, residuals and solution are
+    /// resized in place, reusing their existing allocations.  The problem
+    /// data, cones and KKT system are always rebuilt, with the KKT solver
+    /// selected by `settings`.  If `settings.direct_solve_cache_symbolic` is
+    /// set and the new KKT matrix has the same sparsity pattern as the
+    /// current one, its fill-reducing ordering is reused rather than
+    /// recomputed, which gives identical results.
+    ///
+    /// Any iteration callback, progress receiver and time source are kept.
+    /// Metadata is cleared, since it describes the original problem.
+    pub fn rebuild(
+        &mut self,
+        P: &CscMatrix<T>,
+        q: &[T],
+        A: &CscMatrix<T>,
+        b: &[T],
+        cone_specs: &[SupportedConeT<T>],
+        mut settings: DefaultSettings<T>,
+    ) {
+        //sanity check problem dimensions and settings
+        _unwrap_setup(_check_dimensions(P, q, A, b, cone_specs));
+        _check_settings(&settings);
+        settings.validate();
+
+        // keep the existing timers, and so any custom time
+        // source, unless timing has been switched on or off
+        let mut timers = match self.timers.take() {
+            Some(mut timers) if settings.collect_timings && !timers.is_disabled() => {
+                timers.reset_timer("setup");
+                timers
+            }
+            _ if settings.collect_timings => Timers::default(),
+            _ => Timers::disabled(),
+        };
+
+        timeit! {timers => "setup"; {
+
+        // the ordering of the current KKT matrix can be reused
+        // if the new one has the same sparsity pattern
+        let previous = if settings.direct_solve_cache_symbolic {
+            Some(&self.kktsystem)
+        } else {
+            None
+        };
+
+        let (data, cones, kktsystem, setup_failure) =
+            _setup_problem(P, q, A, b, cone_specs, &settings, None, previous, &mut timers);
+        let (n, m) = (data.n, data.m);
+
+        self.variables.resize(n, m);
+        self.residuals.resize(n, m);
+        self.step_rhs.resize(n, m);
+        self.step_lhs.resize(n, m);
+        self.prev_vars.resize(n, m);
+        self.step_work.resize(n, m);
+        self.solution.reset(data.presolver.mfull, n);
+        self.solution.set_cone_offsets(cone_specs);
+
+        self.data = data;
+        self.cones = cones;
+        self.kktsystem = kktsystem;
+        self.setup_failure = setup_failure;
+        self.solve_state = None;
+        self.info = DefaultInfo::<T>::new();
+        self.info.save_equilibration(&self.data.equilibration);
+        self.info.save_data_norms(&mut self.data);
+        self.settings = settings;
+
+        }} //end "setup" timer.
+
+        self.timers.replace(timers);
+    }
+
+    /// Creates an independent copy of the solver, e.g. to solve several
+    /// variations of a problem from a common setup.
+    ///
+    /// The copy has its own problem data, cones, settings, iterates and
+    /// KKT factorization, so that the two solvers can be updated with
+    /// [`update_b`](Self::update_b) and similar, and solved separately.
+    /// Metadata and the most recent solution are copied.  Any iteration
+    /// callback and progress receiver are not, and the copy takes its times
+    /// from [`std::time::Instant`] whatever the time source of the original.
+    ///
+    /// Returns `None` if the solver uses a custom KKT solver that does not
+    /// support copying.   See [`KKTSolver::try_clone`](crate::solver::KKTSolver::try_clone).
+    pub fn fork(&self) -> Option<Self> {
+        let kktsystem = self.kktsystem.try_clone()?;
+
+        let timers = match &self.timers {
+            Some(timers) if !timers.is_disabled() => Timers::default(),
+            _ => Timers::disabled(),
+        };
+
+        Some(Self {
+            data: self.data.clone(),
+            variables: self.variables.clone(),
+            residuals: self.residuals.clone(),
+            kktsystem,
+            cones: self.cones.clone(),
+            step_lhs: self.step_lhs.clone(),
+            step_rhs: self.step_rhs.clone(),
+            prev_vars: self.prev_vars.clone(),
+            step_work: self.step_work.clone(),
+            info: self.info.clone(),
+            solution: self.solution.clone(),
+            settings: self.settings.clone(),
+            timers: Some(timers),
+            iteration_callback: None,
+            progress_observer: None,
+            setup_failure: self.setup_failure,
+            solve_state: self.solve_state,
+        })
+    }
+
+    /// Solves the problem as for [`solve`](IPSolver::solve), returning the
+    /// solution if the solver terminated normally and an error otherwise.
+    ///
+    /// Any status other than `NumericalError`, `InsufficientProgress` or
+    /// `InsufficientMemory` gives `Ok`, including infeasibility and iteration or
+    /// time limits.   Callers should still check the status of the solution
+    /// in that case.   The solution is also available in `self.solution`
+    /// after an error.
+    pub fn try_solve(&mut self) -> Result<&DefaultSolution<T>, SolveError> {
+        self.solve();
+
+        match self.solution.status {
+            SolverStatus::NumericalError => Err(SolveError::NumericalError),
+            SolverStatus::InsufficientProgress => Err(SolveError::InsufficientProgress),
+            SolverStatus::InsufficientMemory => Err(SolveError::InsufficientMemory),
+            _ => Ok(&self.solution),
+        }
+    }
+
+    /// Summary information about the KKT system and its factorization,
+    /// e.g. the number of nonzeros in the KKT matrix and its factors.
+    pub fn kkt_info(&self) -> KKTInfo {
+        self.kktsystem.kkt_info()
+    }
+
+    /// Equilibration scaling applied to the problem data.
+    pub fn equilibration(&self) -> EquilibrationView<'_, T> {
+        EquilibrationView::from(&self.data.equilibration)
+    }
+
+    /// Scaling `c` applied to the objective, i.e. to both `P` and `q`,
+    /// during setup.   This is the `objective_scaling` setting if one was
+    /// given, and is otherwise chosen during equilibration.
+    pub fn objective_scaling(&self) -> T {
+        self.data.equilibration.c
+    }
+
+    /// Borrowed view of the most recent solution.   Unlike the `solution`
+    /// field itself, the view can be held without cloning any of the
+    /// solution vectors, e.g. to copy them into existing buffers.
+    pub fn solution_view(&self) -> SolutionView<'_, T> {
+        SolutionView::from(&self.solution)
+    }
+
+    /// Summary of the constraints removed by the presolver, or `None`
+    /// if the setting `presolve_enable` is false.
+    pub fn presolve_report(&self) -> Option<PresolveReport> {
+        if self.settings.presolve_enable {
+            Some(self.data.presolver.report())
+        } else {
+            None
+        }
+    }
+
+    /// The settings in use by the solver, i.e. those supplied at
+    /// construction after adjustment by
+    /// [`DefaultSettings::validate`](crate::solver::DefaultSettings::validate).
+    pub fn effective_settings(&self) -> &DefaultSettings<T> {
+        &self.settings
+    }
+
+    /// Dimensions and nonzero counts of the problem, as shown in the
+    /// problem summary printed when `verbose` is set.
+    pub fn problem_dimensions(&self) -> ProblemDimensions {
+        ProblemDimensions {
+            n: self.data.n,
+            m: self.data.m,
+            nnz_P: self.data.P.nnz(),
+            nnz_A: self.data.A.nnz(),
+            cone_degree: self.cones.degree(),
+        }
+    }
+
+    /// Attach a descriptive entry, e.g. a problem name, description or
+    /// units, to the problem.   Entries are saved along with the problem
+    /// data when writing to a file.   Replaces any existing entry with the
+    /// same key.
+    pub fn set_metadata(&mut self, key: &str, value: &str) {
+        self.data
+            .metadata
+            .insert(key.to_string(), value.to_string());
+    }
+
+    /// Descriptive entries attached to the problem.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.data.metadata
+    }
+
+    /// Primal and dual objective values at the most recent iterate.
+    /// Both values are NaN before the first iteration.
+    pub fn current_objective(&self) -> (T, T) {
+        self.info.current_objective()
+    }
+
+    /// Primal and dual relative residuals at the most recent iterate.
+    /// Both values are NaN before the first iteration.
+    ///
+    /// From within an iteration callback, use the equivalent method
+    /// of the [`DefaultInfo`] passed to the callback.
+    pub fn current_residuals(&self) -> (T, T) {
+        self.info.current_residuals()
+    }
+
+    /// Normalized complementarity μ at the initial point and at each
+    /// iteration of the most recent solve, collected when the
+    /// `collect_mu_history` setting is enabled and empty otherwise.
+    pub fn mu_history(&self) -> &[T] {
+        &self.info.mu_history
+    }
+
+    /// The upper triangular part of the assembled KKT matrix, formed from
+    /// the internally equilibrated problem data.
+    ///
+    /// Before solving this holds the matrix as initially assembled.  The
+    /// entries of the cone scaling blocks are updated at every iteration, so
+    /// after solving the matrix reflects the scalings from the final iterate.
+    /// Static regularization is not included.
+    pub fn kkt_matrix(&self) -> &CscMatrix<T> {
+        self.kktsystem.kkt_matrix()
+    }
+
+    /// The fill-reducing ordering of [`kkt_matrix`](Self::kkt_matrix) used
+    /// by the direct LDL solver, i.e. the KKT matrix is factored with its
+    /// rows and columns in the order `perm[0], perm[1], ...`.  `None` if the
+    /// KKT solver does not factor the KKT matrix.
+    ///
+    /// A different ordering can be supplied through a custom KKT solver
+    /// made by [`DirectLDLKKTSolver::new_with_permutation`](crate::solver::DirectLDLKKTSolver::new_with_permutation).
+    pub fn kkt_ordering(&self) -> Option<&[usize]> {
+        self.kktsystem.kkt_ordering()
+    }
+
+    /// The cones of the problem as constructed within the solver.
+    ///
+    /// These reflect any presolve reduction or expansion of complex PSD
+    /// cones into real PSD cones, and so may differ from those supplied by
+    /// the user.
+    pub fn cones(&self) -> &CompositeCone<T> {
+        &self.cones
     }
 }
 
+// setup steps that depend on the problem data and cones, shared by
+// solver construction and rebuild.   Returns the problem data, cones,
+// KKT system and any status to report if setup could not be completed
+#[allow(clippy::too_many_arguments)]
+fn _setup_problem<T: FloatT>(
+    P: &CscMatrix<T>,
+    q: &[T],
+    A: &CscMatrix<T>,
+    b: &[T],
+    cone_specs: &[SupportedConeT<T>],
+    settings: &DefaultSettings<T>,
+    kkt_factory: Option<KKTSolverFactory<T>>,
+    previous: Option<&DefaultKKTSystem<T>>,
+    timers: &mut Timers,
+) -> (
+    DefaultProblemData<T>,
+    CompositeCone<T>,
+    DefaultKKTSystem<T>,
+    Option<SolverStatus>,
+) {
+    // reduce the cone sizes.  (A,b) will be reduced
+    // within the problem data constructor.  Also makes
+    // an internal copy of the user cone specification
+    let presolver = Presolver::<T>::new(A, b, cone_specs, settings);
+
+    let cones = CompositeCone::<T>::new(&presolver.cone_specs);
+    let mut data = DefaultProblemData::<T>::new(P, q, A, b, presolver);
+
+    // equilibrate problem data immediately on setup.
+    // this prevents multiple equlibrations if solve!
+    // is called more than once.
+    timeit! {timers => "equilibration"; {
+        data.equilibrate(&cones,settings);
+    }}
+
+    // check the size of the KKT matrix before allocating it
+    let kkt_too_large = settings.max_kkt_nnz.map_or(false, |max| {
+        predicted_kkt_nnz(&data.P, &data.A, &cones) > max
+    });
+    let setup_failure = if kkt_too_large {
+        Some(SolverStatus::InsufficientMemory)
+    } else {
+        None
+    };
+
+    let kktsystem;
+    timeit! {timers => "kktinit"; {
+        kktsystem = match kkt_factory {
+            _ if kkt_too_large => DefaultKKTSystem::<T>::new_unassembled(&data),
+            Some(factory) => {
+                let kktsolver = factory(&data.P,&data.A,&cones,settings.core());
+                DefaultKKTSystem::<T>::new_with_kkt_solver(&data,kktsolver)
+            }
+            None => DefaultKKTSystem::<T>::new_reusing_ordering(&data,&cones,settings,previous),
+        };
+    }}
+
+    (data, cones, kktsystem, setup_failure)
+}
+
 fn _check_dimensions<T: FloatT>(
     P: &CscMatrix<T>,
     q: &[T],
     A: &CscMatrix<T>,
     b: &[T],
     cone_types: &[SupportedConeT<T>],
-) {
+) -> Result<(), SetupError> {
     let m = b.len();
     let n = q.len();
-    let p = cone_types.iter().fold(0, |acc, cone| acc + cone.nvars());
-
-    assert!(m == A.nrows(), "A and b incompatible dimensions.");
-    assert!(
-        p == m,
-        "Constraint dimensions inconsistent with size of cones."
-    );
-    assert!(n == A.ncols(), "A and q incompatible dimensions.");
-    assert!(n == P.ncols(), "P and q incompatible dimensions.");
-    assert!(P.is_square(), "P not square.");
+
+    let check = |ok: bool, msg| {
+        if ok {
+            Ok(())
+        } else {
+            Err(SetupError::IncompatibleDimension(msg))
+        }
+    };
+
+    check(m == A.nrows(), "A and b incompatible dimensions.")?;
+    check(n == A.ncols(), "A and q incompatible dimensions.")?;
+    validate_cones(cone_types, m)?;
+    check(n == P.ncols(), "P and q incompatible dimensions.")?;
+    check(P.is_square(), "P not square.")?;
+    validate_data(P, q, A, b)?;
+    Ok(())
+}
+
+// panics with the message of a setup error, for the
+// constructors that do not return one
+fn _unwrap_setup<T>(result: Result<T, SetupError>) -> T {
+    match result {
+        Ok(out) => out,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn _check_settings<T: FloatT>(settings: &DefaultSettings<T>) {
+    if let Some(c) = settings.objective_scaling {
+        assert!(
+            c > T::zero() && c.is_finite(),
+            "objective_scaling must be positive and finite."
+        );
+    }
 }
@@ -3,7 +3,7 @@ use crate::algebra::*;
 use crate::solver::core::{
     cones::{CompositeCone, Cone, PrimalOrDualCone},
     traits::{Settings, Variables},
-    ScalingStrategy, StepDirection,
+    ScalingStrategy, StepDirection, WarmStartMode,
 };
 
 // ---------------
@@ -11,6 +11,7 @@ use crate::solver::core::{
 // ---------------
 
 /// Standard-form solver type implementing the [`Variables`](crate::solver::core::traits::Variables) trait
+#[derive(Clone)]
 pub struct DefaultVariables<T> {
     /// scaled primal variables
     pub x: Vec<T>,
@@ -47,6 +48,20 @@ where
 
         Self { x, s, z, τ, κ }
     }
+
+    // resets to the state given by `new`, reusing existing allocations
+    pub(crate) fn resize(&mut self, n: usize, m: usize) {
+        _resize_zeroed(&mut self.x, n);
+        _resize_zeroed(&mut self.s, m);
+        _resize_zeroed(&mut self.z, m);
+        self.τ = T::one();
+        self.κ = T::one();
+    }
+}
+
+pub(crate) fn _resize_zeroed<T: FloatT>(v: &mut Vec<T>, len: usize) {
+    v.clear();
+    v.resize(len, T::zero());
 }
 
 impl<T> Variables<T> for DefaultVariables<T>
@@ -119,7 +134,7 @@ where
         cones: &mut CompositeCone<T>,
         settings: &DefaultSettings<T>,
         step_direction: StepDirection,
-    ) -> T {
+    ) -> (T, T) {
         let ατ = {
             if step.τ < T::zero() {
                 -self.τ / step.τ
@@ -137,19 +152,15 @@ where
         };
 
         let α = [ατ, ακ, T::one()].minimum();
-        let (αz, αs) = cones.step_length(&step.z, &step.s, &self.z, &self.s, settings.core(), α);
-
-        // itself only allows for a single maximum value.
-        // To enable split lengths, we need to also pass a
-        // tuple of limits to the step_length function of
-        // every cone
-        let mut α = T::min(αz, αs);
+        let (mut αz, mut αs) =
+            cones.step_length(&step.z, &step.s, &self.z, &self.s, settings.core(), α);
 
         if step_direction == StepDirection::Combined {
-            α *= settings.core().max_step_fraction;
+            αz *= settings.core().max_step_fraction;
+            αs *= settings.core().max_step_fraction;
         }
 
-        α
+        (αs, αz)
     }
 
     fn add_step(&mut self, step: &Self, α: T) {
@@ -161,8 +172,8 @@ where
     }
 
     fn symmetric_initialization(&mut self, cones: &mut CompositeCone<T>) {
-        _shift_to_cone_interior(&mut self.s, cones, PrimalOrDualCone::PrimalCone);
-        _shift_to_cone_interior(&mut self.z, cones, PrimalOrDualCone::DualCone);
+        _shift_to_cone_interior(&mut self.s, cones, PrimalOrDualCone::PrimalCone, T::one());
+        _shift_to_cone_interior(&mut self.z, cones, PrimalOrDualCone::DualCone, T::one());
 
         self.τ = T::one();
         self.κ = T::one();
@@ -176,6 +187,42 @@ where
         self.κ = T::one();
     }
 
+    fn warm_start(
+        &mut self,
+        guess: &Self,
+        mode: WarmStartMode,
+        λ: T,
+        cones: &mut CompositeCone<T>,
+    ) {
+        // self holds the default starting point here
+        match mode {
+            WarmStartMode::FullGuess => {
+                self.x.copy_from(&guess.x);
+                self.s.copy_from(&guess.s);
+                self.z.copy_from(&guess.z);
+            }
+            WarmStartMode::PrimalOnly => {
+                self.x.copy_from(&guess.x);
+                self.s.copy_from(&guess.s);
+            }
+            WarmStartMode::Scaled => {
+                let (a, b) = (λ, T::one() - λ);
+                self.x.axpby(a, &guess.x, b);
+                self.s.axpby(a, &guess.s, b);
+                self.z.axpby(a, &guess.z, b);
+            }
+        }
+
+        // a smaller minimum shift than for the default start,
+        // so that guesses near the boundary are not lost
+        let floor = T::epsilon().sqrt();
+        _shift_to_cone_interior(&mut self.s, cones, PrimalOrDualCone::PrimalCone, floor);
+        _shift_to_cone_interior(&mut self.z, cones, PrimalOrDualCone::DualCone, floor);
+
+        self.τ = T::one();
+        self.κ = T::one();
+    }
+
     fn copy_from(&mut self, src: &Self) {
         self.x.copy_from(&src.x);
         self.s.copy_from(&src.s);
@@ -215,6 +262,17 @@ where
         barrier
     }
 
+    fn crossover(
+        &mut self,
+        data: &DefaultProblemData<T>,
+        cones: &CompositeCone<T>,
+        settings: &DefaultSettings<T>,
+    ) {
+        if settings.crossover {
+            lp_crossover(self, data, cones, settings);
+        }
+    }
+
     fn rescale(&mut self) {
         let scale = T::max(self.τ, self.κ);
         let invscale = scale.recip();
@@ -227,15 +285,16 @@ where
     }
 }
 
-fn _shift_to_cone_interior<T>(z: &mut [T], cones: &mut CompositeCone<T>, pd: PrimalOrDualCone)
-where
+fn _shift_to_cone_interior<T>(
+    z: &mut [T],
+    cones: &mut CompositeCone<T>,
+    pd: PrimalOrDualCone,
+    floor: T,
+) where
     T: FloatT,
 {
     let (min_margin, pos_margin) = cones.margins(z, pd);
-    let target = T::max(
-        T::one(),
-        (pos_margin * (0.1).as_T()) / cones.degree().as_T(),
-    );
+    let target = T::max(floor, (pos_margin * (0.1).as_T()) / cones.degree().as_T());
 
     if min_margin <= T::zero() {
         // at least some component is outside its cone
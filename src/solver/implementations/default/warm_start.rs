@@ -0,0 +1,166 @@
+#![allow(non_snake_case)]
+use super::*;
+use crate::algebra::*;
+use crate::solver::core::{cones::Cone, IPSolver, SolverStatus, WarmStartError, WarmStartMode};
+
+impl<T> DefaultSolver<T>
+where
+    T: FloatT,
+{
+    /// Solve the problem starting from user-supplied guesses for the primal
+    /// variables `x`, slacks `s` and duals `z`, e.g. a solution to a previous,
+    /// closely related problem.
+    ///
+    /// The guesses are in the same (unscaled) format as the fields of
+    /// [`DefaultSolution`].  The guess `z` is ignored for [`WarmStartMode::PrimalOnly`]
+    /// and may be empty in that case.  The weight `λ` must lie in the
+    /// interval `[0,1]`, and is only used for [`WarmStartMode::Scaled`].
+    ///
+    /// Slacks and duals are shifted into the interior of the cone constraints
+    /// as needed before the solver starts.  This is only possible for symmetric
+    /// cones, so an error is returned if the problem has any exponential, power,
+    /// generalized power or box cones.
+    pub fn solve_warm(
+        &mut self,
+        x: &[T],
+        s: &[T],
+        z: &[T],
+        mode: WarmStartMode,
+        λ: T,
+    ) -> Result<(), WarmStartError> {
+        if !(λ >= T::zero() && λ <= T::one()) {
+            return Err(WarmStartError::InvalidLambda);
+        }
+        if !self.cones.is_symmetric() {
+            return Err(WarmStartError::NonsymmetricCones);
+        }
+
+        let guess = self.internal_guess(x, s, z, mode)?;
+        self.solve_from(Some((&guess, mode, λ)));
+        Ok(())
+    }
+
+    /// Solve the problem warm started from a previous solution, e.g. one
+    /// found for the same problem before an update to its data.
+    ///
+    /// This is equivalent to [`solve_warm`](Self::solve_warm) with the fields
+    /// `x`, `s` and `z` of `sol` as the guess and [`WarmStartMode::FullGuess`].
+    /// The solution vectors are equilibrated as for the problem data, so they
+    /// can be passed back unchanged.  Returns an error if `sol` has dimensions
+    /// incompatible with the problem, holds a certificate of infeasibility
+    /// rather than a solution, or if the problem has nonsymmetric cones.
+    pub fn warm_start_from(&mut self, sol: &DefaultSolution<T>) -> Result<(), WarmStartError> {
+        if sol.status.is_infeasible() {
+            return Err(WarmStartError::InfeasibleSolution);
+        }
+        self.solve_warm(&sol.x, &sol.s, &sol.z, WarmStartMode::FullGuess, T::one())
+    }
+
+    /// Solve a family of problems with constraint data `b = b0 + t*db`,
+    /// for each value of the parameter `t` in `ts` in turn.
+    ///
+    /// Each problem after the first is warm started from the solution to the
+    /// previous one, provided that it was solved and that the problem has only
+    /// symmetric cones.   Since only `b` changes, the KKT system and its symbolic
+    /// factorization are reused throughout.  As for
+    /// [`update_b`](Self::update_b), presolve must be disabled and the problem
+    /// may not contain complex PSD cones.
+    ///
+    /// Returns the solution for each value of `t`.
+    pub fn sweep_b(
+        &mut self,
+        b0: &[T],
+        db: &[T],
+        ts: &[T],
+    ) -> Result<Vec<DefaultSolution<T>>, DataUpdateError> {
+        if b0.len() != db.len() {
+            return Err(SparseFormatError::IncompatibleDimension.into());
+        }
+
+        let mut b = vec![T::zero(); b0.len()];
+        let mut solutions: Vec<DefaultSolution<T>> = Vec::with_capacity(ts.len());
+
+        for &t in ts {
+            b.waxpby(T::one(), b0, t, db);
+            self.update_b(&b)?;
+
+            match solutions.last() {
+                Some(prev) if prev.status == SolverStatus::Solved && self.cones.is_symmetric() => {
+                    // dimensions always match those of the previous solution
+                    self.warm_start_from(prev).unwrap();
+                }
+                _ => self.solve(),
+            }
+            solutions.push(self.solution.clone());
+        }
+
+        Ok(solutions)
+    }
+
+    // Maps user guesses to the solver's internal variables, reversing
+    // the presolve and equilibration steps applied to the problem data.
+    fn internal_guess(
+        &self,
+        x: &[T],
+        s: &[T],
+        z: &[T],
+        mode: WarmStartMode,
+    ) -> Result<DefaultVariables<T>, WarmStartError> {
+        let data = &self.data;
+        let presolver = &data.presolver;
+        let mfull = presolver.mfull;
+
+        let z_ok = z.len() == mfull || (mode == WarmStartMode::PrimalOnly && z.is_empty());
+        if x.len() != data.n || s.len() != mfull || !z_ok {
+            return Err(WarmStartError::IncompatibleDimension);
+        }
+
+        let z = if z.is_empty() {
+            vec![T::zero(); mfull]
+        } else {
+            z.to_vec()
+        };
+
+        let (sint, mut zint) = self.presolved_iterate(s, &z);
+
+        let equil = &data.equilibration;
+        let mut guess = DefaultVariables::<T>::new(data.n, data.m);
+        guess.x.copy_from(x).hadamard(&equil.dinv);
+        guess.s.copy_from(&sint).hadamard(&equil.e);
+        zint.hadamard(&equil.einv).scale(equil.c);
+        guess.z.copy_from(&zint);
+
+        Ok(guess)
+    }
+
+    // Maps slacks and duals for the user's constraints to those for the
+    // constraints as solved, i.e. after presolve expansion and reduction
+    pub(crate) fn presolved_iterate(&self, s: &[T], z: &[T]) -> (Vec<T>, Vec<T>) {
+        let presolver = &self.data.presolver;
+
+        let (sint, zint) = {
+            if let Some(map) = presolver.expand_map.as_ref() {
+                map.expand_iterate(s, z)
+            } else {
+                (s.to_vec(), z.to_vec())
+            }
+        };
+
+        let (mut sint, zint) = {
+            if let Some(map) = presolver.reduce_map.as_ref() {
+                (
+                    sint.select(&map.keep_logical),
+                    zint.select(&map.keep_logical),
+                )
+            } else {
+                (sint, zint)
+            }
+        };
+
+        // cap slacks at INFINITY, as for b
+        let infbound = presolver.infbound.as_T();
+        sint.scalarop(|x| T::min(x, infbound));
+
+        (sint, zint)
+    }
+}
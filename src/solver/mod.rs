@@ -25,15 +25,30 @@ pub(crate) mod utils;
 pub use crate::solver::utils::infbounds::*;
 
 //allows declaration of cone constraints
-pub use crate::solver::core::cones::{SupportedConeT, SupportedConeT::*};
+pub use crate::solver::core::cones::{
+    validate_cones, ConeError, SupportedConeT, SupportedConeT::*, SupportedConeTag,
+};
+
+//description of the cones as constructed within the solver
+pub use crate::solver::core::cones::{CompositeCone, ConeDescriptor};
 
 //user facing traits required to interact with solver
-pub use crate::solver::core::{IPSolver, SolverStatus};
+pub use crate::solver::core::{
+    CallbackAction, IPSolver, SolveError, SolverStatus, WarmStartError, WarmStartMode,
+};
 
 //user facing traits required to define new implementatiions
 pub use crate::solver::core::traits;
 pub use crate::solver::core::CoreSettings;
 
+//summary information about the KKT system
+pub use crate::solver::core::kktsolvers::KKTInfo;
+
+//linear solvers for the KKT system, for use with custom constructors
+pub use crate::solver::core::kktsolvers::{
+    direct::DirectLDLKKTSolver, indirect::IndirectMINRESKKTSolver, KKTSolver,
+};
+
 //If we have implemtations for multple alternative
 //problem formats, they would live here.   Since we
 //only have default, it is exposed at the top level
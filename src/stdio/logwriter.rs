@@ -0,0 +1,64 @@
+// Provides a Writer that emits each complete line of output
+// as a record through the log crate.
+
+use log::Level;
+use std::io::Write;
+
+// log target for all solver output
+const LOG_TARGET: &str = "clarabel";
+
+pub(crate) struct LogWriter {
+    level: Level,
+    buffer: Vec<u8>,
+}
+
+impl LogWriter {
+    pub(crate) fn new(level: Level) -> Self {
+        Self {
+            level,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn emit(&self, line: &[u8]) {
+        // blank lines are only spacing in the console output
+        if !line.is_empty() {
+            let line = String::from_utf8_lossy(line);
+            log::log!(target: LOG_TARGET, self.level, "{}", line);
+        }
+    }
+}
+
+impl Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+
+        // emit every complete line, retaining any partial one
+        while let Some(pos) = self.buffer.iter().position(|&c| c == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            self.emit(&line[..pos]);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // partial lines are held until complete, or until drop
+        Ok(())
+    }
+}
+
+impl Drop for LogWriter {
+    fn drop(&mut self) {
+        let line = std::mem::take(&mut self.buffer);
+        self.emit(&line);
+    }
+}
+
+pub(crate) fn stdout() -> LogWriter {
+    LogWriter::new(Level::Info)
+}
+
+#[allow(dead_code)]
+pub(crate) fn stderr() -> LogWriter {
+    LogWriter::new(Level::Warn)
+}
@@ -1,10 +1,19 @@
-#[cfg(not(feature = "python"))]
+#[cfg(not(any(feature = "python", feature = "logging")))]
 #[allow(unused_imports)]
 pub(crate) use std::io::{stderr, stdout};
 
 // configure python specific stdout and stdin strams
 // when compiled with the python feature.   This avoids
 // problems when running within python notebooks etc.
-#[cfg(feature = "python")]
+#[cfg(all(feature = "python", not(feature = "logging")))]
 #[allow(unused_imports)]
 pub(crate) use crate::python::io::{stderr, stdout};
+
+// route all output through the log crate when compiled
+// with the logging feature.   This takes precedence over
+// the python streams.
+#[cfg(feature = "logging")]
+mod logwriter;
+#[cfg(feature = "logging")]
+#[allow(unused_imports)]
+pub(crate) use logwriter::{stderr, stdout};
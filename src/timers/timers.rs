@@ -1,10 +1,70 @@
-use std::collections::HashMap;
+use std::fmt;
 use std::ops::{Deref, DerefMut};
-use std::time::{Duration, Instant};
+use std::time::Duration;
+
+#[cfg(feature = "std")]
+use std::{collections::HashMap as TimerMap, time::Instant};
+
+// ordered map from alloc, which needs no hasher
+#[cfg(not(feature = "std"))]
+use std::collections::BTreeMap as TimerMap;
+
+/// A source of time for solver [`Timers`].
+///
+/// Implementors return the time elapsed since some fixed reference point
+/// of their choosing.  Only differences between values returned by
+/// [`now`](TimeSource::now) are used, so the reference point can be
+/// arbitrary.  This allows the solver timers to be driven by something
+/// other than [`std::time::Instant`], e.g. on platforms without a
+/// system clock or to produce deterministic timings in tests.
+pub trait TimeSource: Send {
+    fn now(&self) -> Duration;
+}
+
+impl fmt::Debug for dyn TimeSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TimeSource")
+    }
+}
+
+/// The default [`TimeSource`], measuring time with [`std::time::Instant`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct StdTimeSource {
+    origin: Instant,
+}
+
+#[cfg(feature = "std")]
+impl Default for StdTimeSource {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TimeSource for StdTimeSource {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+// the default without the std feature, a clock that never advances
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Default, Clone, Copy)]
+struct ZeroTimeSource;
+
+#[cfg(not(feature = "std"))]
+impl TimeSource for ZeroTimeSource {
+    fn now(&self) -> Duration {
+        Duration::ZERO
+    }
+}
 
 #[derive(Debug, Default)]
 struct InnerTimer {
-    start: Option<Instant>,
+    start: Option<Duration>,
     elapsed: Duration,
     subtimers: SubTimersMap,
 }
@@ -16,30 +76,30 @@ impl InnerTimer {
         self.subtimers.clear();
     }
 
-    fn start(&mut self) {
-        self.start = Some(Instant::now());
+    fn start(&mut self, now: Duration) {
+        self.start = Some(now);
     }
 
-    fn stop(&mut self) {
-        self.elapsed += self.start.unwrap().elapsed();
+    fn stop(&mut self, now: Duration) {
+        self.elapsed += now.saturating_sub(self.start.unwrap());
         self.start = None;
     }
 
-    fn suspend(&mut self) {
+    fn suspend(&mut self, now: Duration) {
         //save current elapsed and suspend
         //subtimers if this timer appears active
-        if let Some(instant) = self.start {
-            self.elapsed += instant.elapsed();
-            self.subtimers.suspend();
+        if let Some(start) = self.start {
+            self.elapsed += now.saturating_sub(start);
+            self.subtimers.suspend(now);
         }
     }
 
-    fn resume(&mut self) {
+    fn resume(&mut self, now: Duration) {
         //resume if this timer appears active.
         //just refresh start time to now.
         if self.start.is_some() {
-            self.start = Some(Instant::now());
-            self.subtimers.resume();
+            self.start = Some(now);
+            self.subtimers.resume(now);
         }
     }
 
@@ -49,10 +109,10 @@ impl InnerTimer {
 }
 
 #[derive(Debug, Default)]
-struct SubTimersMap(HashMap<&'static str, InnerTimer>);
+struct SubTimersMap(TimerMap<&'static str, InnerTimer>);
 
 impl Deref for SubTimersMap {
-    type Target = HashMap<&'static str, InnerTimer>;
+    type Target = TimerMap<&'static str, InnerTimer>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -69,29 +129,29 @@ impl SubTimersMap {
         t.reset();
     }
 
-    fn start_subtimer(&mut self, key: &'static str) {
+    fn start_subtimer(&mut self, key: &'static str, now: Duration) {
         let t = self.0.entry(key).or_default();
-        t.start();
+        t.start(now);
     }
 
     #[allow(dead_code)]
     //not used but included for symmetry
-    fn stop_subtimer(&mut self, key: &'static str) {
+    fn stop_subtimer(&mut self, key: &'static str, now: Duration) {
         let t = self.get_mut(key).unwrap();
-        t.stop();
+        t.stop(now);
     }
 
     //this function suspends every timer in the
     //collection.   Used for notimeit!
-    fn suspend(&mut self) {
+    fn suspend(&mut self, now: Duration) {
         for t in self.values_mut() {
-            t.suspend();
+            t.suspend(now);
         }
     }
 
-    fn resume(&mut self) {
+    fn resume(&mut self, now: Duration) {
         for t in self.values_mut() {
-            t.resume();
+            t.resume(now);
         }
     }
 
@@ -103,19 +163,66 @@ impl SubTimersMap {
     pub fn print(&self, depth: u8) {
         for (key, val) in self.iter() {
             let tabs = format!("{: <1$}", "", 4 * depth as usize);
+            #[cfg(feature = "logging")]
+            log::debug!(target: "clarabel", "{}{:} : {:?}", tabs, *key, val.elapsed);
+            #[cfg(not(feature = "logging"))]
             println!("{}{:} : {:?}", tabs, *key, val.elapsed);
             val.subtimers.print(depth + 1);
         }
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct Timers {
     stack: Vec<&'static str>,
     subtimers: SubTimersMap,
+    disabled: bool,
+    clock: Box<dyn TimeSource>,
+}
+
+impl Default for Timers {
+    #[cfg(feature = "std")]
+    fn default() -> Self {
+        Self::with_time_source(Box::<StdTimeSource>::default())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn default() -> Self {
+        Self::with_time_source(Box::<ZeroTimeSource>::default())
+    }
 }
 
 impl Timers {
+    /// A collection that takes its times from `clock` rather than from
+    /// the default `StdTimeSource`, or a clock that never advances
+    /// without the `std` feature.
+    pub fn with_time_source(clock: Box<dyn TimeSource>) -> Self {
+        Self {
+            stack: Vec::new(),
+            subtimers: SubTimersMap::default(),
+            disabled: false,
+            clock,
+        }
+    }
+
+    /// A collection that reports no timings, so that it appears empty
+    /// and always reports zero total time.  Only the outermost timers
+    /// are still kept, so that time limits can be enforced.
+    pub fn disabled() -> Self {
+        Self {
+            disabled: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.disabled || self.subtimers.is_empty()
+    }
+
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
     fn mut_active_timer(&mut self) -> Option<&mut InnerTimer> {
         if self.stack.is_empty() {
             return None;
@@ -138,15 +245,21 @@ impl Timers {
 
     pub fn start_as_current(&mut self, key: &'static str) {
         //starts a timer with name "str" as the current timer
+        //only the outermost timers are kept when disabled
+        if self.disabled && !self.stack.is_empty() {
+            self.stack.push(key);
+            return;
+        }
 
+        let now = self.clock.now();
         let active_timer = self.mut_active_timer();
 
         if let Some(active) = active_timer {
             // child of current active timer
-            active.subtimers.start_subtimer(key);
+            active.subtimers.start_subtimer(key, now);
         } else {
             // nothing active, create one at root
-            self.subtimers.start_subtimer(key);
+            self.subtimers.start_subtimer(key, now);
         }
 
         //append to timer call stack
@@ -156,8 +269,13 @@ impl Timers {
     pub fn stop_current(&mut self) {
         //stops the current timer.  There should always be one
         // active when this function is reached.
+        if self.disabled && self.stack.len() > 1 {
+            self.stack.pop();
+            return;
+        }
+        let now = self.clock.now();
         let active_timer = self.mut_active_timer();
-        active_timer.unwrap().stop();
+        active_timer.unwrap().stop(now);
 
         //remove from timer call stack
         self.stack.pop();
@@ -165,19 +283,47 @@ impl Timers {
 
     //Suspend every timer in the collection.   Used for notimeit!
     pub fn suspend(&mut self) {
-        self.subtimers.suspend();
+        let now = self.clock.now();
+        self.subtimers.suspend(now);
     }
 
     //Resume every timer in the collection.   Used for notimeit!
     pub fn resume(&mut self) {
-        self.subtimers.resume();
+        let now = self.clock.now();
+        self.subtimers.resume(now);
     }
 
     pub fn total_time(&self) -> Duration {
+        if self.disabled {
+            return Duration::ZERO;
+        }
+        self.subtimers.total_time()
+    }
+
+    /// Total time, whether or not timings are reported.
+    /// Used for enforcing time limits.
+    pub(crate) fn elapsed_time(&self) -> Duration {
         self.subtimers.total_time()
     }
 
+    /// Total time including the time so far of any
+    /// timers that are still running.  Unlike
+    /// [`total_time`](Self::total_time), this is also
+    /// kept for disabled timers, for enforcing time limits.
+    pub fn total_time_now(&self) -> Duration {
+        let now = self.clock.now();
+        self.subtimers.values().fold(Duration::ZERO, |acc, t| {
+            let running = t
+                .start
+                .map_or(Duration::ZERO, |start| now.saturating_sub(start));
+            acc + t.elapsed() + running
+        })
+    }
+
     pub fn print(&self) {
+        if self.disabled {
+            return;
+        }
         self.subtimers.print(0);
     }
 }
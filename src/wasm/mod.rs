@@ -0,0 +1,219 @@
+//! WebAssembly interface, built with `wasm-pack` and the `wasm` feature.
+//!
+//! Problem data is passed from JavaScript as flat typed arrays, with the
+//! matrices `P` and `A` in compressed sparse column format.   The solver
+//! does not use threads, and takes its times from the JavaScript `Date`
+//! clock since `std::time` is unavailable in the browser.
+
+#![allow(non_snake_case)]
+
+use crate::algebra::CscMatrix;
+use crate::solver::core::cones::validate_cones;
+use crate::solver::{DefaultSettings, DefaultSolver, IPSolver, SupportedConeT, SupportedConeT::*};
+use crate::timers::TimeSource;
+use serde::Serialize;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+
+// cone type codes used in the flattened cone descriptors
+const ZERO_CONE: u8 = 0;
+const NONNEGATIVE_CONE: u8 = 1;
+const SECOND_ORDER_CONE: u8 = 2;
+const EXPONENTIAL_CONE: u8 = 3;
+const POWER_CONE: u8 = 4;
+const GEN_POWER_CONE: u8 = 5;
+const BOX_CONE: u8 = 6;
+
+// solver timings taken from the JavaScript clock
+struct JsTimeSource {
+    origin: f64,
+}
+
+impl JsTimeSource {
+    fn new() -> Self {
+        Self {
+            origin: js_sys::Date::now(),
+        }
+    }
+}
+
+impl TimeSource for JsTimeSource {
+    fn now(&self) -> Duration {
+        let millis = f64::max(js_sys::Date::now() - self.origin, 0.);
+        Duration::from_secs_f64(millis / 1000.)
+    }
+}
+
+// solution fields returned to JavaScript as a plain object
+#[derive(Serialize)]
+struct WasmSolution<'a> {
+    x: &'a [f64],
+    s: &'a [f64],
+    z: &'a [f64],
+    status: String,
+    obj_val: f64,
+}
+
+/// Solver for problems in standard conic program form, for use from JavaScript.
+#[wasm_bindgen]
+pub struct WasmSolver {
+    solver: DefaultSolver<f64>,
+}
+
+#[wasm_bindgen]
+impl WasmSolver {
+    /// Creates a solver for problem data `(P,q,A,b)` with `P` upper
+    /// triangular.   `P` is `n × n` and `A` is `m × n`, with `n` and `m`
+    /// taken from the lengths of `q` and `b`.
+    ///
+    /// Cones are described by a type code and a dimension for each cone:
+    ///
+    /// - `0` : zero cone of dimension `dim`
+    /// - `1` : nonnegative cone of dimension `dim`
+    /// - `2` : second order cone of dimension `dim`
+    /// - `3` : exponential cone.   `dim` is ignored.
+    /// - `4` : power cone.   `dim` is ignored and the power is taken from `cone_params`.
+    /// - `5` : generalized power cone, with `dim` the dimension of the 2-norm
+    ///   bounded part.  Takes `[k, α_1, ..., α_k]` from `cone_params`.
+    /// - `6` : box cone with `dim` bounded elements, i.e. of dimension `dim + 1`.
+    ///   Takes the `dim` lower bounds followed by the `dim` upper bounds from
+    ///   `cone_params`.
+    ///
+    /// The parameters of all cones are concatenated in `cone_params`, in order.
+    ///
+    /// `settings` is a plain object with fields named as in [`DefaultSettings`].
+    /// Missing fields take their default values, and `settings` may be
+    /// `undefined` to use the defaults for every field.
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        P_colptr: &[u32],
+        P_rowval: &[u32],
+        P_nzval: &[f64],
+        q: &[f64],
+        A_colptr: &[u32],
+        A_rowval: &[u32],
+        A_nzval: &[f64],
+        b: &[f64],
+        cone_types: &[u8],
+        cone_dims: &[u32],
+        cone_params: &[f64],
+        settings: JsValue,
+    ) -> Result<WasmSolver, JsError> {
+        let (m, n) = (b.len(), q.len());
+
+        let P = csc_from_arrays(n, n, P_colptr, P_rowval, P_nzval)?;
+        let A = csc_from_arrays(m, n, A_colptr, A_rowval, A_nzval)?;
+        if !P.is_triu() {
+            return Err(JsError::new("P must be upper triangular"));
+        }
+
+        let cones = cones_from_arrays(cone_types, cone_dims, cone_params)?;
+        validate_cones(&cones, m)?;
+
+        let settings = {
+            if settings.is_undefined() || settings.is_null() {
+                DefaultSettings::default()
+            } else {
+                serde_wasm_bindgen::from_value::<DefaultSettings<f64>>(settings)?
+            }
+        };
+
+        let clock = Box::new(JsTimeSource::new());
+        let solver = DefaultSolver::new_with_time_source(&P, q, &A, b, &cones, settings, clock);
+
+        Ok(WasmSolver { solver })
+    }
+
+    /// Solves the problem, returning an object with fields `x`, `s`, `z`,
+    /// `status` and `obj_val`.  The status is given as a string, e.g. `"Solved"`.
+    pub fn solve(&mut self) -> Result<JsValue, JsError> {
+        self.solver.solve();
+
+        let solution = &self.solver.solution;
+        let out = WasmSolution {
+            x: &solution.x,
+            s: &solution.s,
+            z: &solution.z,
+            status: solution.status.to_string(),
+            obj_val: solution.obj_val,
+        };
+        Ok(serde_wasm_bindgen::to_value(&out)?)
+    }
+}
+
+fn csc_from_arrays(
+    m: usize,
+    n: usize,
+    colptr: &[u32],
+    rowval: &[u32],
+    nzval: &[f64],
+) -> Result<CscMatrix<f64>, JsError> {
+    let colptr = colptr.iter().map(|&c| c as usize).collect();
+    let rowval: Vec<usize> = rowval.iter().map(|&r| r as usize).collect();
+
+    if rowval.iter().any(|&r| r >= m) {
+        return Err(JsError::new("Matrix row index out of bounds"));
+    }
+
+    let M = CscMatrix::new(m, n, colptr, rowval, nzval.to_vec());
+    M.check_format()?;
+    Ok(M)
+}
+
+fn cones_from_arrays(
+    types: &[u8],
+    dims: &[u32],
+    params: &[f64],
+) -> Result<Vec<SupportedConeT<f64>>, JsError> {
+    if types.len() != dims.len() {
+        return Err(JsError::new(
+            "Cone types and dimensions have different lengths",
+        ));
+    }
+
+    let mut params = params.iter().copied();
+    let mut take = |count: usize| -> Result<Vec<f64>, JsError> {
+        let vals: Vec<f64> = params.by_ref().take(count).collect();
+        if vals.len() == count {
+            Ok(vals)
+        } else {
+            Err(JsError::new("Too few cone parameters"))
+        }
+    };
+
+    let mut cones = Vec::with_capacity(types.len());
+
+    for (&tag, &dim) in types.iter().zip(dims) {
+        let dim = dim as usize;
+        let cone = match tag {
+            ZERO_CONE => ZeroConeT(dim),
+            NONNEGATIVE_CONE => NonnegativeConeT(dim),
+            SECOND_ORDER_CONE => SecondOrderConeT(dim),
+            EXPONENTIAL_CONE => ExponentialConeT(),
+            POWER_CONE => PowerConeT(take(1)?[0]),
+            GEN_POWER_CONE => {
+                let k = take(1)?[0];
+                if !(k >= 0. && k.fract() == 0.) {
+                    return Err(JsError::new(
+                        "Invalid number of generalized power cone powers",
+                    ));
+                }
+                GenPowerConeT(take(k as usize)?, dim)
+            }
+            BOX_CONE => {
+                let lower = take(dim)?;
+                let upper = take(dim)?;
+                BoxConeT { lower, upper }
+            }
+            _ => return Err(JsError::new(&format!("Unrecognized cone type {}", tag))),
+        };
+        cones.push(cone);
+    }
+
+    if params.next().is_some() {
+        return Err(JsError::new("Too many cone parameters"));
+    }
+
+    Ok(cones)
+}
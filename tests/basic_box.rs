@@ -0,0 +1,249 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn basic_box_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // P = [4. 1;1 2]
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ]);
+
+    // x₁ + x₂ = 1, with 0 ≤ x ≤ 0.7 as a box cone.  The
+    // leading box row has zero A and b = 1, so that t = 1,
+    // and A is negated since s = b - Ax is the boxed quantity
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [0., 0.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+
+    let c = vec![1., 1.];
+    let b = vec![1., 1., 0., 0.];
+
+    let cones = vec![
+        ZeroConeT(1),
+        BoxConeT {
+            lower: vec![0., 0.],
+            upper: vec![0.7, 0.7],
+        },
+    ];
+
+    (P, c, A, b, cones)
+}
+
+#[allow(clippy::type_complexity)]
+fn basic_box_data_as_nonnegative() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // the same problem as basic_box_data, with the
+    // bounds split into a pair of nonnegative cones
+    let (P, c, _, _, _) = basic_box_data();
+
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+        [1., 0.],  //
+        [0., 1.],  //
+    ]);
+
+    let b = vec![1., 0., 0., 0.7, 0.7];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(2), NonnegativeConeT(2)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_box_feasible() {
+    let (P, c, A, b, cones) = basic_box_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+
+    let (P, c, A2, b2, cones2) = basic_box_data_as_nonnegative();
+    let settings = DefaultSettings::default();
+    let mut solver2 = DefaultSolver::new(&P, &c, &A2, &b2, &cones2, settings);
+    solver2.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(solver2.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![0.3, 0.7];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+    assert!(solver.solution.x.dist(&solver2.solution.x) <= 1e-6);
+
+    let refobj = 1.8800000298331538;
+    assert!(f64::abs(solver.solution.obj_val - refobj) <= 1e-6);
+
+    // slacks are b - Ax, with t = 1 in the leading box row
+    let (m, n) = (A.m, A.n);
+    let mut s = b.clone();
+    A.gemv(&mut s, &solver.solution.x, -1., 1., MatrixShape::N);
+    assert_eq!(solver.solution.s.len(), m);
+    assert_eq!(solver.solution.x.len(), n);
+    assert!(solver.solution.s.dist(&s) <= 1e-6);
+
+    // duals of the bounded rows are the lower bound duals less the upper ones
+    let (z, z2) = (&solver.solution.z, &solver2.solution.z);
+    assert!(f64::abs(z[0] - z2[0]) <= 1e-6);
+    for i in 0..2 {
+        assert!(f64::abs(z[i + 2] - (z2[i + 1] - z2[i + 3])) <= 1e-6);
+    }
+}
+
+#[test]
+fn test_box_infinite_bounds() {
+    // x₁ bounded below and x₂ above only
+    let (P, c, A, b, _) = basic_box_data();
+    let cones = vec![
+        ZeroConeT(1),
+        BoxConeT {
+            lower: vec![0., -f64::INFINITY],
+            upper: vec![f64::INFINITY, 0.7],
+        },
+    ];
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![0.3, 0.7];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+
+    let mut s = b.clone();
+    A.gemv(&mut s, &solver.solution.x, -1., 1., MatrixShape::N);
+    assert!(solver.solution.s.dist(&s) <= 1e-6);
+}
+
+#[test]
+fn test_box_infeasible() {
+    // x₁ + x₂ = 1 with x₁,x₂ ≤ 0.4
+    let (P, c, A, b, _) = basic_box_data();
+    let cones = vec![
+        ZeroConeT(1),
+        BoxConeT {
+            lower: vec![-1., -1.],
+            upper: vec![0.4, 0.4],
+        },
+    ];
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::PrimalInfeasible);
+}
+
+#[test]
+fn test_box_data_update() {
+    let (P, c, A, mut b, cones) = basic_box_data();
+    let settings = DefaultSettingsBuilder::default()
+        .presolve_enable(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings.clone());
+    solver.solve();
+
+    // x₁ + x₂ = 1.2, so that x₂ is at its upper bound
+    b[0] = 1.2;
+    solver.update_b(&b).unwrap();
+    solver.solve();
+
+    let mut solver2 = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver2.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    let refsol = vec![0.5, 0.7];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+    assert!(solver.solution.x.dist(&solver2.solution.x) <= 1e-6);
+}
+
+#[test]
+fn test_box_scaled_t() {
+    // the leading row need not be fixed.   Here t = 2 - x₁,
+    // and the box gives 0 ≤ x₂ ≤ t
+    let P = CscMatrix::from(&[
+        [1., 0.], //
+        [0., 1.], //
+    ]);
+    let c = vec![0., -4.];
+    let A = CscMatrix::from(&[
+        [1., 0.],  //
+        [0., -1.], //
+    ]);
+    let b = vec![2., 0.];
+    let cones = vec![BoxConeT {
+        lower: vec![0.],
+        upper: vec![1.],
+    }];
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+
+    // minimize x₁²/2 + x₂²/2 - 4x₂ with x₁ + x₂ ≤ 2
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    let refsol = vec![-1., 3.];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn test_box_bad_dimensions() {
+    let (P, c, A, b, _) = basic_box_data();
+    let cones = vec![
+        ZeroConeT(1),
+        BoxConeT {
+            lower: vec![0., 0.],
+            upper: vec![0.7],
+        },
+    ];
+    let settings = DefaultSettings::default();
+    DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+}
+
+#[test]
+#[should_panic]
+fn test_box_crossed_bounds() {
+    let (P, c, A, b, _) = basic_box_data();
+    let cones = vec![
+        ZeroConeT(1),
+        BoxConeT {
+            lower: vec![0., 0.8],
+            upper: vec![0.7, 0.7],
+        },
+    ];
+    let settings = DefaultSettings::default();
+    DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+}
+
+#[test]
+#[should_panic]
+fn test_box_unbounded_element() {
+    let (P, c, A, b, _) = basic_box_data();
+    let cones = vec![
+        ZeroConeT(1),
+        BoxConeT {
+            lower: vec![0., -f64::INFINITY],
+            upper: vec![0.7, f64::INFINITY],
+        },
+    ];
+    let settings = DefaultSettings::default();
+    DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+}
@@ -108,3 +108,50 @@ fn test_expcone_dual_infeasible() {
 
     assert_eq!(solver.solution.status, SolverStatus::DualInfeasible);
 }
+
+#[test]
+fn test_expcone_entropy() {
+    // max  Σ -xᵢ log(xᵢ) + cᵢxᵢ  s.t. Σ xᵢ == 1, as
+    // max  Σ tᵢ + cᵢxᵢ  s.t. (tᵢ, xᵢ, 1) ∈ K_exp, Σ xᵢ == 1
+    //
+    // The optimum is x = softmax(c), with value log(Σ exp(cᵢ))
+
+    let n = 3;
+    let cweights = [0., 1., 2.];
+
+    // variables are (t, x)
+    let P = CscMatrix::<f64>::zeros((2 * n, 2 * n));
+    let c: Vec<f64> = [vec![-1.; n], cweights.iter().map(|&w| -w).collect()].concat();
+
+    let (mut rows, mut cols, mut vals) = (vec![], vec![], vec![]);
+    let mut b = vec![];
+    for i in 0..n {
+        rows.extend([3 * i, 3 * i + 1]);
+        cols.extend([i, n + i]);
+        vals.extend([-1., -1.]);
+        b.extend([0., 0., 1.]);
+    }
+    for i in 0..n {
+        rows.push(3 * n);
+        cols.push(n + i);
+        vals.push(1.);
+    }
+    b.push(1.);
+    let A = CscMatrix::from_triplets(3 * n + 1, 2 * n, &rows, &cols, &vals);
+
+    let mut cones = vec![ExponentialConeT(); n];
+    cones.push(ZeroConeT(1));
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let lse = cweights.iter().map(|w| w.exp()).sum::<f64>().ln();
+    let refx: Vec<f64> = cweights.iter().map(|w| (w - lse).exp()).collect();
+    assert!(solver.solution.x[n..].dist(&refx) <= 1e-4);
+
+    assert!(f64::abs(solver.info.cost_primal + lse) <= 1e-6);
+}
@@ -53,3 +53,94 @@ fn test_powcone() {
     let refobj = -1.8458;
     assert!(f64::abs(solver.info.cost_primal - refobj) <= 1e-3);
 }
+
+#[test]
+fn test_genpowcone_vs_powcone_chain() {
+    // max  x1^0.2 x2^0.3 x3^0.5
+    // s.t. x1 + x2 + x3 == 3
+    // with analytic optimum x = 3*alpha.  As a single generalized power cone
+    // max t
+    // s.t. (x1, x2, x3, t) in K_genpow([0.2, 0.3, 0.5], 1)
+    // and equivalently as a chain of 3D power cones, using
+    // x1^0.2 x2^0.3 x3^0.5 = (x1^0.4 x2^0.6)^0.5 x3^0.5
+    // max t
+    // s.t. (x1, x2, u) in K_pow(0.4)
+    //      (u, x3, t) in K_pow(0.5)
+
+    let alpha = [0.2, 0.3, 0.5];
+    let refsol: Vec<f64> = alpha.iter().map(|a| 3. * a).collect();
+    let refobj = -refsol
+        .iter()
+        .zip(alpha)
+        .map(|(x, a)| x.powf(a))
+        .product::<f64>();
+
+    // x = (x1, x2, x3, t)
+    let P = CscMatrix::<f64>::zeros((4, 4));
+    let c = vec![0., 0., 0., -1.];
+    let mut A1 = CscMatrix::<f64>::identity(4);
+    A1.negate();
+    let A2 = CscMatrix::from(&[[1., 1., 1., 0.]]);
+    let A = CscMatrix::vcat(&A1, &A2);
+    let b = vec![0., 0., 0., 0., 3.];
+    let cones = vec![GenPowerConeT(alpha.to_vec(), 1), ZeroConeT(1)];
+
+    let settings = DefaultSettings::default();
+    let mut genpow = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    genpow.solve();
+
+    // x = (x1, x2, x3, t, u)
+    let P = CscMatrix::<f64>::zeros((5, 5));
+    let c = vec![0., 0., 0., -1., 0.];
+    let A = CscMatrix::from(&[
+        [-1., 0., 0., 0., 0.], // x1
+        [0., -1., 0., 0., 0.], // x2
+        [0., 0., 0., 0., -1.], // u
+        [0., 0., 0., 0., -1.], // u
+        [0., 0., -1., 0., 0.], // x3
+        [0., 0., 0., -1., 0.], // t
+        [1., 1., 1., 0., 0.],  //
+    ]);
+    let b = vec![0., 0., 0., 0., 0., 0., 3.];
+    let cones = vec![PowerConeT(0.4), PowerConeT(0.5), ZeroConeT(1)];
+
+    let settings = DefaultSettings::default();
+    let mut chain = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    chain.solve();
+
+    assert_eq!(genpow.solution.status, SolverStatus::Solved);
+    assert_eq!(chain.solution.status, SolverStatus::Solved);
+
+    assert!(f64::abs(genpow.solution.obj_val - refobj) <= 1e-6);
+    assert!(f64::abs(chain.solution.obj_val - refobj) <= 1e-6);
+    assert!(genpow.solution.x[0..3].dist(&chain.solution.x[0..3]) <= 1e-3);
+    assert!(genpow.solution.x[0..3].dist(&refsol) <= 1e-3);
+}
+
+#[test]
+#[should_panic(expected = "do not sum to 1")]
+fn test_genpowcone_bad_powers_sum() {
+    let P = CscMatrix::<f64>::zeros((3, 3));
+    let c = vec![0., 0., -1.];
+    let mut A = CscMatrix::<f64>::identity(3);
+    A.negate();
+    let b = vec![0.; 3];
+    let cones = vec![GenPowerConeT(vec![0.6, 0.5], 1)];
+
+    let settings = DefaultSettings::default();
+    let _solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+}
+
+#[test]
+#[should_panic(expected = "not all positive")]
+fn test_genpowcone_bad_powers_sign() {
+    let P = CscMatrix::<f64>::zeros((4, 4));
+    let c = vec![0., 0., 0., -1.];
+    let mut A = CscMatrix::<f64>::identity(4);
+    A.negate();
+    let b = vec![0.; 4];
+    let cones = vec![GenPowerConeT(vec![1.2, -0.2, 0.], 1)];
+
+    let settings = DefaultSettings::default();
+    let _solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+}
@@ -47,6 +47,23 @@ fn test_lp_feasible() {
     assert!(f64::abs(solver.solution.obj_val_dual - refobj) <= 1e-8);
 }
 
+#[test]
+fn test_lp_active_constraints() {
+    let (P, c, A, b, cones) = basic_lp_data();
+
+    let settings = DefaultSettings::default();
+
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    // the solution is the vertex x = [-0.5, 0.5, -0.5]
+    let active = solver.solution.active_constraints(&cones, 1e-6);
+    assert_eq!(active, vec![false, true, false, true, false, true]);
+}
+
 #[test]
 fn test_lp_primal_infeasible() {
     let (P, c, A, mut b, cones) = basic_lp_data();
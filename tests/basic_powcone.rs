@@ -50,3 +50,41 @@ fn test_powcone() {
     let refobj = -1.8458;
     assert!(f64::abs(solver.info.cost_primal - refobj) <= 1e-3);
 }
+
+#[test]
+fn test_powcone_geometric_mean() {
+    // max  x^0.5 y^0.5
+    // s.t. x + 2y == 4
+    // which is equivalent to
+    // max t
+    // s.t. (x, y, t) in K_pow(0.5)
+    //      x + 2y == 4
+    // with analytic optimum x = 2, y = 1, t = sqrt(2)
+
+    let P = CscMatrix::<f64>::zeros((3, 3));
+    let c = vec![0., 0., -1.];
+
+    let A = CscMatrix::from(&[
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [1., 2., 0.],  //
+    ]);
+    let b = vec![0., 0., 0., 4.];
+    let cones = vec![PowerConeT(0.5), ZeroConeT(1)];
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![2., 1., f64::sqrt(2.)];
+    // the objective is flat near the optimum, so the
+    // solution is less accurate than the objective value
+    assert!(solver.solution.x.dist(&refsol) <= 1e-3);
+
+    let refobj = -f64::sqrt(2.);
+    assert!(f64::abs(solver.solution.obj_val - refobj) <= 1e-6);
+}
@@ -0,0 +1,104 @@
+#![allow(non_snake_case)]
+#![allow(clippy::type_complexity)]
+#![cfg(feature = "sdp")]
+use clarabel::{algebra::*, solver::*};
+
+fn complex_sdp_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // minimize tr(Cρ) over 2x2 density matrices ρ, i.e.
+    // Hermitian PSD with unit trace, for C = [2 1+i; 1-i 3].
+    // The variables are x = [ρ11, Re ρ12, Im ρ12, ρ22]
+
+    let P = CscMatrix::zeros((4, 4));
+
+    // tr(Cρ) = 2ρ11 + 3ρ22 + 2(Re ρ12 + Im ρ12)
+    let c = vec![2., 2., 2., 3.];
+
+    let r2 = f64::sqrt(2.);
+    let A = CscMatrix::from(&[
+        [1., 0., 0., 1.],  //
+        [-1., 0., 0., 0.], //
+        [0., -r2, 0., 0.], //
+        [0., 0., -r2, 0.], //
+        [0., 0., 0., -1.], //
+    ]);
+    let b = vec![1., 0., 0., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), PSDTriangleComplexConeT(2)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_sdp_complex_density_matrix() {
+    let (P, c, A, b, cones) = complex_sdp_data();
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    // the minimum is the smallest eigenvalue of C, attained at the
+    // projector onto its eigenvector v = [1+i, -1]/√3
+    let refsol = vec![2. / 3., -1. / 3., -1. / 3., 1. / 3.];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+    assert!(f64::abs(solver.solution.obj_val - 1.) <= 1e-6);
+
+    // slacks and duals are reported in the user's layout
+    assert_eq!(solver.solution.s.len(), 5);
+    assert_eq!(solver.solution.z.len(), 5);
+
+    // dual feasibility, i.e. A'z + c = 0
+    let mut r = c.clone();
+    A.gemv(&mut r, &solver.solution.z, 1.0, 1.0, MatrixShape::T);
+    assert!(r.norm_inf() <= 1e-6);
+}
+
+#[test]
+fn test_sdp_complex_imaginary_part() {
+    // maximize Im ρ12 over 2x2 density matrices, which is
+    // attained at ρ = [1 i; -i 1]/2
+    let (P, _, A, b, cones) = complex_sdp_data();
+    let c = vec![0., 0., -1., 0.];
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![0.5, 0., 0.5, 0.5];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+    assert!(f64::abs(solver.solution.obj_val + 0.5) <= 1e-6);
+}
+
+#[test]
+fn test_sdp_complex_update_rejected() {
+    let (P, c, A, b, cones) = complex_sdp_data();
+
+    let settings = DefaultSettingsBuilder::default()
+        .presolve_enable(false)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    assert!(matches!(
+        solver.update_b(&b),
+        Err(DataUpdateError::ComplexPSDConesPresent)
+    ));
+}
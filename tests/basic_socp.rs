@@ -72,6 +72,24 @@ fn test_socp_feasible() {
     assert!(f64::abs(solver.solution.obj_val_dual - refobj) <= 1e-4);
 }
 
+#[test]
+fn test_socp_active_constraints() {
+    let (P, c, A, b, cones) = basic_socp_data();
+
+    let settings = DefaultSettings::<f64>::default();
+
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    // x[0] = -0.5 is at its lower bound, and x is on the boundary of the SOC
+    let active = solver.solution.active_constraints(&cones, 1e-6);
+    let refactive = vec![false, false, false, true, false, false, true, true, true];
+    assert_eq!(active, refactive);
+}
+
 #[test]
 fn test_socp_infeasible() {
     let (P, c, A, mut b, cones) = basic_socp_data();
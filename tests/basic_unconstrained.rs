@@ -34,3 +34,98 @@ fn test_unconstrained_dual_infeasible() {
     solver.solve();
     assert_eq!(solver.solution.status, SolverStatus::DualInfeasible);
 }
+
+fn solve_unconstrained(P: &CscMatrix<f64>, q: &[f64]) -> DefaultSolver<f64> {
+    let A = CscMatrix::zeros((0, q.len())); // <- no constraints
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(P, q, &A, &[], &[], settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_unconstrained_strictly_convex() {
+    // x = -P⁻¹q, with P⁻¹ = [2 -1; -1 4]/7
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ])
+    .to_triu();
+    let q = [1., -1.];
+
+    let solver = solve_unconstrained(&P, &q);
+
+    let refsol = [-3. / 7., 5. / 7.];
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(&refsol) <= 1e-8);
+    assert!(f64::abs(solver.solution.obj_val + 4. / 7.) <= 1e-8);
+    assert!(solver.solution.s.is_empty());
+    assert!(solver.solution.z.is_empty());
+}
+
+#[test]
+fn test_unconstrained_empty_cone() {
+    // a zero dimensional cone is the same as no cones at all
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ])
+    .to_triu();
+    let q = [1., -1.];
+    let A = CscMatrix::zeros((0, 2));
+    let cones = [NonnegativeConeT(0)];
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &[], &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(&[-3. / 7., 5. / 7.]) <= 1e-8);
+}
+
+#[test]
+fn test_unconstrained_zero_objective() {
+    // every x is optimal
+    let P = CscMatrix::zeros((2, 2));
+    let solver = solve_unconstrained(&P, &[0., 0.]);
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(f64::abs(solver.solution.obj_val) <= 1e-8);
+}
+
+#[test]
+fn test_unconstrained_zero_P_certificate() {
+    // unbounded along any x with q'x < 0
+    let P = CscMatrix::zeros((2, 2));
+    let q = [0., -2.];
+    let solver = solve_unconstrained(&P, &q);
+
+    assert_eq!(solver.solution.status, SolverStatus::DualInfeasible);
+    match solver.solution.infeasibility_certificate() {
+        Some(Certificate::DualInfeasible { x }) => assert!(q.dot(&x) < 0.),
+        _ => panic!("expected a dual infeasibility certificate"),
+    }
+}
+
+#[test]
+fn test_unconstrained_singular_P() {
+    // bounded if q is in the range of P, and unbounded otherwise
+    let P = CscMatrix::from(&[
+        [1., 0.], //
+        [0., 0.], //
+    ])
+    .to_triu();
+
+    let solver = solve_unconstrained(&P, &[1., 0.]);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(f64::abs(solver.solution.x[0] + 1.) <= 1e-8);
+
+    let solver = solve_unconstrained(&P, &[1., 1.]);
+    assert_eq!(solver.solution.status, SolverStatus::DualInfeasible);
+}
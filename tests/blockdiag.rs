@@ -0,0 +1,75 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[test]
+fn test_blockdiag_solve() {
+    // two independent QPs with box constraints on each variable,
+    // solved together as a single problem with block diagonal data
+    let P1 = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ])
+    .to_triu();
+    let P2 = CscMatrix::from(&[
+        [2., 0., 0.], //
+        [0., 1., 0.], //
+        [0., 0., 3.], //
+    ]);
+
+    let I2 = CscMatrix::<f64>::identity(2);
+    let I3 = CscMatrix::<f64>::identity(3);
+    let (mut A1, mut A2) = (I2.clone(), I3.clone());
+    A1.negate();
+    A2.negate();
+    let A1 = CscMatrix::vcat(&I2, &A1);
+    let A2 = CscMatrix::vcat(&I3, &A2);
+
+    let P = CscMatrix::blockdiag(&[&P1, &P2]);
+    let A = CscMatrix::blockdiag(&[&A1, &A2]);
+    assert_eq!((A.m, A.n), (10, 5));
+
+    // the same matrices assembled by hand
+    let Pref = CscMatrix::from(&[
+        [4., 1., 0., 0., 0.], //
+        [0., 2., 0., 0., 0.], //
+        [0., 0., 2., 0., 0.], //
+        [0., 0., 0., 1., 0.], //
+        [0., 0., 0., 0., 3.], //
+    ]);
+    let Aref = CscMatrix::from(&[
+        [1., 0., 0., 0., 0.],  //
+        [0., 1., 0., 0., 0.],  //
+        [-1., 0., 0., 0., 0.], //
+        [0., -1., 0., 0., 0.], //
+        [0., 0., 1., 0., 0.],  //
+        [0., 0., 0., 1., 0.],  //
+        [0., 0., 0., 0., 1.],  //
+        [0., 0., -1., 0., 0.], //
+        [0., 0., 0., -1., 0.], //
+        [0., 0., 0., 0., -1.], //
+    ]);
+    assert_eq!(P, Pref);
+    assert_eq!(A, Aref);
+
+    let q = vec![1., -3., 2., -1., 4.];
+    let b = vec![1.; 10];
+    let cones = vec![NonnegativeConeT(4), NonnegativeConeT(6)];
+
+    let settings = || {
+        DefaultSettingsBuilder::default()
+            .verbose(false)
+            .build()
+            .unwrap()
+    };
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings());
+    solver.solve();
+    let mut refsolver = DefaultSolver::new(&Pref, &q, &Aref, &b, &cones, settings());
+    refsolver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(refsolver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(&refsolver.solution.x) <= 1e-8);
+    assert!(f64::abs(solver.solution.obj_val - refsolver.solution.obj_val) <= 1e-8);
+}
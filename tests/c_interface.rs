@@ -0,0 +1,175 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "c")]
+
+use clarabel::{algebra::*, clarabel_c::*, solver::*};
+use std::ptr;
+
+#[allow(clippy::type_complexity)]
+fn socp_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // the SOCP example problem
+    let P = CscMatrix::from(&[
+        [0., 0.], //
+        [0., 2.], //
+    ]);
+
+    let q = vec![0., 0.];
+
+    let A = CscMatrix::from(&[
+        [0., 0.],  //
+        [-2., 0.], //
+        [0., -1.], //
+    ]);
+
+    let b = vec![1., -2., -2.];
+
+    let cones = vec![SecondOrderConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn to_c(M: &CscMatrix<f64>) -> ClarabelCscMatrix {
+    ClarabelCscMatrix {
+        m: M.m,
+        n: M.n,
+        colptr: M.colptr.as_ptr(),
+        rowval: M.rowval.as_ptr(),
+        nzval: M.nzval.as_ptr(),
+    }
+}
+
+fn soc_spec(dim: usize) -> ClarabelConeSpec {
+    ClarabelConeSpec {
+        tag: ClarabelConeTag::SecondOrderConeT as u32,
+        dim,
+        param: 0.,
+        vec: ptr::null(),
+        vec_len: 0,
+    }
+}
+
+#[test]
+fn test_c_socp() {
+    let (P, q, A, b, cones) = socp_test_data();
+
+    // reference solution through the Rust interface
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, DefaultSettings::default());
+    solver.solve();
+
+    let (Pc, Ac) = (to_c(&P), to_c(&A));
+    let conesc = [soc_spec(3)];
+
+    let mut settings = clarabel_default_settings();
+    settings.verbose = false;
+
+    unsafe {
+        let handle = clarabel_solver_new(
+            &Pc,
+            q.as_ptr(),
+            &Ac,
+            b.as_ptr(),
+            conesc.len(),
+            conesc.as_ptr(),
+            &settings,
+        );
+        assert!(!handle.is_null());
+
+        let status = clarabel_solve(handle);
+
+        assert_eq!(status, ClarabelSolverStatus::Solved);
+        assert_eq!(
+            clarabel_solution_status(handle),
+            ClarabelSolverStatus::Solved
+        );
+
+        let x = std::slice::from_raw_parts(clarabel_solution_x(handle), A.n);
+        let z = std::slice::from_raw_parts(clarabel_solution_z(handle), A.m);
+        let s = std::slice::from_raw_parts(clarabel_solution_s(handle), A.m);
+        assert!(x.dist(&solver.solution.x) <= 1e-8);
+        assert!(z.dist(&solver.solution.z) <= 1e-8);
+        assert!(s.dist(&solver.solution.s) <= 1e-8);
+
+        let refobj = solver.solution.obj_val;
+        assert!(f64::abs(clarabel_solution_obj_val(handle) - refobj) <= 1e-8);
+        assert!(f64::abs(clarabel_solution_obj_val_dual(handle) - refobj) <= 1e-6);
+
+        clarabel_solver_free(handle);
+    }
+}
+
+#[test]
+fn test_c_invalid_data() {
+    let (P, q, A, b, _) = socp_test_data();
+    let (Pc, Ac) = (to_c(&P), to_c(&A));
+    let settings = clarabel_default_settings();
+
+    unsafe {
+        // cone dimensions inconsistent with A
+        let conesc = [soc_spec(2)];
+        let handle = clarabel_solver_new(
+            &Pc,
+            q.as_ptr(),
+            &Ac,
+            b.as_ptr(),
+            conesc.len(),
+            conesc.as_ptr(),
+            &settings,
+        );
+        assert!(handle.is_null());
+
+        // unrecognized cone tag
+        let mut conesc = [soc_spec(3)];
+        conesc[0].tag = 100;
+        let handle = clarabel_solver_new(
+            &Pc,
+            q.as_ptr(),
+            &Ac,
+            b.as_ptr(),
+            conesc.len(),
+            conesc.as_ptr(),
+            &settings,
+        );
+        assert!(handle.is_null());
+
+        // non-finite data
+        let mut qbad = q.clone();
+        qbad[0] = f64::NAN;
+        let conesc = [soc_spec(3)];
+        let handle = clarabel_solver_new(
+            &Pc,
+            qbad.as_ptr(),
+            &Ac,
+            b.as_ptr(),
+            conesc.len(),
+            conesc.as_ptr(),
+            &settings,
+        );
+        assert!(handle.is_null());
+
+        // freeing null is allowed
+        clarabel_solver_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn test_c_null_handle() {
+    let handle: *mut ClarabelDefaultSolver = ptr::null_mut();
+
+    unsafe {
+        assert_eq!(clarabel_solve(handle), ClarabelSolverStatus::InternalError);
+        assert_eq!(
+            clarabel_solution_status(handle),
+            ClarabelSolverStatus::InternalError
+        );
+        assert!(clarabel_solution_x(handle).is_null());
+        assert!(clarabel_solution_z(handle).is_null());
+        assert!(clarabel_solution_s(handle).is_null());
+        assert!(clarabel_solution_obj_val(handle).is_nan());
+        assert!(clarabel_solution_obj_val_dual(handle).is_nan());
+    }
+}
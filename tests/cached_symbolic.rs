@@ -0,0 +1,102 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn cached_symbolic_data(
+    scale: f64,
+) -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // a family of QPs with identical sparsity and varying values
+    let P = CscMatrix::from(&[
+        [4. * scale, 1., 0.], //
+        [1., 2., 0.],         //
+        [0., 0., scale],      //
+    ])
+    .to_triu();
+    let q = vec![1., scale, -1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., scale], //
+        [-1., 0., 0.],   //
+        [0., -1., 0.],   //
+        [0., 0., -1.],   //
+        [1., 0., 1.],    //
+    ]);
+    let b = vec![1., 0., 0., 0., 2. * scale];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(4)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings(cache: bool) -> DefaultSettings<f64> {
+    // these KKT systems are small enough to be factored densely
+    // by default, so the sparse factorization is forced
+    DefaultSettingsBuilder::default()
+        .dense_kkt_threshold(0)
+        .direct_solve_cache_symbolic(cache)
+        .verbose(false)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_cached_symbolic_reused() {
+    let (P, q, A, b, cones) = cached_symbolic_data(1.);
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(true));
+    solver.solve();
+    assert_eq!(solver.kkt_info().num_symbolic_factorizations, 1);
+
+    let (P, q, A, b, cones) = cached_symbolic_data(3.);
+    solver.rebuild(&P, &q, &A, &b, &cones, settings(true));
+    solver.solve();
+
+    // the second problem skips the symbolic phase
+    assert_eq!(solver.kkt_info().num_symbolic_factorizations, 0);
+
+    let mut fresh = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(true));
+    fresh.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(solver.solution.iterations, fresh.solution.iterations);
+    assert_eq!(solver.solution.x, fresh.solution.x);
+    assert_eq!(solver.solution.z, fresh.solution.z);
+    assert_eq!(solver.solution.s, fresh.solution.s);
+}
+
+#[test]
+fn test_cached_symbolic_disabled() {
+    let (P, q, A, b, cones) = cached_symbolic_data(1.);
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(false));
+
+    let (P, q, A, b, cones) = cached_symbolic_data(3.);
+    solver.rebuild(&P, &q, &A, &b, &cones, settings(false));
+
+    assert_eq!(solver.kkt_info().num_symbolic_factorizations, 1);
+}
+
+#[test]
+fn test_cached_symbolic_sparsity_changed() {
+    let (P, q, A, b, cones) = cached_symbolic_data(1.);
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(true));
+
+    // an extra nonzero in P changes the KKT sparsity pattern
+    let (_, q, A, b, cones) = cached_symbolic_data(3.);
+    let P = CscMatrix::from(&[
+        [12., 1., 1.], //
+        [1., 2., 0.],  //
+        [1., 0., 3.],  //
+    ])
+    .to_triu();
+    solver.rebuild(&P, &q, &A, &b, &cones, settings(true));
+    solver.solve();
+
+    assert_eq!(solver.kkt_info().num_symbolic_factorizations, 1);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+}
@@ -0,0 +1,142 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[allow(clippy::type_complexity)]
+fn corrections_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½x'Px + q'x  s.t.  ‖Dᵢx‖ ≤ tᵢ on blocks of three
+    // variables, with P and D scaled over several orders of magnitude
+    let n = 12;
+    let nblocks = n / 3;
+
+    let scale = |i: usize| 10f64.powf(i as f64 / (n - 1) as f64 * 3. - 1.5);
+
+    let diag: Vec<usize> = (0..n).collect();
+    let Pdiag: Vec<f64> = (0..n).map(|i| scale(n - 1 - i)).collect();
+    let P = CscMatrix::from_triplets(n, n, &diag, &diag, &Pdiag);
+    let q: Vec<f64> = (0..n).map(|i| (i as f64 + 0.5).sin() * scale(i)).collect();
+
+    // each cone is (tᵢ, -Dᵢxᵢ) with tᵢ = 1 + i
+    let (mut rows, mut cols, mut vals) = (vec![], vec![], vec![]);
+    let mut b = vec![];
+    for k in 0..nblocks {
+        b.push(1. + k as f64);
+        for j in 0..3 {
+            let i = 3 * k + j;
+            rows.push(4 * k + 1 + j);
+            cols.push(i);
+            vals.push(-scale(i));
+            b.push(0.);
+        }
+    }
+    let A = CscMatrix::from_triplets(4 * nblocks, n, &rows, &cols, &vals);
+
+    let cones = vec![SecondOrderConeT(4); nblocks];
+
+    (P, q, A, b, cones)
+}
+
+// counts the KKT solves made by the solver
+struct CountingKKTSolver {
+    inner: DirectLDLKKTSolver<f64>,
+    nsolves: Arc<AtomicUsize>,
+}
+
+impl KKTSolver<f64> for CountingKKTSolver {
+    fn update(&mut self, cones: &CompositeCone<f64>, settings: &CoreSettings<f64>) -> bool {
+        self.inner.update(cones, settings)
+    }
+    fn setrhs(&mut self, x: &[f64], z: &[f64]) {
+        self.inner.setrhs(x, z)
+    }
+    fn solve(
+        &mut self,
+        x: Option<&mut [f64]>,
+        z: Option<&mut [f64]>,
+        settings: &CoreSettings<f64>,
+    ) -> bool {
+        self.nsolves.fetch_add(1, Ordering::Relaxed);
+        self.inner.solve(x, z, settings)
+    }
+    fn update_P(&mut self, P: &CscMatrix<f64>) {
+        self.inner.update_P(P)
+    }
+    fn update_A(&mut self, A: &CscMatrix<f64>) {
+        self.inner.update_A(A)
+    }
+    fn kkt_info(&self) -> KKTInfo {
+        self.inner.kkt_info()
+    }
+    fn kkt_matrix(&self) -> &CscMatrix<f64> {
+        self.inner.kkt_matrix()
+    }
+}
+
+// solves with the given number of corrections, returning the
+// solver together with the number of KKT solves made
+fn solve_with_corrections(ncorrections: u32) -> (DefaultSolver<f64>, usize) {
+    let (P, q, A, b, cones) = corrections_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .max_centrality_corrections(ncorrections)
+        .build()
+        .unwrap();
+
+    let nsolves = Arc::new(AtomicUsize::new(0));
+    let counter = nsolves.clone();
+    let factory: KKTSolverFactory<f64> = Box::new(move |P, A, cones, settings| {
+        let inner = DirectLDLKKTSolver::new(P, A, cones, A.m, A.n, settings);
+        Box::new(CountingKKTSolver {
+            inner,
+            nsolves: counter,
+        })
+    });
+
+    let mut solver = DefaultSolver::new_with_kkt_solver(&P, &q, &A, &b, &cones, settings, factory);
+    solver.solve();
+    let nsolves = nsolves.load(Ordering::Relaxed);
+    (solver, nsolves)
+}
+
+#[test]
+fn test_centrality_corrections_default() {
+    let (P, q, A, b, cones) = corrections_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    assert_eq!(settings.max_centrality_corrections, 0);
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+
+    let (corrected, _) = solve_with_corrections(0);
+    assert_eq!(corrected.solution.iterations, solver.solution.iterations);
+    assert_eq!(corrected.solution.x, solver.solution.x);
+}
+
+#[test]
+fn test_centrality_corrections() {
+    let (solver0, nsolves0) = solve_with_corrections(0);
+    let (solver2, nsolves2) = solve_with_corrections(2);
+
+    assert_eq!(solver0.solution.status, SolverStatus::Solved);
+    assert_eq!(solver2.solution.status, SolverStatus::Solved);
+    assert!((solver2.solution.obj_val - solver0.solution.obj_val).abs() <= 1e-6);
+
+    // fewer iterations, but more KKT solves in each of them
+    let iters0 = solver0.solution.iterations;
+    let iters2 = solver2.solution.iterations;
+    assert!(iters2 < iters0);
+    assert!(nsolves2 as f64 / iters2 as f64 > nsolves0 as f64 / iters0 as f64);
+}
@@ -0,0 +1,117 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn check_solution_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // x1 + x2 = 1, x ≥ 0 and ‖x‖ ≤ 1
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ])
+    .to_triu();
+    let q = vec![1., 1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+        [0., 0.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1., 0., 0., 1., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(2), SecondOrderConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn solved(
+    P: &CscMatrix<f64>,
+    q: &[f64],
+    A: &CscMatrix<f64>,
+    b: &[f64],
+    cones: &[SupportedConeT<f64>],
+) -> DefaultSolver<f64> {
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(P, q, A, b, cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    solver
+}
+
+#[test]
+fn test_check_solution_at_solution() {
+    let (P, q, A, b, cones) = check_solution_data();
+    let solver = solved(&P, &q, &A, &b, &cones);
+    let sol = &solver.solution;
+
+    let report = solver.check_solution(&sol.x, &sol.s, &sol.z);
+
+    assert_eq!(report.primal.len(), 6);
+    assert_eq!(report.dual.len(), 2);
+    assert_eq!(report.cone_violation_s.len(), 3);
+    assert!(report.primal_norm_inf() <= 1e-7);
+    assert!(report.dual_norm_inf() <= 1e-7);
+    assert!(f64::abs(report.gap()) <= 1e-7);
+    assert!(report.cone_violation() <= 1e-7);
+}
+
+#[test]
+fn test_check_solution_perturbed() {
+    let (P, q, A, b, cones) = check_solution_data();
+    let solver = solved(&P, &q, &A, &b, &cones);
+    let sol = &solver.solution;
+
+    let x: Vec<f64> = sol.x.iter().map(|&xi| xi + 0.1).collect();
+    let s: Vec<f64> = sol.s.iter().map(|&si| si - 0.5).collect();
+    let z: Vec<f64> = sol.z.iter().map(|&zi| zi + 0.5).collect();
+
+    let report = solver.check_solution(&x, &s, &z);
+
+    assert!(report.primal_norm_inf() >= 0.1);
+    assert!(report.dual_norm_inf() >= 0.1);
+
+    // the zero cone slack is 0.5 in magnitude, and the nonnegative
+    // slacks fall below zero by the shift less the smaller slack
+    let smin = f64::min(sol.s[1], sol.s[2]);
+    assert!(f64::abs(report.cone_violation_s[0] - 0.5) <= 1e-7);
+    assert!(f64::abs(report.cone_violation_s[1] - (0.5 - smin)) <= 1e-7);
+    assert!(report.cone_violation_s[1] > 0.);
+
+    // the dual of the zero cone is unrestricted
+    assert_eq!(report.cone_violation_z[0], 0.);
+}
+
+#[test]
+fn test_check_solution_expcone() {
+    // max x s.t. exp(x) ≤ e, i.e. (x, 1, e) ∈ K_exp
+    let P = CscMatrix::zeros((1, 1));
+    let q = vec![-1.];
+    let A = CscMatrix::from(&[[-1.], [0.], [0.]]);
+    let b = vec![0., 1., f64::exp(1.)];
+    let cones = vec![ExponentialConeT()];
+
+    let solver = solved(&P, &q, &A, &b, &cones);
+    let sol = &solver.solution;
+    assert!(f64::abs(sol.x[0] - 1.) <= 1e-6);
+
+    let report = solver.check_solution(&sol.x, &sol.s, &sol.z);
+    assert!(report.primal_norm_inf() <= 1e-7);
+    assert!(report.dual_norm_inf() <= 1e-7);
+    assert!(report.cone_violation() <= 1e-7);
+
+    // x = 2 is infeasible, i.e. s is outside of the cone
+    let report = solver.check_solution(&[2.], &[2., 1., f64::exp(1.)], &sol.z);
+    assert!(report.cone_violation_s[0] > 1e-3);
+}
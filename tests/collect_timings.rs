@@ -0,0 +1,146 @@
+#![allow(non_snake_case)]
+
+use clarabel::timers::TimeSource;
+use clarabel::{algebra::*, solver::*};
+use std::cell::Cell;
+use std::time::Duration;
+
+#[allow(clippy::type_complexity)]
+fn collect_timings_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ])
+    .to_triu();
+    let c = vec![1., 1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1.],   //
+        [1., 0.],   //
+        [0., 1.],   //
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+    ]);
+    let b = vec![1., 0.7, 0.7, -1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(6)];
+
+    (P, c, A, b, cones)
+}
+
+fn solve(collect_timings: bool) -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = collect_timings_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .collect_timings(collect_timings)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_collect_timings_default() {
+    let settings = DefaultSettings::<f64>::default();
+    assert!(settings.collect_timings);
+
+    let solver = solve(true);
+    assert!(!solver.timers.as_ref().unwrap().is_empty());
+    assert!(solver.solution.solve_time > 0.);
+}
+
+#[test]
+fn test_collect_timings_disabled() {
+    let timed = solve(true);
+    let untimed = solve(false);
+
+    assert!(untimed.timers.as_ref().unwrap().is_empty());
+    assert_eq!(untimed.solution.solve_time, 0.);
+
+    // numeric results are unaffected
+    assert_eq!(untimed.solution.status, SolverStatus::Solved);
+    assert_eq!(untimed.solution.x, timed.solution.x);
+    assert_eq!(untimed.solution.z, timed.solution.z);
+    assert_eq!(untimed.solution.s, timed.solution.s);
+    assert_eq!(untimed.solution.iterations, timed.solution.iterations);
+
+    // and the debug output is reproducible between runs
+    let again = solve(false);
+    assert_eq!(
+        format!("{:?}", untimed.solution),
+        format!("{:?}", again.solution)
+    );
+}
+
+#[test]
+fn test_collect_timings_disabled_time_limit() {
+    let (P, c, A, b, cones) = collect_timings_test_data();
+
+    // the time limit is still enforced, though no time is reported
+    for time_limit_hard in [false, true] {
+        let settings = DefaultSettingsBuilder::default()
+            .verbose(false)
+            .collect_timings(false)
+            .time_limit(1e-9)
+            .time_limit_hard(time_limit_hard)
+            .build()
+            .unwrap();
+        let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+        solver.solve();
+
+        assert_eq!(solver.solution.status, SolverStatus::MaxTime);
+        assert_eq!(solver.solution.solve_time, 0.);
+        assert!(solver.timers.as_ref().unwrap().is_empty());
+    }
+}
+
+// a clock that advances by a fixed tick every time it is read
+struct MockClock {
+    ticks: Cell<u32>,
+}
+
+impl TimeSource for MockClock {
+    fn now(&self) -> Duration {
+        let ticks = self.ticks.get();
+        self.ticks.set(ticks + 1);
+        Duration::from_millis(10) * ticks
+    }
+}
+
+fn solve_with_mock_clock() -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = collect_timings_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let clock = Box::new(MockClock {
+        ticks: Cell::new(0),
+    });
+    let mut solver = DefaultSolver::new_with_time_source(&P, &c, &A, &b, &cones, settings, clock);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_collect_timings_time_source() {
+    let solver = solve_with_mock_clock();
+    let solve_time = solver.solution.solve_time;
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    // every reported time is a whole number of ticks
+    let ticks = solve_time / 0.01;
+    assert!(ticks >= 1.);
+    assert!(f64::abs(ticks - ticks.round()) <= 1e-9);
+
+    // and is reproducible between runs
+    let again = solve_with_mock_clock();
+    assert_eq!(solve_time, again.solution.solve_time);
+}
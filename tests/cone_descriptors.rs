@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn cone_descriptors_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let cones = vec![
+        ZeroConeT(1),
+        NonnegativeConeT(2),
+        SecondOrderConeT(3),
+        ExponentialConeT(),
+        PowerConeT(0.3),
+    ];
+
+    // one variable per constraint row
+    let n = 1 + 2 + 3 + 3 + 3;
+    let P = CscMatrix::<f64>::zeros((n, n));
+    let c = vec![0.; n];
+    let mut A = CscMatrix::<f64>::identity(n);
+    A.negate();
+    let b = vec![0.; n];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_cone_descriptors() {
+    let (P, c, A, b, cones) = cone_descriptors_test_data();
+    let solver = DefaultSolver::new(&P, &c, &A, &b, &cones, DefaultSettings::default());
+
+    let descriptors: Vec<ConeDescriptor> = solver.cones().descriptors().collect();
+    let expected = [
+        (SupportedConeTag::ZeroCone, 1),
+        (SupportedConeTag::NonnegativeCone, 2),
+        (SupportedConeTag::SecondOrderCone, 3),
+        (SupportedConeTag::ExponentialCone, 3),
+        (SupportedConeTag::PowerCone, 3),
+    ];
+
+    assert_eq!(solver.cones().len(), cones.len());
+    assert_eq!(descriptors.len(), expected.len());
+    for (d, (kind, dim)) in descriptors.iter().zip(expected) {
+        assert_eq!(d.kind, kind);
+        assert_eq!(d.dim, dim);
+    }
+
+    // zero cone has degree 0, the SOC 1, and each 3d cone 3
+    assert_eq!(solver.cones().degree(), 2 + 1 + 3 + 3);
+}
+
+#[test]
+fn test_cone_descriptors_box() {
+    // a box cone has one more element than it has bounds
+    let P = CscMatrix::<f64>::zeros((2, 2));
+    let c = vec![0.; 2];
+    let A = CscMatrix::vcat(
+        &CscMatrix::<f64>::zeros((1, 2)),
+        &CscMatrix::<f64>::identity(2),
+    );
+    let b = vec![1., 0., 0.];
+    let cones = [BoxConeT {
+        lower: vec![-1.; 2],
+        upper: vec![1.; 2],
+    }];
+
+    let solver = DefaultSolver::new(&P, &c, &A, &b, &cones, DefaultSettings::default());
+    let descriptors: Vec<ConeDescriptor> = solver.cones().descriptors().collect();
+    assert_eq!(
+        descriptors,
+        vec![ConeDescriptor {
+            kind: SupportedConeTag::BoxCone,
+            dim: 3
+        }]
+    );
+
+    // one for t, and one for each finite bound
+    assert_eq!(solver.cones().degree(), 5);
+}
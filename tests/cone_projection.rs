@@ -0,0 +1,58 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[test]
+fn test_project_nonnegative() {
+    let cones = CompositeCone::<f64>::new(&[NonnegativeConeT(4)]);
+    let mut v = vec![1., -2., 0., 3.5];
+
+    cones.project(&mut v).unwrap();
+
+    assert_eq!(v, vec![1., 0., 0., 3.5]);
+}
+
+#[test]
+fn test_project_secondorder() {
+    let cones = CompositeCone::<f64>::new(&[SecondOrderConeT(3)]);
+
+    // inside the cone, so unchanged
+    let mut v = vec![5., 3., 4.];
+    cones.project(&mut v).unwrap();
+    assert_eq!(v, vec![5., 3., 4.]);
+
+    // inside the polar cone, so projected to the origin
+    let mut v = vec![-5., 3., 4.];
+    cones.project(&mut v).unwrap();
+    assert_eq!(v, vec![0., 0., 0.]);
+
+    // otherwise ((t + ‖x‖)/2)(1, x/‖x‖), here with ‖x‖ = 5
+    let mut v = vec![1., 3., 4.];
+    cones.project(&mut v).unwrap();
+    assert!(v.dist(&[3., 1.8, 2.4]) <= 1e-12);
+}
+
+#[test]
+fn test_project_composite() {
+    let cones =
+        CompositeCone::<f64>::new(&[ZeroConeT(2), NonnegativeConeT(2), SecondOrderConeT(3)]);
+    let mut v = vec![1., -1., -1., 2., 0., 0., 2.];
+
+    cones.project(&mut v).unwrap();
+
+    assert!(v.dist(&[0., 0., 0., 2., 1., 0., 1.]) <= 1e-12);
+}
+
+#[test]
+fn test_project_unsupported() {
+    let cones = CompositeCone::<f64>::new(&[NonnegativeConeT(1), ExponentialConeT()]);
+    let mut v = vec![-1., 1., 2., 3.];
+
+    let err = cones.project(&mut v).unwrap_err();
+
+    assert_eq!(
+        err,
+        ConeError::NoProjection(1, SupportedConeTag::ExponentialCone)
+    );
+    assert_eq!(v, vec![-1., 1., 2., 3.]);
+}
@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn cone_residuals_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min x1 + x2 + x3, s.t. x >= 0, ||(x1,x2)|| <= x3 + 1
+    let P = CscMatrix::<f64>::zeros((3, 3));
+    let c = vec![1., 1., 1.];
+
+    let A = CscMatrix::from(&[
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [0., 0., -1.], //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+    ]);
+    let b = vec![0., 0., 0., 1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(3), SecondOrderConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_per_cone_residuals() {
+    let (P, c, A, b, cones) = cone_residuals_test_data();
+
+    // stop early so that the residuals are not negligible, and
+    // without equilibration so that the residuals can be
+    // recomputed from the solution
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .max_iter(2)
+        .equilibrate_enable(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::MaxIterations);
+
+    let residuals = solver.info.per_cone_residuals();
+    let tags: Vec<_> = residuals.iter().map(|r| r.0).collect();
+    assert_eq!(
+        tags,
+        vec![
+            SupportedConeTag::NonnegativeCone,
+            SupportedConeTag::SecondOrderCone
+        ]
+    );
+
+    // primal terms combine to the overall primal residual
+    let res_primal = residuals.iter().map(|r| r.1 * r.1).sum::<f64>().sqrt();
+    assert!(f64::abs(res_primal - solver.info.res_primal) <= 1e-12);
+
+    // dual terms are the components of each cone's term A_i'z_i
+    // along the dual residual r = Px + q + A'z, here with P = 0
+    let z = &solver.solution.z;
+    let mut r = c.clone();
+    A.gemv(&mut r, z, 1., 1., MatrixShape::T);
+    let res_dual = solver.info.res_dual;
+    assert!(res_dual > 1e-6);
+
+    for (k, rng) in [0..3, 3..6].into_iter().enumerate() {
+        let mut zi = vec![0.; z.len()];
+        zi[rng.clone()].copy_from_slice(&z[rng]);
+        let mut Atzi = vec![0.; c.len()];
+        A.gemv(&mut Atzi, &zi, 1., 0., MatrixShape::T);
+        let expected = res_dual * r.dot(&Atzi) / r.dot(&r);
+        assert!(f64::abs(residuals[k].2 - expected) <= 1e-10 * res_dual);
+    }
+}
@@ -0,0 +1,135 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[test]
+fn test_validate_cones_ok() {
+    let cones = vec![
+        ZeroConeT(1),
+        NonnegativeConeT(2),
+        SecondOrderConeT(3),
+        ExponentialConeT(),
+        PowerConeT(0.3),
+        GenPowerConeT(vec![0.25, 0.75], 2),
+        BoxConeT {
+            lower: vec![0., f64::NEG_INFINITY],
+            upper: vec![1., 1.],
+        },
+    ];
+    assert_eq!(validate_cones(&cones, 19), Ok(()));
+
+    // empty cones are allowed
+    let cones: Vec<SupportedConeT<f64>> = vec![ZeroConeT(0), NonnegativeConeT(0)];
+    assert_eq!(validate_cones(&cones, 0), Ok(()));
+}
+
+#[test]
+fn test_validate_cones_errors() {
+    let cones: Vec<SupportedConeT<f64>> = vec![NonnegativeConeT(2), SecondOrderConeT(0)];
+    assert_eq!(
+        validate_cones(&cones, 2),
+        Err(ConeError::BadSecondOrderDimension(1, 0))
+    );
+
+    let cones: Vec<SupportedConeT<f64>> = vec![SecondOrderConeT(1)];
+    assert_eq!(
+        validate_cones(&cones, 1),
+        Err(ConeError::BadSecondOrderDimension(0, 1))
+    );
+
+    let cones = vec![PowerConeT(1.5)];
+    assert_eq!(validate_cones(&cones, 3), Err(ConeError::BadPower(0)));
+
+    let cones = vec![PowerConeT(f64::NAN)];
+    assert_eq!(validate_cones(&cones, 3), Err(ConeError::BadPower(0)));
+
+    let cones: Vec<SupportedConeT<f64>> = vec![GenPowerConeT(vec![], 1)];
+    assert_eq!(validate_cones(&cones, 1), Err(ConeError::EmptyGenPowers(0)));
+
+    let cones = vec![GenPowerConeT(vec![1.5, -0.5], 1)];
+    assert_eq!(
+        validate_cones(&cones, 3),
+        Err(ConeError::NonpositiveGenPowers(0))
+    );
+
+    let cones = vec![GenPowerConeT(vec![0.5, 0.6], 1)];
+    assert_eq!(
+        validate_cones(&cones, 3),
+        Err(ConeError::BadGenPowersSum(0))
+    );
+
+    let cones = vec![BoxConeT {
+        lower: vec![0.],
+        upper: vec![1., 2.],
+    }];
+    assert_eq!(
+        validate_cones(&cones, 2),
+        Err(ConeError::BoxBoundsLength(0))
+    );
+
+    let cones = vec![BoxConeT {
+        lower: vec![0., 2.],
+        upper: vec![1., 2.],
+    }];
+    assert_eq!(validate_cones(&cones, 3), Err(ConeError::BoxBoundsOrder(0)));
+
+    let cones = vec![BoxConeT {
+        lower: vec![0., f64::NEG_INFINITY],
+        upper: vec![1., f64::INFINITY],
+    }];
+    assert_eq!(
+        validate_cones(&cones, 3),
+        Err(ConeError::BoxBoundsInfinite(0))
+    );
+
+    let cones: Vec<SupportedConeT<f64>> = vec![ZeroConeT(1), NonnegativeConeT(2)];
+    assert_eq!(
+        validate_cones(&cones, 4),
+        Err(ConeError::TotalDimension(3, 4))
+    );
+
+    // the first invalid cone is reported
+    let cones = vec![SecondOrderConeT(1), PowerConeT(2.)];
+    assert_eq!(
+        validate_cones(&cones, 4),
+        Err(ConeError::BadSecondOrderDimension(0, 1))
+    );
+}
+
+#[test]
+#[should_panic(expected = "SecondOrderConeT at position 0 has dimension 0")]
+fn test_validate_cones_on_construction() {
+    let P = CscMatrix::<f64>::zeros((1, 1));
+    let q = vec![1.];
+    let A = CscMatrix::<f64>::zeros((0, 1));
+    let b = vec![];
+    let cones = vec![SecondOrderConeT(0)];
+
+    let settings = DefaultSettings::default();
+    let _solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+}
+
+#[test]
+fn test_try_new_errors() {
+    let P = CscMatrix::<f64>::zeros((1, 1));
+    let q = vec![1.];
+    let A = CscMatrix::<f64>::zeros((0, 1));
+    let b = vec![];
+    let settings = DefaultSettings::default();
+
+    let cones = vec![SecondOrderConeT(0)];
+    let result = DefaultSolver::try_new(&P, &q, &A, &b, &cones, settings.clone());
+    let err = ConeError::BadSecondOrderDimension(0, 0);
+    assert_eq!(result.err(), Some(SetupError::BadCones(err)));
+
+    let b = vec![1.];
+    let cones = vec![ZeroConeT(1)];
+    let result = DefaultSolver::try_new(&P, &q, &A, &b, &cones, settings.clone());
+    assert!(matches!(
+        result.err(),
+        Some(SetupError::IncompatibleDimension(_))
+    ));
+
+    let A = CscMatrix::<f64>::zeros((1, 1));
+    assert!(DefaultSolver::try_new(&P, &q, &A, &b, &cones, settings).is_ok());
+}
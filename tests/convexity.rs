@@ -0,0 +1,98 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+fn solver_with_P(P: &CscMatrix<f64>) -> DefaultSolver<f64> {
+    // x ≥ -1, so that the data is otherwise well posed
+    let n = P.n;
+    let q = vec![1.; n];
+    let mut A = CscMatrix::identity(n);
+    A.negate();
+    let b = vec![1.; n];
+    let cones = vec![NonnegativeConeT(n)];
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    DefaultSolver::new(P, &q, &A, &b, &cones, settings)
+}
+
+#[test]
+fn test_convexity_positive_definite() {
+    // diagonally dominant
+    let P = CscMatrix::from(&[
+        [4., 1., 0.],  //
+        [1., 2., -1.], //
+        [0., -1., 3.], //
+    ]);
+    assert!(P.is_symmetric(0.));
+
+    // either as a full matrix or as its upper triangle
+    for P in [P.clone(), P.to_triu()] {
+        let check = solver_with_P(&P).verify_convexity();
+        assert!(check.symmetric);
+        assert_eq!(check.definiteness, Definiteness::PositiveSemidefinite);
+        assert!(check.is_verified());
+        assert_eq!(check.to_string(), "");
+    }
+
+    // an LP is trivially convex
+    let check = solver_with_P(&CscMatrix::zeros((3, 3))).verify_convexity();
+    assert!(check.is_verified());
+}
+
+#[test]
+fn test_convexity_positive_definite_unknown() {
+    // eigenvalues 2.8, 0.1 and 0.1, but not diagonally dominant
+    let P = CscMatrix::from(&[
+        [1., 0.9, 0.9], //
+        [0.9, 1., 0.9], //
+        [0.9, 0.9, 1.], //
+    ])
+    .to_triu();
+
+    let check = solver_with_P(&P).verify_convexity();
+    assert!(check.symmetric);
+    assert_ne!(check.definiteness, Definiteness::Indefinite);
+}
+
+#[test]
+fn test_convexity_indefinite() {
+    // a negative 2×2 principal minor, eigenvalues 3 and -1
+    let P = CscMatrix::from(&[
+        [1., 2.], //
+        [2., 1.], //
+    ])
+    .to_triu();
+    let check = solver_with_P(&P).verify_convexity();
+    assert!(check.symmetric);
+    assert_eq!(check.definiteness, Definiteness::Indefinite);
+    assert!(!check.is_verified());
+
+    // all 2×2 principal minors are positive, but
+    // xᵀPx = -2.4 for x = (1,-1,-1)
+    let P = CscMatrix::from(&[
+        [1., 0.9, 0.9],  //
+        [0.9, 1., -0.9], //
+        [0.9, -0.9, 1.], //
+    ])
+    .to_triu();
+    let check = solver_with_P(&P).verify_convexity();
+    assert_eq!(check.definiteness, Definiteness::Indefinite);
+    assert!(!check.to_string().is_empty());
+}
+
+#[test]
+fn test_convexity_asymmetric() {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [2., 2.], //
+    ]);
+    assert!(!P.is_symmetric(1e-8));
+
+    let check = solver_with_P(&P).verify_convexity();
+    assert!(!check.symmetric);
+    assert!(!check.is_verified());
+    assert!(check.to_string().contains("not symmetric"));
+}
@@ -0,0 +1,139 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn crossover_test_data(
+    n: usize,
+) -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min Σxᵢ  s.t.  Σxᵢ ≥ 1,  0 ≤ x ≤ 1
+    //
+    // Every point of the face Σxᵢ = 1, x ≥ 0 is optimal, and
+    // an interior point method converges to the middle of that face
+    let P = CscMatrix::<f64>::zeros((n, n));
+    let q = vec![1.; n];
+
+    let I = CscMatrix::<f64>::identity(n);
+    let mut negI = CscMatrix::<f64>::identity(n);
+    negI.negate();
+
+    let A = CscMatrix::vcat(&CscMatrix::from(&[vec![-1.; n]]), &negI);
+    let A = CscMatrix::vcat(&A, &I);
+    let b = [vec![-1.], vec![0.; n], vec![1.; n]].concat();
+
+    let cones = vec![NonnegativeConeT(2 * n + 1)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve(n: usize, crossover: bool) -> DefaultSolution<f64> {
+    let (P, q, A, b, cones) = crossover_test_data(n);
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .crossover(crossover)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver.solution
+}
+
+fn count_active(s: &[f64]) -> usize {
+    s.iter().filter(|&&si| si.abs() <= 1e-9).count()
+}
+
+#[test]
+fn test_crossover_vertex() {
+    let (_, q, A, b, _) = crossover_test_data(3);
+
+    let interior = solve(3, false);
+    assert_eq!(interior.status, SolverStatus::Solved);
+    assert!(interior.x.iter().all(|&xi| xi > 0.1));
+
+    let vertex = solve(3, true);
+    assert_eq!(vertex.status, SolverStatus::Solved);
+
+    // a vertex of the three dimensional feasible set.  The optimal
+    // vertices are degenerate, with the first constraint, two lower
+    // bounds and one upper bound all active
+    assert_eq!(count_active(&vertex.s), 4);
+    let nunit = vertex
+        .x
+        .iter()
+        .filter(|&&xi| (xi - 1.).abs() <= 1e-9)
+        .count();
+    let nzero = vertex.x.iter().filter(|&&xi| xi.abs() <= 1e-9).count();
+    assert_eq!((nunit, nzero), (1, 2));
+
+    // feasible, and with the same objective
+    let mut s = b.clone();
+    A.gemv(&mut s, &vertex.x, -1., 1., MatrixShape::N);
+    assert!(s.iter().all(|&si| si >= -1e-9));
+    assert!(s.dist(&vertex.s) <= 1e-9);
+    assert!((q.dot(&vertex.x) - q.dot(&interior.x)).abs() <= 1e-8);
+    assert!((vertex.obj_val - interior.obj_val).abs() <= 1e-8);
+
+    // the duals are unchanged
+    assert!(vertex.z.dist(&interior.z) <= 1e-12);
+}
+
+#[test]
+fn test_crossover_equality() {
+    // min x₁ + x₂  s.t.  x₁ + x₂ = 1,  x ≥ 0
+    let P = CscMatrix::<f64>::zeros((2, 2));
+    let q = vec![1., 1.];
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1., 0., 0.];
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(2)];
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .crossover(true)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let x = &solver.solution.x;
+    assert!((x[0] + x[1] - 1.).abs() <= 1e-9);
+    assert!(x[0].min(x[1]).abs() <= 1e-9);
+}
+
+#[test]
+fn test_crossover_ignored_for_qp() {
+    let (_, q, A, b, cones) = crossover_test_data(3);
+    let P = CscMatrix::<f64>::identity(3);
+
+    let mut solutions = Vec::new();
+    for crossover in [false, true] {
+        let settings = DefaultSettingsBuilder::default()
+            .verbose(false)
+            .crossover(crossover)
+            .build()
+            .unwrap();
+        let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+        solver.solve();
+        solutions.push(solver.solution.x);
+    }
+    assert_eq!(solutions[0], solutions[1]);
+}
+
+#[test]
+fn test_crossover_skipped_when_large() {
+    // crossover is skipped above 200 variables or constraints
+    let interior = solve(201, false);
+    let crossed = solve(201, true);
+    assert_eq!(crossed.status, SolverStatus::Solved);
+    assert_eq!(crossed.x, interior.x);
+}
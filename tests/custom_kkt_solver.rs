@@ -0,0 +1,108 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[allow(clippy::type_complexity)]
+fn custom_kkt_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // P = [4. 1;1 2]
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [0., 2.], //
+    ]);
+
+    let A = CscMatrix::from(&[
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+        [0., 0.],   //
+        [-1., 0.],  //
+        [0., -1.],  //
+    ]);
+
+    let c = vec![1., 1.];
+    let b = vec![-1., 0., 0., 1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(3), SecondOrderConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+// forwards everything to the default direct solver,
+// counting the number of solves along the way
+struct PassThroughKKTSolver {
+    inner: DirectLDLKKTSolver<f64>,
+    nsolves: Arc<AtomicUsize>,
+}
+
+impl KKTSolver<f64> for PassThroughKKTSolver {
+    fn update(&mut self, cones: &CompositeCone<f64>, settings: &CoreSettings<f64>) -> bool {
+        self.inner.update(cones, settings)
+    }
+    fn setrhs(&mut self, x: &[f64], z: &[f64]) {
+        self.inner.setrhs(x, z)
+    }
+    fn solve(
+        &mut self,
+        x: Option<&mut [f64]>,
+        z: Option<&mut [f64]>,
+        settings: &CoreSettings<f64>,
+    ) -> bool {
+        self.nsolves.fetch_add(1, Ordering::Relaxed);
+        self.inner.solve(x, z, settings)
+    }
+    fn update_P(&mut self, P: &CscMatrix<f64>) {
+        self.inner.update_P(P)
+    }
+    fn update_A(&mut self, A: &CscMatrix<f64>) {
+        self.inner.update_A(A)
+    }
+    fn kkt_info(&self) -> KKTInfo {
+        self.inner.kkt_info()
+    }
+    fn kkt_matrix(&self) -> &CscMatrix<f64> {
+        self.inner.kkt_matrix()
+    }
+}
+
+#[test]
+fn test_custom_kkt_solver() {
+    let (P, c, A, b, cones) = custom_kkt_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+
+    let mut refsolver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings.clone());
+    refsolver.solve();
+    assert_eq!(refsolver.solution.status, SolverStatus::Solved);
+
+    let nsolves = Arc::new(AtomicUsize::new(0));
+    let counter = nsolves.clone();
+    let factory: KKTSolverFactory<f64> = Box::new(move |P, A, cones, settings| {
+        let inner = DirectLDLKKTSolver::new(P, A, cones, A.m, A.n, settings);
+        Box::new(PassThroughKKTSolver {
+            inner,
+            nsolves: counter,
+        })
+    });
+
+    let mut solver = DefaultSolver::new_with_kkt_solver(&P, &c, &A, &b, &cones, settings, factory);
+    solver.solve();
+
+    // the custom solver was used, and gives the same result
+    assert!(nsolves.load(Ordering::Relaxed) > 0);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(solver.solution.iterations, refsolver.solution.iterations);
+    assert!(solver.solution.x.dist(&refsolver.solution.x) <= 1e-12);
+    assert!(solver.solution.z.dist(&refsolver.solution.z) <= 1e-12);
+}
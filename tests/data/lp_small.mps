@@ -0,0 +1,23 @@
+* minimize   -x - 2y
+* subject to -2 <= x + y <= 4
+*            x - y >= -2
+*            0 <= x <= 3, y >= 0
+*
+* optimal solution x = 1, y = 3, objective -7
+NAME          LPSMALL
+ROWS
+ N  COST
+ L  LIM1
+ G  LIM2
+COLUMNS
+    X         COST        -1.0   LIM1         1.0
+    X         LIM2         1.0
+    Y         COST        -2.0   LIM1         1.0
+    Y         LIM2        -1.0
+RHS
+    RHS       LIM1         4.0   LIM2        -2.0
+RANGES
+    RNG       LIM1         6.0
+BOUNDS
+ UP BND       X            3.0
+ENDATA
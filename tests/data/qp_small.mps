@@ -0,0 +1,22 @@
+* minimize   x^2 + xy + y^2
+* subject to x + y = 2
+*            0 <= x <= 0.5, y free
+*
+* optimal solution x = 0.5, y = 1.5, objective 3.25
+NAME          QPSMALL
+ROWS
+ N  OBJ
+ E  C1
+COLUMNS
+    X         C1           1.0
+    Y         C1           1.0
+RHS
+    RHS       C1           2.0
+BOUNDS
+ UP BND       X            0.5
+ FR BND       Y
+QUADOBJ
+    X         X            2.0
+    X         Y            1.0
+    Y         Y            2.0
+ENDATA
@@ -40,6 +40,37 @@ fn updating_test_data() -> (
     (P, q, A, b, cones, settings)
 }
 
+#[allow(clippy::type_complexity)]
+fn cost_scaled_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+    DefaultSettings<f64>,
+) {
+    // large P and q give cost scaling in the equilibration
+    let P = CscMatrix::from(&[
+        [4000., 1000.], //
+        [1000., 2000.], //
+    ])
+    .to_triu();
+    let q = vec![-1000., -3000.];
+
+    let mut A = CscMatrix::<f64>::identity(2);
+    A.negate();
+    let b = vec![0.; 2];
+
+    let cones = vec![NonnegativeConeT(2)];
+
+    let settings = DefaultSettingsBuilder::default()
+        .presolve_enable(false)
+        .build()
+        .unwrap();
+
+    (P, q, A, b, cones, settings)
+}
+
 #[test]
 fn test_update_P_matrix_form() {
     // original problem
@@ -183,6 +214,90 @@ fn test_update_A_tuple_form() {
     assert!(solver1.solution.x.dist(&solver2.solution.x) <= 1e-7);
 }
 
+#[test]
+fn test_update_P_A_values() {
+    // original problem
+    let (P, q, A, b, cones, settings) = updating_test_data();
+    let mut solver1 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    solver1.solve();
+
+    // change values only and re-solve
+    let mut P2 = P.to_triu();
+    P2.nzval[0] = 100.;
+    let mut A2 = A.clone();
+    A2.nzval[2] = -1000.;
+
+    assert!(solver1.update_P_values(&P2.nzval).is_ok());
+    assert!(solver1.update_A_values(&A2.nzval).is_ok());
+    solver1.solve();
+
+    //new solver
+    let mut solver2 = DefaultSolver::new(&P2, &q, &A2, &b, &cones, settings);
+    solver2.solve();
+
+    assert!(solver1.solution.x.dist(&solver2.solution.x) <= 1e-7);
+}
+
+#[test]
+fn test_update_P_A_values_cost_scaled() {
+    // as above, with large P and q so that the cost is scaled
+    let (P, q, A, b, cones, settings) = cost_scaled_test_data();
+    let mut solver1 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    assert!(solver1.equilibration().c != 1.);
+    solver1.solve();
+    let x = solver1.solution.x.clone();
+
+    // unchanged values leave the solution as it was
+    assert!(solver1.update_P_values(&P.nzval).is_ok());
+    solver1.solve();
+    assert!(solver1.solution.x.dist(&x) <= 1e-7);
+
+    // change values only and re-solve
+    let mut P2 = P.clone();
+    P2.nzval[0] = 8000.;
+    let mut A2 = A.clone();
+    A2.nzval[1] = -2.;
+
+    assert!(solver1.update_P_values(&P2.nzval).is_ok());
+    assert!(solver1.update_A_values(&A2.nzval).is_ok());
+    solver1.solve();
+
+    //new solver
+    let mut solver2 = DefaultSolver::new(&P2, &q, &A2, &b, &cones, settings);
+    solver2.solve();
+
+    assert_eq!(solver1.solution.status, SolverStatus::Solved);
+    assert!(solver1.solution.x.dist(&solver2.solution.x) <= 1e-7);
+}
+
+#[test]
+fn test_update_P_A_values_errors() {
+    let (P, q, A, b, cones, mut settings) = updating_test_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+
+    // empty inputs are not treated as no-ops here
+    let (Pnnz, Annz) = (P.to_triu().nnz(), A.nnz());
+    assert_eq!(
+        solver.update_P_values(&[]),
+        Err(SparseUpdateError::NnzMismatch(0, Pnnz))
+    );
+    assert_eq!(
+        solver.update_A_values(&vec![1.; Annz + 1]),
+        Err(SparseUpdateError::NnzMismatch(Annz + 1, Annz))
+    );
+
+    settings.presolve_enable = true;
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    assert_eq!(
+        solver.update_P_values(&vec![1.; Pnnz]),
+        Err(SparseUpdateError::PresolveEnabled)
+    );
+    assert_eq!(
+        solver.update_A_values(&vec![1.; Annz]),
+        Err(SparseUpdateError::PresolveEnabled)
+    );
+}
+
 #[test]
 fn test_update_q() {
     // original problem
@@ -228,6 +343,76 @@ fn test_update_q_tuple() {
     assert!(solver1.solution.x.dist(&solver2.solution.x) <= 1e-7);
 }
 
+#[test]
+fn test_update_q_entries() {
+    // large P and q give cost scaling in the equilibration
+    let (mut P, q, A, b, cones, settings) = updating_test_data();
+    P.scale(1000.);
+    let q: Vec<f64> = q.iter().map(|&v| 1000. * v).collect();
+
+    let mut q2 = q.clone();
+    q2[1] = 5000.;
+
+    // sparse update of the original solver
+    let mut solver1 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    solver1.solve();
+    assert!(solver1.update_q_entries(&[1], &[5000.]).is_ok());
+    solver1.solve();
+
+    // full update of the original solver
+    let mut solver2 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    solver2.solve();
+    assert!(solver2.update_q(&q2).is_ok());
+    solver2.solve();
+
+    //new solver
+    let mut solver3 = DefaultSolver::new(&P, &q2, &A, &b, &cones, settings);
+    solver3.solve();
+
+    assert!(solver1.solution.x.dist(&solver2.solution.x) <= 1e-7);
+    assert!(solver1.solution.x.dist(&solver3.solution.x) <= 1e-7);
+}
+
+#[test]
+fn test_update_data_cost_scaled() {
+    let (P, q, A, b, cones, settings) = cost_scaled_test_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    assert!(solver.equilibration().c != 1.);
+
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    let x = solver.solution.x.clone();
+    assert!(x.dist(&[0., 1.5]) <= 1e-6);
+
+    // updating with unchanged data leaves the problem as it was
+    assert!(solver.update_data(&P.nzval, &q, &A.nzval, &b).is_ok());
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(&x) <= 1e-7);
+}
+
+#[test]
+fn test_update_q_entries_errors() {
+    let (P, q, A, b, cones, mut settings) = updating_test_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+
+    assert_eq!(
+        solver.update_q_entries(&[0, 1], &[1.]),
+        Err(SparseUpdateError::LengthMismatch(2, 1))
+    );
+    assert_eq!(
+        solver.update_q_entries(&[0, 2], &[1., 1.]),
+        Err(SparseUpdateError::IndexOutOfBounds(2))
+    );
+
+    settings.presolve_enable = true;
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    assert_eq!(
+        solver.update_q_entries(&[0], &[1.]),
+        Err(SparseUpdateError::PresolveEnabled)
+    );
+}
+
 #[test]
 fn test_update_b() {
     // original problem
@@ -329,3 +514,48 @@ fn test_fail_on_presolve_enable() {
         Some(DataUpdateError::PresolveEnabled)
     ));
 }
+
+#[test]
+fn test_update_b_scaled() {
+    // tighten the lower bounds on x so that they are active
+    let (P, q, A, b, cones, settings) = updating_test_data();
+    let b2 = vec![0.1, 0.1, 1., 1.];
+
+    let mut solver1 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    solver1.update_b(&b2).unwrap();
+    solver1.solve();
+
+    // scaling b2 by e gives the same problem as update_b
+    let e = solver1.equilibration().e.to_vec();
+    assert!(e.iter().any(|&ei| ei != 1.));
+    let b2_scaled: Vec<f64> = zip(&b2, &e).map(|(bi, ei)| bi * ei).collect();
+
+    let mut solver2 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    solver2.update_b_scaled(&b2_scaled).unwrap();
+    solver2.solve();
+    assert!(solver1.solution.x.dist(&solver2.solution.x) <= 1e-12);
+
+    // without scaling, it is the problem with b2 divided by e
+    let mut solver3 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    solver3.update_b_scaled(&b2).unwrap();
+    solver3.solve();
+    assert_eq!(solver3.solution.status, SolverStatus::Solved);
+    assert!(solver1.solution.x.dist(&solver3.solution.x) > 1e-3);
+
+    let b3: Vec<f64> = zip(&b2, &e).map(|(bi, ei)| bi / ei).collect();
+    let mut solver4 = DefaultSolver::new(&P, &q, &A, &b3, &cones, settings);
+    solver4.solve();
+    assert!(solver3.solution.x.dist(&solver4.solution.x) <= 1e-7);
+
+    // the slacks are consistent with the effective b
+    let mut Ax = vec![0.; A.m];
+    A.gemv(&mut Ax, &solver3.solution.x, 1., 0., MatrixShape::N);
+    for i in 0..A.m {
+        assert!((Ax[i] + solver3.solution.s[i] - b3[i]).abs() <= 1e-7);
+    }
+
+    assert!(matches!(
+        solver3.update_b_scaled(&[1.]).err(),
+        Some(DataUpdateError::BadFormat(_))
+    ));
+}
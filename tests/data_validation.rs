@@ -0,0 +1,109 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn data_validation_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [0., 2.], //
+    ]);
+    let q = vec![1., 1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(2)];
+
+    (P, q, A, b, cones)
+}
+
+#[test]
+fn test_validate_data() {
+    let (P, q, A, b, _) = data_validation_test_data();
+    assert_eq!(validate_data(&P, &q, &A, &b), Ok(()));
+
+    let nonfinite = |field, index| Err(DataError::NonFinite { field, index });
+
+    let mut P1 = P.clone();
+    P1.nzval[2] = f64::NAN;
+    assert_eq!(validate_data(&P1, &q, &A, &b), nonfinite("P", 2));
+
+    let mut q1 = q.clone();
+    q1[1] = f64::NAN;
+    assert_eq!(validate_data(&P, &q1, &A, &b), nonfinite("q", 1));
+
+    let mut A1 = A.clone();
+    A1.nzval[1] = f64::NAN;
+    assert_eq!(validate_data(&P, &q, &A1, &b), nonfinite("A", 1));
+
+    let mut b1 = b.clone();
+    b1[2] = f64::NAN;
+    assert_eq!(validate_data(&P, &q, &A, &b1), nonfinite("b", 2));
+}
+
+#[test]
+fn test_validate_data_infinite() {
+    let (P, q, A, b, _) = data_validation_test_data();
+
+    let mut q1 = q.clone();
+    q1[0] = f64::INFINITY;
+    assert!(validate_data(&P, &q1, &A, &b).is_err());
+
+    let mut A1 = A.clone();
+    A1.nzval[0] = f64::NEG_INFINITY;
+    assert!(validate_data(&P, &q, &A1, &b).is_err());
+
+    // only +∞ is allowed in b, marking an absent bound
+    let mut b1 = b.clone();
+    b1[1] = f64::NEG_INFINITY;
+    assert!(validate_data(&P, &q, &A, &b1).is_err());
+    b1[1] = f64::INFINITY;
+    assert_eq!(validate_data(&P, &q, &A, &b1), Ok(()));
+}
+
+#[test]
+fn test_validate_data_infinite_bound_solve() {
+    let (P, q, A, mut b, cones) = data_validation_test_data();
+    b[1] = f64::INFINITY;
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+}
+
+#[test]
+#[should_panic(expected = "Non-finite value in A at index 1")]
+fn test_solver_nonfinite_data() {
+    let (P, q, mut A, b, cones) = data_validation_test_data();
+    A.nzval[1] = f64::NAN;
+    DefaultSolver::new(&P, &q, &A, &b, &cones, DefaultSettings::default());
+}
+
+#[test]
+fn test_try_new_nonfinite_data() {
+    let (P, q, mut A, b, cones) = data_validation_test_data();
+    A.nzval[1] = f64::NAN;
+
+    let settings = DefaultSettings::default();
+    let result = DefaultSolver::try_new(&P, &q, &A, &b, &cones, settings);
+    let err = DataError::NonFinite {
+        field: "A",
+        index: 1,
+    };
+    assert_eq!(result.err(), Some(SetupError::BadData(err)));
+}
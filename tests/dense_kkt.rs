@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn dense_kkt_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½x'Px + q'x, s.t. x1 + x2 + x3 = 1, x >= 0
+    let P = CscMatrix::from(&[
+        [4., 1., 0.], //
+        [1., 2., 0.], //
+        [0., 0., 1.], //
+    ])
+    .to_triu();
+    let q = vec![1., -1., 0.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+    ]);
+    let b = vec![1., 0., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve_with(threshold: usize) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = dense_kkt_data();
+    let settings = DefaultSettingsBuilder::default()
+        .dense_kkt_threshold(threshold)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_dense_kkt_selected() {
+    // the default threshold selects the dense factorization,
+    // which computes no fill-reducing ordering
+    let dense = solve_with(DefaultSettings::<f64>::default().dense_kkt_threshold);
+    let sparse = solve_with(0);
+
+    let dim = dense.kkt_matrix().n;
+    assert_eq!(dim, 7);
+    assert_eq!(dense.kkt_info().num_symbolic_factorizations, 0);
+    assert_eq!(dense.kkt_info().nnz_L, dim * (dim - 1) / 2);
+    let identity: Vec<usize> = (0..dim).collect();
+    assert_eq!(dense.kkt_ordering().unwrap(), identity.as_slice());
+
+    assert_eq!(sparse.kkt_info().num_symbolic_factorizations, 1);
+    assert!(sparse.kkt_info().nnz_L < dim * (dim - 1) / 2);
+
+    assert_eq!(dense.solution.status, SolverStatus::Solved);
+    assert_eq!(sparse.solution.status, SolverStatus::Solved);
+    assert!(dense.solution.x.dist(&sparse.solution.x) <= 1e-8);
+    assert!(dense.solution.z.dist(&sparse.solution.z) <= 1e-8);
+    assert!(f64::abs(dense.solution.obj_val - sparse.solution.obj_val) <= 1e-8);
+}
+
+#[test]
+fn test_dense_kkt_threshold() {
+    // the KKT dimension must be strictly less than the threshold
+    let solver = solve_with(7);
+    assert_eq!(solver.kkt_info().num_symbolic_factorizations, 1);
+    let solver = solve_with(8);
+    assert_eq!(solver.kkt_info().num_symbolic_factorizations, 0);
+}
+
+#[test]
+fn test_dense_kkt_socp() {
+    // a problem with a sparse expanded second order cone,
+    // which adds extra dimensions to the KKT system
+    let P = CscMatrix::<f64>::identity(6);
+    let q = vec![1., -1., 1., -1., 1., -1.];
+    let mut A = CscMatrix::<f64>::identity(6);
+    A.negate();
+    let A = CscMatrix::vcat(&CscMatrix::from(&[[0.; 6]]), &A);
+    let mut b = vec![0.; 7];
+    b[0] = 1.;
+    let cones = vec![SecondOrderConeT(7)];
+
+    let solve = |threshold| {
+        let settings = DefaultSettingsBuilder::default()
+            .dense_kkt_threshold(threshold)
+            .verbose(false)
+            .build()
+            .unwrap();
+        let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+        solver.solve();
+        solver
+    };
+    let dense = solve(100);
+    let sparse = solve(0);
+
+    assert_eq!(dense.kkt_info().num_symbolic_factorizations, 0);
+    assert_eq!(dense.solution.status, SolverStatus::Solved);
+    assert_eq!(sparse.solution.status, SolverStatus::Solved);
+    assert!(dense.solution.x.dist(&sparse.solution.x) <= 1e-8);
+}
@@ -0,0 +1,119 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn diagnostics_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min x1² + x2² + x1 s.t. x1 + x2 = 1, x ≥ 0, which has no issues
+    let P = CscMatrix::from(&[
+        [2., 0.], //
+        [0., 2.], //
+    ]);
+    let q = vec![1., 0.];
+
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(2)];
+
+    (P, q, A, b, cones)
+}
+
+fn diagnostics(
+    P: &CscMatrix<f64>,
+    q: &[f64],
+    A: &CscMatrix<f64>,
+    b: &[f64],
+    cones: &[SupportedConeT<f64>],
+) -> Diagnostics {
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    DefaultSolver::new(P, q, A, b, cones, settings).diagnostics()
+}
+
+// appends a variable with the given objective terms and no constraints
+fn with_extra_variable(
+    q: &[f64],
+    A: &CscMatrix<f64>,
+    Pjj: f64,
+    qj: f64,
+) -> (CscMatrix<f64>, Vec<f64>, CscMatrix<f64>) {
+    let P = CscMatrix::from(&[
+        [2., 0., 0.],  //
+        [0., 2., 0.],  //
+        [0., 0., Pjj], //
+    ]);
+    let mut q = q.to_vec();
+    q.push(qj);
+    let A = CscMatrix::hcat(A, &CscMatrix::zeros((A.m, 1)));
+    (P, q, A)
+}
+
+#[test]
+fn test_diagnostics_none() {
+    let (P, q, A, b, cones) = diagnostics_data();
+    let report = diagnostics(&P, &q, &A, &b, &cones);
+
+    assert!(report.is_empty());
+    assert_eq!(report.to_string(), "");
+}
+
+#[test]
+fn test_diagnostics_zero_column() {
+    let (_, q, A, b, cones) = diagnostics_data();
+    let (P, q, A) = with_extra_variable(&q, &A, 0., 0.);
+    let report = diagnostics(&P, &q, &A, &b, &cones);
+
+    assert_eq!(report.zero_columns, vec![2]);
+    assert!(report.redundant_constraints.is_empty());
+    assert!(report.objective_only.is_empty());
+}
+
+#[test]
+fn test_diagnostics_objective_only() {
+    let (_, q, A, b, cones) = diagnostics_data();
+
+    // once in the linear and once in the quadratic term
+    for (Pjj, qj) in [(0., 1.), (1., 0.)] {
+        let (P, q, A) = with_extra_variable(&q, &A, Pjj, qj);
+        let report = diagnostics(&P, &q, &A, &b, &cones);
+
+        assert_eq!(report.objective_only, vec![2]);
+        assert!(report.zero_columns.is_empty());
+        assert!(report.redundant_constraints.is_empty());
+        assert!(report.to_string().contains("only in the objective"));
+    }
+}
+
+#[test]
+fn test_diagnostics_redundant_constraints() {
+    let (P, q, A, _, _) = diagnostics_data();
+
+    // zero rows for 0 = 0, 0 ≤ 1 and 0 ≤ -1, of which only
+    // the last is not redundant (and is infeasible)
+    let A = CscMatrix::vcat(&A, &CscMatrix::zeros((3, 2)));
+    let b = vec![1., 0., 0., 0., 1., -1.];
+    let cones = vec![
+        ZeroConeT(1),
+        NonnegativeConeT(2),
+        ZeroConeT(1),
+        NonnegativeConeT(2),
+    ];
+    let report = diagnostics(&P, &q, &A, &b, &cones);
+
+    assert_eq!(report.redundant_constraints, vec![3, 4]);
+    assert!(report.zero_columns.is_empty());
+    assert!(report.objective_only.is_empty());
+}
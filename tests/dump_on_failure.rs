@@ -0,0 +1,178 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "serde")]
+
+use clarabel::{algebra::*, solver::*};
+use std::fs::File;
+use std::sync::{Arc, Mutex};
+
+#[allow(clippy::type_complexity)]
+fn dump_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [0., 2.], //
+    ]);
+
+    let A = CscMatrix::from(&[
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+        [1., 1.],   //
+    ]);
+
+    let c = vec![1., 1.];
+    let b = vec![-1., 0., 0., 2.];
+
+    let cones = vec![NonnegativeConeT(4)];
+
+    (P, c, A, b, cones)
+}
+
+// fails every factorization or linear solve once the first few
+// have succeeded, recording the right hand side of each solve
+struct FailingKKTSolver {
+    inner: DirectLDLKKTSolver<f64>,
+    nupdates: usize,
+    nsolves: usize,
+    max_updates: usize,
+    max_solves: usize,
+    rhs: Arc<Mutex<Vec<f64>>>,
+}
+
+impl KKTSolver<f64> for FailingKKTSolver {
+    fn update(&mut self, cones: &CompositeCone<f64>, settings: &CoreSettings<f64>) -> bool {
+        self.nupdates += 1;
+        self.nupdates <= self.max_updates && self.inner.update(cones, settings)
+    }
+    fn setrhs(&mut self, x: &[f64], z: &[f64]) {
+        *self.rhs.lock().unwrap() = [x, z].concat();
+        self.inner.setrhs(x, z)
+    }
+    fn solve(
+        &mut self,
+        x: Option<&mut [f64]>,
+        z: Option<&mut [f64]>,
+        settings: &CoreSettings<f64>,
+    ) -> bool {
+        self.nsolves += 1;
+        self.nsolves <= self.max_solves && self.inner.solve(x, z, settings)
+    }
+    fn update_P(&mut self, P: &CscMatrix<f64>) {
+        self.inner.update_P(P)
+    }
+    fn update_A(&mut self, A: &CscMatrix<f64>) {
+        self.inner.update_A(A)
+    }
+    fn kkt_info(&self) -> KKTInfo {
+        self.inner.kkt_info()
+    }
+    fn kkt_matrix(&self) -> &CscMatrix<f64> {
+        self.inner.kkt_matrix()
+    }
+}
+
+fn failing_solver(
+    settings: DefaultSettings<f64>,
+    max_updates: usize,
+    max_solves: usize,
+    rhs: Arc<Mutex<Vec<f64>>>,
+) -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = dump_test_data();
+    let factory: KKTSolverFactory<f64> = Box::new(move |P, A, cones, settings| {
+        let inner = DirectLDLKKTSolver::new(P, A, cones, A.m, A.n, settings);
+        Box::new(FailingKKTSolver {
+            inner,
+            nupdates: 0,
+            nsolves: 0,
+            max_updates,
+            max_solves,
+            rhs,
+        })
+    });
+    DefaultSolver::new_with_kkt_solver(&P, &c, &A, &b, &cones, settings, factory)
+}
+
+#[test]
+fn test_dump_on_failure() {
+    let path = std::env::temp_dir().join("clarabel_dump_on_failure.json");
+    let _ = std::fs::remove_file(&path);
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .dump_on_failure(Some(path.clone()))
+        .build()
+        .unwrap();
+    let rhs = Arc::new(Mutex::new(vec![]));
+    let mut solver = failing_solver(settings, usize::MAX, 4, rhs.clone());
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::NumericalError);
+
+    assert!(path.exists());
+    let dump = KKTDump::read_from_file(&mut File::open(&path).unwrap()).unwrap();
+
+    // the KKT matrix round trips, up to JSON float parsing
+    let K = solver.kkt_matrix();
+    assert!(dump.kkt.check_format().is_ok());
+    assert_eq!((dump.kkt.m, dump.kkt.n), (K.m, K.n));
+    assert_eq!(dump.kkt.colptr, K.colptr);
+    assert_eq!(dump.kkt.rowval, K.rowval);
+    assert!(dump.kkt.nzval.dist(&K.nzval) <= 1e-14);
+
+    // the right hand side of the failed solve
+    let (n, m) = (2, 4);
+    let dumprhs = dump.rhs.unwrap();
+    assert_eq!(dumprhs.len(), n + m);
+    assert!(dumprhs.dist(&rhs.lock().unwrap()) <= 1e-14);
+
+    // diagonal scalings for the nonnegative cone
+    assert_eq!(dump.Hs.len(), m);
+    assert!(dump.Hs.iter().all(|&v| v > 0.));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_dump_on_failure_factorization() {
+    let path = std::env::temp_dir().join("clarabel_dump_on_failure_factor.json");
+    let _ = std::fs::remove_file(&path);
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .dump_on_failure(Some(path.clone()))
+        .build()
+        .unwrap();
+    let rhs = Arc::new(Mutex::new(vec![]));
+    let mut solver = failing_solver(settings, 3, usize::MAX, rhs);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::NumericalError);
+
+    // there is no right hand side for a failed factorization
+    let dump = KKTDump::read_from_file(&mut File::open(&path).unwrap()).unwrap();
+    assert!(dump.rhs.is_none());
+    assert_eq!(dump.kkt.n, solver.kkt_matrix().n);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_dump_on_failure_not_written() {
+    let path = std::env::temp_dir().join("clarabel_dump_on_success.json");
+    let _ = std::fs::remove_file(&path);
+
+    // no file is written for a successful solve
+    let (P, c, A, b, cones) = dump_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .dump_on_failure(Some(path.clone()))
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(!path.exists());
+}
@@ -0,0 +1,74 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn effective_settings_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::identity(2);
+    let q = vec![1., -1.];
+    let A = CscMatrix::identity(2);
+    let b = vec![1., 1.];
+    let cones = vec![NonnegativeConeT(2)];
+
+    (P, q, A, b, cones)
+}
+
+#[test]
+fn test_effective_settings_adjusted() {
+    let (P, q, A, b, cones) = effective_settings_test_data();
+
+    // out of range values
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .tol_gap_abs(-1e-8)
+        .tol_feas(0.)
+        .max_step_fraction(1.5)
+        .print_stride(0)
+        .build()
+        .unwrap();
+
+    let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    let effective = solver.effective_settings();
+
+    assert_ne!(effective.tol_gap_abs, settings.tol_gap_abs);
+    assert_eq!(effective.tol_gap_abs, f64::EPSILON);
+    assert_eq!(effective.tol_feas, f64::EPSILON);
+    assert_eq!(effective.max_step_fraction, 1.);
+    assert_eq!(effective.print_stride, 1);
+
+    // values within range are unchanged
+    assert_eq!(effective.tol_gap_rel, settings.tol_gap_rel);
+    assert_eq!(effective.max_iter, settings.max_iter);
+}
+
+#[test]
+fn test_effective_settings_default() {
+    let (P, q, A, b, cones) = effective_settings_test_data();
+    let settings = DefaultSettings::default();
+    let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+
+    let effective = solver.effective_settings();
+    assert_eq!(effective.tol_gap_abs, settings.tol_gap_abs);
+    assert_eq!(effective.tol_feas, settings.tol_feas);
+    assert_eq!(effective.max_step_fraction, settings.max_step_fraction);
+    assert_eq!(effective.print_stride, settings.print_stride);
+}
+
+#[test]
+fn test_effective_settings_rebuild() {
+    let (P, q, A, b, cones) = effective_settings_test_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, DefaultSettings::default());
+
+    let settings = DefaultSettings {
+        tol_gap_rel: -1.,
+        ..Default::default()
+    };
+    solver.rebuild(&P, &q, &A, &b, &cones, settings);
+    assert_eq!(solver.effective_settings().tol_gap_rel, f64::EPSILON);
+}
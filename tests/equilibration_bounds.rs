@@ -95,3 +95,122 @@ fn test_equilibrate_zero_rows() {
 
     assert!(e.iter().all(|&v| v == 1.));
 }
+
+#[test]
+fn test_equilibration_view() {
+    let (P, c, mut A, b, cones) = equilibration_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .equilibrate_enable(true)
+        .build()
+        .unwrap();
+
+    // badly scaled rows and columns
+    A.nzval[0] = 1e+8;
+    A.nzval[3] = 1e-8;
+
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings.clone());
+    solver.solve();
+
+    let equil = solver.equilibration();
+
+    assert_eq!(equil.d.len(), A.n);
+    assert_eq!(equil.e.len(), A.m);
+    assert!(equil.d.minimum() >= settings.equilibrate_min_scaling);
+    assert!(equil.e.minimum() >= settings.equilibrate_min_scaling);
+    assert!(equil.d.maximum() <= settings.equilibrate_max_scaling);
+    assert!(equil.e.maximum() <= settings.equilibrate_max_scaling);
+    assert!(equil.c > 0.);
+
+    // the data was actually rescaled
+    assert!(equil.d.iter().chain(equil.e.iter()).any(|&v| v != 1.));
+
+    for (&e, &einv) in equil.e.iter().zip(equil.einv.iter()) {
+        assert!(f64::abs(e * einv - 1.) <= 1e-12);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn ill_conditioned_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // the standard test problem with badly
+    // scaled rows and columns
+    let (mut P, mut c, mut A, mut b, cones) = equilibration_test_data();
+
+    let d = vec![1e4, 1e-4];
+    let e = vec![1e-4, 1e4, 1., 1e4, 1e-4, 1.];
+
+    P.lrscale(&d, &d);
+    A.lrscale(&e, &d);
+    c.hadamard(&d);
+    b.hadamard(&e);
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_equilibration_methods_bounds() {
+    let (P, c, A, b, cones) = ill_conditioned_test_data();
+
+    for method in ["ruiz", "l2", "geometric"] {
+        for (min_scaling, max_scaling) in [(1e-5, 1e5), (1e-2, 1e2)] {
+            let settings = DefaultSettingsBuilder::default()
+                .equilibration_method(method.to_string())
+                .equilibrate_min_scaling(min_scaling)
+                .equilibrate_max_scaling(max_scaling)
+                .build()
+                .unwrap();
+
+            let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+            solver.solve();
+
+            assert_eq!(solver.solution.status, SolverStatus::Solved);
+            assert!(f64::abs(solver.solution.obj_val - 1.88) <= 1e-5);
+
+            let equil = solver.equilibration();
+            assert!(equil.d.minimum() >= min_scaling);
+            assert!(equil.e.minimum() >= min_scaling);
+            assert!(equil.d.maximum() <= max_scaling);
+            assert!(equil.e.maximum() <= max_scaling);
+        }
+    }
+}
+
+#[test]
+fn test_equilibration_ruiz_iterations() {
+    let (P, c, A, b, cones) = ill_conditioned_test_data();
+
+    let settings = DefaultSettingsBuilder::default()
+        .equilibrate_enable(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    let iter_none = solver.solution.iterations;
+
+    let settings = DefaultSettingsBuilder::default()
+        .equilibration_method("ruiz".to_string())
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    let iter_ruiz = solver.solution.iterations;
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(iter_ruiz < iter_none);
+}
+
+#[test]
+#[should_panic]
+fn test_equilibration_bad_method() {
+    let (P, c, A, b, cones) = equilibration_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .equilibration_method("foo".to_string())
+        .build()
+        .unwrap();
+    DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+}
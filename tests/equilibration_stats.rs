@@ -0,0 +1,97 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn badly_scaled_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // a QP with entries of P and A over many orders of magnitude
+    let P = CscMatrix::from(&[
+        [1e4, 1., 0.],  //
+        [1., 1e-2, 0.], //
+        [0., 0., 1e-3], //
+    ])
+    .to_triu();
+    let q = vec![1., -1e3, 1e-2];
+
+    let A = CscMatrix::from(&[
+        [1e3, 1e-3, 1.], //
+        [-1e3, 0., 0.],  //
+        [0., -1e-3, 0.], //
+        [0., 0., -1e2],  //
+    ]);
+    let b = vec![1., 0., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings(max_iter: u32) -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .equilibrate_max_iter(max_iter)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_equilibration_iters() {
+    let (P, q, A, b, cones) = badly_scaled_data();
+    let settings = settings(10);
+    let max_iter = settings.equilibrate_max_iter;
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    assert!(solver.info.equilibration_iters > 0);
+    assert!(solver.info.equilibration_iters <= max_iter);
+    assert!(solver.info.equilibration_ratio >= 1.);
+
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(
+        solver.solution.equilibration_iters,
+        solver.info.equilibration_iters
+    );
+}
+
+#[test]
+fn test_equilibration_ratio_improves() {
+    // more iterations give better equilibrated data
+    let (P, q, A, b, cones) = badly_scaled_data();
+    let solver1 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(1));
+    let solver10 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(10));
+
+    assert_eq!(solver1.info.equilibration_iters, 1);
+    assert!(solver10.info.equilibration_ratio < solver1.info.equilibration_ratio);
+}
+
+#[test]
+fn test_equilibration_iters_early_exit() {
+    // already equilibrated data is left unchanged, with no iterations
+    let P = CscMatrix::identity(2);
+    let q = vec![1., 1.];
+    let A = CscMatrix::identity(2);
+    let b = vec![1., 1.];
+    let cones = vec![NonnegativeConeT(2)];
+
+    let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(10));
+    assert_eq!(solver.info.equilibration_iters, 0);
+    assert_eq!(solver.info.equilibration_ratio, 1.);
+    assert_eq!(solver.equilibration().d, &[1., 1.]);
+}
+
+#[test]
+fn test_equilibration_iters_disabled() {
+    let (P, q, A, b, cones) = badly_scaled_data();
+    let mut settings = settings(10);
+    settings.equilibrate_enable = false;
+
+    let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    assert_eq!(solver.info.equilibration_iters, 0);
+    assert!(solver.info.equilibration_ratio.is_nan());
+}
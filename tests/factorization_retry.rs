@@ -0,0 +1,88 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn factorization_retry_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min x1 s.t. x1 ≥ 1.  The variable x2 appears nowhere, so
+    // the KKT matrix is rank deficient, with a zero row and column
+    let P = CscMatrix::<f64>::zeros((2, 2));
+    let q = vec![1., 0.];
+
+    let A = CscMatrix::from(&[
+        [-1., 0.], //
+    ]);
+    let b = vec![-1.];
+
+    let cones = vec![NonnegativeConeT(1)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings(max_retries: u32) -> DefaultSettings<f64> {
+    // without static regularization and with a zero dynamic
+    // regularization threshold, the zero pivot for x2 is left in
+    // place and the factorization fails.  One retry raises the
+    // threshold to machine epsilon, so that the pivot is regularized
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .equilibrate_enable(false)
+        .static_regularization_enable(false)
+        .dynamic_regularization_eps(0.)
+        .factorization_retry_factor(100.)
+        .factorization_max_retries(max_retries)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_factorization_retry_succeeds() {
+    let (P, q, A, b, cones) = factorization_retry_data();
+
+    for kkt_form in ["full", "reduced"] {
+        let mut settings = settings(3);
+        settings.kkt_form = kkt_form.to_string();
+        let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+        solver.solve();
+
+        assert_eq!(solver.solution.status, SolverStatus::Solved);
+        assert!(f64::abs(solver.solution.x[0] - 1.) <= 1e-6);
+
+        // the configured threshold is restored after every retry, so
+        // each factorization needs exactly one.  There is one for the
+        // initial point and another in each iteration
+        let nfactor = solver.solution.iterations as usize + 1;
+        assert_eq!(solver.info.factorization_retries, nfactor);
+        assert_eq!(solver.kkt_info().num_factorization_retries, nfactor);
+    }
+}
+
+#[test]
+fn test_factorization_retry_disabled() {
+    let (P, q, A, b, cones) = factorization_retry_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(0));
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::NumericalError);
+    assert_eq!(solver.info.factorization_retries, 0);
+}
+
+#[test]
+fn test_factorization_retry_not_needed() {
+    let (P, q, A, b, cones) = factorization_retry_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(solver.info.factorization_retries, 0);
+}
@@ -0,0 +1,176 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+// simple deterministic generator for test data in [-1,1]
+fn lcg_values(seed: u64, len: usize) -> Vec<f64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 11) as f64) / ((1u64 << 53) as f64) * 2. - 1.
+        })
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+fn medium_socp_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // x in R^24, split into blocks with ||x_k|| <= 1, and
+    // random linear inequalities Gx <= 1 feasible at x = 0
+    let n = 24;
+    let nblocks = 4;
+    let blockdim = n / nblocks;
+    let mlin = 16;
+
+    let P = CscMatrix::<f64>::identity(n);
+    let q = lcg_values(1, n);
+
+    let g = lcg_values(2, mlin * n);
+    let (mut rows, mut cols, mut vals) = (vec![], vec![], vec![]);
+    for i in 0..mlin {
+        for j in 0..n {
+            if (i + j) % 3 == 0 {
+                rows.push(i);
+                cols.push(j);
+                vals.push(g[i * n + j]);
+            }
+        }
+    }
+    let mut b = vec![1.; mlin];
+    let mut cones = vec![NonnegativeConeT(mlin)];
+
+    // second order cone blocks (1, x_k), of dimension
+    // large enough to use the sparse SOC representation
+    for k in 0..nblocks {
+        let row0 = mlin + k * (blockdim + 1);
+        for j in 0..blockdim {
+            rows.push(row0 + 1 + j);
+            cols.push(k * blockdim + j);
+            vals.push(-1.);
+        }
+        b.push(1.);
+        b.extend(vec![0.; blockdim]);
+        cones.push(SecondOrderConeT(blockdim + 1));
+    }
+
+    let m = b.len();
+    let A = CscMatrix::from_triplets(m, n, &rows, &cols, &vals);
+
+    (P, q, A, b, cones)
+}
+
+fn solve_with(direct: bool) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = medium_socp_data();
+    let settings = DefaultSettingsBuilder::default()
+        .direct_kkt_solver(direct)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_indirect_minres_socp() {
+    let direct = solve_with(true);
+    let indirect = solve_with(false);
+
+    assert_eq!(direct.solution.status, SolverStatus::Solved);
+    assert_eq!(indirect.solution.status, SolverStatus::Solved);
+
+    // no factorization takes place in the indirect solver
+    assert_eq!(indirect.kkt_info().nnz_L, 0);
+    assert!(indirect.kkt_info().nnz_kkt > 0);
+
+    let (x, refx) = (&indirect.solution.x, &direct.solution.x);
+    assert!(x.dist(refx) <= 1e-6);
+    assert!(indirect.solution.z.dist(&direct.solution.z) <= 1e-6);
+
+    let refobj = direct.solution.obj_val;
+    assert!(f64::abs(indirect.solution.obj_val - refobj) <= 1e-6);
+}
+
+#[test]
+fn test_indirect_minres_eq_constrained() {
+    // min ½x'x + q'x, s.t. x1 + x2 + x3 = 1, x >= 0
+    let P = CscMatrix::<f64>::identity(3);
+    let q = vec![1., 3., 4.];
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+    ]);
+    let b = vec![1., 0., 0., 0.];
+    let cones = [ZeroConeT(1), NonnegativeConeT(3)];
+
+    let settings = DefaultSettingsBuilder::default()
+        .direct_kkt_solver(false)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    let refsol = vec![1., 0., 0.];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+}
+
+#[test]
+fn test_indirect_stop_metric() {
+    let (P, q, A, b, cones) = medium_socp_data();
+
+    let solve_with_metric = |metric: &str| {
+        let settings = DefaultSettingsBuilder::default()
+            .direct_kkt_solver(false)
+            .indirect_stop_metric(metric.to_string())
+            .verbose(false)
+            .build()
+            .unwrap();
+        let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+        solver.solve();
+        solver
+    };
+
+    let direct = solve_with(true);
+    let refobj = direct.solution.obj_val;
+    assert_eq!(direct.kkt_info().num_iterative_solver_iters, 0);
+
+    let mut counts = vec![];
+    for metric in ["absolute", "relative", "preconditioned"] {
+        let solver = solve_with_metric(metric);
+        assert_eq!(solver.solution.status, SolverStatus::Solved);
+        assert!(solver.solution.x.dist(&direct.solution.x) <= 1e-6);
+        assert!(f64::abs(solver.solution.obj_val - refobj) <= 1e-6);
+        counts.push(solver.kkt_info().num_iterative_solver_iters);
+    }
+
+    // the absolute metric is the most stringent
+    assert!(counts.iter().all(|&c| c > 0));
+    assert!(counts[0] > counts[1]);
+    assert!(counts[0] > counts[2]);
+}
+
+#[test]
+#[should_panic]
+fn test_indirect_stop_metric_unrecognized() {
+    let (P, q, A, b, cones) = medium_socp_data();
+    let settings = DefaultSettingsBuilder::default()
+        .direct_kkt_solver(false)
+        .indirect_stop_metric("foo".to_string())
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+}
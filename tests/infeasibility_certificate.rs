@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn certificate_lp_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // -1 <= x <= 1
+    let P = CscMatrix::<f64>::zeros((2, 2));
+
+    let I1 = CscMatrix::<f64>::identity(2);
+    let mut I2 = CscMatrix::<f64>::identity(2);
+    I2.negate();
+    let A = CscMatrix::vcat(&I1, &I2);
+
+    let c = vec![1., -1.];
+    let b = vec![1.; 4];
+
+    let cones = vec![NonnegativeConeT(4)];
+
+    (P, c, A, b, cones)
+}
+
+fn solve(
+    P: &CscMatrix<f64>,
+    c: &[f64],
+    A: &CscMatrix<f64>,
+    b: &[f64],
+    cones: &[SupportedConeT<f64>],
+) -> DefaultSolver<f64> {
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(P, c, A, b, cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_certificate_feasible() {
+    let (P, c, A, b, cones) = certificate_lp_data();
+    let solver = solve(&P, &c, &A, &b, &cones);
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.infeasibility_certificate().is_none());
+}
+
+#[test]
+fn test_certificate_primal_infeasible() {
+    // x1 <= -1 and x1 >= 1
+    let (P, c, A, mut b, cones) = certificate_lp_data();
+    b[0] = -1.;
+    b[2] = -1.;
+
+    let solver = solve(&P, &c, &A, &b, &cones);
+    assert_eq!(solver.solution.status, SolverStatus::PrimalInfeasible);
+
+    let cert = solver.solution.infeasibility_certificate().unwrap();
+    let tol = solver.settings.tol_infeas_abs;
+    assert!(cert.is_valid(&P, &c, &A, &b, tol));
+
+    match &cert {
+        Certificate::PrimalInfeasible { z } => {
+            // z in the dual cone, Aᵀz = 0 and bᵀz < 0
+            let mut Atz = vec![0.; A.n];
+            A.gemv(&mut Atz, z, 1., 0., MatrixShape::T);
+            assert!(z.iter().all(|&zi| zi >= 0.));
+            assert!(Atz.norm_inf() <= 1e-8);
+            assert!(b.dot(z) < 0.);
+        }
+        _ => panic!("expected a primal certificate"),
+    }
+
+    // and is rejected for the feasible problem
+    let (_, _, _, b, _) = certificate_lp_data();
+    assert!(!cert.is_valid(&P, &c, &A, &b, tol));
+}
+
+#[test]
+fn test_certificate_dual_infeasible() {
+    // drop the lower bound on x1, so that min x1 is unbounded
+    let (P, c, A, b, _) = certificate_lp_data();
+    let A = A.select_rows(&vec![true, true, false, true]);
+    let b = b[0..3].to_vec();
+    let cones = vec![NonnegativeConeT(3)];
+
+    let solver = solve(&P, &c, &A, &b, &cones);
+    assert_eq!(solver.solution.status, SolverStatus::DualInfeasible);
+
+    let cert = solver.solution.infeasibility_certificate().unwrap();
+    let tol = solver.settings.tol_infeas_abs;
+    assert!(cert.is_valid(&P, &c, &A, &b, tol));
+
+    match &cert {
+        Certificate::DualInfeasible { x } => {
+            // -Ax in the cone and qᵀx < 0
+            let mut Ax = vec![0.; A.m];
+            A.gemv(&mut Ax, x, 1., 0., MatrixShape::N);
+            assert!(Ax.iter().all(|&v| v <= 1e-8));
+            assert!(c.dot(x) < 0.);
+        }
+        _ => panic!("expected a dual certificate"),
+    }
+}
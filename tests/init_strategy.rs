@@ -0,0 +1,117 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn equality_qp_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½x'Px + q'x  s.t.  Aeq x = beq,  -1000 ≤ x ≤ 1000,
+    // with mostly equality constraints and inactive bounds
+    let n = 50;
+    let meq = 45;
+
+    let idx: Vec<usize> = (0..n).collect();
+    let Pdiag: Vec<f64> = (0..n).map(|i| 1. + (i % 4) as f64).collect();
+    let P = CscMatrix::from_triplets(n, n, &idx, &idx, &Pdiag);
+    let q: Vec<f64> = (0..n).map(|i| 100. * (((i * 7) % 5) as f64 - 2.)).collect();
+
+    let (mut rows, mut cols, mut vals) = (vec![], vec![], vec![]);
+    for i in 0..meq {
+        for j in [i, i + 1, i + 5] {
+            rows.push(i);
+            cols.push(j);
+            vals.push(1. + ((i + 2 * j) % 3) as f64);
+        }
+    }
+    let Aeq = CscMatrix::from_triplets(meq, n, &rows, &cols, &vals);
+    let beq: Vec<f64> = (0..meq).map(|i| (i % 3) as f64 - 1.).collect();
+
+    let I = CscMatrix::identity(n);
+    let mut mI = I.clone();
+    mI.negate();
+    let A = CscMatrix::vcat(&Aeq, &CscMatrix::vcat(&I, &mI));
+
+    let mut b = beq;
+    b.extend(vec![1000.; 2 * n]);
+
+    let cones = vec![ZeroConeT(meq), NonnegativeConeT(2 * n)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings(init_strategy: &str) -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .init_strategy(init_strategy.to_string())
+        .build()
+        .unwrap()
+}
+
+fn solve_with(init_strategy: &str) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = equality_qp_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(init_strategy));
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_init_strategy_equality_project() {
+    let default = solve_with("default");
+    let project = solve_with("equality_project");
+
+    assert_eq!(default.solution.status, SolverStatus::Solved);
+    assert_eq!(project.solution.status, SolverStatus::Solved);
+    assert!(project.solution.iterations < default.solution.iterations);
+
+    assert!(project.solution.x.dist(&default.solution.x) <= 1e-6);
+    let refobj = default.solution.obj_val;
+    assert!(f64::abs(project.solution.obj_val - refobj) <= 1e-8 * f64::abs(refobj));
+}
+
+#[test]
+fn test_init_strategy_equality_project_dense() {
+    // the projection is factored with the same LDL solver
+    // as the KKT system, here the dense one
+    let (P, q, A, b, cones) = equality_qp_data();
+    let mut settings = settings("equality_project");
+    settings.dense_kkt_threshold = 1000;
+    let mut dense = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    dense.solve();
+
+    let sparse = solve_with("equality_project");
+
+    assert_eq!(dense.solution.status, SolverStatus::Solved);
+    assert_eq!(dense.solution.iterations, sparse.solution.iterations);
+    assert!(dense.solution.x.dist(&sparse.solution.x) <= 1e-6);
+}
+
+#[test]
+fn test_init_strategy_no_equalities() {
+    // without zero cones the initial point is unchanged
+    let P = CscMatrix::identity(2);
+    let q = vec![1., -1.];
+    let A = CscMatrix::identity(2);
+    let b = vec![1., 1.];
+    let cones = vec![NonnegativeConeT(2)];
+
+    let mut solver1 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings("default"));
+    solver1.solve();
+    let mut solver2 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings("equality_project"));
+    solver2.solve();
+
+    assert_eq!(solver1.solution.iterations, solver2.solution.iterations);
+    assert_eq!(solver1.solution.x, solver2.solution.x);
+}
+
+#[test]
+#[should_panic(expected = "Unrecognized initialization strategy")]
+fn test_init_strategy_unrecognized() {
+    let mut solver = solve_with("default");
+    solver.settings.init_strategy = "bad".to_string();
+    solver.solve();
+}
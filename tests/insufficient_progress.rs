@@ -0,0 +1,78 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn stalling_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min x1 + x2  s.t.  x1 + x2 ≥ 1,  x ≥ 0
+    let P = CscMatrix::zeros((2, 2));
+    let q = vec![1., 1.];
+
+    let A = CscMatrix::from(&[
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+    ]);
+    let b = vec![-1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+// short steps, so that the residuals decrease only slowly
+fn stalling_settings(window: u32) -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .max_step_fraction(0.01)
+        .max_iter(50)
+        .insufficient_progress_window(window)
+        .insufficient_progress_tol(0.05)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_insufficient_progress_window() {
+    let (P, q, A, b, cones) = stalling_data();
+
+    // runs to the iteration limit by default
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, stalling_settings(0));
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::MaxIterations);
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, stalling_settings(5));
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::InsufficientProgress);
+    assert!(solver.solution.iterations >= 5);
+    assert!(solver.solution.iterations < 50);
+}
+
+#[test]
+fn test_insufficient_progress_window_default() {
+    // the check is disabled by default, and
+    // does not stop a well-behaved solve
+    let (P, q, A, b, cones) = stalling_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    assert_eq!(settings.insufficient_progress_window, 0);
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    solver.solve();
+
+    let mut settings = settings;
+    settings.insufficient_progress_window = 5;
+    let mut solver5 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver5.solve();
+
+    assert_eq!(solver5.solution.status, SolverStatus::Solved);
+    assert_eq!(solver5.solution.iterations, solver.solution.iterations);
+}
@@ -0,0 +1,130 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "serde")]
+
+use clarabel::io::jl::*;
+use clarabel::{algebra::*, solver::*};
+use std::fs::File;
+
+#[allow(clippy::type_complexity)]
+fn jl_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1., 0.], //
+        [1., 2., 0.], //
+        [0., 0., 0.], //
+    ])
+    .to_triu();
+    let q = vec![1., 1., -1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 0.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [0., 0., 0.],  //
+        [0., 0., 0.],  //
+        [0., 0., -1.], //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+    ]);
+    let b = vec![1., 0., 0., 1., 1., 1., 1., 0., 0.];
+
+    let cones = vec![
+        ZeroConeT(1),
+        NonnegativeConeT(2),
+        ExponentialConeT(),
+        PowerConeT(0.3),
+    ];
+
+    (P, q, A, b, cones)
+}
+
+#[test]
+fn test_jl_round_trip() {
+    let (P, q, A, b, cones) = jl_test_data();
+
+    let mut buffer = Vec::new();
+    write_jl(&mut buffer, &P, &q, &A, &b, &cones).unwrap();
+
+    // one based indices and Julia cone type names
+    let text = String::from_utf8(buffer.clone()).unwrap();
+    assert!(text.contains(r#""colptr":[1,"#));
+    assert!(text.contains(r#"{"type":"PowerConeT","α":0.3}"#));
+    assert!(text.contains(r#"{"type":"ExponentialConeT"}"#));
+
+    let (P2, q2, A2, b2, cones2) = read_jl(buffer.as_slice()).unwrap();
+    assert_eq!(P, P2);
+    assert_eq!(q, q2);
+    assert_eq!(A, A2);
+    assert_eq!(b, b2);
+    assert_eq!(format!("{:?}", cones), format!("{:?}", cones2));
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver1 = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    let mut solver2 = DefaultSolver::new(&P2, &q2, &A2, &b2, &cones2, settings);
+    solver1.solve();
+    solver2.solve();
+    assert_eq!(solver1.solution.status, solver2.solution.status);
+    assert_eq!(solver1.solution.x, solver2.solution.x);
+}
+
+#[test]
+fn test_jl_unsupported_cones() {
+    let P = CscMatrix::<f64>::zeros((1, 1));
+    let q = vec![1.];
+    let A = CscMatrix::from(&[[0.], [1.]]);
+    let b = vec![1., 1.];
+    let cones = vec![BoxConeT {
+        lower: vec![0.],
+        upper: vec![1.],
+    }];
+
+    let mut buffer = Vec::new();
+    let err = write_jl(&mut buffer, &P, &q, &A, &b, &cones).unwrap_err();
+    assert!(matches!(err, JlError::UnsupportedCones(_)));
+}
+
+#[test]
+fn test_jl_bad_matrix() {
+    // zero based indices are rejected
+    let text = r#"{
+        "P": {"m":1,"n":1,"colptr":[0,1],"rowval":[0],"nzval":[1.0]},
+        "q": [1.0],
+        "A": {"m":1,"n":1,"colptr":[1,2],"rowval":[1],"nzval":[1.0]},
+        "b": [1.0],
+        "cones": [{"type":"NonnegativeConeT","dim":1}]
+    }"#;
+    let err = read_jl(text.as_bytes()).unwrap_err();
+    assert!(matches!(err, JlError::BadMatrix("P")));
+}
+
+#[test]
+fn test_jl_fixture() {
+    // the basic SOCP test problem, as exported from Clarabel.jl
+    let path = format!(
+        "{}/tests/data/socp_clarabel_jl.json",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let (P, q, A, b, cones) = read_jl(File::open(path).unwrap()).unwrap();
+    assert!(P.is_triu());
+    assert_eq!(cones.len(), 3);
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    let refsol = vec![-0.5, 0.435603, -0.245459];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-4);
+}
@@ -0,0 +1,213 @@
+#![allow(non_snake_case)]
+
+use clarabel::io::lp::*;
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn lp_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min (1/2)x'Px + q'x, s.t. x1 + x2 + x3 = 1, x >= 0, 0.1 <= x3 <= 0.5
+    let P = CscMatrix::from(&[
+        [4., 1., 0.], //
+        [1., 2., 0.], //
+        [0., 0., 1.], //
+    ])
+    .to_triu();
+    let q = vec![1., -1., 0.5];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [0., 0., 0.],  //
+        [0., 0., 1.],  //
+    ]);
+    let b = vec![1., 0., 0., 0., 1., 0.];
+
+    let cones = vec![
+        ZeroConeT(1),
+        NonnegativeConeT(3),
+        BoxConeT {
+            lower: vec![-0.5],
+            upper: vec![-0.1],
+        },
+    ];
+
+    (P, q, A, b, cones)
+}
+
+// A minimal reader for the subset of the LP format produced by
+// write_lp, i.e. with all equalities and inequalities rewritten
+// as ZeroConeT and NonnegativeConeT constraints.
+#[allow(clippy::type_complexity)]
+fn read_lp_minimal(
+    text: &str,
+    n: usize,
+) -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let var = |tok: &str| tok.strip_prefix('x').unwrap().parse::<usize>().unwrap();
+    let num = |tok: &str| tok.parse::<f64>().unwrap();
+
+    let lines: Vec<&str> = text.lines().filter(|l| !l.starts_with('\\')).collect();
+    let start = |name: &str| lines.iter().position(|l| *l == name).unwrap();
+    let tokens = |from: usize, to: usize| -> Vec<String> {
+        lines[from + 1..to]
+            .iter()
+            .flat_map(|l| l.split_whitespace().map(|t| t.to_string()))
+            .collect()
+    };
+    let objtoks = tokens(start("Minimize"), start("Subject To"));
+    let contoks = tokens(start("Subject To"), start("Bounds"));
+
+    // objective terms
+    let mut q = vec![0.; n];
+    let (mut Prows, mut Pcols, mut Pvals) = (vec![], vec![], vec![]);
+    let mut k = 1; // skip "obj:"
+    while k < objtoks.len() {
+        match objtoks[k].as_str() {
+            "[" | "]" | "/" | "2" => k += 1,
+            s @ ("+" | "-") if objtoks[k + 1] != "[" => {
+                let v = if s == "-" { -1. } else { 1. } * num(&objtoks[k + 1]);
+                let i = var(&objtoks[k + 2]);
+                if objtoks.get(k + 3).map(|t| t.as_str()) == Some("^") {
+                    Prows.push(i);
+                    Pcols.push(i);
+                    Pvals.push(v);
+                    k += 5;
+                } else if objtoks.get(k + 3).map(|t| t.as_str()) == Some("*") {
+                    let j = var(&objtoks[k + 4]);
+                    Prows.push(i);
+                    Pcols.push(j);
+                    Pvals.push(v / 2.);
+                    k += 5;
+                } else {
+                    q[i] = v;
+                    k += 3;
+                }
+            }
+            _ => k += 1,
+        }
+    }
+    let P = CscMatrix::from_triplets(n, n, &Prows, &Pcols, &Pvals);
+
+    // constraint rows, with equalities first
+    let mut eqrows = vec![];
+    let mut ineqrows = vec![];
+    let mut k = 0;
+    while k < contoks.len() {
+        // skip the name
+        k += 1;
+        let mut row = vec![];
+        if contoks[k] == "0" {
+            // an empty row
+            k += 2;
+        }
+        while !["=", "<=", ">="].contains(&contoks[k].as_str()) {
+            let v = if contoks[k] == "-" { -1. } else { 1. } * num(&contoks[k + 1]);
+            row.push((var(&contoks[k + 2]), v));
+            k += 3;
+        }
+        let rhs = num(&contoks[k + 1]);
+        match contoks[k].as_str() {
+            "=" => eqrows.push((row, rhs)),
+            "<=" => ineqrows.push((row, rhs)),
+            _ => ineqrows.push((row.iter().map(|&(j, v)| (j, -v)).collect(), -rhs)),
+        }
+        k += 2;
+    }
+
+    let (meq, mineq) = (eqrows.len(), ineqrows.len());
+    let (mut Arows, mut Acols, mut Avals) = (vec![], vec![], vec![]);
+    let mut b = vec![];
+    for (i, (row, rhs)) in eqrows.into_iter().chain(ineqrows).enumerate() {
+        for (j, v) in row {
+            Arows.push(i);
+            Acols.push(j);
+            Avals.push(v);
+        }
+        b.push(rhs);
+    }
+    let A = CscMatrix::from_triplets(meq + mineq, n, &Arows, &Acols, &Avals);
+    let cones = vec![ZeroConeT(meq), NonnegativeConeT(mineq)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve(
+    P: &CscMatrix<f64>,
+    q: &[f64],
+    A: &CscMatrix<f64>,
+    b: &[f64],
+    cones: &[SupportedConeT<f64>],
+) -> DefaultSolution<f64> {
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(P, q, A, b, cones, settings);
+    solver.solve();
+    solver.solution
+}
+
+#[test]
+fn test_lp_round_trip() {
+    let (P, q, A, b, cones) = lp_test_data();
+
+    let mut buffer = Vec::new();
+    write_lp(&mut buffer, &P, &q, &A, &b, &cones).unwrap();
+    let text = String::from_utf8(buffer).unwrap();
+
+    assert!(text.contains("Minimize"));
+    assert!(text.contains(" c0: + 1.0 x0 + 1.0 x1 + 1.0 x2 = 1.0"));
+    assert!(text.contains(" x2 free"));
+
+    let (P2, q2, A2, b2, cones2) = read_lp_minimal(&text, q.len());
+
+    let sol = solve(&P, &q, &A, &b, &cones);
+    let sol2 = solve(&P2, &q2, &A2, &b2, &cones2);
+
+    assert_eq!(sol.status, SolverStatus::Solved);
+    assert_eq!(sol2.status, SolverStatus::Solved);
+    assert!(sol.x.dist(&sol2.x) <= 1e-6);
+    assert!(f64::abs(sol.obj_val - sol2.obj_val) <= 1e-6);
+}
+
+#[test]
+fn test_lp_unsupported_cones() {
+    let (P, q, A, b, _) = lp_test_data();
+    let cones = vec![
+        SecondOrderConeT(2),
+        ExponentialConeT(),
+        SecondOrderConeT(2),
+        NonnegativeConeT(1),
+    ];
+
+    // each unsupported cone type is reported once
+    let err = write_lp(Vec::new(), &P, &q, &A, &b, &cones).unwrap_err();
+    match err {
+        LpError::UnsupportedCones(names) => {
+            assert_eq!(names, vec!["SecondOrderCone", "ExponentialCone"])
+        }
+        _ => panic!("unexpected error"),
+    }
+}
+
+#[test]
+fn test_lp_bad_dimensions() {
+    let (P, q, A, _, cones) = lp_test_data();
+    let b = vec![0.; 2];
+
+    let err = write_lp(Vec::new(), &P, &q, &A, &b, &cones).unwrap_err();
+    assert!(matches!(err, LpError::IncompatibleDimensions));
+}
@@ -0,0 +1,111 @@
+#![allow(non_snake_case)]
+
+use clarabel::io::mps::*;
+use clarabel::{algebra::*, solver::*};
+use std::fs::File;
+
+fn solve_mps_file(filename: &str) -> DefaultSolver<f64> {
+    let path = format!("{}/tests/data/{}", env!("CARGO_MANIFEST_DIR"), filename);
+    let file = File::open(path).unwrap();
+    let (P, q, A, b, cones) = read_mps(file).unwrap();
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_mps_lp() {
+    let solver = solve_mps_file("lp_small.mps");
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![1., 3.];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+
+    let refobj = -7.;
+    assert!(f64::abs(solver.solution.obj_val - refobj) <= 1e-6);
+}
+
+#[test]
+fn test_mps_qp() {
+    let solver = solve_mps_file("qp_small.mps");
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![0.5, 1.5];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+
+    let refobj = 3.25;
+    assert!(f64::abs(solver.solution.obj_val - refobj) <= 1e-6);
+}
+
+#[test]
+fn test_mps_structure() {
+    let mps = "\
+NAME TEST
+OBJSENSE
+    MAX
+ROWS
+ N  obj
+ E  c1
+ L  c2
+COLUMNS
+    x  obj  1.0  c1  1.0
+    x  c2   2.0
+    y  obj  3.0  c2  1.0
+RHS
+    rhs  c1  1.0  c2  4.0
+BOUNDS
+ MI bnd  y
+ UP bnd  y  2.0
+ENDATA
+";
+    let (P, q, A, b, cones) = read_mps(mps.as_bytes()).unwrap();
+
+    // maximization is converted to minimization
+    assert_eq!(q, vec![-1., -3.]);
+    assert_eq!(P.nnz(), 0);
+
+    // rows are [c1; c2; x >= 0; y <= 2]
+    assert_eq!(A.m, 4);
+    assert_eq!(b, vec![1., 4., 0., 2.]);
+    assert_eq!(A.colptr, vec![0, 3, 5]);
+    assert_eq!(A.rowval, vec![0, 1, 2, 1, 3]);
+    assert_eq!(A.nzval, vec![1., 2., -1., 1., 1.]);
+    assert!(matches!(cones[..], [ZeroConeT(1), NonnegativeConeT(3)]));
+}
+
+#[test]
+fn test_mps_errors() {
+    let mps = "ROWS\n N obj\nCOLUMNS\n x obj 1.0 c1 2.0\nENDATA\n";
+    assert!(matches!(
+        read_mps(mps.as_bytes()),
+        Err(MpsError::UnknownRow(_))
+    ));
+
+    let mps = "ROWS\n L c1\nCOLUMNS\n x c1 1.0\nENDATA\n";
+    assert!(matches!(
+        read_mps(mps.as_bytes()),
+        Err(MpsError::NoObjective)
+    ));
+
+    let mps = "ROWS\n N obj\nCOLUMNS\n x obj abc\nENDATA\n";
+    assert!(matches!(
+        read_mps(mps.as_bytes()),
+        Err(MpsError::BadLine(4, _))
+    ));
+
+    let mps = "ROWS\n N obj\nFOO\nENDATA\n";
+    assert!(matches!(
+        read_mps(mps.as_bytes()),
+        Err(MpsError::UnknownSection(_))
+    ));
+
+    let mps = "ROWS\n N obj\nCOLUMNS\n x obj 1.0\nBOUNDS\n UP bnd z 1.0\nENDATA\n";
+    assert!(matches!(
+        read_mps(mps.as_bytes()),
+        Err(MpsError::UnknownColumn(_))
+    ));
+}
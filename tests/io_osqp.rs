@@ -0,0 +1,114 @@
+#![allow(non_snake_case)]
+
+use clarabel::io::osqp::*;
+use clarabel::{algebra::*, solver::*};
+use std::fs::File;
+
+fn push_ints(bytes: &mut Vec<u8>, v: &[usize]) {
+    v.iter()
+        .for_each(|&x| bytes.extend((x as i64).to_le_bytes()));
+}
+
+fn push_floats(bytes: &mut Vec<u8>, v: &[f64]) {
+    v.iter().for_each(|&x| bytes.extend(x.to_le_bytes()));
+}
+
+// serialize data in OSQP's CSC binary format
+fn osqp_bytes(P: &CscMatrix<f64>, q: &[f64], A: &CscMatrix<f64>, l: &[f64], u: &[f64]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    push_ints(&mut bytes, &[A.n, A.m]);
+    for (M, v) in [(P, q), (A, l)] {
+        push_ints(&mut bytes, &[M.m, M.n, M.nnz()]);
+        push_ints(&mut bytes, &M.colptr);
+        push_ints(&mut bytes, &M.rowval);
+        push_floats(&mut bytes, &M.nzval);
+        push_floats(&mut bytes, v);
+    }
+    push_floats(&mut bytes, u);
+    bytes
+}
+
+#[test]
+fn test_osqp_demo() {
+    // the OSQP demo problem, with known solution from OSQP
+    let path = format!("{}/tests/data/osqp_demo.bin", env!("CARGO_MANIFEST_DIR"));
+    let (P, q, A, b, cones) = read_osqp(File::open(path).unwrap()).unwrap();
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![0.3, 0.7];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+
+    let refobj = 1.88;
+    assert!(f64::abs(solver.solution.obj_val - refobj) <= 1e-6);
+}
+
+#[test]
+fn test_osqp_structure() {
+    let P = CscMatrix::from(&[
+        [1., 0.], //
+        [0., 0.], //
+    ]);
+    let q = vec![1., -1.];
+    let A = CscMatrix::from(&[
+        [1., 1.], //
+        [2., 0.], //
+        [0., 3.], //
+        [4., 0.], //
+    ]);
+    let l = vec![1., -OSQP_INFTY, -1., f64::NEG_INFINITY];
+    let u = vec![1., 5., OSQP_INFTY, f64::INFINITY];
+
+    let bytes = osqp_bytes(&P, &q, &A, &l, &u);
+    let (P2, q2, A2, b, cones) = read_osqp(bytes.as_slice()).unwrap();
+
+    assert_eq!(P2, P);
+    assert_eq!(q2, q);
+
+    // rows are [x1 + x2 = 1; 2x1 <= 5; -3x2 <= 1], with the free row dropped
+    assert_eq!(A2.m, 3);
+    assert_eq!(b, vec![1., 5., 1.]);
+    assert_eq!(A2.colptr, vec![0, 2, 4]);
+    assert_eq!(A2.rowval, vec![0, 1, 0, 2]);
+    assert_eq!(A2.nzval, vec![1., 2., 1., -3.]);
+    assert!(matches!(cones[..], [ZeroConeT(1), NonnegativeConeT(2)]));
+}
+
+#[test]
+fn test_osqp_errors() {
+    let P = CscMatrix::identity(2);
+    let q = vec![1., 1.];
+    let A = CscMatrix::identity(2);
+
+    // truncated data
+    let bytes = osqp_bytes(&P, &q, &A, &[0., 0.], &[1., 1.]);
+    assert!(matches!(
+        read_osqp(&bytes[..bytes.len() - 1]),
+        Err(OsqpError::Io(_))
+    ));
+
+    // lower bound exceeding the upper bound
+    let bytes = osqp_bytes(&P, &q, &A, &[0., 2.], &[1., 1.]);
+    assert!(matches!(
+        read_osqp(bytes.as_slice()),
+        Err(OsqpError::BadFormat(_))
+    ));
+
+    // P not upper triangular
+    let P = CscMatrix::from(&[
+        [1., 0.], //
+        [1., 1.], //
+    ]);
+    let bytes = osqp_bytes(&P, &q, &A, &[0., 0.], &[1., 1.]);
+    assert!(matches!(
+        read_osqp(bytes.as_slice()),
+        Err(OsqpError::BadFormat(_))
+    ));
+}
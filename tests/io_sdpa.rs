@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "sdp")]
+
+use clarabel::io::sdpa::*;
+use clarabel::{algebra::*, solver::*};
+use std::fs::File;
+
+#[test]
+fn test_sdpa_example1() {
+    let path = format!("{}/tests/data/example1.dat-s", env!("CARGO_MANIFEST_DIR"));
+    let file = File::open(path).unwrap();
+    let prob = read_sdpa(file).unwrap();
+
+    assert_eq!(prob.q, vec![48., -8., 20.]);
+    assert_eq!(prob.A.m, 3);
+    assert_eq!(prob.A.n, 3);
+    assert!(matches!(prob.cones[..], [PSDTriangleConeT(2)]));
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&prob.P, &prob.q, &prob.A, &prob.b, &prob.cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![-1.1, -2.7375, -0.55];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+
+    let refobj = -41.9;
+    assert!(f64::abs(solver.solution.obj_val - refobj) <= 1e-6);
+}
+
+#[test]
+fn test_sdpa_diagonal_block() {
+    // one 2x2 PSD block followed by a 2 element diagonal block
+    let sdpa = "\
+* comment line
+2
+2
+(2, -2)
+1.0 2.0
+0 2 1 1 3.0
+1 1 1 2 1.0
+1 2 2 2 -1.0
+2 1 2 2 4.0
+";
+    let prob = read_sdpa(sdpa.as_bytes()).unwrap();
+
+    assert_eq!(prob.q, vec![1., 2.]);
+    assert!(matches!(
+        prob.cones[..],
+        [PSDTriangleConeT(2), NonnegativeConeT(2)]
+    ));
+    assert_eq!(prob.b, vec![0., 0., 0., -3., 0.]);
+    assert_eq!(prob.A.colptr, vec![0, 2, 3]);
+    assert_eq!(prob.A.rowval, vec![1, 4, 2]);
+    assert_eq!(prob.A.nzval, vec![-f64::sqrt(2.), 1., -4.]);
+    assert_eq!(prob.P.nnz(), 0);
+}
+
+#[test]
+fn test_sdpa_errors() {
+    // off diagonal entry in a diagonal block
+    let sdpa = "1\n1\n-2\n1.0\n1 1 1 2 1.0\n";
+    assert!(matches!(
+        read_sdpa(sdpa.as_bytes()),
+        Err(SdpaError::BadFormat(_))
+    ));
+
+    // block index out of range
+    let sdpa = "1\n1\n2\n1.0\n1 2 1 1 1.0\n";
+    assert!(matches!(
+        read_sdpa(sdpa.as_bytes()),
+        Err(SdpaError::BadFormat(_))
+    ));
+
+    // incomplete entry
+    let sdpa = "1\n1\n2\n1.0\n1 1 1 1\n";
+    assert!(matches!(
+        read_sdpa(sdpa.as_bytes()),
+        Err(SdpaError::BadFormat(_))
+    ));
+}
@@ -0,0 +1,135 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc, Mutex,
+};
+
+#[allow(clippy::type_complexity)]
+fn callback_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::<f64>::identity(3);
+
+    let I1 = CscMatrix::<f64>::identity(3);
+    let mut I2 = CscMatrix::<f64>::identity(3);
+    I2.negate();
+    let mut A = CscMatrix::vcat(&I1, &I2);
+    A.scale(2.);
+
+    let c = vec![3., -2., 1.];
+    let b = vec![1.; 6];
+
+    let cones = vec![NonnegativeConeT(3), NonnegativeConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_callback_terminate() {
+    let (P, c, A, b, cones) = callback_test_data();
+
+    let settings = DefaultSettings::default();
+
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    solver.set_iteration_callback(|info, _variables| {
+        if info.iterations >= 3 {
+            CallbackAction::Terminate
+        } else {
+            CallbackAction::Continue
+        }
+    });
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::CallbackTerminated);
+    assert_eq!(solver.solution.iterations, 3);
+    assert_eq!(solver.solution.x.len(), 3);
+}
+
+#[test]
+fn test_callback_continue() {
+    let (P, c, A, b, cones) = callback_test_data();
+
+    let settings = DefaultSettings::default();
+
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    let ncalls = Arc::new(AtomicU32::new(0));
+    let counter = Arc::clone(&ncalls);
+    solver.set_iteration_callback(move |_info, _variables| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        CallbackAction::Continue
+    });
+
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(ncalls.load(Ordering::SeqCst), solver.solution.iterations);
+
+    // removing the callback restores normal behaviour
+    solver.unset_iteration_callback();
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(ncalls.load(Ordering::SeqCst), solver.solution.iterations);
+}
+
+#[test]
+fn test_callback_current_iterate() {
+    // LP : max x₁ + x₂ subject to x ≥ 0, x₁ + 2x₂ ≤ 2, 2x₁ + x₂ ≤ 2
+    let P = CscMatrix::<f64>::zeros((2, 2));
+    let mut I = CscMatrix::<f64>::identity(2);
+    I.negate();
+    let A = CscMatrix::from(&[
+        [1., 2.], //
+        [2., 1.], //
+    ]);
+    let A = CscMatrix::vcat(&I, &A);
+    let c = vec![-1., -1.];
+    let b = vec![0., 0., 2., 2.];
+    let cones = vec![NonnegativeConeT(4)];
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    // nothing to report before the first iteration
+    let (pcost, dcost) = solver.current_objective();
+    let (rprim, rdual) = solver.current_residuals();
+    assert!(pcost.is_nan() && dcost.is_nan());
+    assert!(rprim.is_nan() && rdual.is_nan());
+
+    let history = Arc::new(Mutex::new(Vec::new()));
+    let record = Arc::clone(&history);
+    solver.set_iteration_callback(move |info, _variables| {
+        let (pcost, dcost) = info.current_objective();
+        let (rprim, rdual) = info.current_residuals();
+        record.lock().unwrap().push((pcost, dcost, rprim, rdual));
+        CallbackAction::Continue
+    });
+
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let history = history.lock().unwrap();
+    assert!(history.len() >= 3);
+    assert!(history
+        .iter()
+        .all(|v| v.0.is_finite() && v.1.is_finite() && v.2.is_finite() && v.3.is_finite()));
+
+    // residuals should decrease overall, even if not at every iteration
+    let (first, last) = (history[0], history[history.len() - 1]);
+    assert!(last.2 < first.2);
+    let increases = history.windows(2).filter(|w| w[1].2 > w[0].2).count();
+    assert!(increases <= history.len() / 2);
+
+    // the values after solving are from the final iterate
+    let (pcost, _) = solver.current_objective();
+    assert!(f64::abs(pcost - solver.solution.obj_val) <= 1e-12);
+    assert!(f64::abs(pcost + 4. / 3.) <= 1e-6);
+}
@@ -0,0 +1,113 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn iteration_records_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ])
+    .to_triu();
+    let q = vec![1., 1.];
+
+    let A = CscMatrix::from(&[
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+        [1., 0.],   //
+        [0., 1.],   //
+    ]);
+    let b = vec![-1., 0., 0., 0.7, 0.7];
+
+    let cones = vec![NonnegativeConeT(5)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve_with(collect: bool) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = iteration_records_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .collect_iteration_records(collect)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_iteration_records() {
+    let solver = solve_with(true);
+    let info = &solver.info;
+    assert_eq!(info.status, SolverStatus::Solved);
+
+    let records = &info.iteration_records;
+    assert_eq!(records.len(), info.iterations as usize);
+
+    for (k, record) in records.iter().enumerate() {
+        assert_eq!(record.iteration, k as u32 + 1);
+        assert!(record.step_length > 0.);
+    }
+
+    let last = records.last().unwrap();
+    assert_eq!(last.res_primal, info.res_primal);
+    assert_eq!(last.res_dual, info.res_dual);
+    assert_eq!(last.gap_abs, info.gap_abs);
+    assert_eq!(last.gap_rel, info.gap_rel);
+    assert_eq!(last.cost_primal, info.cost_primal);
+    assert_eq!(last.mu, info.μ);
+}
+
+#[test]
+fn test_iteration_records_disabled() {
+    let solver = solve_with(false);
+    assert_eq!(solver.info.status, SolverStatus::Solved);
+    assert!(solver.info.iteration_records.is_empty());
+}
+
+#[test]
+fn test_iteration_records_cleared_on_resolve() {
+    let mut solver = solve_with(true);
+    let len = solver.info.iteration_records.len();
+    solver.solve();
+    assert_eq!(solver.info.iteration_records.len(), len);
+}
+
+#[test]
+fn test_iteration_records_step_lengths() {
+    // the first constraint is active at the solution,
+    // so steps are limited by the cone boundary
+    let (P, q, A, b, cones) = iteration_records_data();
+    let max_step_fraction = 0.9;
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .collect_iteration_records(true)
+        .max_step_fraction(max_step_fraction)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let records = &solver.info.iteration_records;
+    for record in records.iter() {
+        for α in [record.step_length_primal, record.step_length_dual] {
+            assert!(α > 0. && α <= max_step_fraction);
+        }
+        // the step taken is common to the primal and dual
+        let αmin = f64::min(record.step_length_primal, record.step_length_dual);
+        assert!(record.step_length <= αmin);
+    }
+
+    let last = records.last().unwrap();
+    assert_eq!(solver.solution.step_length_primal, last.step_length_primal);
+    assert_eq!(solver.solution.step_length_dual, last.step_length_dual);
+}
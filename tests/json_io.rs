@@ -0,0 +1,160 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "serde")]
+
+use clarabel::{algebra::*, solver::*};
+use std::fs::File;
+use std::io::{Seek, SeekFrom};
+
+fn temp_json_file(name: &str) -> File {
+    let path = std::env::temp_dir().join(name);
+    File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .unwrap()
+}
+
+#[allow(clippy::type_complexity)]
+fn json_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // P = [4. 1;1 2]
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ]);
+
+    // A = [1. 1;1 0; 0 1]; A = [-A;A]
+    let A = CscMatrix::from(&[
+        [1., 1.], //
+        [1., 0.], //
+        [0., 1.], //
+    ]);
+
+    let (mut A1, A2) = (A.clone(), A);
+    A1.negate();
+    let A = CscMatrix::vcat(&A1, &A2);
+
+    let c = vec![1., 1.];
+    let b = vec![-1., 0., 0., 1., 0.7, 0.7];
+
+    let cones = vec![NonnegativeConeT(3), NonnegativeConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_json_round_trip() {
+    let (P, c, A, b, cones) = json_test_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.set_metadata("source", "test_json_round_trip");
+    solver.set_metadata("note", "a \"quoted\" value");
+    solver.solve();
+
+    let mut file = temp_json_file("clarabel_json_round_trip.json");
+    solver.write_to_file(&mut file).unwrap();
+    file.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut solver2 = DefaultSolver::<f64>::read_from_file(&mut file, None).unwrap();
+    assert_eq!(solver2.metadata(), solver.metadata());
+    assert_eq!(
+        solver2.metadata().get("source").map(String::as_str),
+        Some("test_json_round_trip")
+    );
+
+    solver2.solve();
+    assert_eq!(solver2.solution.status, SolverStatus::Solved);
+    assert!(solver2.solution.x.dist(&solver.solution.x) <= 1e-8);
+    assert!(f64::abs(solver2.solution.obj_val - solver.solution.obj_val) <= 1e-8);
+}
+
+#[test]
+fn test_json_settings_override() {
+    let (P, c, A, b, cones) = json_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .max_iter(1)
+        .build()
+        .unwrap();
+    let solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    let mut file = temp_json_file("clarabel_json_settings_override.json");
+    solver.write_to_file(&mut file).unwrap();
+
+    // saved settings are used by default
+    file.seek(SeekFrom::Start(0)).unwrap();
+    let solver2 = DefaultSolver::<f64>::read_from_file(&mut file, None).unwrap();
+    assert_eq!(solver2.settings.max_iter, 1);
+    assert_eq!(solver2.settings.time_limit, f64::INFINITY);
+
+    file.seek(SeekFrom::Start(0)).unwrap();
+    let settings = DefaultSettings::default();
+    let mut solver3 = DefaultSolver::<f64>::read_from_file(&mut file, Some(settings)).unwrap();
+    solver3.solve();
+    assert_eq!(solver3.solution.status, SolverStatus::Solved);
+}
+
+#[test]
+fn test_json_without_metadata() {
+    // files written before metadata was supported have no metadata key
+    let mut file = File::open("tests/data/json_no_metadata.json").unwrap();
+    let mut solver = DefaultSolver::<f64>::read_from_file(&mut file, None).unwrap();
+    assert!(solver.metadata().is_empty());
+
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let refsol = vec![0.3, 0.7];
+    assert!(solver.solution.x.dist(&refsol) <= 1e-6);
+}
+
+#[test]
+fn test_settings_json_round_trip() {
+    let settings = DefaultSettingsBuilder::<f64>::default()
+        .max_iter(50)
+        .tol_gap_abs(1e-6)
+        .equilibration_method("l2".to_string())
+        .save_iterates_max(Some(10))
+        .build()
+        .unwrap();
+
+    let json = settings.to_json();
+    let settings2 = DefaultSettings::<f64>::from_json(&json).unwrap();
+
+    assert_eq!(settings2.max_iter, 50);
+    assert_eq!(settings2.tol_gap_abs, 1e-6);
+    assert_eq!(settings2.equilibration_method, "l2");
+    assert_eq!(settings2.save_iterates_max, Some(10));
+
+    // infinite time limits survive the round trip
+    assert_eq!(settings2.time_limit, f64::INFINITY);
+
+    // and the output is unchanged on writing again
+    assert_eq!(settings2.to_json(), json);
+}
+
+#[test]
+fn test_settings_json_missing_and_unknown_fields() {
+    // e.g. settings written by an older or newer version
+    let json = r#"{"max_iter": 25, "verbose": false, "not_a_setting": 1.0}"#;
+    let settings = DefaultSettings::<f64>::from_json(json).unwrap();
+    let defaults = DefaultSettings::<f64>::default();
+
+    assert_eq!(settings.max_iter, 25);
+    assert!(!settings.verbose);
+    assert_eq!(settings.tol_feas, defaults.tol_feas);
+    assert_eq!(settings.direct_solve_method, defaults.direct_solve_method);
+    assert_eq!(settings.time_limit, defaults.time_limit);
+}
+
+#[test]
+fn test_settings_json_invalid() {
+    assert!(DefaultSettings::<f64>::from_json("not json").is_err());
+    assert!(DefaultSettings::<f64>::from_json(r#"{"max_iter": "many"}"#).is_err());
+}
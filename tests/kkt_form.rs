@@ -0,0 +1,142 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn kkt_form_lp_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min -x1 - 2x2 - 3x3  s.t.  Ax ≤ b,  0 ≤ x ≤ 4
+    let P = CscMatrix::zeros((3, 3));
+    let q = vec![-1., -2., -3.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [2., 1., 0.],  //
+        [0., 1., 3.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [1., 0., 0.],  //
+        [0., 1., 0.],  //
+        [0., 0., 1.],  //
+    ]);
+    let b = vec![6., 8., 9., 0., 0., 0., 4., 4., 4.];
+
+    let cones = vec![NonnegativeConeT(3), NonnegativeConeT(6)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings(kkt_form: &str) -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .kkt_form(kkt_form.to_string())
+        .build()
+        .unwrap()
+}
+
+fn solve_lp(settings: DefaultSettings<f64>) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = kkt_form_lp_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_kkt_form_reduced_lp() {
+    let full = solve_lp(settings("full"));
+    let reduced = solve_lp(settings("reduced"));
+
+    assert_eq!(full.solution.status, SolverStatus::Solved);
+    assert_eq!(reduced.solution.status, SolverStatus::Solved);
+    assert!(!full.kkt_info().is_reduced);
+    assert!(reduced.kkt_info().is_reduced);
+
+    // the reduced system has the dimension of x only
+    assert_eq!(full.kkt_matrix().n, 3 + 9);
+    assert_eq!(reduced.kkt_matrix().n, 3);
+
+    assert!((full.solution.obj_val - reduced.solution.obj_val).abs() <= 1e-6);
+    assert!(full.solution.x.dist(&reduced.solution.x) <= 1e-5);
+    assert!(full.solution.z.dist(&reduced.solution.z) <= 1e-5);
+}
+
+#[test]
+fn test_kkt_form_reduced_sparse() {
+    // factor with QDLDL rather than the dense LDL used for small systems
+    let mut settings = settings("reduced");
+    settings.dense_kkt_threshold = 0;
+    let reduced = solve_lp(settings);
+    let full = solve_lp(self::settings("full"));
+
+    assert_eq!(reduced.solution.status, SolverStatus::Solved);
+    assert!(reduced.kkt_info().is_reduced);
+    assert!(reduced.kkt_info().nnz_L > 0);
+    assert!((full.solution.obj_val - reduced.solution.obj_val).abs() <= 1e-6);
+}
+
+#[test]
+fn test_kkt_form_reduced_qp_update() {
+    // a QP with data updated after setup
+    let (_, q, A, b, cones) = kkt_form_lp_data();
+    let P = CscMatrix::from(&[
+        [2., 1., 0.], //
+        [1., 2., 0.], //
+        [0., 0., 1.], //
+    ])
+    .to_triu();
+    let mut A2 = A.clone();
+    A2.nzval[0] = 2.;
+
+    let mut fullsettings = settings("full");
+    fullsettings.presolve_enable = false;
+    let mut reducedsettings = settings("reduced");
+    reducedsettings.presolve_enable = false;
+
+    let mut full = DefaultSolver::new(&P, &q, &A2, &b, &cones, fullsettings);
+    full.solve();
+
+    let mut reduced = DefaultSolver::new(&P, &q, &A, &b, &cones, reducedsettings);
+    reduced.update_A(&A2).unwrap();
+    reduced.solve();
+
+    assert!(reduced.kkt_info().is_reduced);
+    assert_eq!(reduced.solution.status, SolverStatus::Solved);
+    assert!((full.solution.obj_val - reduced.solution.obj_val).abs() <= 1e-6);
+    assert!(full.solution.x.dist(&reduced.solution.x) <= 1e-5);
+}
+
+#[test]
+fn test_kkt_form_reduced_fallback() {
+    // equality constraints are not supported in the reduced form
+    let P = CscMatrix::zeros((2, 2));
+    let q = vec![1., 1.];
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1., 0., 0.];
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(2)];
+
+    let mut full = DefaultSolver::new(&P, &q, &A, &b, &cones, settings("full"));
+    full.solve();
+
+    let mut reduced = DefaultSolver::new(&P, &q, &A, &b, &cones, settings("reduced"));
+    reduced.solve();
+
+    assert!(!reduced.kkt_info().is_reduced);
+    assert_eq!(reduced.solution.status, SolverStatus::Solved);
+    assert_eq!(reduced.solution.x, full.solution.x);
+}
+
+#[test]
+#[should_panic]
+fn test_kkt_form_unrecognized() {
+    solve_lp(settings("partial"));
+}
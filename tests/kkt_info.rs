@@ -0,0 +1,129 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn kkt_info_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // P = [4. 1;1 2]
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ]);
+
+    // A = [1. 1;1 0; 0 1]; A = [-A;A]
+    let A = CscMatrix::from(&[
+        [1., 1.], //
+        [1., 0.], //
+        [0., 1.], //
+    ]);
+
+    let (mut A1, A2) = (A.clone(), A);
+    A1.negate();
+    let A = CscMatrix::vcat(&A1, &A2);
+
+    let c = vec![1., 1.];
+    let b = vec![-1., 0., 0., 1., 0.7, 0.7];
+
+    let cones = vec![NonnegativeConeT(3), NonnegativeConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_kkt_info() {
+    let (P, c, A, b, cones) = kkt_info_test_data();
+    let (m, n) = (A.m, A.n);
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let info = solver.kkt_info();
+
+    // triu of P, A and a full diagonal for the lower right block
+    assert_eq!(info.nnz_kkt, P.to_triu().nnz() + A.nnz() + m);
+
+    // the strict lower triangle of the KKT matrix has at least as
+    // many entries as off-diagonal entries in the original data
+    let nnz_kkt_lower_triangle = info.nnz_kkt - (m + n);
+    assert!(info.nnz_L >= nnz_kkt_lower_triangle);
+}
+
+#[test]
+fn test_kkt_info_refinement_disabled() {
+    let (P, c, A, b, cones) = kkt_info_test_data();
+
+    let settings = DefaultSettingsBuilder::default()
+        .iterative_refinement_enable(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+
+    assert_eq!(solver.kkt_info().num_refinement_steps, 0);
+}
+
+#[test]
+fn test_kkt_matrix() {
+    let (P, c, A, b, cones) = kkt_info_test_data();
+    let (m, n) = (A.m, A.n);
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    // available as assembled before solving
+    let KKT = solver.kkt_matrix();
+    assert_eq!((KKT.m, KKT.n), (m + n, m + n));
+    assert_eq!(KKT.nnz(), solver.kkt_info().nnz_kkt);
+    assert!(KKT.is_triu());
+
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    // the lower right block holds -W^TW, with nonpositive diagonal
+    let KKT = solver.kkt_matrix();
+    assert!((n..(m + n)).all(|i| KKT.get_entry((i, i)).unwrap() <= 0.));
+}
+
+#[test]
+fn test_max_kkt_nnz() {
+    let (P, c, A, b, cones) = kkt_info_test_data();
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    let nnz_kkt = solver.kkt_info().nnz_kkt;
+
+    // a limit at the actual size has no effect
+    let settings = DefaultSettingsBuilder::default()
+        .max_kkt_nnz(Some(nnz_kkt))
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    // but any smaller limit prevents the solver from running
+    let settings = DefaultSettingsBuilder::default()
+        .max_kkt_nnz(Some(nnz_kkt - 1))
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    assert_eq!(solver.kkt_info().nnz_kkt, 0);
+
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::InsufficientMemory);
+    assert_eq!(solver.solution.iterations, 0);
+    assert_eq!(solver.solution.x.len(), A.n);
+
+    // and subsequent solves report the same
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::InsufficientMemory);
+}
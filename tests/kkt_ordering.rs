@@ -0,0 +1,104 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, qdldl::QDLDLError, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn kkt_ordering_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ‖x‖² + qᵀx s.t. x ≥ 0, sum(x) = 1.  The equality row
+    // couples all of the variables
+    let n = 6;
+    let P = CscMatrix::identity(n);
+    let q: Vec<f64> = (0..n).map(|i| i as f64 - 2.).collect();
+
+    let mut A = CscMatrix::identity(n);
+    A.negate();
+    let A = CscMatrix::vcat(&CscMatrix::from(&[[1.; 6]]), &A);
+    let mut b = vec![0.; n + 1];
+    b[0] = 1.;
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(n)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings() -> DefaultSettings<f64> {
+    // these KKT systems are small enough to be factored densely
+    // by default, so the sparse factorization is forced
+    DefaultSettingsBuilder::default()
+        .dense_kkt_threshold(0)
+        .verbose(false)
+        .build()
+        .unwrap()
+}
+
+fn is_permutation(perm: &[usize]) -> bool {
+    let mut sorted = perm.to_vec();
+    sorted.sort_unstable();
+    sorted.iter().enumerate().all(|(i, &p)| i == p)
+}
+
+#[test]
+fn test_kkt_ordering_default() {
+    let (P, q, A, b, cones) = kkt_ordering_data();
+    let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings());
+
+    let perm = solver.kkt_ordering().unwrap();
+    assert_eq!(perm.len(), solver.kkt_matrix().m);
+    assert!(is_permutation(perm));
+}
+
+#[test]
+fn test_kkt_ordering_identity() {
+    let (P, q, A, b, cones) = kkt_ordering_data();
+
+    let mut refsolver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings());
+    refsolver.solve();
+
+    let dim = refsolver.kkt_matrix().m;
+    let identity: Vec<usize> = (0..dim).collect();
+
+    let perm = identity.clone();
+    let factory: KKTSolverFactory<f64> = Box::new(move |P, A, cones, settings| {
+        let kktsolver =
+            DirectLDLKKTSolver::new_with_permutation(P, A, cones, A.m, A.n, settings, &perm);
+        Box::new(kktsolver.unwrap())
+    });
+
+    let mut solver =
+        DefaultSolver::new_with_kkt_solver(&P, &q, &A, &b, &cones, settings(), factory);
+    assert_eq!(solver.kkt_ordering().unwrap(), identity.as_slice());
+    assert_eq!(solver.kkt_info().num_symbolic_factorizations, 0);
+
+    solver.solve();
+
+    // the natural ordering eliminates the variables first, so that
+    // the dense equality row fills in the factor
+    assert!(solver.kkt_info().nnz_L > refsolver.kkt_info().nnz_L);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(&refsolver.solution.x) <= 1e-6);
+}
+
+#[test]
+fn test_kkt_ordering_invalid() {
+    let (P, _, A, _, cones) = kkt_ordering_data();
+    let cones = CompositeCone::new(&cones);
+    let settings = settings();
+    let (m, n) = (A.m, A.n);
+
+    // wrong length
+    let perm: Vec<usize> = (0..m + n - 1).collect();
+    let result = DirectLDLKKTSolver::new_with_permutation(&P, &A, &cones, m, n, &settings, &perm);
+    assert!(matches!(result, Err(QDLDLError::InvalidPermutation)));
+
+    // repeated entry
+    let mut perm: Vec<usize> = (0..m + n).collect();
+    perm[1] = 0;
+    let result = DirectLDLKKTSolver::new_with_permutation(&P, &A, &cones, m, n, &settings, &perm);
+    assert!(matches!(result, Err(QDLDLError::InvalidPermutation)));
+}
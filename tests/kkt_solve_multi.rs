@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[test]
+fn test_kkt_solve_multi() {
+    let P = CscMatrix::from(&[
+        [4., 1., 0.], //
+        [0., 2., 1.], //
+        [0., 0., 3.], //
+    ]);
+    let A = CscMatrix::from(&[
+        [1., 1., 0.],  //
+        [0., 1., -1.], //
+        [-1., 0., 0.], //
+        [0., 0., -1.], //
+    ]);
+    let (m, n) = (A.m, A.n);
+
+    let cones = CompositeCone::new(&[ZeroConeT(1), NonnegativeConeT(3)]);
+    let settings = DefaultSettings::<f64>::default();
+
+    let mut kkt = DirectLDLKKTSolver::new(&P, &A, &cones, m, n, &settings);
+    assert!(kkt.update(&cones, &settings));
+
+    let rhsx = [vec![1., 0., -1.], vec![0.5, 2., 0.], vec![-3., 1., 1.]];
+    let rhsz = [
+        vec![0., 1., 0., 2.],
+        vec![1., -1., 1., 0.],
+        vec![0., 0., 0., 1.],
+    ];
+
+    // each right hand side solved individually
+    let mut expected = Vec::new();
+    for (bx, bz) in rhsx.iter().zip(rhsz.iter()) {
+        let (mut x, mut z) = (vec![0.; n], vec![0.; m]);
+        kkt.setrhs(bx, bz);
+        assert!(kkt.solve(Some(&mut x), Some(&mut z), &settings));
+        expected.push((x, z));
+    }
+
+    // and all together against the same factorization
+    let mut xs = vec![vec![0.; n]; 3];
+    let mut zs = vec![vec![0.; m]; 3];
+    let rhs: Vec<(&[f64], &[f64])> = rhsx
+        .iter()
+        .zip(rhsz.iter())
+        .map(|(bx, bz)| (bx.as_slice(), bz.as_slice()))
+        .collect();
+    let mut out: Vec<(&mut [f64], &mut [f64])> = xs
+        .iter_mut()
+        .zip(zs.iter_mut())
+        .map(|(x, z)| (x.as_mut_slice(), z.as_mut_slice()))
+        .collect();
+    assert!(kkt.solve_multi(&rhs, &mut out, &settings));
+
+    for ((x, z), (xexp, zexp)) in xs.iter().zip(zs.iter()).zip(expected.iter()) {
+        assert_eq!(x, xexp);
+        assert_eq!(z, zexp);
+    }
+
+    // the solutions are distinct
+    assert!(xs[0].dist(&xs[1]) > 1e-3);
+    assert!(xs[1].dist(&xs[2]) > 1e-3);
+}
+
+#[test]
+#[should_panic]
+fn test_kkt_solve_multi_bad_lengths() {
+    let P = CscMatrix::<f64>::identity(2);
+    let A = CscMatrix::<f64>::identity(2);
+    let cones = CompositeCone::new(&[NonnegativeConeT(2)]);
+    let settings = DefaultSettings::<f64>::default();
+
+    let mut kkt = DirectLDLKKTSolver::new(&P, &A, &cones, 2, 2, &settings);
+    kkt.update(&cones, &settings);
+
+    let b = vec![1., 1.];
+    let mut x = vec![0.; 2];
+    let mut z = vec![0.; 2];
+    kkt.solve_multi(&[(&b, &b), (&b, &b)], &mut [(&mut x, &mut z)], &settings);
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "logging")]
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+use lazy_static::lazy_static;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::Mutex;
+
+// a logger that captures every record emitted by the solver
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target() == "clarabel"
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let msg = record.args().to_string();
+            self.records.lock().unwrap().push((record.level(), msg));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+lazy_static! {
+    static ref LOGGER: CapturingLogger = CapturingLogger {
+        records: Mutex::new(Vec::new()),
+    };
+}
+
+#[allow(clippy::type_complexity)]
+fn logging_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ])
+    .to_triu();
+    let c = vec![1., 1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1.],   //
+        [1., 0.],   //
+        [0., 1.],   //
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+    ]);
+    let b = vec![1., 0.7, 0.7, -1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(6)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_logging_iteration_table() {
+    log::set_logger(&*LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    let (P, c, A, b, cones) = logging_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(true)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    solver.timers.as_ref().unwrap().print();
+
+    let records = LOGGER.records.lock().unwrap();
+    let info: Vec<&String> = records
+        .iter()
+        .filter(|(level, _)| *level == Level::Info)
+        .map(|(_, msg)| msg)
+        .collect();
+
+    // the header is emitted as a single record with its column formatting intact
+    let header = info.iter().position(|msg| msg.starts_with("iter    pcost"));
+    assert!(header.is_some());
+    let header = header.unwrap();
+    assert!(info[header].contains("pres      dres      k/t"));
+
+    // followed by the separator and at least one iteration row
+    assert!(info[header + 1].starts_with("-----"));
+    assert!(info[header + 2].starts_with("  0  "));
+    assert!(info.iter().any(|msg| msg.starts_with("  1  ")));
+
+    // timers are reported at debug level
+    assert!(records
+        .iter()
+        .any(|(level, msg)| *level == Level::Debug && msg.starts_with("solve")));
+}
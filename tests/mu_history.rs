@@ -0,0 +1,81 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn mu_history_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½x'Px + q'x  s.t.  -1 ≤ x ≤ 1,  x1 + x2 + x3 = 1
+    let P = CscMatrix::from(&[
+        [4., 1., 0.], //
+        [1., 2., 0.], //
+        [0., 0., 3.], //
+    ])
+    .to_triu();
+    let q = vec![1., -2., 1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [1., 0., 0.],  //
+        [0., 1., 0.],  //
+        [0., 0., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+    ]);
+    let b = vec![1., 1., 1., 1., 1., 1., 1.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(6)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings(collect: bool) -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .collect_mu_history(collect)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_mu_history() {
+    let (P, q, A, b, cones) = mu_history_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(true));
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    // the initial point and one entry per iteration
+    let mu = solver.mu_history();
+    assert_eq!(mu.len(), solver.solution.iterations as usize + 1);
+    assert_eq!(*mu.last().unwrap(), solver.info.μ);
+    assert_eq!(solver.solution.mu_hist, mu);
+
+    // decreasing, up to numerical noise
+    for w in mu.windows(2) {
+        assert!(w[1] <= w[0] * (1. + 1e-8));
+    }
+    assert!(mu[mu.len() - 1] < 1e-6 * mu[0]);
+
+    // cleared when solving again
+    solver.solve();
+    assert_eq!(
+        solver.mu_history().len(),
+        solver.solution.iterations as usize + 1
+    );
+}
+
+#[test]
+fn test_mu_history_disabled() {
+    let (P, q, A, b, cones) = mu_history_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings(false));
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.mu_history().is_empty());
+    assert!(solver.solution.mu_hist.is_empty());
+}
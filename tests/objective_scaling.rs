@@ -0,0 +1,90 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn objective_scaling_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // a QP with a badly scaled objective, so that
+    // equilibration chooses an objective scaling c ≠ 1
+    let P = CscMatrix::from(&[
+        [400., 100.], //
+        [100., 200.], //
+    ])
+    .to_triu();
+    let q = vec![100., 100.];
+
+    let A = CscMatrix::from(&[
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+    ]);
+    let b = vec![-1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve_with(objective_scaling: Option<f64>, equilibrate: bool) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = objective_scaling_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .equilibrate_enable(equilibrate)
+        .objective_scaling(objective_scaling)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_objective_scaling_automatic() {
+    let solver = solve_with(None, true);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.objective_scaling() != 1.);
+    assert_eq!(solver.objective_scaling(), solver.equilibration().c);
+}
+
+#[test]
+fn test_objective_scaling_pinned() {
+    let auto = solve_with(None, true);
+    let pinned = solve_with(Some(1.), true);
+
+    assert_eq!(pinned.objective_scaling(), 1.);
+    assert_eq!(pinned.equilibration().c, 1.);
+
+    // the objective is reported for the unscaled problem either way
+    assert_eq!(pinned.solution.status, SolverStatus::Solved);
+    assert!(f64::abs(pinned.solution.obj_val - auto.solution.obj_val) <= 1e-6);
+    assert!(pinned.solution.x.dist(&auto.solution.x) <= 1e-6);
+}
+
+#[test]
+fn test_objective_scaling_without_equilibration() {
+    let unscaled = solve_with(None, false);
+    let scaled = solve_with(Some(1e-2), false);
+
+    assert_eq!(unscaled.objective_scaling(), 1.);
+    assert_eq!(scaled.objective_scaling(), 1e-2);
+    assert_eq!(scaled.solution.status, SolverStatus::Solved);
+    assert!(f64::abs(scaled.solution.obj_val - unscaled.solution.obj_val) <= 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "objective_scaling must be positive and finite")]
+fn test_objective_scaling_nonpositive() {
+    solve_with(Some(0.), true);
+}
+
+#[test]
+#[should_panic(expected = "objective_scaling must be positive and finite")]
+fn test_objective_scaling_infinite() {
+    solve_with(Some(f64::INFINITY), true);
+}
@@ -92,3 +92,60 @@ fn test_presolve_settable_bound() {
     default_infinity();
     assert_eq!(get_infinity(), default_bound);
 }
+
+#[test]
+fn test_presolve_report() {
+    let (P, c, A, mut b, cones) = presolve_test_data();
+
+    // the second row is redundant and should be removed
+    b[1] = 1e30_f64;
+
+    let settings = DefaultSettings::default();
+    let solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    let report = solver.presolve_report().unwrap();
+    assert_eq!(report.rows_original, 6);
+    assert_eq!(report.rows_expanded, 6);
+    assert_eq!(report.rows_removed, 1);
+    assert_eq!(report.row_map, vec![0, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_presolve_report_box_cone() {
+    let (P, c, A, mut b, _) = presolve_test_data();
+
+    // box rows are kept as they are, and only the
+    // redundant nonnegative row is removed
+    b[3] = 1e30_f64;
+    let cones = vec![
+        BoxConeT {
+            lower: vec![-1.],
+            upper: vec![1e30_f64],
+        },
+        NonnegativeConeT(4),
+    ];
+
+    let settings = DefaultSettings::default();
+    let solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    let report = solver.presolve_report().unwrap();
+    assert_eq!(report.rows_original, 6);
+    assert_eq!(report.rows_expanded, 6);
+    assert_eq!(report.rows_removed, 1);
+    assert_eq!(report.row_map, vec![0, 1, 2, 4, 5]);
+}
+
+#[test]
+fn test_presolve_report_disabled() {
+    let (P, c, A, mut b, cones) = presolve_test_data();
+
+    b[1] = 1e30_f64;
+
+    let settings = DefaultSettingsBuilder::default()
+        .presolve_enable(false)
+        .build()
+        .unwrap();
+    let solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    assert!(solver.presolve_report().is_none());
+}
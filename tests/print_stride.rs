@@ -0,0 +1,99 @@
+#![cfg(feature = "logging")]
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+use lazy_static::lazy_static;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::Mutex;
+
+// a logger that captures the solver output
+struct CapturingLogger {
+    lines: Mutex<Vec<String>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target() == "clarabel" && metadata.level() == Level::Info
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.lines.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+lazy_static! {
+    static ref LOGGER: CapturingLogger = CapturingLogger {
+        lines: Mutex::new(Vec::new()),
+    };
+}
+
+#[allow(clippy::type_complexity)]
+fn print_stride_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // an SOCP needing a fair number of iterations
+    let n = 20;
+    let P = CscMatrix::<f64>::identity(n);
+    let q: Vec<f64> = (0..n).map(|i| (i as f64 + 0.5).sin()).collect();
+
+    // ‖x‖ ≤ 1, and x ≥ 0
+    let mut negI = CscMatrix::<f64>::identity(n);
+    negI.negate();
+    let A = CscMatrix::vcat(&CscMatrix::zeros((1, n)), &negI);
+    let A = CscMatrix::vcat(&A, &negI);
+    let mut b = vec![0.; 2 * n + 1];
+    b[0] = 1.;
+
+    let cones = vec![SecondOrderConeT(n + 1), NonnegativeConeT(n)];
+
+    (P, q, A, b, cones)
+}
+
+#[test]
+fn test_print_stride() {
+    log::set_logger(&*LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Info);
+
+    let (P, q, A, b, cones) = print_stride_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(true)
+        .print_stride(5)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let iterations = solver.info.iterations;
+    assert!(iterations > 5);
+
+    // rows of the iteration table lie between the separator
+    // below the header and the one above the footer
+    let lines = LOGGER.lines.lock().unwrap();
+    let header = lines
+        .iter()
+        .position(|line| line.starts_with("iter    pcost"))
+        .unwrap();
+    let rows: Vec<&String> = lines[header + 2..]
+        .iter()
+        .take_while(|line| !line.starts_with("-----"))
+        .collect();
+
+    // iterations 0, 5, 10, ..., and the final one
+    let mut expected: Vec<u32> = (0..=iterations).step_by(5).collect();
+    if iterations % 5 != 0 {
+        expected.push(iterations);
+    }
+    assert_eq!(rows.len(), expected.len());
+    for (row, k) in rows.iter().zip(expected) {
+        assert!(row.starts_with(&format!("{:>3}  ", k)));
+    }
+}
@@ -0,0 +1,62 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[test]
+fn test_problem_dimensions_socp() {
+    // the SOCP example
+    let P = CscMatrix::from(&[
+        [0., 0.], //
+        [0., 2.], //
+    ]);
+    let q = vec![0., 0.];
+    let A = CscMatrix::from(&[
+        [0., 0.],  //
+        [-2., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1., -2., -2.];
+    let cones = [SecondOrderConeT(3)];
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+
+    let dims = solver.problem_dimensions();
+    assert_eq!(dims.n, 2);
+    assert_eq!(dims.m, 3);
+    assert_eq!(dims.nnz_P, P.to_triu().nnz());
+    assert_eq!(dims.nnz_A, A.nnz());
+    assert_eq!(dims.cone_degree, 1);
+}
+
+#[test]
+fn test_problem_dimensions_presolved() {
+    // rows with infinite bounds are removed by presolve
+    let P = CscMatrix::identity(2);
+    let q = vec![1., 1.];
+    let A = CscMatrix::from(&[
+        [1., 0.],  //
+        [0., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1., f64::INFINITY, 1., 1.];
+    let cones = [ZeroConeT(1), NonnegativeConeT(3)];
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+
+    let dims = solver.problem_dimensions();
+    assert_eq!(dims.n, 2);
+    assert_eq!(dims.m, 3);
+    assert_eq!(dims.nnz_P, 2);
+    assert_eq!(dims.nnz_A, 3);
+    // the zero cone has degree 0
+    assert_eq!(dims.cone_degree, 2);
+}
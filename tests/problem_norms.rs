@@ -0,0 +1,98 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn norms_test_data() -> (
+    [[f64; 3]; 3],
+    Vec<f64>,
+    [[f64; 3]; 4],
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½x'Px + q'x  s.t.  x1 + x2 - x3 = 1,  x ≥ 0
+    let P = [
+        [3., -1., 0.],  //
+        [-1., 2., 0.5], //
+        [0., 0.5, 1.],  //
+    ];
+    let q = vec![-2., 4., 1.];
+
+    let A = [
+        [1., 1., -1.], //
+        [-1., 0., 0.], //
+        [0., -5., 0.], //
+        [0., 0., -1.], //
+    ];
+    let b = vec![1., 0., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn dense_norm_inf<const N: usize>(M: &[[f64; N]]) -> f64 {
+    M.iter()
+        .flatten()
+        .fold(0., |acc, &v| f64::max(acc, v.abs()))
+}
+
+#[test]
+fn test_problem_norms() {
+    let (Pd, q, Ad, b, _) = norms_test_data();
+    let P = CscMatrix::from(&Pd);
+    let A = CscMatrix::from(&Ad);
+
+    let norms = problem_norms(&P, &q, &A, &b);
+    assert_eq!(norms.norm_q, 4.);
+    assert_eq!(norms.norm_b, 1.);
+    assert_eq!(norms.norm_P, dense_norm_inf(&Pd));
+    assert_eq!(norms.norm_A, dense_norm_inf(&Ad));
+    assert_eq!(norms.tol_scale(), 4.);
+
+    // the same for the upper triangle of P
+    let normsu = problem_norms(&P.to_triu(), &q, &A, &b);
+    assert_eq!(normsu, norms);
+}
+
+#[test]
+fn test_problem_norms_residuals() {
+    // reproduce the solver's relative residuals
+    let (Pd, q, Ad, b, cones) = norms_test_data();
+    let P = CscMatrix::from(&Pd).to_triu();
+    let A = CscMatrix::from(&Ad);
+    let norms = problem_norms(&P, &q, &A, &b);
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .equilibrate_enable(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    let (x, s, z) = (&solver.solution.x, &solver.solution.s, &solver.solution.z);
+
+    // naive dense residuals
+    let mut rp = [0.; 4];
+    let mut rd = q.clone();
+    for i in 0..4 {
+        rp[i] = s[i] - b[i];
+        for j in 0..3 {
+            rp[i] += Ad[i][j] * x[j];
+            rd[j] += Ad[i][j] * z[i];
+        }
+    }
+    for i in 0..3 {
+        for j in 0..3 {
+            rd[i] += Pd[i][j] * x[j];
+        }
+    }
+
+    let res_primal = rp.norm() / f64::max(1., norms.norm_b + x.norm() + s.norm());
+    let res_dual = rd.norm() / f64::max(1., norms.norm_q + x.norm() + z.norm());
+
+    let (info_primal, info_dual) = solver.current_residuals();
+    assert!((res_primal - info_primal).abs() <= 1e-12);
+    assert!((res_dual - info_dual).abs() <= 1e-12);
+}
@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+use std::thread;
+
+#[allow(clippy::type_complexity)]
+fn progress_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::<f64>::identity(3);
+
+    let I1 = CscMatrix::<f64>::identity(3);
+    let mut I2 = CscMatrix::<f64>::identity(3);
+    I2.negate();
+    let mut A = CscMatrix::vcat(&I1, &I2);
+    A.scale(2.);
+
+    let c = vec![3., -2., 1.];
+    let b = vec![1.; 6];
+
+    let cones = vec![NonnegativeConeT(3), NonnegativeConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+fn new_solver() -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = progress_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    DefaultSolver::new(&P, &c, &A, &b, &cones, settings)
+}
+
+#[test]
+fn test_progress_receiver() {
+    let mut solver = new_solver();
+    let receiver = solver.progress_receiver();
+
+    // collect events until the sender is dropped with the solver
+    let collector = thread::spawn(move || receiver.iter().collect::<Vec<_>>());
+
+    solver.solve();
+    let iterations = solver.solution.iterations;
+    let final_gap = solver.info.gap_rel;
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    drop(solver);
+
+    let events = collector.join().unwrap();
+    assert_eq!(events.len(), iterations as usize);
+
+    for (k, event) in events.iter().enumerate() {
+        assert_eq!(event.iteration, k as u32 + 1);
+    }
+    assert_eq!(events.last().unwrap().gap, final_gap);
+}
+
+#[test]
+fn test_progress_receiver_repeated_solve() {
+    let mut solver = new_solver();
+    let receiver = solver.progress_receiver();
+
+    solver.solve();
+    solver.solve();
+    let iterations = solver.solution.iterations as usize;
+
+    let events: Vec<_> = receiver.try_iter().collect();
+    assert_eq!(events.len(), 2 * iterations);
+    assert_eq!(events[iterations].iteration, 1);
+}
+
+#[test]
+fn test_progress_receiver_dropped() {
+    let mut solver = new_solver();
+    drop(solver.progress_receiver());
+
+    // solve must not block or fail without a receiver
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+}
@@ -0,0 +1,75 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn random_seed_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½‖x‖² + x1 - x3  s.t.  x1 + x2 + x3 = 1,  x ≥ 0,  ‖(x1,x2)‖ ≤ 2
+    let P = CscMatrix::identity(3);
+    let q = vec![1., 0., -1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [0., 0., 0.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+    ]);
+    let b = vec![1., 0., 0., 0., 2., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(3), SecondOrderConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve_with_seed(seed: Option<u64>) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = random_seed_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .save_iterates(true)
+        .random_seed(seed)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_random_seed_default() {
+    let settings = DefaultSettings::<f64>::default();
+    assert_eq!(settings.random_seed, None);
+}
+
+#[test]
+fn test_random_seed_reproducible() {
+    let solver1 = solve_with_seed(Some(1234));
+    let solver2 = solve_with_seed(Some(1234));
+    let (sol1, sol2) = (&solver1.solution, &solver2.solution);
+
+    assert_eq!(sol1.status, SolverStatus::Solved);
+    assert_eq!(sol1.iterations, sol2.iterations);
+    assert!(!sol1.xhist.is_empty());
+
+    // identical, not merely close, iterate histories
+    assert_eq!(sol1.xhist, sol2.xhist);
+    assert_eq!(sol1.zhist, sol2.zhist);
+    assert_eq!(sol1.shist, sol2.shist);
+    assert_eq!(sol1.x, sol2.x);
+}
+
+#[test]
+fn test_random_seed_unseeded_matches() {
+    // the solver is currently deterministic, so the seed has no effect
+    let seeded = solve_with_seed(Some(1234));
+    let unseeded = solve_with_seed(None);
+    assert_eq!(seeded.solution.xhist, unseeded.solution.xhist);
+}
@@ -0,0 +1,120 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn rebuild_lp_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min x1 + x2 s.t. -1 ≤ x ≤ 1
+    let P = CscMatrix::zeros((2, 2));
+    let q = vec![1., 1.];
+
+    let A = CscMatrix::from(&[
+        [1., 0.],  //
+        [0., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1.; 4];
+
+    let cones = vec![NonnegativeConeT(4)];
+
+    (P, q, A, b, cones)
+}
+
+#[allow(clippy::type_complexity)]
+fn rebuild_socp_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min x1 + x2 - x3 s.t. ‖(x1,x2,x3)‖ ≤ 1, x3 = 0
+    let P = CscMatrix::zeros((3, 3));
+    let q = vec![1., 1., -1.];
+
+    let A = CscMatrix::from(&[
+        [0., 0., 1.],  //
+        [0., 0., 0.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+    ]);
+    let b = vec![0., 1., 0., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), SecondOrderConeT(4)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings() -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_rebuild_lp_to_socp() {
+    let (P, q, A, b, cones) = rebuild_lp_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings());
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(&[-1., -1.]) <= 1e-6);
+
+    // rebuild with a different number of variables, constraints and cones
+    let (P, q, A, b, cones) = rebuild_socp_data();
+    solver.rebuild(&P, &q, &A, &b, &cones, settings());
+
+    assert_eq!(solver.solution.status, SolverStatus::Unsolved);
+    let kinds: Vec<_> = solver.cones().descriptors().map(|c| c.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            SupportedConeTag::ZeroCone,
+            SupportedConeTag::SecondOrderCone
+        ]
+    );
+
+    solver.solve();
+
+    let mut fresh = DefaultSolver::new(&P, &q, &A, &b, &cones, settings());
+    fresh.solve();
+
+    let r = f64::sqrt(0.5);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(solver.solution.x.len(), 3);
+    assert_eq!(solver.solution.s.len(), 5);
+    assert!(solver.solution.x.dist(&[-r, -r, 0.]) <= 1e-6);
+    assert!(solver.solution.x.dist(&fresh.solution.x) <= 1e-8);
+    assert!(solver.solution.z.dist(&fresh.solution.z) <= 1e-8);
+    assert_eq!(solver.solution.iterations, fresh.solution.iterations);
+}
+
+#[test]
+fn test_rebuild_round_trip() {
+    // rebuilding back to the original problem gives the original solution
+    let (P, q, A, b, cones) = rebuild_lp_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings());
+    solver.solve();
+    let original = solver.solution.clone();
+
+    let (P2, q2, A2, b2, cones2) = rebuild_socp_data();
+    solver.rebuild(&P2, &q2, &A2, &b2, &cones2, settings());
+    solver.solve();
+
+    solver.rebuild(&P, &q, &A, &b, &cones, settings());
+    solver.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(solver.solution.iterations, original.iterations);
+    assert!(solver.solution.x.dist(&original.x) <= 1e-12);
+    assert!(solver.solution.z.dist(&original.z) <= 1e-12);
+}
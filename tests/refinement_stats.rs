@@ -0,0 +1,71 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn refinement_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // a QP with badly scaled data, so that the KKT system is
+    // ill-conditioned without equilibration
+    let P = CscMatrix::from(&[
+        [1e6, 0., 0.],  //
+        [0., 1e-6, 0.], //
+        [0., 0., 1.],   //
+    ]);
+    let q = vec![1., -1e-3, 1e3];
+
+    let A = CscMatrix::from(&[
+        [1e4, 1e-4, 1.], //
+        [-1., 0., 0.],   //
+        [0., -1., 0.],   //
+        [0., 0., -1.],   //
+    ]);
+    let b = vec![1e3, 0., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve_with_refinement(enable: bool) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = refinement_data();
+    let settings = DefaultSettingsBuilder::default()
+        .equilibrate_enable(false)
+        .iterative_refinement_enable(enable)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_refinement_stats_enabled() {
+    let mut solver = solve_with_refinement(true);
+    let iters = solver.solution.total_refinement_iters;
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(iters > 0);
+    assert_eq!(iters, solver.info.total_refinement_iters);
+    assert!(solver.solution.final_refinement_residual.is_finite());
+    assert!(solver.solution.final_refinement_residual >= 0.);
+
+    // counts are per solve, not cumulative
+    solver.solve();
+    assert_eq!(solver.solution.total_refinement_iters, iters);
+    assert!(solver.kkt_info().num_refinement_steps >= 2 * iters);
+}
+
+#[test]
+fn test_refinement_stats_disabled() {
+    let solver = solve_with_refinement(false);
+
+    assert_eq!(solver.solution.total_refinement_iters, 0);
+    assert!(solver.solution.final_refinement_residual.is_nan());
+}
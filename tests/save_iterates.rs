@@ -0,0 +1,176 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn save_iterates_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // P = [4. 1;1 2]
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ]);
+
+    // A = [1. 1;1 0; 0 1]; A = [-A;A]
+    let A = CscMatrix::from(&[
+        [1., 1.], //
+        [1., 0.], //
+        [0., 1.], //
+    ]);
+
+    let (mut A1, A2) = (A.clone(), A);
+    A1.negate();
+    let A = CscMatrix::vcat(&A1, &A2);
+
+    let c = vec![1., 1.];
+    let b = vec![-1., 0., 0., 1., 0.7, 0.7];
+
+    let cones = vec![NonnegativeConeT(3), NonnegativeConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+fn solve_with(save_iterates: bool, max: Option<u32>, max_iter: u32) -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = save_iterates_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .save_iterates(save_iterates)
+        .save_iterates_max(max)
+        .max_iter(max_iter)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_save_iterates_disabled() {
+    let solver = solve_with(false, None, 200);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.xhist.is_empty());
+    assert!(solver.solution.zhist.is_empty());
+    assert!(solver.solution.shist.is_empty());
+}
+
+#[test]
+fn test_save_iterates_unbounded() {
+    let solver = solve_with(true, None, 200);
+    let sol = &solver.solution;
+    assert_eq!(sol.status, SolverStatus::Solved);
+
+    // one iterate for every step taken, plus the final one
+    assert_eq!(sol.xhist.len(), sol.iterations as usize + 1);
+    assert_eq!(sol.zhist.len(), sol.xhist.len());
+    assert_eq!(sol.shist.len(), sol.xhist.len());
+    assert!(sol.xhist.iter().all(|x| x.len() == 2));
+    assert!(sol.shist.iter().all(|s| s.len() == 6));
+}
+
+#[test]
+fn test_save_iterates_final() {
+    // the last saved iterate is the returned solution,
+    // whether or not the solver converged
+    for max_iter in [2, 200] {
+        let solver = solve_with(true, None, max_iter);
+        let sol = &solver.solution;
+        assert_eq!(sol.xhist.back().unwrap(), &sol.x);
+        assert_eq!(sol.zhist.back().unwrap(), &sol.z);
+        assert_eq!(sol.shist.back().unwrap(), &sol.s);
+    }
+}
+
+#[test]
+fn test_save_iterates_capped() {
+    let full = solve_with(true, None, 200);
+    let niter = full.solution.iterations;
+    assert!(niter > 3);
+
+    // history never exceeds the cap, whenever the solver stops
+    for max_iter in 1..=niter {
+        let solver = solve_with(true, Some(3), max_iter);
+        let sol = &solver.solution;
+        assert_eq!(sol.xhist.len(), usize::min(sol.iterations as usize + 1, 3));
+        assert_eq!(sol.zhist.len(), sol.xhist.len());
+        assert_eq!(sol.shist.len(), sol.xhist.len());
+    }
+
+    // and holds the most recent iterates
+    let capped = solve_with(true, Some(3), 200);
+    let (sol, fullsol) = (&capped.solution, &full.solution);
+    assert_eq!(sol.xhist.len(), 3);
+
+    let skip = fullsol.xhist.len() - 3;
+    for (a, b) in sol.xhist.iter().zip(fullsol.xhist.iter().skip(skip)) {
+        assert_eq!(a, b);
+    }
+    for (a, b) in sol.zhist.iter().zip(fullsol.zhist.iter().skip(skip)) {
+        assert_eq!(a, b);
+    }
+    for (a, b) in sol.shist.iter().zip(fullsol.shist.iter().skip(skip)) {
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_save_iterates_cleared_on_resolve() {
+    let mut solver = solve_with(true, None, 200);
+    let len = solver.solution.xhist.len();
+    solver.solve();
+    assert_eq!(solver.solution.xhist.len(), len);
+}
+
+#[test]
+fn test_save_iterates_csv() {
+    let solver = solve_with(true, None, 200);
+    let sol = &solver.solution;
+
+    let mut buf = Vec::new();
+    sol.write_iterate_history_csv(&mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let rows: Vec<Vec<&str>> = text.lines().map(|l| l.split(',').collect()).collect();
+
+    // header, then one row per iterate with the index and x
+    assert_eq!(rows.len(), sol.xhist.len() + 1);
+    assert_eq!(rows[0], vec!["iter", "x0", "x1"]);
+    for (k, (row, x)) in rows[1..].iter().zip(&sol.xhist).enumerate() {
+        assert_eq!(row.len(), 3);
+        assert_eq!(row[0].parse::<usize>().unwrap(), k);
+        let vals: Vec<f64> = row[1..].iter().map(|v| v.parse().unwrap()).collect();
+        assert_eq!(&vals, x);
+    }
+
+    // with s and z as well
+    let mut buf = Vec::new();
+    sol.write_full_iterate_history_csv(&mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let rows: Vec<Vec<&str>> = text.lines().map(|l| l.split(',').collect()).collect();
+
+    assert_eq!(rows.len(), sol.xhist.len() + 1);
+    assert!(rows.iter().all(|row| row.len() == 1 + 2 + 6 + 6));
+    assert_eq!(rows[0][3], "s0");
+    assert_eq!(rows[0][9], "z0");
+}
+
+#[test]
+fn test_save_iterates_csv_capped() {
+    let solver = solve_with(true, Some(3), 200);
+    let sol = &solver.solution;
+
+    let mut buf = Vec::new();
+    sol.write_iterate_history_csv(&mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+
+    // indices are those of the most recent iterates
+    let first = sol.iterations as usize + 1 - 3;
+    let indices: Vec<usize> = text
+        .lines()
+        .skip(1)
+        .map(|l| l.split(',').next().unwrap().parse().unwrap())
+        .collect();
+    assert_eq!(indices, vec![first, first + 1, first + 2]);
+}
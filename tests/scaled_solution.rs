@@ -0,0 +1,88 @@
+#![allow(non_snake_case)]
+// the only Greek identifier here is the ScaledSolution::τ field
+#![allow(mixed_script_confusables)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn scaled_solution_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // a badly scaled QP, so that equilibration is nontrivial
+    let P = CscMatrix::new(3, 3, vec![0, 1, 2, 3], vec![0, 1, 2], vec![4., 1e-2, 100.]);
+    let q = vec![1., -1e+2, 1e-1];
+
+    let A = CscMatrix::from(&[
+        [1., 1e+3, 0.],  //
+        [0., 1., 1e-2],  //
+        [-1., 0., 0.],   //
+        [0., -1e+2, 0.], //
+        [0., 0., -1.],   //
+    ]);
+    let b = vec![1e+2, 1., 0., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(4)];
+
+    (P, q, A, b, cones)
+}
+
+#[test]
+fn test_scaled_solution() {
+    let (P, q, A, b, cones) = scaled_solution_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .return_scaled(true)
+        .build()
+        .unwrap();
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let scaled = solver.solution.scaled_solution().unwrap();
+    let equil = solver.equilibration();
+    assert!(equil.d.iter().chain(equil.e.iter()).any(|&v| v != 1.));
+
+    // x = d∘x̂/τ, s = e⁻¹∘ŝ/τ, z = e∘ẑ/(cτ)
+    let tau = scaled.τ;
+    let x: Vec<f64> = scaled
+        .x
+        .iter()
+        .zip(equil.d)
+        .map(|(&x, &d)| d * x / tau)
+        .collect();
+    let s: Vec<f64> = scaled
+        .s
+        .iter()
+        .zip(equil.einv)
+        .map(|(&s, &ei)| ei * s / tau)
+        .collect();
+    let z: Vec<f64> = scaled
+        .z
+        .iter()
+        .zip(equil.e)
+        .map(|(&z, &e)| e * z / (equil.c * tau))
+        .collect();
+
+    let sol = &solver.solution;
+    assert!(x.dist(&sol.x) <= 1e-12 * f64::max(1., sol.x.norm_inf()));
+    assert!(s.dist(&sol.s) <= 1e-12 * f64::max(1., sol.s.norm_inf()));
+    assert!(z.dist(&sol.z) <= 1e-12 * f64::max(1., sol.z.norm_inf()));
+}
+
+#[test]
+fn test_scaled_solution_disabled() {
+    let (P, q, A, b, cones) = scaled_solution_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert!(solver.solution.scaled_solution().is_none());
+}
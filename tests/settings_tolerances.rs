@@ -0,0 +1,71 @@
+use clarabel::solver::*;
+
+#[test]
+fn test_tolerances() {
+    let settings = DefaultSettingsBuilder::<f64>::default()
+        .tolerances(1e-6, 1e-5)
+        .build()
+        .unwrap();
+
+    assert_eq!(settings.tol_gap_abs, 1e-6);
+    assert_eq!(settings.tol_gap_rel, 1e-5);
+    assert_eq!(settings.tol_feas, 1e-5);
+    assert_eq!(settings.tol_infeas_abs, 1e-6);
+    assert_eq!(settings.tol_infeas_rel, 1e-5);
+    assert_eq!(settings.tol_ktratio, 100. * 1e-5);
+
+    // reduced accuracy settings are unaffected
+    let defaults = DefaultSettings::<f64>::default();
+    assert_eq!(settings.reduced_tol_gap_abs, defaults.reduced_tol_gap_abs);
+    assert_eq!(settings.reduced_tol_feas, defaults.reduced_tol_feas);
+}
+
+#[test]
+fn test_reduced_tolerances() {
+    let settings = DefaultSettingsBuilder::<f64>::default()
+        .reduced_tolerances(1e-4, 1e-3)
+        .build()
+        .unwrap();
+
+    assert_eq!(settings.reduced_tol_gap_abs, 1e-4);
+    assert_eq!(settings.reduced_tol_gap_rel, 1e-3);
+    assert_eq!(settings.reduced_tol_feas, 2. * 1e-3);
+    assert_eq!(settings.reduced_tol_infeas_abs, 1e-4);
+    assert_eq!(settings.reduced_tol_infeas_rel, 1e-3);
+    assert_eq!(settings.reduced_tol_ktratio, 2. * 1e-3);
+
+    // full accuracy settings are unaffected
+    let defaults = DefaultSettings::<f64>::default();
+    assert_eq!(settings.tol_gap_abs, defaults.tol_gap_abs);
+    assert_eq!(settings.tol_ktratio, defaults.tol_ktratio);
+}
+
+#[test]
+fn test_tolerances_reproduce_defaults() {
+    let settings = DefaultSettingsBuilder::<f64>::default()
+        .tolerances(1e-8, 1e-8)
+        .reduced_tolerances(5e-5, 5e-5)
+        .build()
+        .unwrap();
+    let defaults = DefaultSettings::<f64>::default();
+
+    assert_eq!(settings.tol_gap_abs, defaults.tol_gap_abs);
+    assert_eq!(settings.tol_gap_rel, defaults.tol_gap_rel);
+    assert_eq!(settings.tol_feas, defaults.tol_feas);
+    assert_eq!(settings.tol_infeas_abs, defaults.tol_infeas_abs);
+    assert_eq!(settings.tol_infeas_rel, defaults.tol_infeas_rel);
+    assert!(f64::abs(settings.tol_ktratio - defaults.tol_ktratio) <= 1e-15);
+
+    assert_eq!(settings.reduced_tol_gap_abs, defaults.reduced_tol_gap_abs);
+    assert_eq!(settings.reduced_tol_gap_rel, defaults.reduced_tol_gap_rel);
+    assert_eq!(settings.reduced_tol_feas, defaults.reduced_tol_feas);
+    assert_eq!(
+        settings.reduced_tol_infeas_abs,
+        defaults.reduced_tol_infeas_abs
+    );
+    assert_eq!(
+        settings.reduced_tol_infeas_rel,
+        defaults.reduced_tol_infeas_rel
+    );
+    assert_eq!(settings.reduced_tol_ktratio, defaults.reduced_tol_ktratio);
+}
@@ -0,0 +1,98 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn by_cone_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // x ∈ R³ in several cones, including a box cone with
+    // an infinite bound and a leading row fixing t = 1
+    let P = CscMatrix::<f64>::identity(3);
+    let q = vec![1., -1., 1.];
+
+    let I = CscMatrix::<f64>::identity(3);
+    let mut negI = CscMatrix::<f64>::identity(3);
+    negI.negate();
+
+    let A = CscMatrix::vcat(&CscMatrix::from(&[[1., 1., 1.]]), &negI);
+    let A = CscMatrix::vcat(&A, &negI);
+    let A = CscMatrix::vcat(&A, &CscMatrix::zeros((1, 3)));
+    let A = CscMatrix::vcat(&A, &I);
+    let b = vec![
+        1., //
+        0., 0., 0., //
+        1., 0., 0., //
+        1., 0., 0., 0., //
+    ];
+
+    let cones = vec![
+        ZeroConeT(1),
+        NonnegativeConeT(3),
+        SecondOrderConeT(3),
+        BoxConeT {
+            lower: vec![-1., -1., f64::NEG_INFINITY],
+            upper: vec![1., 1., 1.],
+        },
+    ];
+
+    (P, q, A, b, cones)
+}
+
+#[test]
+fn test_solution_by_cone() {
+    let (P, q, A, b, cones) = by_cone_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let solution = &solver.solution;
+    let zs = solution.dual_by_cone();
+    let ss = solution.slacks_by_cone();
+
+    let lengths = vec![1, 3, 3, 4];
+    assert_eq!(zs.iter().map(|z| z.len()).collect::<Vec<_>>(), lengths);
+    assert_eq!(ss.iter().map(|s| s.len()).collect::<Vec<_>>(), lengths);
+
+    assert_eq!(zs.concat(), solution.z);
+    assert_eq!(ss.concat(), solution.s);
+
+    // the equality constraint has zero slack
+    assert_eq!(ss[0], &[0.]);
+}
+
+#[test]
+fn test_solution_by_cone_rebuild() {
+    let (P, q, A, b, cones) = by_cone_data();
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+
+    // rebuild without the box cone
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+    ]);
+    let b = vec![1., 0., 0., 0.];
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(3)];
+    solver.rebuild(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+
+    let zs = solver.solution.dual_by_cone();
+    assert_eq!(zs.len(), 2);
+    assert_eq!(zs[1].len(), 3);
+    assert_eq!(zs.concat(), solver.solution.z);
+}
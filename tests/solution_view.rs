@@ -0,0 +1,63 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn solution_view_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [0., 2.], //
+    ]);
+
+    let A = CscMatrix::from(&[
+        [1., 1.],   //
+        [1., 0.],   //
+        [0., 1.],   //
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+    ]);
+
+    let c = vec![1., 1.];
+    let b = vec![1., 0.7, 0.7, -1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(6)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_solution_view() {
+    let (P, c, A, b, cones) = solution_view_test_data();
+
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+
+    let view = solver.solution_view();
+    let solution = &solver.solution;
+
+    assert_eq!(view.status, SolverStatus::Solved);
+    assert_eq!(view.iterations, solution.iterations);
+    assert_eq!(view.obj_val, solution.obj_val);
+    assert_eq!(view.obj_val_dual, solution.obj_val_dual);
+    assert_eq!(view.r_prim, solution.r_prim);
+    assert_eq!(view.r_dual, solution.r_dual);
+
+    // the view borrows the solution vectors rather than copying them
+    assert!(std::ptr::eq(view.x, solution.x.as_slice()));
+    assert!(std::ptr::eq(view.s, solution.s.as_slice()));
+    assert!(std::ptr::eq(view.z, solution.z.as_slice()));
+
+    let refsol = vec![0.3, 0.7];
+    assert!(view.x.dist(&refsol) <= 1e-6);
+}
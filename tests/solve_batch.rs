@@ -0,0 +1,64 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "rayon")]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn batch_lp_data(
+    k: usize,
+) -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min cᵀx s.t. x1 + x2 ≤ 1 + k/100, x ≥ 0, with a cost that
+    // rotates through the problems in the batch
+    let angle = 2. * std::f64::consts::PI * (k as f64) / 100.;
+    let P = CscMatrix::zeros((2, 2));
+    let c = vec![f64::cos(angle), f64::sin(angle)];
+
+    let A = CscMatrix::from(&[
+        [1., 1.],  //
+        [-1., 0.], //
+        [0., -1.], //
+    ]);
+    let b = vec![1. + (k as f64) / 100., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+fn batch_solver(k: usize) -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = batch_lp_data(k);
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap();
+    DefaultSolver::new(&P, &c, &A, &b, &cones, settings)
+}
+
+#[test]
+fn test_solve_batch() {
+    let mut solvers: Vec<_> = (0..100).map(batch_solver).collect();
+    let solutions = solve_batch(&mut solvers);
+    assert_eq!(solutions.len(), 100);
+
+    for (k, sol) in solutions.iter().enumerate() {
+        let mut solver = batch_solver(k);
+        solver.solve();
+
+        assert_eq!(sol.status, solver.solution.status);
+        assert_eq!(sol.iterations, solver.solution.iterations);
+        assert_eq!(sol.x, solver.solution.x);
+        assert_eq!(sol.z, solver.solution.z);
+    }
+}
+
+#[test]
+fn test_solve_batch_empty() {
+    let mut solvers: Vec<DefaultSolver<f64>> = vec![];
+    assert!(solve_batch(&mut solvers).is_empty());
+}
@@ -0,0 +1,183 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+use std::thread;
+
+#[allow(clippy::type_complexity)]
+fn fork_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½‖x‖² - x1 - 2x2 + x3 s.t. x1 + x2 + x3 = b1,
+    // 0 ≤ x ≤ (b2,b3,b4) and ‖(x1,x2)‖ ≤ b5
+    let P = CscMatrix::identity(3);
+    let q = vec![-1., -2., 1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [1., 0., 0.],  //
+        [0., 1., 0.],  //
+        [0., 0., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [0., 0., 0.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+    ]);
+    let b = vec![1., 1., 1., 1., 0., 0., 0., 2., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(6), SecondOrderConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn settings() -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .presolve_enable(false)
+        .build()
+        .unwrap()
+}
+
+fn solve_fresh(b: &[f64], settings: DefaultSettings<f64>) -> Vec<f64> {
+    let (P, q, A, _, cones) = fork_test_data();
+    let mut solver = DefaultSolver::new(&P, &q, &A, b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    solver.solution.x.clone()
+}
+
+fn test_fork_with(settings: DefaultSettings<f64>) {
+    let (P, q, A, b, cones) = fork_test_data();
+    let mut b1 = b.clone();
+    b1[0] = 0.5;
+    let mut b2 = b.clone();
+    b2[0] = 1.5;
+    b2[7] = 1.;
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings.clone());
+    let mut forked = solver.fork().unwrap();
+
+    solver.update_b(&b1).unwrap();
+    forked.update_b(&b2).unwrap();
+
+    solver.solve();
+    forked.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(forked.solution.status, SolverStatus::Solved);
+
+    let x1 = solve_fresh(&b1, settings.clone());
+    let x2 = solve_fresh(&b2, settings);
+    assert!(solver.solution.x.dist(&x1) <= 1e-8);
+    assert!(forked.solution.x.dist(&x2) <= 1e-8);
+    assert!(x1.dist(&x2) > 0.1);
+}
+
+#[test]
+fn test_fork_qdldl() {
+    let mut settings = settings();
+    settings.dense_kkt_threshold = 0;
+    test_fork_with(settings);
+}
+
+#[test]
+fn test_fork_dense_ldl() {
+    let mut settings = settings();
+    settings.dense_kkt_threshold = usize::MAX;
+    test_fork_with(settings);
+}
+
+#[test]
+fn test_fork_indirect() {
+    let mut settings = settings();
+    settings.direct_kkt_solver = false;
+    settings.tol_gap_abs = 1e-7;
+    settings.tol_gap_rel = 1e-7;
+    test_fork_with(settings);
+}
+
+#[test]
+fn test_fork_after_solve() {
+    let (P, q, A, b, cones) = fork_test_data();
+
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings());
+    solver.set_metadata("name", "original");
+    solver.solve();
+    let x = solver.solution.x.clone();
+
+    // the fork starts from a copy of the solved state
+    let mut forked = solver.fork().unwrap();
+    assert_eq!(forked.solution.x, x);
+    assert_eq!(forked.metadata()["name"], "original");
+
+    let mut b2 = b.clone();
+    b2[0] = 1.5;
+    forked.update_b(&b2).unwrap();
+
+    // solve the fork on another thread
+    let forked = thread::spawn(move || {
+        forked.solve();
+        forked
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(forked.solution.status, SolverStatus::Solved);
+    assert!(forked.solution.x.dist(&solve_fresh(&b2, settings())) <= 1e-8);
+
+    // the original is unaffected, and gives the same result again
+    assert_eq!(solver.solution.x, x);
+    solver.solve();
+    assert!(solver.solution.x.dist(&x) <= 1e-12);
+}
+
+// a custom KKT solver that does not support copying
+struct OpaqueKKTSolver {
+    inner: DirectLDLKKTSolver<f64>,
+}
+
+impl KKTSolver<f64> for OpaqueKKTSolver {
+    fn update(&mut self, cones: &CompositeCone<f64>, settings: &CoreSettings<f64>) -> bool {
+        self.inner.update(cones, settings)
+    }
+    fn setrhs(&mut self, x: &[f64], z: &[f64]) {
+        self.inner.setrhs(x, z)
+    }
+    fn solve(
+        &mut self,
+        x: Option<&mut [f64]>,
+        z: Option<&mut [f64]>,
+        settings: &CoreSettings<f64>,
+    ) -> bool {
+        self.inner.solve(x, z, settings)
+    }
+    fn update_P(&mut self, P: &CscMatrix<f64>) {
+        self.inner.update_P(P)
+    }
+    fn update_A(&mut self, A: &CscMatrix<f64>) {
+        self.inner.update_A(A)
+    }
+    fn kkt_info(&self) -> KKTInfo {
+        self.inner.kkt_info()
+    }
+    fn kkt_matrix(&self) -> &CscMatrix<f64> {
+        self.inner.kkt_matrix()
+    }
+}
+
+#[test]
+fn test_fork_custom_kkt_solver() {
+    let (P, q, A, b, cones) = fork_test_data();
+
+    let factory: KKTSolverFactory<f64> = Box::new(|P, A, cones, settings| {
+        let inner = DirectLDLKKTSolver::new(P, A, cones, A.m, A.n, settings);
+        Box::new(OpaqueKKTSolver { inner })
+    });
+    let solver = DefaultSolver::new_with_kkt_solver(&P, &q, &A, &b, &cones, settings(), factory);
+
+    assert!(solver.fork().is_none());
+}
@@ -0,0 +1,111 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn step_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min ½‖x‖² + x1 - x3  s.t.  x1 + x2 + x3 = 1,  x ≥ 0,  ‖(x1,x2)‖ ≤ 2
+    let P = CscMatrix::identity(3);
+    let q = vec![1., 0., -1.];
+
+    let A = CscMatrix::from(&[
+        [1., 1., 1.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+        [0., 0., -1.], //
+        [0., 0., 0.],  //
+        [-1., 0., 0.], //
+        [0., -1., 0.], //
+    ]);
+    let b = vec![1., 0., 0., 0., 2., 0., 0.];
+
+    let cones = vec![ZeroConeT(1), NonnegativeConeT(3), SecondOrderConeT(3)];
+
+    (P, q, A, b, cones)
+}
+
+fn step_test_solver(settings: DefaultSettings<f64>) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = step_test_data();
+    DefaultSolver::new(&P, &q, &A, &b, &cones, settings)
+}
+
+fn settings() -> DefaultSettings<f64> {
+    DefaultSettingsBuilder::default()
+        .verbose(false)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_step_matches_solve() {
+    let mut refsolver = step_test_solver(settings());
+    refsolver.solve();
+    assert_eq!(refsolver.solution.status, SolverStatus::Solved);
+
+    let mut solver = step_test_solver(settings());
+    let mut ncalls = 0;
+    loop {
+        ncalls += 1;
+        if solver.step(1) {
+            break;
+        }
+        assert_eq!(solver.info.iterations, ncalls);
+
+        // the solution is only populated on termination
+        assert_eq!(solver.info.status, SolverStatus::Unsolved);
+        assert_eq!(solver.solution.status, SolverStatus::Unsolved);
+    }
+
+    assert_eq!(ncalls, refsolver.solution.iterations);
+    assert_eq!(solver.solution.status, refsolver.solution.status);
+    assert_eq!(solver.solution.iterations, refsolver.solution.iterations);
+    assert_eq!(solver.solution.x, refsolver.solution.x);
+    assert_eq!(solver.solution.z, refsolver.solution.z);
+    assert_eq!(solver.solution.s, refsolver.solution.s);
+    assert_eq!(solver.solution.obj_val, refsolver.solution.obj_val);
+}
+
+#[test]
+fn test_step_several() {
+    let mut refsolver = step_test_solver(settings());
+    refsolver.solve();
+
+    let mut solver = step_test_solver(settings());
+    assert!(!solver.step(3));
+    assert_eq!(solver.info.iterations, 3);
+    assert!(!solver.step(0));
+    assert_eq!(solver.info.iterations, 3);
+    assert!(solver.step(1000));
+    assert_eq!(solver.solution.x, refsolver.solution.x);
+
+    // the next call starts a new solve
+    assert!(!solver.step(1));
+    assert_eq!(solver.info.iterations, 1);
+    assert_eq!(solver.info.status, SolverStatus::Unsolved);
+
+    // as does solve, discarding the one in progress
+    solver.solve();
+    assert_eq!(solver.solution.iterations, refsolver.solution.iterations);
+    assert_eq!(solver.solution.x, refsolver.solution.x);
+}
+
+#[test]
+fn test_step_max_iter() {
+    let mut settings = settings();
+    settings.max_iter = 3;
+    let mut solver = step_test_solver(settings);
+
+    let mut ncalls = 1;
+    while !solver.step(1) {
+        ncalls += 1;
+    }
+    assert_eq!(ncalls, 3);
+    assert_eq!(solver.solution.status, SolverStatus::MaxIterations);
+    assert_eq!(solver.solution.iterations, 3);
+}
@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn termination_mode_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min x1 + 2x2, s.t. 1 <= x1 + x2 <= 1.001, x >= 0
+    let P = CscMatrix::<f64>::zeros((2, 2));
+    let c = vec![1., 2.];
+
+    let A = CscMatrix::from(&[
+        [1., 1.],   //
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+    ]);
+    let b = vec![1.001, -1., 0., 0.];
+
+    let cones = vec![NonnegativeConeT(4)];
+
+    (P, c, A, b, cones)
+}
+
+fn solve_with_mode(mode: &str) -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = termination_mode_test_data();
+
+    // a strict feasibility tolerance, so that feasibility
+    // is the last of the standard criteria to be satisfied
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .tol_feas(1e-12)
+        .termination_mode(mode.to_string())
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_termination_mode_default() {
+    let settings = DefaultSettings::<f64>::default();
+    assert_eq!(settings.termination_mode, "standard");
+}
+
+#[test]
+fn test_termination_mode_gap_priority() {
+    let standard = solve_with_mode("standard");
+    let gap_priority = solve_with_mode("gap_priority");
+
+    assert_eq!(standard.solution.status, SolverStatus::Solved);
+    assert_eq!(gap_priority.solution.status, SolverStatus::AlmostSolved);
+    assert!(standard.info.res_primal < 1e-12);
+    assert!(standard.info.res_dual < 1e-12);
+
+    // stops as soon as the gap is small and feasibility is within
+    // the reduced tolerance, ahead of the standard criteria, and
+    // so reports a reduced accuracy solution
+    let info = &gap_priority.info;
+    let settings = &gap_priority.settings;
+    assert!(gap_priority.solution.iterations < standard.solution.iterations);
+    assert!(info.gap_rel < settings.tol_gap_rel);
+    assert!(info.res_primal < settings.reduced_tol_feas);
+    assert!(info.res_dual < settings.reduced_tol_feas);
+
+    // and at essentially the same solution
+    let refsol = &standard.solution;
+    assert!(gap_priority.solution.x.dist(&refsol.x) <= 1e-6);
+    assert!(f64::abs(gap_priority.solution.obj_val - refsol.obj_val) <= 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn test_termination_mode_bad_mode() {
+    solve_with_mode("foo");
+}
@@ -0,0 +1,113 @@
+#![allow(non_snake_case)]
+
+use clarabel::timers::TimeSource;
+use clarabel::{algebra::*, solver::*};
+use std::cell::Cell;
+use std::time::Duration;
+
+// a clock that advances by one millisecond every time it is read
+struct MockClock {
+    ticks: Cell<u32>,
+}
+
+impl TimeSource for MockClock {
+    fn now(&self) -> Duration {
+        let ticks = self.ticks.get();
+        self.ticks.set(ticks + 1);
+        Duration::from_millis(1) * ticks
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn time_limit_test_data(
+    n: usize,
+) -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // tridiagonal P with box constraints on x
+    let mut rows = vec![];
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for i in 0..n {
+        rows.push(i);
+        cols.push(i);
+        vals.push(4.);
+        if i + 1 < n {
+            rows.push(i);
+            cols.push(i + 1);
+            vals.push(-1.);
+        }
+    }
+    let P = CscMatrix::from_triplets(n, n, &rows, &cols, &vals);
+    let q: Vec<f64> = (0..n).map(|i| f64::sin(i as f64)).collect();
+
+    let I1 = CscMatrix::<f64>::identity(n);
+    let mut I2 = CscMatrix::<f64>::identity(n);
+    I2.negate();
+    let A = CscMatrix::vcat(&I1, &I2);
+    let b = vec![0.1; 2 * n];
+
+    let cones = vec![NonnegativeConeT(2 * n)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve(time_limit: f64, time_limit_hard: bool) -> DefaultSolver<f64> {
+    let (P, q, A, b, cones) = time_limit_test_data(200);
+    let settings = DefaultSettingsBuilder::default()
+        .verbose(false)
+        .time_limit(time_limit)
+        .time_limit_hard(time_limit_hard)
+        .build()
+        .unwrap();
+    let clock = Box::new(MockClock {
+        ticks: Cell::new(0),
+    });
+    let mut solver = DefaultSolver::new_with_time_source(&P, &q, &A, &b, &cones, settings, clock);
+    solver.solve();
+    solver
+}
+
+#[test]
+fn test_time_limit_hard_default() {
+    let settings = DefaultSettings::<f64>::default();
+    assert!(!settings.time_limit_hard);
+
+    // no effect without a finite time limit
+    let soft = solve(f64::INFINITY, false);
+    let hard = solve(f64::INFINITY, true);
+    assert_eq!(hard.solution.status, SolverStatus::Solved);
+    assert_eq!(hard.solution.x, soft.solution.x);
+}
+
+#[test]
+fn test_time_limit_hard() {
+    let unlimited = solve(f64::INFINITY, false).solution.solve_time;
+
+    // limits falling at every point within the solve
+    let mut is_stopped_sooner = false;
+    for ticks in 1..(1000. * unlimited) as u32 {
+        let time_limit = ticks as f64 / 1000.;
+        let soft = solve(time_limit, false);
+        let hard = solve(time_limit, true);
+
+        if soft.solution.status != SolverStatus::MaxTime {
+            continue;
+        }
+        assert_eq!(hard.solution.status, SolverStatus::MaxTime);
+
+        // the hard limit never stops later, and the solution
+        // is still populated from the most recent iterate
+        assert!(hard.solution.solve_time <= soft.solution.solve_time);
+        assert!(hard.solution.iterations <= soft.solution.iterations);
+        assert!(hard.solution.x.iter().all(|v| v.is_finite()));
+        assert_eq!(hard.solution.x.len(), 200);
+
+        is_stopped_sooner |= hard.solution.solve_time < soft.solution.solve_time;
+    }
+    assert!(is_stopped_sooner);
+}
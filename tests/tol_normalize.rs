@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn tol_normalize_data(
+    scale: f64,
+) -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // min scale⋅(x1 + x2) s.t. 0 ≤ x ≤ 1, whose optimal value is zero
+    // so that the relative gap is no easier to meet than the absolute one
+    let P = CscMatrix::zeros((2, 2));
+    let q = vec![scale, scale];
+
+    let A = CscMatrix::from(&[
+        [-1., 0.], //
+        [0., -1.], //
+        [1., 0.],  //
+        [0., 1.],  //
+    ]);
+    let b = vec![0., 0., 1., 1.];
+
+    let cones = vec![NonnegativeConeT(4)];
+
+    (P, q, A, b, cones)
+}
+
+fn solve(scale: f64, normalize: bool) -> DefaultSolution<f64> {
+    let (P, q, A, b, cones) = tol_normalize_data(scale);
+    let settings = DefaultSettingsBuilder::default()
+        .tol_normalize(normalize)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.solve();
+    solver.solution
+}
+
+#[test]
+fn test_tol_normalize_scale_invariant() {
+    let small = solve(1., true);
+    let large = solve(1e6, true);
+
+    assert_eq!(small.status, SolverStatus::Solved);
+    assert_eq!(large.status, SolverStatus::Solved);
+    assert_eq!(small.iterations, large.iterations);
+    assert!(large.x.dist(&small.x) <= 1e-6);
+}
+
+#[test]
+fn test_tol_normalize_disabled() {
+    // the absolute gap scales with the data, so needs more iterations
+    let small = solve(1., false);
+    let large = solve(1e6, false);
+
+    assert_eq!(small.status, SolverStatus::Solved);
+    assert_eq!(large.status, SolverStatus::Solved);
+    assert!(large.iterations > small.iterations);
+}
+
+#[test]
+fn test_tol_normalize_after_update_q() {
+    // the scaling is taken from the updated data, not that at setup
+    let (P, q, A, b, cones) = tol_normalize_data(1.);
+    let settings = DefaultSettingsBuilder::default()
+        .tol_normalize(true)
+        .presolve_enable(false)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &q, &A, &b, &cones, settings);
+    solver.update_q(&vec![1e6, 1e6]).unwrap();
+    solver.solve();
+
+    let large = solve(1e6, true);
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert_eq!(solver.solution.iterations, large.iterations);
+}
@@ -0,0 +1,130 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn try_solve_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [0., 2.], //
+    ]);
+
+    let A = CscMatrix::from(&[
+        [-1., -1.], //
+        [-1., 0.],  //
+        [0., -1.],  //
+        [1., 1.],   //
+    ]);
+
+    let c = vec![1., 1.];
+    let b = vec![-1., 0., 0., 2.];
+
+    let cones = vec![NonnegativeConeT(4)];
+
+    (P, c, A, b, cones)
+}
+
+fn new_solver(settings: DefaultSettings<f64>) -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = try_solve_test_data();
+    DefaultSolver::new(&P, &c, &A, &b, &cones, settings)
+}
+
+fn quiet_settings() -> DefaultSettingsBuilder<f64> {
+    let mut builder = DefaultSettingsBuilder::default();
+    builder.verbose(false);
+    builder
+}
+
+// fails every linear solve once the first few have succeeded
+struct FailingKKTSolver {
+    inner: DirectLDLKKTSolver<f64>,
+    nsolves: usize,
+}
+
+impl KKTSolver<f64> for FailingKKTSolver {
+    fn update(&mut self, cones: &CompositeCone<f64>, settings: &CoreSettings<f64>) -> bool {
+        self.inner.update(cones, settings)
+    }
+    fn setrhs(&mut self, x: &[f64], z: &[f64]) {
+        self.inner.setrhs(x, z)
+    }
+    fn solve(
+        &mut self,
+        x: Option<&mut [f64]>,
+        z: Option<&mut [f64]>,
+        settings: &CoreSettings<f64>,
+    ) -> bool {
+        self.nsolves += 1;
+        self.nsolves <= 4 && self.inner.solve(x, z, settings)
+    }
+    fn update_P(&mut self, P: &CscMatrix<f64>) {
+        self.inner.update_P(P)
+    }
+    fn update_A(&mut self, A: &CscMatrix<f64>) {
+        self.inner.update_A(A)
+    }
+    fn kkt_info(&self) -> KKTInfo {
+        self.inner.kkt_info()
+    }
+    fn kkt_matrix(&self) -> &CscMatrix<f64> {
+        self.inner.kkt_matrix()
+    }
+}
+
+#[test]
+fn test_try_solve_solved() -> Result<(), SolveError> {
+    let mut solver = new_solver(quiet_settings().build().unwrap());
+
+    let solution = solver.try_solve()?;
+    assert_eq!(solution.status, SolverStatus::Solved);
+    assert_eq!(solution.x.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_try_solve_ok_statuses() {
+    // iteration limits are not errors
+    let settings = quiet_settings().max_iter(1).build().unwrap();
+    let mut solver = new_solver(settings);
+    let solution = solver.try_solve().unwrap();
+    assert_eq!(solution.status, SolverStatus::MaxIterations);
+
+    // nor is an infeasibility certificate
+    let (P, c, A, mut b, cones) = try_solve_test_data();
+    b[3] = -2.;
+    let settings = quiet_settings().build().unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    let solution = solver.try_solve().unwrap();
+    assert_eq!(solution.status, SolverStatus::PrimalInfeasible);
+}
+
+#[test]
+fn test_try_solve_numerical_error() {
+    let (P, c, A, b, cones) = try_solve_test_data();
+    let settings = quiet_settings().build().unwrap();
+
+    let factory: KKTSolverFactory<f64> = Box::new(|P, A, cones, settings| {
+        let inner = DirectLDLKKTSolver::new(P, A, cones, A.m, A.n, settings);
+        Box::new(FailingKKTSolver { inner, nsolves: 0 })
+    });
+    let mut solver = DefaultSolver::new_with_kkt_solver(&P, &c, &A, &b, &cones, settings, factory);
+
+    let err = solver.try_solve().unwrap_err();
+    assert_eq!(err, SolveError::NumericalError);
+    assert_eq!(solver.solution.status, SolverStatus::NumericalError);
+}
+
+#[test]
+fn test_try_solve_insufficient_memory() {
+    let settings = quiet_settings().max_kkt_nnz(Some(0)).build().unwrap();
+    let mut solver = new_solver(settings);
+
+    let err = solver.try_solve().unwrap_err();
+    assert_eq!(err, SolveError::InsufficientMemory);
+}
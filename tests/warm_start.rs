@@ -0,0 +1,363 @@
+#![allow(non_snake_case)]
+
+use clarabel::{algebra::*, solver::*};
+
+#[allow(clippy::type_complexity)]
+fn warm_start_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // P = [4. 1;1 2]
+    let P = CscMatrix::from(&[
+        [4., 1.], //
+        [1., 2.], //
+    ]);
+
+    // A = [1. 1;1 0; 0 1]; A = [-A;A]
+    let A = CscMatrix::from(&[
+        [1., 1.], //
+        [1., 0.], //
+        [0., 1.], //
+    ]);
+
+    let (mut A1, A2) = (A.clone(), A);
+    A1.negate();
+    let A = CscMatrix::vcat(&A1, &A2);
+
+    let c = vec![1., 1.];
+    let b = vec![-1., 0., 0., 1., 0.7, 0.7];
+
+    let cones = vec![NonnegativeConeT(3), NonnegativeConeT(3)];
+
+    (P, c, A, b, cones)
+}
+
+fn cold_solve() -> DefaultSolver<f64> {
+    let (P, c, A, b, cones) = warm_start_test_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    solver
+}
+
+#[test]
+fn test_warm_start_modes() {
+    let cold = cold_solve();
+    let (x, s, z) = (&cold.solution.x, &cold.solution.s, &cold.solution.z);
+
+    for (mode, λ) in [
+        (WarmStartMode::FullGuess, 1.),
+        (WarmStartMode::PrimalOnly, 1.),
+        (WarmStartMode::Scaled, 0.5),
+    ] {
+        let (P, c, A, b, cones) = warm_start_test_data();
+        let settings = DefaultSettings::default();
+        let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+        solver.solve_warm(x, s, z, mode, λ).unwrap();
+
+        assert_eq!(solver.solution.status, SolverStatus::Solved);
+        assert!(solver.solution.x.dist(x) <= 1e-6);
+        assert!(f64::abs(solver.solution.obj_val - cold.solution.obj_val) <= 1e-6);
+    }
+}
+
+#[test]
+fn test_warm_start_fewer_iterations() {
+    let cold = cold_solve();
+    let (x, s, z) = (&cold.solution.x, &cold.solution.s, &cold.solution.z);
+
+    let (P, c, A, b, cones) = warm_start_test_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver
+        .solve_warm(x, s, z, WarmStartMode::FullGuess, 1.)
+        .unwrap();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.iterations < cold.solution.iterations);
+}
+
+#[test]
+fn test_warm_start_primal_only_without_duals() {
+    let cold = cold_solve();
+    let (x, s) = (&cold.solution.x, &cold.solution.s);
+
+    let (P, c, A, b, cones) = warm_start_test_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver
+        .solve_warm(x, s, &[], WarmStartMode::PrimalOnly, 1.)
+        .unwrap();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(x) <= 1e-6);
+}
+
+#[test]
+fn test_warm_start_invalid_inputs() {
+    let (P, c, A, b, cones) = warm_start_test_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    let (x, s, z) = (vec![0.; 2], vec![0.; 6], vec![0.; 6]);
+
+    for λ in [-0.1, 1.1, f64::NAN] {
+        assert!(matches!(
+            solver.solve_warm(&x, &s, &z, WarmStartMode::Scaled, λ),
+            Err(WarmStartError::InvalidLambda)
+        ));
+    }
+
+    assert!(matches!(
+        solver.solve_warm(&x[0..1], &s, &z, WarmStartMode::FullGuess, 1.),
+        Err(WarmStartError::IncompatibleDimension)
+    ));
+    assert!(matches!(
+        solver.solve_warm(&x, &s, &[], WarmStartMode::FullGuess, 1.),
+        Err(WarmStartError::IncompatibleDimension)
+    ));
+
+    // rejected guesses should not run the solver
+    assert_eq!(solver.solution.status, SolverStatus::Unsolved);
+}
+
+#[test]
+fn test_warm_start_mode_from_int() {
+    assert_eq!(
+        WarmStartMode::try_from(0).unwrap(),
+        WarmStartMode::FullGuess
+    );
+    assert_eq!(
+        WarmStartMode::try_from(1).unwrap(),
+        WarmStartMode::PrimalOnly
+    );
+    assert_eq!(WarmStartMode::try_from(2).unwrap(), WarmStartMode::Scaled);
+    assert!(matches!(
+        WarmStartMode::try_from(3),
+        Err(WarmStartError::InvalidMode(3))
+    ));
+    assert!(matches!(
+        WarmStartMode::try_from(-1),
+        Err(WarmStartError::InvalidMode(-1))
+    ));
+}
+
+#[test]
+fn test_warm_start_box_cone() {
+    // same problem with the bounds as a box cone, which is
+    // nonsymmetric and so can not be warm started
+    let cold = cold_solve();
+
+    let (P, c, A, _, _) = warm_start_test_data();
+    let A = A.select_rows(&vec![false, true, true, false, false, false]);
+    let A = CscMatrix::vcat(&CscMatrix::from(&[[1., 1.], [0., 0.]]), &A);
+    let b = vec![1., 1., 0., 0.];
+    let cones = vec![
+        ZeroConeT(1),
+        BoxConeT {
+            lower: vec![0., 0.],
+            upper: vec![0.7, 0.7],
+        },
+    ];
+
+    let mut s = b.clone();
+    A.gemv(&mut s, &cold.solution.x, -1., 1., MatrixShape::N);
+    let z = vec![0.; b.len()];
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    assert!(matches!(
+        solver.solve_warm(&cold.solution.x, &s, &z, WarmStartMode::PrimalOnly, 1.),
+        Err(WarmStartError::NonsymmetricCones)
+    ));
+
+    solver.solve();
+    assert!(solver.solution.x.dist(&cold.solution.x) <= 1e-6);
+}
+
+#[test]
+fn test_warm_start_from_solution() {
+    let (P, c, A, b, cones) = warm_start_test_data();
+    let settings = DefaultSettingsBuilder::default()
+        .presolve_enable(false)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings.clone());
+    solver.solve();
+    let old = solver.solution.clone();
+
+    // perturb the upper bounds on x
+    let mut b = b;
+    b[4] += 0.01;
+    b[5] -= 0.01;
+    solver.update_b(&b).unwrap();
+    solver.warm_start_from(&old).unwrap();
+
+    let mut cold = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    cold.solve();
+
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    assert!(solver.solution.x.dist(&cold.solution.x) <= 1e-6);
+    assert!(solver.solution.iterations < cold.solution.iterations);
+}
+
+#[test]
+fn test_warm_start_from_invalid() {
+    let cold = cold_solve();
+
+    let (P, c, A, b, cones) = warm_start_test_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+
+    let mut sol = cold.solution.clone();
+    sol.x.push(0.);
+    assert!(matches!(
+        solver.warm_start_from(&sol),
+        Err(WarmStartError::IncompatibleDimension)
+    ));
+
+    let mut sol = cold.solution;
+    sol.status = SolverStatus::PrimalInfeasible;
+    assert!(matches!(
+        solver.warm_start_from(&sol),
+        Err(WarmStartError::InfeasibleSolution)
+    ));
+}
+
+#[test]
+fn test_sweep_b() {
+    let (P, c, A, b0, cones) = warm_start_test_data();
+
+    // relax the upper bounds on x, so that the bound on x[1]
+    // becomes inactive part way through the sweep.  The point
+    // t = 0.05 where this happens is degenerate and is skipped
+    let db = vec![0., 0., 0., 0., 1., 1.];
+    let ts: Vec<f64> = (0..=8).map(|k| 0.015 * k as f64).collect();
+
+    let settings = DefaultSettingsBuilder::default()
+        .presolve_enable(false)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b0, &cones, settings.clone());
+    let solutions = solver.sweep_b(&b0, &db, &ts).unwrap();
+    assert_eq!(solutions.len(), ts.len());
+
+    for (sol, &t) in solutions.iter().zip(&ts) {
+        assert_eq!(sol.status, SolverStatus::Solved);
+
+        // x = (0.3 - t, 0.7 + t) until x[1] reaches 0.75
+        let x1 = f64::min(0.7 + t, 0.75);
+        let refsol = vec![1. - x1, x1];
+        assert!(sol.x.dist(&refsol) <= 1e-6);
+
+        // matches a cold solve of the same problem
+        let mut b = b0.clone();
+        b.axpby(t, &db, 1.);
+        let mut cold = DefaultSolver::new(&P, &c, &A, &b, &cones, settings.clone());
+        cold.solve();
+        assert!(sol.x.dist(&cold.solution.x) <= 1e-6);
+        assert!(f64::abs(sol.obj_val - cold.solution.obj_val) <= 1e-6);
+    }
+
+    // the solution path is continuous, with unit slope in t
+    for (prev, next) in solutions.iter().zip(solutions.iter().skip(1)) {
+        assert!(prev.x.dist(&next.x) <= 0.015 * f64::sqrt(2.) + 1e-6);
+    }
+}
+
+#[test]
+fn test_sweep_b_presolve_enabled() {
+    let (P, c, A, b0, cones) = warm_start_test_data();
+    let db = vec![0.; b0.len()];
+
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b0, &cones, settings);
+    assert!(matches!(
+        solver.sweep_b(&b0, &db, &[0., 1.]),
+        Err(DataUpdateError::PresolveEnabled)
+    ));
+}
+
+#[allow(clippy::type_complexity)]
+fn expcone_test_data() -> (
+    CscMatrix<f64>,
+    Vec<f64>,
+    CscMatrix<f64>,
+    Vec<f64>,
+    Vec<SupportedConeT<f64>>,
+) {
+    // max x s.t. y * exp(x / y) <= z, with y == 1, z == exp(5)
+    let P = CscMatrix::<f64>::zeros((3, 3));
+    let c = vec![-1., 0., 0.];
+
+    let mut A1 = CscMatrix::<f64>::identity(3);
+    A1.negate();
+    let A2 = CscMatrix::from(&[
+        [0., 1., 0.], //
+        [0., 0., 1.], //
+    ]);
+    let A = CscMatrix::vcat(&A1, &A2);
+    let b = vec![0., 0., 0., 1., f64::exp(5.)];
+
+    let cones = vec![ExponentialConeT(), ZeroConeT(2)];
+
+    (P, c, A, b, cones)
+}
+
+#[test]
+fn test_warm_start_nonsymmetric() {
+    let (P, c, A, b, cones) = expcone_test_data();
+    let settings = DefaultSettings::default();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b, &cones, settings);
+    solver.solve();
+    assert_eq!(solver.solution.status, SolverStatus::Solved);
+    let sol = solver.solution.clone();
+
+    for mode in [
+        WarmStartMode::FullGuess,
+        WarmStartMode::PrimalOnly,
+        WarmStartMode::Scaled,
+    ] {
+        assert!(matches!(
+            solver.solve_warm(&sol.x, &sol.s, &sol.z, mode, 0.5),
+            Err(WarmStartError::NonsymmetricCones)
+        ));
+    }
+    assert!(matches!(
+        solver.warm_start_from(&sol),
+        Err(WarmStartError::NonsymmetricCones)
+    ));
+
+    // the solver is left unchanged
+    assert_eq!(solver.solution.x, sol.x);
+}
+
+#[test]
+fn test_sweep_b_nonsymmetric() {
+    let (P, c, A, b0, cones) = expcone_test_data();
+
+    // each problem is solved from the default start
+    let db = vec![0., 0., 0., 0., 1.];
+    let ts = [0., 10., 20.];
+
+    let settings = DefaultSettingsBuilder::default()
+        .presolve_enable(false)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let mut solver = DefaultSolver::new(&P, &c, &A, &b0, &cones, settings);
+    let solutions = solver.sweep_b(&b0, &db, &ts).unwrap();
+
+    for (sol, &t) in solutions.iter().zip(&ts) {
+        assert_eq!(sol.status, SolverStatus::Solved);
+        let x = f64::ln(f64::exp(5.) + t);
+        assert!(f64::abs(sol.x[0] - x) <= 1e-6);
+    }
+}
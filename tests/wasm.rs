@@ -0,0 +1,56 @@
+// Run with `wasm-pack test --node -- --features wasm`
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use clarabel::wasm::WasmSolver;
+use serde::Deserialize;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+#[derive(Deserialize)]
+struct Solution {
+    x: Vec<f64>,
+    s: Vec<f64>,
+    z: Vec<f64>,
+    status: String,
+    obj_val: f64,
+}
+
+// SOCP example, with P and A in CSC form
+fn socp_solver(settings: JsValue) -> Result<WasmSolver, wasm_bindgen::JsError> {
+    WasmSolver::new(
+        &[0, 0, 1],
+        &[1],
+        &[2.],
+        &[0., 0.],
+        &[0, 1, 2],
+        &[1, 2],
+        &[-2., -1.],
+        &[1., -2., -2.],
+        &[2],
+        &[3],
+        &[],
+        settings,
+    )
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_socp() {
+    let mut solver = socp_solver(JsValue::UNDEFINED).unwrap();
+    let solution: Solution = serde_wasm_bindgen::from_value(solver.solve().unwrap()).unwrap();
+
+    assert_eq!(solution.status, "Solved");
+    assert!(f64::abs(solution.x[0] - 1.) <= 1e-6);
+    assert!(f64::abs(solution.x[1] - 1.) <= 1e-6);
+    assert!(f64::abs(solution.obj_val - 1.) <= 1e-6);
+    assert_eq!(solution.s.len(), 3);
+    assert_eq!(solution.z.len(), 3);
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_socp_settings() {
+    let settings = js_sys::JSON::parse(r#"{"verbose": false, "max_iter": 1}"#).unwrap();
+    let mut solver = socp_solver(settings).unwrap();
+    let solution: Solution = serde_wasm_bindgen::from_value(solver.solve().unwrap()).unwrap();
+
+    assert_eq!(solution.status, "MaxIterations");
+}